@@ -0,0 +1,325 @@
+//! Fits the tables that convert RGB colors to spectra for the color spaces
+//! that `RGBColorSpace` provides, as pbrt-v4's `rgb2spec_opt` does, and
+//! writes them to `OUT_DIR`, where `RGBToSpectrumTable` includes them.
+//!
+//! Each table holds the coefficients of the sigmoid polynomial whose
+//! reflectance, lit by the color space's illuminant, has the color of each
+//! node of a grid over the RGB cube. The fits take a few seconds per color
+//! space, so they run in parallel and `Cargo.toml` builds this script with
+//! optimizations.
+//!
+//! A table file is the resolution `res` as a `u32`, followed by `res` node
+//! positions along the largest component and `3 * res^3` coefficient
+//! triples, indexed by the largest component, the two ratios and the node,
+//! all little-endian `f32`s.
+
+use std::io::Write;
+use std::path::Path;
+
+#[allow(dead_code, unused_imports, clippy::wrong_self_convention)]
+#[path = "src/math/mod.rs"]
+mod math;
+
+#[allow(dead_code, unused_imports)]
+#[path = "src/random/mod.rs"]
+mod random;
+
+#[allow(dead_code)]
+#[path = "src/spectrum/cie.rs"]
+mod cie;
+
+/// The definitions from `src/spectrum/mod.rs` that `cie` refers to.
+mod spectrum {
+    pub const LAMBDA_MIN: f32 = 360.0;
+    pub const LAMBDA_MAX: f32 = 830.0;
+}
+
+use cie::{CIE_S_LAMBDA_MIN, CIE_S_LAMBDA_STEP, CIE_X, CIE_Y, CIE_Z, cie_daylight};
+use math::square_matrix::SquareMatrix;
+use math::vector3::Vector3;
+use spectrum::{LAMBDA_MAX, LAMBDA_MIN};
+
+/// A color space to fit a table for: the name of its table file, the
+/// chromaticities of its primaries, and the temperature of its daylight
+/// illuminant.
+struct ColorSpace {
+    name: &'static str,
+    primaries: [(f64, f64); 3],
+    cct: f32,
+}
+
+const COLOR_SPACES: [ColorSpace; 4] = [
+    ColorSpace {
+        name: "srgb",
+        primaries: [(0.64, 0.33), (0.3, 0.6), (0.15, 0.06)],
+        cct: 6504.0,
+    },
+    ColorSpace {
+        name: "dci_p3",
+        primaries: [(0.68, 0.32), (0.265, 0.69), (0.15, 0.06)],
+        cct: 6504.0,
+    },
+    ColorSpace {
+        name: "rec2020",
+        primaries: [(0.708, 0.292), (0.17, 0.797), (0.131, 0.046)],
+        cct: 6504.0,
+    },
+    ColorSpace {
+        name: "aces2065_1",
+        primaries: [(0.7347, 0.2653), (0.0, 1.0), (0.0001, -0.077)],
+        cct: 6000.0,
+    },
+];
+
+/// The number of nodes along each axis of a table.
+const RES: usize = 64;
+
+/// The maximum number of Gauss–Newton iterations per fit.
+const FIT_ITERATIONS: usize = 15;
+
+/// The spacing, in nanometers, of the quadrature nodes of the fits.
+const FIT_SPACING: usize = 10;
+
+fn main() {
+    println!("cargo::rerun-if-changed=build.rs");
+    println!("cargo::rerun-if-changed=src/math");
+    println!("cargo::rerun-if-changed=src/random");
+    println!("cargo::rerun-if-changed=src/spectrum/cie.rs");
+    let out_dir = std::env::var("OUT_DIR").expect("OUT_DIR is set by Cargo");
+    std::thread::scope(|scope| {
+        for cs in &COLOR_SPACES {
+            let out_dir = &out_dir;
+            scope.spawn(move || {
+                let fit = SpectrumFit::new(cs);
+                let path = Path::new(out_dir).join(format!("rgbspectrum_{}.bin", cs.name));
+                let mut file = std::io::BufWriter::new(
+                    std::fs::File::create(&path).expect("cannot create the table file"),
+                );
+                write_table(&fit, &mut file).expect("cannot write the table file");
+            });
+        }
+    });
+}
+
+/// Fits the coefficients at every node of the table and writes them out.
+fn write_table(fit: &SpectrumFit, out: &mut impl Write) -> std::io::Result<()> {
+    let smooth_step = |x: f64| x * x * (3.0 - 2.0 * x);
+    // The nodes along the largest component are spaced more densely near 0
+    // and 1, where the coefficients change fastest.
+    let z_nodes: Vec<f64> = (0..RES)
+        .map(|k| smooth_step(smooth_step(k as f64 / (RES - 1) as f64)))
+        .collect();
+    out.write_all(&(RES as u32).to_le_bytes())?;
+    for &z in &z_nodes {
+        out.write_all(&(z as f32).to_le_bytes())?;
+    }
+    let start = RES / 5;
+    let mut coeffs = vec![[0.0f32; 3]; RES];
+    for l in 0..3 {
+        for j in 0..RES {
+            let y = j as f64 / (RES - 1) as f64;
+            for i in 0..RES {
+                let x = i as f64 / (RES - 1) as f64;
+                // Fit along z from a moderate brightness towards both ends,
+                // starting each fit from the previous solution.
+                let mut sweep = |ks: &mut dyn Iterator<Item = usize>| {
+                    let mut c = Vector3::new(0.0, 0.0, 0.0);
+                    for k in ks {
+                        let z = z_nodes[k];
+                        let mut rgb = Vector3::new(0.0, 0.0, 0.0);
+                        rgb[l] = z;
+                        rgb[(l + 1) % 3] = x * z;
+                        rgb[(l + 2) % 3] = y * z;
+                        fit.gauss_newton(&mut c, fit.lab_with_jacobian(rgb).0);
+                        coeffs[k] = fit.to_nanometers(c);
+                    }
+                };
+                sweep(&mut (start..RES));
+                sweep(&mut (0..=start).rev());
+                for c in &coeffs {
+                    for v in c {
+                        out.write_all(&v.to_le_bytes())?;
+                    }
+                }
+            }
+        }
+    }
+    out.flush()
+}
+
+/// The precomputed quantities for fitting sigmoid polynomials to colors
+/// of one color space.
+///
+/// The fit is done with wavelengths mapped to `t` in `[0, 1]`, which keeps
+/// the coefficients of similar magnitude, and in double precision.
+struct SpectrumFit {
+    /// The normalized wavelengths of the quadrature points.
+    t: Vec<f64>,
+    /// The contribution of each quadrature point to the RGB color of a
+    /// reflectance under the illuminant.
+    rgb_weights: Vec<Vector3<f64>>,
+    xyz_from_rgb: SquareMatrix<f64, 3>,
+    /// The XYZ color of the illuminant, with a luminance of one.
+    white: Vector3<f64>,
+}
+
+impl SpectrumFit {
+    fn new(cs: &ColorSpace) -> Self {
+        // The illuminant at each nanometer, interpolated linearly between
+        // the samples of the daylight spectrum.
+        let daylight = cie_daylight(cs.cct);
+        let illuminant: Vec<f64> = (LAMBDA_MIN as i32..=LAMBDA_MAX as i32)
+            .map(|lambda| {
+                let offset = (lambda as f32 - CIE_S_LAMBDA_MIN) / CIE_S_LAMBDA_STEP;
+                let i = (offset as usize).min(daylight.len() - 2);
+                let u = offset - i as f32;
+                f64::from((1.0 - u) * daylight[i] + u * daylight[i + 1])
+            })
+            .collect();
+        let cmf = |i: usize| {
+            Vector3::new(
+                f64::from(CIE_X[i]),
+                f64::from(CIE_Y[i]),
+                f64::from(CIE_Z[i]),
+            )
+        };
+        let mut white = Vector3::new(0.0, 0.0, 0.0);
+        for (i, &illum) in illuminant.iter().enumerate() {
+            white += cmf(i) * illum;
+        }
+        let white = white / white.y;
+
+        // Scale the primaries so that they add up to the white point.
+        let [r, g, b] = cs
+            .primaries
+            .map(|(x, y)| Vector3::new(x / y, 1.0, (1.0 - x - y) / y));
+        let rgb = SquareMatrix::new([[r.x, g.x, b.x], [r.y, g.y, b.y], [r.z, g.z, b.z]]);
+        let c = rgb.inverse().expect("color space primaries are collinear") * white;
+        let xyz_from_rgb = rgb * SquareMatrix::diag([c.x, c.y, c.z]);
+        let rgb_from_xyz = xyz_from_rgb
+            .inverse()
+            .expect("color space primaries are collinear");
+
+        // Integrate over nodes every FIT_SPACING nm. Each 1nm sample is
+        // spread over its three nearest nodes by quadratic interpolation, so
+        // that spectra that are piecewise quadratic integrate exactly.
+        let range = f64::from(LAMBDA_MAX - LAMBDA_MIN);
+        let n = (LAMBDA_MAX - LAMBDA_MIN) as usize / FIT_SPACING + 1;
+        let t: Vec<f64> = (0..n).map(|i| i as f64 / (n - 1) as f64).collect();
+        let mut rgb_weights = vec![Vector3::new(0.0, 0.0, 0.0); n];
+        let mut y_sum = 0.0;
+        for (i, &illum) in illuminant.iter().enumerate() {
+            let xyz = cmf(i) * illum;
+            y_sum += xyz.y;
+            let rgb = rgb_from_xyz * xyz;
+            let offset = i as f64 / range * (n - 1) as f64;
+            let node = ((offset.round() as usize).max(1) - 1).min(n - 3);
+            let u = offset - node as f64;
+            let basis = [
+                (u - 1.0) * (u - 2.0) / 2.0,
+                -u * (u - 2.0),
+                u * (u - 1.0) / 2.0,
+            ];
+            for (j, b) in basis.into_iter().enumerate() {
+                rgb_weights[node + j] += rgb * b;
+            }
+        }
+        for w in &mut rgb_weights {
+            *w /= y_sum;
+        }
+        Self {
+            t,
+            rgb_weights,
+            xyz_from_rgb,
+            white,
+        }
+    }
+
+    /// Converts coefficients for `t` to coefficients for wavelengths in
+    /// nanometers.
+    fn to_nanometers(&self, c: Vector3<f64>) -> [f32; 3] {
+        let w = f64::from(LAMBDA_MAX - LAMBDA_MIN);
+        let m = f64::from(LAMBDA_MIN);
+        [
+            c.x / (w * w),
+            c.y / w - 2.0 * c.x * m / (w * w),
+            c.z - c.y * m / w + c.x * m * m / (w * w),
+        ]
+        .map(|v| v as f32)
+    }
+
+    /// Refines the coefficients `c` until the color of their reflectance
+    /// is within a small distance of `goal`, in CIELAB.
+    fn gauss_newton(&self, c: &mut Vector3<f64>, goal: Vector3<f64>) {
+        for _ in 0..FIT_ITERATIONS {
+            let (rgb, rgb_jacobian) = self.rgb(*c);
+            let (lab, lab_jacobian) = self.lab_with_jacobian(rgb);
+            let residual = lab - goal;
+            if residual.length_squared() < 1e-6 {
+                return;
+            }
+            let Some(inverse) = (lab_jacobian * rgb_jacobian).inverse() else {
+                return;
+            };
+            *c -= inverse * residual;
+            // Keep the sigmoid from saturating completely, where the
+            // Jacobian vanishes.
+            let max = c.x.abs().max(c.y.abs()).max(c.z.abs());
+            if max > 200.0 {
+                *c *= 200.0 / max;
+            }
+        }
+    }
+
+    /// Returns the RGB color of the reflectance with coefficients `c`, and
+    /// its derivatives with respect to them.
+    fn rgb(&self, c: Vector3<f64>) -> (Vector3<f64>, SquareMatrix<f64, 3>) {
+        let mut rgb = Vector3::new(0.0, 0.0, 0.0);
+        let mut jacobian = [Vector3::new(0.0, 0.0, 0.0); 3];
+        for (&t, &w) in self.t.iter().zip(&self.rgb_weights) {
+            let x = (c.x * t + c.y) * t + c.z;
+            let q = 1.0 / (1.0 + x * x).sqrt();
+            rgb += w * (0.5 + 0.5 * x * q);
+            let dw = w * (0.5 * q * q * q);
+            jacobian[0] += dw * (t * t);
+            jacobian[1] += dw * t;
+            jacobian[2] += dw;
+        }
+        let [d0, d1, d2] = jacobian;
+        let jacobian =
+            SquareMatrix::new([[d0.x, d1.x, d2.x], [d0.y, d1.y, d2.y], [d0.z, d1.z, d2.z]]);
+        (rgb, jacobian)
+    }
+
+    /// Converts an RGB color to CIELAB, where distances are roughly
+    /// proportional to perceived differences, and returns it with its
+    /// derivatives with respect to the RGB components.
+    fn lab_with_jacobian(&self, rgb: Vector3<f64>) -> (Vector3<f64>, SquareMatrix<f64, 3>) {
+        const DELTA: f64 = 6.0 / 29.0;
+        // CIELAB as a linear function of the nonlinearity of each
+        // normalized XYZ component, which is offset by (-16, 0, 0).
+        let lab_from_f = SquareMatrix::new([
+            [0.0, 116.0, 0.0],
+            [500.0, -500.0, 0.0],
+            [0.0, 200.0, -200.0],
+        ]);
+        let xyz = self.xyz_from_rgb * rgb;
+        // The nonlinearity, and its derivative with respect to the
+        // unnormalized component.
+        let [(fx, dx), (fy, dy), (fz, dz)] = [0, 1, 2].map(|i| {
+            let t = xyz[i] / self.white[i];
+            if t > DELTA * DELTA * DELTA {
+                let f = t.cbrt();
+                (f, 1.0 / (3.0 * f * f * self.white[i]))
+            } else {
+                (
+                    t / (3.0 * DELTA * DELTA) + 4.0 / 29.0,
+                    1.0 / (3.0 * DELTA * DELTA * self.white[i]),
+                )
+            }
+        });
+        let lab = lab_from_f * Vector3::new(fx, fy, fz) - Vector3::new(16.0, 0.0, 0.0);
+        let jacobian = lab_from_f * SquareMatrix::diag([dx, dy, dz]) * self.xyz_from_rgb;
+        (lab, jacobian)
+    }
+}
@@ -1 +1,11 @@
+//! Building blocks for a physically based renderer, following the design of
+//! pbrt-v4.
+
+#![warn(missing_docs)]
+
 pub mod math;
+pub mod random;
+pub mod sampling;
+pub mod spectrum;
+#[cfg(test)]
+mod testing;
@@ -0,0 +1,517 @@
+//! Transformations interpolated over time.
+
+use crate::math::bounds3::Bounds3;
+use crate::math::interval::Interval;
+use crate::math::number_traits::Float;
+use crate::math::point3::Point3;
+use crate::math::quaternion::Quaternion;
+use crate::math::ray::Ray;
+use crate::math::square_matrix::SquareMatrix;
+use crate::math::transform::Transform;
+use crate::math::vector3::Vector3;
+
+// An affine function kc + kx * p.x + ky * p.y + kz * p.z of a point.
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct DerivativeTerm<T> {
+    kc: T,
+    kx: T,
+    ky: T,
+    kz: T,
+}
+
+impl<T: Float> DerivativeTerm<T> {
+    const ZERO: Self = Self {
+        kc: T::ZERO,
+        kx: T::ZERO,
+        ky: T::ZERO,
+        kz: T::ZERO,
+    };
+
+    fn eval(&self, p: Point3<T>) -> T {
+        self.kc + self.kx * p.x + self.ky * p.y + self.kz * p.z
+    }
+}
+
+/// A transformation that changes over time, interpolated between a start
+/// and an end transform by decomposing both into translation, rotation and
+/// scale. Used for motion blur.
+///
+/// ```
+/// use pbrt::math::{AnimatedTransform, Point3, Transform, Vector3};
+///
+/// let start = Transform::translate(Vector3::new(0.0, 0.0, 0.0));
+/// let end = Transform::translate(Vector3::new(4.0, 0.0, 0.0)) * Transform::rotate_z(90.0);
+/// let t = AnimatedTransform::new(start, 0.0, end, 1.0);
+/// let p = t.interpolate(0.5) * Point3::new(1.0, 0.0, 0.0);
+/// let expected = Point3::new(2.0 + 0.5f64.sqrt(), 0.5f64.sqrt(), 0.0);
+/// assert!(p.distance(expected) < 1e-12);
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct AnimatedTransform<T> {
+    start_transform: Transform<T>,
+    end_transform: Transform<T>,
+    start_time: T,
+    end_time: T,
+    actually_animated: bool,
+    translations: [Vector3<T>; 2],
+    rotations: [Quaternion<T>; 2],
+    scales: [SquareMatrix<T, 4>; 2],
+    has_rotation: bool,
+    // Per axis, the coefficients c1..c5 of the time derivative of a moving
+    // point, c1 + (c2 + c3 t) cos(2 theta t) + (c4 + c5 t) sin(2 theta t),
+    // as affine functions of the point.
+    derivative_terms: [[DerivativeTerm<T>; 5]; 3],
+}
+
+impl<T: Float> AnimatedTransform<T> {
+    /// Creates a transform animated from `start_transform` at `start_time`
+    /// to `end_transform` at `end_time`.
+    pub fn new(
+        start_transform: Transform<T>,
+        start_time: T,
+        end_transform: Transform<T>,
+        end_time: T,
+    ) -> Self {
+        let (t0, r0, s0) = start_transform.decompose();
+        let (t1, mut r1, s1) = end_transform.decompose();
+        // Take the shortest path between the two rotations.
+        if r0.dot(r1) < T::ZERO {
+            r1 = -r1;
+        }
+        let has_rotation = r0.dot(r1) < T::from_f64(0.9995);
+        let mut transform = Self {
+            start_transform,
+            end_transform,
+            start_time,
+            end_time,
+            actually_animated: start_transform != end_transform,
+            translations: [t0, t1],
+            rotations: [r0, r1],
+            scales: [s0, s1],
+            has_rotation,
+            derivative_terms: [[DerivativeTerm::ZERO; 5]; 3],
+        };
+        if transform.actually_animated && has_rotation {
+            transform.derivative_terms = transform.compute_derivative_terms();
+        }
+        transform
+    }
+
+    /// Returns the transform at the start time.
+    pub fn start_transform(&self) -> &Transform<T> {
+        &self.start_transform
+    }
+
+    /// Returns the transform at the end time.
+    pub fn end_transform(&self) -> &Transform<T> {
+        &self.end_transform
+    }
+
+    /// Returns whether the start and end transforms differ.
+    pub fn is_animated(&self) -> bool {
+        self.actually_animated
+    }
+
+    /// Returns whether the transform scales at either end.
+    pub fn has_scale(&self) -> bool {
+        self.start_transform.has_scale() || self.end_transform.has_scale()
+    }
+
+    /// Returns the transform at `time`, which is clamped to the animated
+    /// range.
+    pub fn interpolate(&self, time: T) -> Transform<T> {
+        if !self.actually_animated || time <= self.start_time {
+            return self.start_transform;
+        }
+        if time >= self.end_time {
+            return self.end_transform;
+        }
+        let dt = (time - self.start_time) / (self.end_time - self.start_time);
+        let translation = self.translations[0] * (T::ONE - dt) + self.translations[1] * dt;
+        let rotation = self.rotations[0].slerp(self.rotations[1], dt);
+        let mut scale = SquareMatrix::identity();
+        for i in 0..3 {
+            for j in 0..3 {
+                scale[i][j] = (T::ONE - dt) * self.scales[0][i][j] + dt * self.scales[1][i][j];
+            }
+        }
+        Transform::translate(translation) * rotation.to_transform() * Transform::new(scale)
+    }
+
+    /// Applies the transform at the time of the ray, see
+    /// [`Transform::apply_ray`].
+    pub fn apply_ray<M>(&self, r: Ray<T, M>, t_max: Option<&mut T>) -> Ray<T, M> {
+        self.interpolate(r.time).apply_ray(r, t_max)
+    }
+
+    /// Returns a box containing `b` over the whole time range.
+    ///
+    /// ```
+    /// use pbrt::math::{AnimatedTransform, Bounds3, Point3, Transform};
+    ///
+    /// let t = AnimatedTransform::new(Transform::rotate_z(0.0), 0.0, Transform::rotate_z(90.0), 1.0);
+    /// let b = Bounds3::new(Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0));
+    /// // The point sweeps a quarter circle.
+    /// let swept = t.motion_bounds(&b);
+    /// assert!(swept.p_min.distance(Point3::new(0.0, 0.0, 0.0)) < 1e-9);
+    /// assert!(swept.p_max.distance(Point3::new(1.0, 1.0, 0.0)) < 1e-9);
+    /// ```
+    pub fn motion_bounds(&self, b: &Bounds3<T>) -> Bounds3<T> {
+        if !self.actually_animated {
+            return self.start_transform * *b;
+        }
+        if !self.has_rotation {
+            return (self.start_transform * *b).union(self.end_transform * *b);
+        }
+        // The bounds of a moving box are the union of the bounds of its
+        // moving corners.
+        (0..8).fold(Bounds3::empty(), |bounds, corner| {
+            bounds.union(self.bound_point_motion(b.corner(corner)))
+        })
+    }
+
+    /// Returns the tightest box containing `p` over the whole time range.
+    /// Extrema of the path along each axis are found as the zeros of its
+    /// time derivative.
+    pub fn bound_point_motion(&self, p: Point3<T>) -> Bounds3<T> {
+        let mut bounds = Bounds3::new(self.start_transform * p, self.end_transform * p);
+        if !self.actually_animated || !self.has_rotation {
+            return bounds;
+        }
+        let theta = self.rotations[0].angle_between(self.rotations[1]);
+        for terms in &self.derivative_terms {
+            let c = terms.map(|term| term.eval(p));
+            let mut zeros = Vec::new();
+            interval_find_zeros(c, theta, Interval::new(T::ZERO, T::ONE), &mut zeros, 8);
+            for zero in zeros {
+                let time = (T::ONE - zero) * self.start_time + zero * self.end_time;
+                bounds = bounds.union_point(self.interpolate(time) * p);
+            }
+        }
+        bounds
+    }
+
+    // The moving point is M(t) p = T(t) + R(t) S(t) p. Translation and scale
+    // are linear in t, and the slerped rotation matrix is a combination of
+    // constant, cos(2 theta t) and sin(2 theta t) terms, so every coordinate
+    // has the form A + B t + (C + D t) cos(2 theta t) + (E + F t) sin(2 theta t)
+    // with A..F affine in p.
+    fn compute_derivative_terms(&self) -> [[DerivativeTerm<T>; 5]; 3] {
+        let [q0, q1] = self.rotations;
+        let theta = q0.angle_between(q1);
+        let q_perp = (q1 - q0 * theta.cos()).normalize();
+
+        let q00 = rotation_form(q0, q0);
+        let qpp = rotation_form(q_perp, q_perp);
+        let q0p = rotation_form(q0, q_perp);
+        let r_constant = (q00 + qpp) * T::HALF;
+        let r_cos = (q00 + qpp * -T::ONE) * T::HALF;
+        let r_sin = q0p;
+
+        let [s0, s1] = self.scales;
+        let ds = s1 + s0 * -T::ONE;
+        let [t0, t1] = self.translations;
+        let omega = T::TWO * theta;
+
+        let mut terms = [[DerivativeTerm::ZERO; 5]; 3];
+        for (axis, axis_terms) in terms.iter_mut().enumerate() {
+            let term = |r: &SquareMatrix<T, 3>, s: &SquareMatrix<T, 4>, kc: T| {
+                let k = |column: usize| (0..3).map(|j| r[axis][j] * s[j][column]).sum::<T>();
+                DerivativeTerm {
+                    kc: kc + k(3),
+                    kx: k(0),
+                    ky: k(1),
+                    kz: k(2),
+                }
+            };
+            let b = term(&r_constant, &ds, t1[axis] - t0[axis]);
+            let c = term(&r_cos, &s0, T::ZERO);
+            let d = term(&r_cos, &ds, T::ZERO);
+            let e = term(&r_sin, &s0, T::ZERO);
+            let f = term(&r_sin, &ds, T::ZERO);
+            let combine = |a: DerivativeTerm<T>, b: DerivativeTerm<T>, scale: T| DerivativeTerm {
+                kc: a.kc + scale * b.kc,
+                kx: a.kx + scale * b.kx,
+                ky: a.ky + scale * b.ky,
+                kz: a.kz + scale * b.kz,
+            };
+            let zero = DerivativeTerm::ZERO;
+            *axis_terms = [
+                b,
+                combine(d, e, omega),
+                combine(zero, f, omega),
+                combine(f, c, -omega),
+                combine(zero, d, -omega),
+            ];
+        }
+        terms
+    }
+}
+
+// The symmetric bilinear form Q with Q(q, q) equal to the rotation matrix of
+// the unit quaternion q.
+fn rotation_form<T: Float>(a: Quaternion<T>, b: Quaternion<T>) -> SquareMatrix<T, 3> {
+    let homogeneous = |q: Quaternion<T>| {
+        let Vector3 { x, y, z } = q.v;
+        let w = q.w;
+        SquareMatrix::new([
+            [
+                w * w + x * x - y * y - z * z,
+                T::TWO * (x * y - w * z),
+                T::TWO * (x * z + w * y),
+            ],
+            [
+                T::TWO * (x * y + w * z),
+                w * w - x * x + y * y - z * z,
+                T::TWO * (y * z - w * x),
+            ],
+            [
+                T::TWO * (x * z - w * y),
+                T::TWO * (y * z + w * x),
+                w * w - x * x - y * y + z * z,
+            ],
+        ])
+    };
+    (homogeneous(a + b) + homogeneous(a - b) * -T::ONE) * (T::HALF * T::HALF)
+}
+
+// Finds the zeros of c1 + (c2 + c3 t) cos(2 theta t) + (c4 + c5 t) sin(2 theta t)
+// in t_interval by bisection on interval bounds of the function, followed by
+// a few Newton steps once the interval is small.
+fn interval_find_zeros<T: Float>(
+    c: [T; 5],
+    theta: T,
+    t_interval: Interval<T>,
+    zeros: &mut Vec<T>,
+    depth: u32,
+) {
+    let [c1, c2, c3, c4, c5] = c.map(Interval::from);
+    let angle = Interval::from(T::TWO * theta) * t_interval;
+    let range = c1 + (c2 + c3 * t_interval) * angle.cos() + (c4 + c5 * t_interval) * angle.sin();
+    if range.lower_bound() > T::ZERO
+        || range.upper_bound() < T::ZERO
+        || range.lower_bound() == range.upper_bound()
+    {
+        return;
+    }
+    if depth > 0 {
+        let mid = t_interval.midpoint();
+        interval_find_zeros(
+            c,
+            theta,
+            Interval::new(t_interval.lower_bound(), mid),
+            zeros,
+            depth - 1,
+        );
+        interval_find_zeros(
+            c,
+            theta,
+            Interval::new(mid, t_interval.upper_bound()),
+            zeros,
+            depth - 1,
+        );
+        return;
+    }
+
+    let [c1, c2, c3, c4, c5] = c;
+    let mut t = t_interval.midpoint();
+    for _ in 0..4 {
+        let (sin, cos) = ((T::TWO * theta * t).sin(), (T::TWO * theta * t).cos());
+        let f = c1 + (c2 + c3 * t) * cos + (c4 + c5 * t) * sin;
+        let f_prime = (c3 + T::TWO * (c4 + c5 * t) * theta) * cos
+            + (c5 - T::TWO * (c2 + c3 * t) * theta) * sin;
+        if f == T::ZERO || f_prime == T::ZERO {
+            break;
+        }
+        t -= f / f_prime;
+    }
+    let tolerance = T::from_f64(1e-3);
+    if t >= t_interval.lower_bound() - tolerance && t < t_interval.upper_bound() + tolerance {
+        zeros.push(t);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::animated_transform::AnimatedTransform;
+    use crate::math::bounds3::Bounds3;
+    use crate::math::point3::Point3;
+    use crate::math::ray::Ray;
+    use crate::math::transform::Transform;
+    use crate::math::vector3::Vector3;
+    use crate::testing::assert_point_near;
+
+    #[test]
+    fn test_not_animated() {
+        let t = Transform::translate(Vector3::new(1.0, 2.0, 3.0));
+        let animated = AnimatedTransform::new(t, 0.0, t, 1.0);
+        assert!(!animated.is_animated());
+        assert_eq!(animated.interpolate(0.5), t);
+        let p = Point3::new(1.0, 1.0, 1.0);
+        assert_eq!(animated.bound_point_motion(p), Bounds3::from_point(t * p));
+    }
+
+    #[test]
+    fn test_interpolate_endpoints() {
+        let start = Transform::translate(Vector3::new(1.0, 0.0, 0.0));
+        let end = Transform::rotate_y(90.0) * Transform::scale(2.0, 2.0, 2.0);
+        let animated = AnimatedTransform::new(start, 1.0, end, 2.0);
+        assert!(animated.is_animated());
+        assert!(animated.has_scale());
+        assert_eq!(animated.interpolate(0.0), start);
+        assert_eq!(animated.interpolate(1.0), start);
+        assert_eq!(animated.interpolate(2.0), end);
+        assert_eq!(animated.interpolate(3.0), end);
+    }
+
+    #[test]
+    fn test_interpolate_translation() {
+        let start = Transform::translate(Vector3::new(0.0, 0.0, 0.0));
+        let end = Transform::translate(Vector3::new(4.0, -2.0, 1.0));
+        let animated = AnimatedTransform::new(start, 0.0, end, 1.0);
+        assert_point_near(
+            animated.interpolate(0.25) * Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, -0.5, 0.25),
+            1e-6,
+        );
+    }
+
+    #[test]
+    fn test_interpolate_rotation() {
+        let start = Transform::rotate_z(0.0);
+        let end = Transform::rotate_z(120.0);
+        let animated = AnimatedTransform::new(start, 0.0, end, 1.0);
+        let p = Point3::new(1.0, 0.0, 0.0);
+        for (time, degrees) in [(0.25, 30.0), (0.5, 60.0), (0.75, 90.0)] {
+            assert_point_near(
+                animated.interpolate(time) * p,
+                Transform::rotate_z(degrees) * p,
+                1e-6,
+            );
+        }
+    }
+
+    #[test]
+    fn test_interpolate_scale() {
+        let start = Transform::scale(1.0, 1.0, 1.0);
+        let end = Transform::scale(3.0, 1.0, 5.0);
+        let animated = AnimatedTransform::new(start, 0.0, end, 1.0);
+        assert_point_near(
+            animated.interpolate(0.5) * Point3::new(1.0, 1.0, 1.0),
+            Point3::new(2.0, 1.0, 3.0),
+            1e-6,
+        );
+    }
+
+    #[test]
+    fn test_bound_point_motion_translation() {
+        let start = Transform::translate(Vector3::new(-1.0, 0.0, 0.0));
+        let end = Transform::translate(Vector3::new(1.0, 2.0, 0.0));
+        let animated = AnimatedTransform::new(start, 0.0, end, 1.0);
+        let Bounds3 {
+            p_min: min,
+            p_max: max,
+        } = animated.bound_point_motion(Point3::new(0.0, 0.0, 0.0));
+        assert_eq!(min, Point3::new(-1.0, 0.0, 0.0));
+        assert_eq!(max, Point3::new(1.0, 2.0, 0.0));
+    }
+
+    #[test]
+    fn test_bound_point_motion_rotation() {
+        let configurations = [
+            (
+                Transform::rotate_z(0.0),
+                Transform::rotate_z(170.0),
+                Point3::new(1.0, 0.0, 0.0),
+            ),
+            (
+                Transform::translate(Vector3::new(0.5, 0.0, -1.0)) * Transform::rotate_x(-40.0),
+                Transform::translate(Vector3::new(-0.5, 1.0, 2.0))
+                    * Transform::rotate(120.0, Vector3::new(1.0, 1.0, 0.0))
+                    * Transform::scale(2.0, 0.5, 1.0),
+                Point3::new(0.3, -2.0, 1.5),
+            ),
+            (
+                Transform::rotate(10.0, Vector3::new(0.2, 0.9, 0.1)),
+                Transform::rotate(150.0, Vector3::new(-0.5, 0.1, 0.8)),
+                Point3::new(-1.0, 4.0, 2.0),
+            ),
+        ];
+        for (start, end, p) in configurations {
+            let animated = AnimatedTransform::new(start, 0.0, end, 1.0);
+            let Bounds3 {
+                p_min: min,
+                p_max: max,
+            } = animated.bound_point_motion(p);
+            let mut sampled_min = Point3::new(f64::MAX, f64::MAX, f64::MAX);
+            let mut sampled_max = Point3::new(f64::MIN, f64::MIN, f64::MIN);
+            for i in 0..=1000 {
+                let moved = animated.interpolate(f64::from(i) / 1000.0) * p;
+                sampled_min = sampled_min.min(moved);
+                sampled_max = sampled_max.max(moved);
+                for axis in 0..3 {
+                    assert!(moved[axis] >= min[axis] - 1e-6);
+                    assert!(moved[axis] <= max[axis] + 1e-6);
+                }
+            }
+            // The bounds must also be tight, not just conservative.
+            assert!(
+                (sampled_min - min).length() < 1e-4,
+                "{sampled_min} vs {min}"
+            );
+            assert!(
+                (sampled_max - max).length() < 1e-4,
+                "{sampled_max} vs {max}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_motion_bounds() {
+        let b = Bounds3::new(Point3::new(-1.0, -1.0, -1.0), Point3::new(1.0, 1.0, 1.0));
+        let t = Transform::translate(Vector3::new(1.0, 0.0, 0.0));
+        let still = AnimatedTransform::new(t, 0.0, t, 1.0);
+        assert_eq!(still.motion_bounds(&b), t * b);
+
+        let end = Transform::translate(Vector3::new(0.0, 3.0, 0.0));
+        let translating = AnimatedTransform::new(t, 0.0, end, 1.0);
+        assert_eq!(
+            translating.motion_bounds(&b),
+            Bounds3::new(Point3::new(-1.0, -1.0, -1.0), Point3::new(2.0, 4.0, 1.0))
+        );
+
+        // A cube spinning by 90 degrees about z sweeps out a box reaching
+        // sqrt(2) from the axis at 45 degrees.
+        let spinning = AnimatedTransform::new(
+            Transform::rotate_z(0.0),
+            0.0,
+            Transform::rotate_z(90.0),
+            1.0,
+        );
+        let bounds = spinning.motion_bounds(&b);
+        let r = std::f64::consts::SQRT_2;
+        assert!(bounds.p_min.distance(Point3::new(-r, -r, -1.0)) < 1e-6);
+        assert!(bounds.p_max.distance(Point3::new(r, r, 1.0)) < 1e-6);
+        for i in 0..=100 {
+            let moved = spinning.interpolate(f64::from(i) / 100.0) * b;
+            assert!(bounds.expand(1e-9).inside(moved.p_min));
+            assert!(bounds.expand(1e-9).inside(moved.p_max));
+        }
+    }
+
+    #[test]
+    fn test_apply_ray() {
+        let start = Transform::translate(Vector3::new(0.0, 0.0, 0.0));
+        let end = Transform::translate(Vector3::new(2.0, 0.0, 0.0));
+        let animated = AnimatedTransform::new(start, 0.0, end, 1.0);
+        let r: Ray<f64> = Ray::new(
+            Point3::new(0.0, 1.0, 0.0),
+            Vector3::new(0.0, 0.0, 1.0),
+            0.5,
+            None,
+        );
+        let tr = animated.apply_ray(r, None);
+        assert_point_near(tr.o, Point3::new(1.0, 1.0, 0.0), 1e-6);
+        assert_eq!(tr.d, r.d);
+    }
+}
@@ -0,0 +1,210 @@
+//! Two-dimensional axis-aligned bounding boxes.
+
+use crate::math::macros::n_bounds_impl;
+use crate::math::number_traits::Number;
+use crate::math::point2::Point2;
+use crate::math::vector2::Vector2;
+
+n_bounds_impl! {
+    /// An axis-aligned rectangle. Integer bounds iterate over the pixels
+    /// they cover.
+    ///
+    /// ```
+    /// use pbrt::math::{Bounds2, Point2};
+    ///
+    /// let tile = Bounds2::new(Point2::new(0, 0), Point2::new(2, 2));
+    /// let pixels: Vec<_> = tile.into_iter().collect();
+    /// assert_eq!(pixels, [Point2::new(0, 0), Point2::new(1, 0), Point2::new(0, 1), Point2::new(1, 1)]);
+    /// assert_eq!(tile.area(), 4);
+    /// assert_eq!(tile.union_point(Point2::new(-1, 3)).area(), 9);
+    /// ```
+    Bounds2, Point2, Vector2, x, y
+}
+
+impl<T: Number> Bounds2<T> {
+    /// Returns one of the four corners. Corners are numbered so that bit 0
+    /// selects the x and bit 1 the y coordinate of `p_max` over `p_min`.
+    pub fn corner(&self, corner: usize) -> Point2<T> {
+        Point2::new(self[corner & 1].x, self[(corner & 2) >> 1].y)
+    }
+
+    /// Returns the area.
+    pub fn area(&self) -> T {
+        let d = self.diagonal();
+        d.x * d.y
+    }
+}
+
+/// Iterates over the integer points inside a box, excluding the upper
+/// boundary, row by row with x varying fastest.
+#[derive(Clone, Debug)]
+pub struct Bounds2Iterator {
+    bounds: Bounds2<i32>,
+    p: Point2<i32>,
+}
+
+impl Iterator for Bounds2Iterator {
+    type Item = Point2<i32>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.bounds.inside_exclusive(self.p) {
+            return None;
+        }
+        let p = self.p;
+        self.p.x += 1;
+        if self.p.x == self.bounds.p_max.x {
+            self.p.x = self.bounds.p_min.x;
+            self.p.y += 1;
+        }
+        Some(p)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if !self.bounds.inside_exclusive(self.p) {
+            return (0, Some(0));
+        }
+        let width = (self.bounds.p_max.x - self.bounds.p_min.x) as usize;
+        let rows_left = (self.bounds.p_max.y - self.p.y) as usize;
+        let remaining = rows_left * width - (self.p.x - self.bounds.p_min.x) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Bounds2Iterator {}
+
+impl IntoIterator for Bounds2<i32> {
+    type Item = Point2<i32>;
+    type IntoIter = Bounds2Iterator;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Bounds2Iterator {
+            bounds: self,
+            p: self.p_min,
+        }
+    }
+}
+
+impl IntoIterator for &Bounds2<i32> {
+    type Item = Point2<i32>;
+    type IntoIter = Bounds2Iterator;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (*self).into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::bounds2::Bounds2;
+    use crate::math::point2::Point2;
+    use crate::math::vector2::Vector2;
+
+    #[test]
+    fn test_new_orders_corners() {
+        let b = Bounds2::new(Point2::new(3, -1), Point2::new(1, 2));
+        assert_eq!(b.p_min, Point2::new(1, -1));
+        assert_eq!(b.p_max, Point2::new(3, 2));
+    }
+
+    #[test]
+    fn test_empty() {
+        let b = Bounds2::<f32>::empty();
+        assert!(b.is_empty());
+        assert!(b.is_degenerate());
+        assert!(!b.inside(Point2::new(0.0, 0.0)));
+        assert_eq!(Bounds2::default(), b);
+        let p = Point2::new(1.0, 2.0);
+        assert_eq!(b.union_point(p), Bounds2::from_point(p));
+    }
+
+    #[test]
+    fn test_union_and_intersect() {
+        let a = Bounds2::new(Point2::new(0, 0), Point2::new(4, 4));
+        let b = Bounds2::new(Point2::new(2, -2), Point2::new(6, 3));
+        assert_eq!(
+            a.union(b),
+            Bounds2::new(Point2::new(0, -2), Point2::new(6, 4))
+        );
+        assert_eq!(
+            a.intersect(b),
+            Bounds2::new(Point2::new(2, 0), Point2::new(4, 3))
+        );
+        assert!(a.overlaps(&b));
+        let c = Bounds2::new(Point2::new(5, 5), Point2::new(6, 6));
+        assert!(!a.overlaps(&c));
+        assert!(a.intersect(c).is_degenerate());
+    }
+
+    #[test]
+    fn test_inside() {
+        let b = Bounds2::new(Point2::new(0, 0), Point2::new(2, 2));
+        assert!(b.inside(Point2::new(2, 1)));
+        assert!(!b.inside_exclusive(Point2::new(2, 1)));
+        assert!(b.inside_exclusive(Point2::new(0, 1)));
+        assert!(!b.inside(Point2::new(-1, 1)));
+    }
+
+    #[test]
+    fn test_expand_and_diagonal() {
+        let b = Bounds2::new(Point2::new(0, 0), Point2::new(3, 1)).expand(1);
+        assert_eq!(b, Bounds2::new(Point2::new(-1, -1), Point2::new(4, 2)));
+        assert_eq!(b.diagonal(), Vector2::new(5, 3));
+        assert_eq!(b.maximum_extent(), 0);
+        assert_eq!(b.area(), 15);
+    }
+
+    #[test]
+    fn test_corner() {
+        let b = Bounds2::new(Point2::new(0, 1), Point2::new(2, 3));
+        assert_eq!(b.corner(0), Point2::new(0, 1));
+        assert_eq!(b.corner(1), Point2::new(2, 1));
+        assert_eq!(b.corner(2), Point2::new(0, 3));
+        assert_eq!(b.corner(3), Point2::new(2, 3));
+    }
+
+    #[test]
+    fn test_lerp_and_offset() {
+        let b = Bounds2::new(Point2::new(1.0, 2.0), Point2::new(3.0, 6.0));
+        let p = b.lerp(Point2::new(0.5, 0.25));
+        assert_eq!(p, Point2::new(2.0, 3.0));
+        assert_eq!(b.offset(p), Vector2::new(0.5, 0.25));
+    }
+
+    #[test]
+    fn test_bounding_sphere() {
+        let b = Bounds2::new(Point2::new(0.0, 0.0), Point2::new(6.0, 8.0));
+        assert_eq!(b.bounding_sphere(), (Point2::new(3.0, 4.0), 5.0));
+    }
+
+    #[test]
+    fn test_iterate() {
+        let b = Bounds2::new(Point2::new(1, -1), Point2::new(3, 1));
+        let points: Vec<_> = b.into_iter().collect();
+        assert_eq!(
+            points,
+            vec![
+                Point2::new(1, -1),
+                Point2::new(2, -1),
+                Point2::new(1, 0),
+                Point2::new(2, 0),
+            ]
+        );
+        let mut iter = b.into_iter();
+        assert_eq!(iter.len(), 4);
+        iter.next();
+        assert_eq!(iter.len(), 3);
+    }
+
+    #[test]
+    fn test_iterate_empty() {
+        let b = Bounds2::new(Point2::new(1, 1), Point2::new(1, 5));
+        assert_eq!((&b).into_iter().count(), 0);
+        assert_eq!(Bounds2::<i32>::empty().into_iter().count(), 0);
+    }
+
+    #[test]
+    fn test_display() {
+        let b = Bounds2::new(Point2::new(0, 1), Point2::new(2, 3));
+        assert_eq!(b.to_string(), "Bounds2(Point2(0, 1), Point2(2, 3))");
+    }
+}
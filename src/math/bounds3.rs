@@ -0,0 +1,307 @@
+//! Three-dimensional axis-aligned bounding boxes.
+
+use crate::math::macros::n_bounds_impl;
+use crate::math::number_traits::{Float, Number};
+use crate::math::point3::Point3;
+use crate::math::vector3::Vector3;
+
+n_bounds_impl! {
+    /// An axis-aligned box.
+    ///
+    /// ```
+    /// use pbrt::math::{Bounds3, Point3, Vector3};
+    ///
+    /// let a = Bounds3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 2.0, 3.0));
+    /// let b = Bounds3::from_point(Point3::new(2.0, 0.0, 0.0));
+    /// let both = a.union(b);
+    /// assert_eq!(both.diagonal(), Vector3::new(2.0, 2.0, 3.0));
+    /// assert_eq!(both.maximum_extent(), 2);
+    /// assert_eq!(a.volume(), 6.0);
+    /// assert!(!a.overlaps(&b));
+    /// ```
+    Bounds3, Point3, Vector3, x, y, z
+}
+
+impl<T: Number> Bounds3<T> {
+    /// Returns one of the eight corners. Corners are numbered so that bits
+    /// 0, 1 and 2 select the x, y and z coordinate of `p_max` over `p_min`.
+    pub fn corner(&self, corner: usize) -> Point3<T> {
+        Point3::new(
+            self[corner & 1].x,
+            self[(corner & 2) >> 1].y,
+            self[(corner & 4) >> 2].z,
+        )
+    }
+
+    /// Returns the total area of the six faces.
+    pub fn surface_area(&self) -> T {
+        let d = self.diagonal();
+        (d.x * d.y + d.x * d.z + d.y * d.z) * (T::ONE + T::ONE)
+    }
+
+    /// Returns the volume.
+    pub fn volume(&self) -> T {
+        let d = self.diagonal();
+        d.x * d.y * d.z
+    }
+}
+
+impl<T: Float> Bounds3<T> {
+    /// Slab test for the ray `o + t * d` with `t` in `[0, t_max]`. Returns the
+    /// parametric range over which the ray is inside the box. The far distance
+    /// of each slab is scaled up by `1 + 2 * gamma(3)` so that rounding error
+    /// can never make a ray miss a box it actually hits.
+    ///
+    /// ```
+    /// use pbrt::math::{Bounds3, Point3, Vector3};
+    ///
+    /// let b = Bounds3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0));
+    /// let o = Point3::new(0.5, 0.5, -1.0);
+    /// let (t0, _) = b.intersect_p(o, Vector3::new(0.0, 0.0, 1.0), f64::INFINITY).unwrap();
+    /// assert_eq!(t0, 1.0);
+    /// assert!(b.intersect_p(o, Vector3::new(0.0, 0.0, -1.0), f64::INFINITY).is_none());
+    /// ```
+    pub fn intersect_p(&self, o: Point3<T>, d: Vector3<T>, t_max: T) -> Option<(T, T)> {
+        let mut t0 = T::ZERO;
+        let mut t1 = t_max;
+        for i in 0..3 {
+            let inv_ray_dir = T::ONE / d[i];
+            let mut t_near = (self.p_min[i] - o[i]) * inv_ray_dir;
+            let mut t_far = (self.p_max[i] - o[i]) * inv_ray_dir;
+            if t_near > t_far {
+                std::mem::swap(&mut t_near, &mut t_far);
+            }
+            t_far *= T::ONE + T::TWO * T::gamma(3);
+            // Written so that a NaN from a 0 / 0 slab leaves the range as is.
+            t0 = if t_near > t0 { t_near } else { t0 };
+            t1 = if t_far < t1 { t_far } else { t1 };
+            if t0 > t1 {
+                return None;
+            }
+        }
+        Some((t0, t1))
+    }
+
+    /// Faster variant of [`intersect_p`](Self::intersect_p) for testing one
+    /// ray against many boxes, taking the precomputed reciprocal direction
+    /// and, per axis, whether the direction is negative. Only reports
+    /// whether there is a hit before `ray_t_max`.
+    pub fn intersect_p_inv_dir(
+        &self,
+        o: Point3<T>,
+        ray_t_max: T,
+        inv_dir: Vector3<T>,
+        dir_is_neg: [usize; 3],
+    ) -> bool {
+        let gamma_scale = T::ONE + T::TWO * T::gamma(3);
+        let mut t_min = (self[dir_is_neg[0]].x - o.x) * inv_dir.x;
+        let mut t_max = (self[1 - dir_is_neg[0]].x - o.x) * inv_dir.x;
+        let ty_min = (self[dir_is_neg[1]].y - o.y) * inv_dir.y;
+        let ty_max = (self[1 - dir_is_neg[1]].y - o.y) * inv_dir.y * gamma_scale;
+        t_max *= gamma_scale;
+        if t_min > ty_max || ty_min > t_max {
+            return false;
+        }
+        if ty_min > t_min {
+            t_min = ty_min;
+        }
+        if ty_max < t_max {
+            t_max = ty_max;
+        }
+
+        let tz_min = (self[dir_is_neg[2]].z - o.z) * inv_dir.z;
+        let tz_max = (self[1 - dir_is_neg[2]].z - o.z) * inv_dir.z * gamma_scale;
+        if t_min > tz_max || tz_min > t_max {
+            return false;
+        }
+        if tz_min > t_min {
+            t_min = tz_min;
+        }
+        if tz_max < t_max {
+            t_max = tz_max;
+        }
+        t_min < ray_t_max && t_max > T::ZERO
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::bounds3::Bounds3;
+    use crate::math::point3::Point3;
+    use crate::math::vector3::Vector3;
+
+    fn unit_box() -> Bounds3<f64> {
+        Bounds3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0))
+    }
+
+    #[test]
+    fn test_new_orders_corners() {
+        let b = Bounds3::new(Point3::new(3, -1, 0), Point3::new(1, 2, -5));
+        assert_eq!(b.p_min, Point3::new(1, -1, -5));
+        assert_eq!(b.p_max, Point3::new(3, 2, 0));
+    }
+
+    #[test]
+    fn test_empty() {
+        let b = Bounds3::<f64>::empty();
+        assert!(b.is_empty());
+        assert!(!b.overlaps(&unit_box()));
+        assert_eq!(b.union(unit_box()), unit_box());
+        assert!(Bounds3::from_point(Point3::new(1, 1, 1)).is_empty());
+        assert!(!Bounds3::from_point(Point3::new(1, 1, 1)).is_degenerate());
+    }
+
+    #[test]
+    fn test_union_and_intersect() {
+        let a = Bounds3::new(Point3::new(0, 0, 0), Point3::new(2, 2, 2));
+        let b = Bounds3::new(Point3::new(1, -1, 1), Point3::new(3, 1, 4));
+        assert_eq!(
+            a.union(b),
+            Bounds3::new(Point3::new(0, -1, 0), Point3::new(3, 2, 4))
+        );
+        assert_eq!(
+            a.intersect(b),
+            Bounds3::new(Point3::new(1, 0, 1), Point3::new(2, 1, 2))
+        );
+        assert_eq!(
+            a.union_point(Point3::new(-1, 5, 1)),
+            Bounds3::new(Point3::new(-1, 0, 0), Point3::new(2, 5, 2))
+        );
+    }
+
+    #[test]
+    fn test_overlaps_and_inside() {
+        let b = unit_box();
+        assert!(b.overlaps(&b.expand(1.0)));
+        assert!(b.overlaps(&Bounds3::from_point(Point3::new(1.0, 1.0, 1.0))));
+        assert!(b.inside(Point3::new(1.0, 0.5, 0.0)));
+        assert!(!b.inside_exclusive(Point3::new(1.0, 0.5, 0.0)));
+        assert!(!b.inside(Point3::new(0.5, 0.5, 1.5)));
+    }
+
+    #[test]
+    fn test_measures() {
+        let b = Bounds3::new(Point3::new(0, 0, 0), Point3::new(1, 2, 3));
+        assert_eq!(b.diagonal(), Vector3::new(1, 2, 3));
+        assert_eq!(b.surface_area(), 22);
+        assert_eq!(b.volume(), 6);
+        assert_eq!(b.maximum_extent(), 2);
+    }
+
+    #[test]
+    fn test_corner() {
+        let b = Bounds3::new(Point3::new(0, 1, 2), Point3::new(3, 4, 5));
+        assert_eq!(b.corner(0), b.p_min);
+        assert_eq!(b.corner(7), b.p_max);
+        assert_eq!(b.corner(1), Point3::new(3, 1, 2));
+        assert_eq!(b.corner(2), Point3::new(0, 4, 2));
+        assert_eq!(b.corner(4), Point3::new(0, 1, 5));
+        assert_eq!(b.corner(6), Point3::new(0, 4, 5));
+    }
+
+    #[test]
+    fn test_lerp_and_offset() {
+        let b = Bounds3::new(Point3::new(1.0, 2.0, 3.0), Point3::new(3.0, 6.0, 3.0));
+        let p = b.lerp(Point3::new(0.5, 0.75, 0.5));
+        assert_eq!(p, Point3::new(2.0, 5.0, 3.0));
+        // Degenerate axes are left unscaled.
+        assert_eq!(b.offset(p), Vector3::new(0.5, 0.75, 0.0));
+    }
+
+    #[test]
+    fn test_bounding_sphere() {
+        let (center, radius) = unit_box().bounding_sphere();
+        assert_eq!(center, Point3::new(0.5, 0.5, 0.5));
+        assert!((radius - 0.75f64.sqrt()).abs() < 1e-15);
+        assert_eq!(Bounds3::<f64>::empty().bounding_sphere().1, 0.0);
+    }
+
+    #[test]
+    fn test_intersect_p() {
+        let b = unit_box();
+        let o = Point3::new(-1.0, 0.5, 0.5);
+        let (t0, t1) = b
+            .intersect_p(o, Vector3::new(1.0, 0.0, 0.0), f64::INFINITY)
+            .unwrap();
+        assert_eq!(t0, 1.0);
+        assert!((2.0..2.0 + 1e-12).contains(&t1));
+        assert!(b.intersect_p(o, Vector3::new(1.0, 0.0, 0.0), 0.5).is_none());
+        assert!(
+            b.intersect_p(o, Vector3::new(-1.0, 0.0, 0.0), f64::INFINITY)
+                .is_none()
+        );
+        assert!(
+            b.intersect_p(o, Vector3::new(1.0, 1.0, 0.0), f64::INFINITY)
+                .is_none()
+        );
+        // Starting inside the box.
+        let (t0, _) = b
+            .intersect_p(
+                Point3::new(0.5, 0.5, 0.5),
+                Vector3::new(0.0, 0.0, -1.0),
+                f64::INFINITY,
+            )
+            .unwrap();
+        assert_eq!(t0, 0.0);
+    }
+
+    #[test]
+    fn test_intersect_p_grazing() {
+        // A ray running exactly along a face is still a hit thanks to the
+        // conservative far distance.
+        let b = unit_box();
+        let hit = b.intersect_p(
+            Point3::new(-1.0, 1.0, 0.5),
+            Vector3::new(1.0, 0.0, 0.0),
+            f64::INFINITY,
+        );
+        assert!(hit.is_some());
+    }
+
+    #[test]
+    fn test_intersect_p_inv_dir_matches() {
+        let b = Bounds3::new(Point3::new(-1.0, 0.0, 2.0), Point3::new(1.0, 3.0, 4.0));
+        let origins = [
+            Point3::new(0.0, 1.0, 0.0),
+            Point3::new(-3.0, -2.0, 5.0),
+            Point3::new(0.5, 2.0, 3.0),
+        ];
+        let directions = [
+            Vector3::new(0.1, 0.2, 1.0),
+            Vector3::new(1.0, 1.0, -0.5),
+            Vector3::new(-0.3, 0.0, 0.2),
+            Vector3::new(0.0, -1.0, 0.0),
+        ];
+        for o in origins {
+            for d in directions {
+                for t_max in [1.0, 10.0, f64::INFINITY] {
+                    let inv_dir = Vector3::new(1.0 / d.x, 1.0 / d.y, 1.0 / d.z);
+                    let dir_is_neg = [
+                        usize::from(inv_dir.x < 0.0),
+                        usize::from(inv_dir.y < 0.0),
+                        usize::from(inv_dir.z < 0.0),
+                    ];
+                    assert_eq!(
+                        b.intersect_p(o, d, t_max).is_some(),
+                        b.intersect_p_inv_dir(o, t_max, inv_dir, dir_is_neg),
+                        "o = {o}, d = {d}, t_max = {t_max}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_index() {
+        let mut b = unit_box();
+        assert_eq!(b[0], b.p_min);
+        b[1] = Point3::new(2.0, 2.0, 2.0);
+        assert_eq!(b.p_max, Point3::new(2.0, 2.0, 2.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds() {
+        let _ = unit_box()[2];
+    }
+}
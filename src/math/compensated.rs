@@ -0,0 +1,285 @@
+//! Floating-point operations with compensated rounding error.
+
+use crate::math::number_traits::Float;
+
+/// Computes `a * b - c * d` with the rounding error of `c * d` recovered
+/// through an FMA, which avoids catastrophic cancellation when both
+/// products are close.
+///
+/// ```
+/// use pbrt::math::difference_of_products;
+///
+/// let e = f32::EPSILON;
+/// // Evaluated naively, (1 + e) * (1 - e) rounds to 1 and the result to 0.
+/// assert_eq!(difference_of_products(1.0 + e, 1.0 - e, 1.0, 1.0), -e * e);
+/// ```
+pub fn difference_of_products<T: Float>(a: T, b: T, c: T, d: T) -> T {
+    let cd = c * d;
+    let difference = a.mul_add(b, -cd);
+    let error = (-c).mul_add(d, cd);
+    difference + error
+}
+
+/// Computes `a * b + c * d` with the same error compensation as
+/// [`difference_of_products`].
+///
+/// ```
+/// use pbrt::math::sum_of_products;
+///
+/// assert_eq!(sum_of_products(3.0, 4.0, 2.0, 5.0), 22.0);
+/// ```
+pub fn sum_of_products<T: Float>(a: T, b: T, c: T, d: T) -> T {
+    let cd = c * d;
+    let sum = a.mul_add(b, cd);
+    let error = c.mul_add(d, -cd);
+    sum + error
+}
+
+/// A value together with an estimate of the rounding error made computing
+/// it, so that `v + err` is more accurate than `v` alone.
+///
+/// ```
+/// use pbrt::math::two_sum;
+///
+/// let s = two_sum(1.0f64, 1e-17);
+/// // The rounded sum loses the small term, but the error term keeps it.
+/// assert_eq!(s.v, 1.0);
+/// assert_eq!(s.err, 1e-17);
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct CompensatedFloat<T> {
+    /// The rounded value.
+    pub v: T,
+    /// The rounding error of `v`.
+    pub err: T,
+}
+
+impl<T: Float> CompensatedFloat<T> {
+    /// Creates a compensated value from a rounded value and its error.
+    pub fn new(v: T, err: T) -> Self {
+        Self { v, err }
+    }
+
+    /// Returns the value with its error folded back in.
+    pub fn value(self) -> T {
+        self.v + self.err
+    }
+}
+
+/// Returns the product `a * b` and its exact rounding error, recovered
+/// through an FMA.
+///
+/// ```
+/// use pbrt::math::two_prod;
+///
+/// let e = f64::EPSILON;
+/// let p = two_prod(1.0 + e, 1.0 + e);
+/// // The exact product 1 + 2e + e^2 is split without loss.
+/// assert_eq!(p.v, 1.0 + 2.0 * e);
+/// assert_eq!(p.err, e * e);
+/// ```
+pub fn two_prod<T: Float>(a: T, b: T) -> CompensatedFloat<T> {
+    let ab = a * b;
+    CompensatedFloat::new(ab, a.mul_add(b, -ab))
+}
+
+/// Returns the sum `a + b` and its exact rounding error, using Knuth's
+/// branch-free algorithm.
+pub fn two_sum<T: Float>(a: T, b: T) -> CompensatedFloat<T> {
+    let s = a + b;
+    let delta = s - a;
+    CompensatedFloat::new(s, (a - (s - delta)) + (b - delta))
+}
+
+/// Returns the inner product of `a` and `b`, accumulating the rounding
+/// errors of every product and partial sum so that the result is nearly as
+/// accurate as if it were computed in twice the precision.
+///
+/// ```
+/// use pbrt::math::inner_product;
+///
+/// // The large terms cancel exactly, which a naive sum would miss.
+/// let a = [1e16f64, 1.0, -1e16];
+/// let b = [1.0, 1.0, 1.0];
+/// assert_eq!(inner_product(a, b), 1.0);
+/// ```
+pub fn inner_product<T: Float, const N: usize>(a: [T; N], b: [T; N]) -> T {
+    if N == 0 {
+        return T::ZERO;
+    }
+    // Accumulate from the last term, matching the usual recursive
+    // formulation.
+    let mut acc = two_prod(a[N - 1], b[N - 1]);
+    for i in (0..N - 1).rev() {
+        let ab = two_prod(a[i], b[i]);
+        let sum = two_sum(ab.v, acc.v);
+        acc = CompensatedFloat::new(sum.v, ab.err + (acc.err + sum.err));
+    }
+    acc.value()
+}
+
+/// A running sum that uses Kahan's compensated summation, keeping the
+/// accumulated rounding error bounded independently of the number of terms.
+///
+/// ```
+/// use pbrt::math::KahanSum;
+///
+/// let mut sum = KahanSum::new();
+/// for _ in 0..10_000_000 {
+///     sum += 0.1f32;
+/// }
+/// assert_eq!(sum.sum(), 1_000_000.0);
+///
+/// // A naive f32 sum of the same terms drifts far from the true value.
+/// let naive: f32 = std::iter::repeat_n(0.1f32, 10_000_000).sum();
+/// assert!((naive - 1_000_000.0).abs() > 1000.0);
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct KahanSum<T> {
+    sum: T,
+    c: T,
+}
+
+impl<T: Float> KahanSum<T> {
+    /// Creates a sum of zero.
+    pub fn new() -> Self {
+        Self {
+            sum: T::ZERO,
+            c: T::ZERO,
+        }
+    }
+
+    /// Returns the current sum.
+    pub fn sum(&self) -> T {
+        self.sum
+    }
+}
+
+impl<T: Float> std::ops::AddAssign<T> for KahanSum<T> {
+    fn add_assign(&mut self, v: T) {
+        let delta = v - self.c;
+        let t = self.sum + delta;
+        self.c = (t - self.sum) - delta;
+        self.sum = t;
+    }
+}
+
+impl<T: Float> FromIterator<T> for KahanSum<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut sum = Self::new();
+        for v in iter {
+            sum += v;
+        }
+        sum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::compensated::{
+        CompensatedFloat, KahanSum, difference_of_products, inner_product, sum_of_products,
+        two_prod, two_sum,
+    };
+
+    #[test]
+    fn test_difference_of_products() {
+        assert_eq!(difference_of_products(3.0, 4.0, 2.0, 5.0), 2.0);
+        let e = f32::EPSILON;
+        let exact = (1.0f64 + f64::from(e)) * (1.0 - f64::from(e)) - 1.0;
+        assert_eq!(
+            f64::from(difference_of_products(1.0 + e, 1.0 - e, 1.0, 1.0)),
+            exact
+        );
+    }
+
+    #[test]
+    fn test_sum_of_products() {
+        assert_eq!(sum_of_products(3.0, 4.0, 2.0, 5.0), 22.0);
+        let e = f32::EPSILON;
+        let exact = (1.0f64 + f64::from(e)) * (1.0 - f64::from(e)) + -1.0;
+        assert_eq!(
+            f64::from(sum_of_products(1.0 + e, 1.0 - e, -1.0, 1.0)),
+            exact
+        );
+    }
+
+    #[test]
+    fn test_two_prod_is_exact() {
+        let values = [
+            1.0f32 / 3.0,
+            0.1,
+            7.0,
+            -2.5e-3,
+            1.0 + f32::EPSILON,
+            12345.679,
+        ];
+        for a in values {
+            for b in values {
+                let p = two_prod(a, b);
+                let exact = f64::from(a) * f64::from(b);
+                assert_eq!(f64::from(p.v) + f64::from(p.err), exact);
+                assert_eq!(p.v, a * b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_two_sum_is_exact() {
+        let values = [
+            1.0f32 / 3.0,
+            0.1,
+            7e7,
+            -2.5e-3,
+            1.0 + f32::EPSILON,
+            -12345.679,
+        ];
+        for a in values {
+            for b in values {
+                let s = two_sum(a, b);
+                let exact = f64::from(a) + f64::from(b);
+                assert_eq!(f64::from(s.v) + f64::from(s.err), exact);
+                assert_eq!(s.v, a + b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_compensated_float() {
+        let c = CompensatedFloat::new(1.0f64, 1e-17);
+        assert_eq!(c.value(), 1.0 + 1e-17);
+        assert_eq!(CompensatedFloat::<f64>::default().value(), 0.0);
+    }
+
+    #[test]
+    fn test_inner_product() {
+        assert_eq!(inner_product::<f64, 0>([], []), 0.0);
+        assert_eq!(inner_product([3.0f32], [4.0]), 12.0);
+        assert_eq!(inner_product([1.0, 2.0, 3.0], [4.0, 5.0, 6.0]), 32.0);
+
+        // An ill-conditioned dot product whose exact value is -e^2.
+        let e = f32::EPSILON;
+        let a = [1.0 + e, 2.0, -1.0, -2.0];
+        let b = [1.0 - e, 3.0, 1.0, 3.0];
+        let exact = -f64::from(e) * f64::from(e);
+        let naive: f32 = a.iter().zip(&b).map(|(a, b)| a * b).sum();
+        assert_ne!(f64::from(naive), exact);
+        assert_eq!(f64::from(inner_product(a, b)), exact);
+    }
+
+    #[test]
+    fn test_kahan_sum() {
+        assert_eq!(KahanSum::<f32>::new().sum(), 0.0);
+        let mut sum = KahanSum::new();
+        sum += 1.0f32;
+        for _ in 0..1000 {
+            sum += f32::EPSILON / 4.0;
+        }
+        // Each small term is lost by a naive sum but not here.
+        let expected = 1.0 + 1000.0 * f64::from(f32::EPSILON) / 4.0;
+        assert!((f64::from(sum.sum()) - expected).abs() < f64::from(f32::EPSILON));
+
+        let collected: KahanSum<f64> = (1..=100_000).map(|i| 1.0 / f64::from(i)).collect();
+        let reference: f64 = (1..=100_000).rev().map(|i| 1.0 / f64::from(i)).sum();
+        assert!((collected.sum() - reference).abs() < 1e-13);
+    }
+}
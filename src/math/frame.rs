@@ -0,0 +1,238 @@
+//! Orthonormal coordinate frames.
+
+use crate::math::normal3::Normal3;
+use crate::math::number_traits::Float;
+use crate::math::square_matrix::SquareMatrix;
+use crate::math::transform::Transform;
+use crate::math::vector3::Vector3;
+
+/// Returns two unit vectors that form an orthonormal basis together with the
+/// unit vector `v`, using the branchless construction of Duff et al., "Building
+/// an Orthonormal Basis, Revisited" (2017). The basis is right-handed:
+/// `v1.cross(v2) == v`.
+///
+/// ```
+/// use pbrt::math::{coordinate_system, Vector3};
+///
+/// let v = Vector3::new(0.0f64, 0.6, 0.8);
+/// let (v1, v2) = coordinate_system(v);
+/// assert!(v.dot(v1).abs() < 1e-12 && v.dot(v2).abs() < 1e-12 && v1.dot(v2).abs() < 1e-12);
+/// assert!((v1.cross(v2) - v).length() < 1e-12);
+/// ```
+pub fn coordinate_system<T: Float>(v: Vector3<T>) -> (Vector3<T>, Vector3<T>) {
+    let sign = T::ONE.copysign(v.z);
+    let a = -T::ONE / (sign + v.z);
+    let b = v.x * v.y * a;
+    (
+        Vector3::new(T::ONE + sign * v.x * v.x * a, sign * b, -sign * v.x),
+        Vector3::new(b, sign + v.y * v.y * a, -v.y),
+    )
+}
+
+/// An orthonormal basis, used to move directions between world space and a
+/// local frame such as the shading frame of a surface, where the normal is
+/// the z axis.
+///
+/// ```
+/// use pbrt::math::{Frame, Vector3};
+///
+/// let frame = Frame::from_z(Vector3::new(0.0f64, 1.0, 0.0));
+/// let w = Vector3::new(0.3, 0.9, -0.1);
+/// let local = frame.to_local(w);
+/// // The local z component is the cosine with the frame's z axis.
+/// assert!((local.z - 0.9).abs() < 1e-12);
+/// assert!((frame.from_local(local) - w).length() < 1e-12);
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Frame<T> {
+    /// The first axis.
+    pub x: Vector3<T>,
+    /// The second axis.
+    pub y: Vector3<T>,
+    /// The third axis.
+    pub z: Vector3<T>,
+}
+
+impl<T: Float> Frame<T> {
+    /// Creates a frame from three orthonormal vectors.
+    pub fn new(x: Vector3<T>, y: Vector3<T>, z: Vector3<T>) -> Self {
+        let tolerance = T::from_f64(1e-4);
+        debug_assert!((x.length_squared() - T::ONE).abs() < tolerance);
+        debug_assert!((y.length_squared() - T::ONE).abs() < tolerance);
+        debug_assert!((z.length_squared() - T::ONE).abs() < tolerance);
+        debug_assert!(x.abs_dot(y) < tolerance);
+        debug_assert!(y.abs_dot(z) < tolerance);
+        debug_assert!(z.abs_dot(x) < tolerance);
+        Self { x, y, z }
+    }
+
+    /// Creates a frame from its x and z axes, which must be orthonormal.
+    pub fn from_xz(x: Vector3<T>, z: Vector3<T>) -> Self {
+        Self::new(x, z.cross(x), z)
+    }
+
+    /// Creates a frame from its x and y axes, which must be orthonormal.
+    pub fn from_xy(x: Vector3<T>, y: Vector3<T>) -> Self {
+        Self::new(x, y, x.cross(y))
+    }
+
+    /// Creates a frame from its y and z axes, which must be orthonormal.
+    pub fn from_yz(y: Vector3<T>, z: Vector3<T>) -> Self {
+        Self::new(y.cross(z), y, z)
+    }
+
+    /// Creates a frame with the unit vector `x` as its x axis.
+    pub fn from_x(x: Vector3<T>) -> Self {
+        let (y, z) = coordinate_system(x);
+        Self::new(x, y, z)
+    }
+
+    /// Creates a frame with the unit vector `y` as its y axis.
+    pub fn from_y(y: Vector3<T>) -> Self {
+        let (z, x) = coordinate_system(y);
+        Self::new(x, y, z)
+    }
+
+    /// Creates a frame with the unit vector `z` as its z axis, with the
+    /// other two axes chosen by [`coordinate_system`].
+    pub fn from_z(z: Vector3<T>) -> Self {
+        let (x, y) = coordinate_system(z);
+        Self::new(x, y, z)
+    }
+
+    /// Expresses a world-space vector in the frame.
+    pub fn to_local(&self, v: Vector3<T>) -> Vector3<T> {
+        Vector3::new(v.dot(self.x), v.dot(self.y), v.dot(self.z))
+    }
+
+    /// Expresses a world-space normal in the frame. Since the frame is
+    /// orthonormal, normals transform like vectors.
+    pub fn to_local_normal(&self, n: Normal3<T>) -> Normal3<T> {
+        Normal3::from(self.to_local(Vector3::from(n)))
+    }
+
+    /// Converts a vector expressed in the frame back to world space.
+    pub fn from_local(&self, v: Vector3<T>) -> Vector3<T> {
+        self.x * v.x + self.y * v.y + self.z * v.z
+    }
+
+    /// Converts a normal expressed in the frame back to world space.
+    pub fn from_local_normal(&self, n: Normal3<T>) -> Normal3<T> {
+        Normal3::from(self.from_local(Vector3::from(n)))
+    }
+}
+
+/// The transform from world space to the frame.
+impl<T: Float> From<Frame<T>> for Transform<T> {
+    fn from(frame: Frame<T>) -> Self {
+        let Frame { x, y, z } = frame;
+        let m = SquareMatrix::new([
+            [x.x, x.y, x.z, T::ZERO],
+            [y.x, y.y, y.z, T::ZERO],
+            [z.x, z.y, z.z, T::ZERO],
+            [T::ZERO, T::ZERO, T::ZERO, T::ONE],
+        ]);
+        Transform::from_matrices(m, m.transpose())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::frame::{Frame, coordinate_system};
+    use crate::math::normal3::Normal3;
+    use crate::math::transform::Transform;
+    use crate::math::vector3::Vector3;
+    use crate::testing::directions;
+
+    fn assert_orthonormal(frame: &Frame<f64>) {
+        let tolerance = 1e-12;
+        for axis in [frame.x, frame.y, frame.z] {
+            assert!((axis.length() - 1.0).abs() < tolerance, "{axis}");
+        }
+        assert!(frame.x.dot(frame.y).abs() < tolerance);
+        assert!(frame.y.dot(frame.z).abs() < tolerance);
+        assert!(frame.z.dot(frame.x).abs() < tolerance);
+        // Right-handed.
+        assert!((frame.x.cross(frame.y) - frame.z).length() < tolerance);
+    }
+
+    #[test]
+    fn test_coordinate_system() {
+        for v in directions(500) {
+            let (v1, v2) = coordinate_system(v);
+            assert_orthonormal(&Frame { x: v1, y: v2, z: v });
+        }
+    }
+
+    #[test]
+    fn test_coordinate_system_poles() {
+        let (v1, v2) = coordinate_system(Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(v1, Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(v2, Vector3::new(0.0, 1.0, 0.0));
+        let (v1, v2) = coordinate_system(Vector3::new(0.0, 0.0, -1.0));
+        assert!(!v1.has_nan() && !v2.has_nan());
+        assert_eq!(v1.cross(v2), Vector3::new(0.0, 0.0, -1.0));
+    }
+
+    #[test]
+    fn test_from_single_axis() {
+        for v in directions(500) {
+            let frame = Frame::from_z(v);
+            assert_eq!(frame.z, v);
+            assert_orthonormal(&frame);
+            let frame = Frame::from_x(v);
+            assert_eq!(frame.x, v);
+            assert_orthonormal(&frame);
+            let frame = Frame::from_y(v);
+            assert_eq!(frame.y, v);
+            assert_orthonormal(&frame);
+        }
+    }
+
+    #[test]
+    fn test_from_two_axes() {
+        let x = Vector3::new(1.0, 1.0, 0.0).normalize();
+        let y = Vector3::new(-1.0, 1.0, 0.0).normalize();
+        let z = Vector3::new(0.0, 0.0, 1.0);
+        for frame in [
+            Frame::from_xy(x, y),
+            Frame::from_xz(x, z),
+            Frame::from_yz(y, z),
+        ] {
+            assert_orthonormal(&frame);
+            assert!((frame.x - x).length() < 1e-15);
+            assert!((frame.y - y).length() < 1e-15);
+            assert!((frame.z - z).length() < 1e-15);
+        }
+    }
+
+    #[test]
+    fn test_local_round_trip() {
+        let w = Vector3::new(0.2, -0.7, 0.4);
+        for v in directions(500) {
+            let frame = Frame::from_z(v);
+            let local = frame.to_local(w);
+            assert!((local.length() - w.length()).abs() < 1e-12);
+            assert!((local.z - w.dot(v)).abs() < 1e-15);
+            assert!((frame.from_local(local) - w).length() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_local_normal() {
+        let frame = Frame::from_xy(Vector3::new(0.0, 1.0, 0.0), Vector3::new(0.0, 0.0, 1.0));
+        let n = Normal3::new(1.0, 2.0, 3.0);
+        let local = frame.to_local_normal(n);
+        assert_eq!(local, Normal3::new(2.0, 3.0, 1.0));
+        assert_eq!(frame.from_local_normal(local), n);
+    }
+
+    #[test]
+    fn test_transform() {
+        let frame = Frame::from_z(Vector3::new(1.0, 2.0, 2.0) / 3.0);
+        let t = Transform::from(frame);
+        let w = Vector3::new(0.5, 0.1, -0.3);
+        assert!((t * w - frame.to_local(w)).length() < 1e-15);
+        assert!((t.inverse() * frame.to_local(w) - w).length() < 1e-15);
+    }
+}
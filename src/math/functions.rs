@@ -0,0 +1,537 @@
+//! Numerical helper functions: interpolation, clamped inverse trigonometry,
+//! polynomial evaluation, root finding and small linear solves.
+
+use crate::math::compensated::difference_of_products;
+use crate::math::interval::Interval;
+use crate::math::number_traits::Float;
+
+/// Linearly interpolates between `a` at `t = 0` and `b` at `t = 1`.
+///
+/// ```
+/// use pbrt::math::lerp;
+///
+/// assert_eq!(lerp(0.25f32, 2.0, 6.0), 3.0);
+/// ```
+pub fn lerp<T: Float>(t: T, a: T, b: T) -> T {
+    (T::ONE - t) * a + t * b
+}
+
+/// Returns the square root of `x`, treating slightly negative values from
+/// roundoff as zero.
+///
+/// ```
+/// use pbrt::math::safe_sqrt;
+///
+/// assert_eq!(safe_sqrt(-1e-12f64), 0.0);
+/// assert_eq!(safe_sqrt(4.0f64), 2.0);
+/// ```
+pub fn safe_sqrt<T: Float>(x: T) -> T {
+    debug_assert!(x >= T::from_f64(-1e-3), "safe_sqrt of {}", x.to_f64());
+    x.max(T::ZERO).sqrt()
+}
+
+/// Returns the arcsine of `x`, clamping it to `[-1, 1]` first so that
+/// values just outside from roundoff do not produce NaN.
+///
+/// ```
+/// use pbrt::math::safe_asin;
+///
+/// assert_eq!(safe_asin(1.0000001f64), std::f64::consts::FRAC_PI_2);
+/// ```
+pub fn safe_asin<T: Float>(x: T) -> T {
+    debug_assert!(
+        x >= T::from_f64(-1.0001) && x <= T::from_f64(1.0001),
+        "safe_asin of {}",
+        x.to_f64()
+    );
+    x.max(-T::ONE).min(T::ONE).asin()
+}
+
+/// Returns the arccosine of `x`, clamping it to `[-1, 1]` first.
+///
+/// ```
+/// use pbrt::math::safe_acos;
+///
+/// assert_eq!(safe_acos(-1.0000001f64), std::f64::consts::PI);
+/// ```
+pub fn safe_acos<T: Float>(x: T) -> T {
+    debug_assert!(
+        x >= T::from_f64(-1.0001) && x <= T::from_f64(1.0001),
+        "safe_acos of {}",
+        x.to_f64()
+    );
+    x.max(-T::ONE).min(T::ONE).acos()
+}
+
+/// Evaluates the polynomial with the given coefficients, lowest degree
+/// first, at `t` with Horner's rule and FMAs.
+///
+/// ```
+/// use pbrt::math::evaluate_polynomial;
+///
+/// // 1 + 2t + 3t^2 at t = 2.
+/// assert_eq!(evaluate_polynomial(2.0f64, &[1.0, 2.0, 3.0]), 17.0);
+/// ```
+pub fn evaluate_polynomial<T: Float>(t: T, coefficients: &[T]) -> T {
+    coefficients
+        .iter()
+        .rev()
+        .fold(T::ZERO, |result, &c| result.mul_add(t, c))
+}
+
+/// Finds a zero of `f` in `[x0, x1]`, where `f` returns the value of the
+/// function and its derivative and must change sign over the interval.
+/// Newton steps are taken where they land inside the current bracket and
+/// bisection steps otherwise, so the search always converges. It stops once
+/// the bracket is narrower than `x_eps` or `|f| < f_eps`.
+///
+/// ```
+/// use pbrt::math::newton_bisection;
+///
+/// let root = newton_bisection(0.0f64, 2.0, |x| (x * x - 2.0, 2.0 * x), 1e-12, 1e-12);
+/// assert!((root - 2.0f64.sqrt()).abs() < 1e-12);
+/// ```
+pub fn newton_bisection<T: Float>(
+    mut x0: T,
+    mut x1: T,
+    mut f: impl FnMut(T) -> (T, T),
+    x_eps: T,
+    f_eps: T,
+) -> T {
+    debug_assert!(x0 < x1);
+    let fx0 = f(x0).0;
+    let fx1 = f(x1).0;
+    if fx0.abs() < f_eps {
+        return x0;
+    }
+    if fx1.abs() < f_eps {
+        return x1;
+    }
+    let start_is_negative = fx0 < T::ZERO;
+
+    // Start from the zero of the line through the endpoints.
+    let mut x_mid = x0 + (x1 - x0) * -fx0 / (fx1 - fx0);
+    loop {
+        // Fall back to bisection if the last step left the bracket.
+        if !(x0 < x_mid && x_mid < x1) {
+            x_mid = (x0 + x1) * T::HALF;
+        }
+        let (fx_mid, dfx_mid) = f(x_mid);
+        if start_is_negative == (fx_mid < T::ZERO) {
+            x0 = x_mid;
+        } else {
+            x1 = x_mid;
+        }
+        if x1 - x0 < x_eps || fx_mid.abs() < f_eps {
+            return x_mid;
+        }
+        x_mid -= fx_mid / dfx_mid;
+    }
+}
+
+/// Returns the real roots of `a t^2 + b t + c` in increasing order, or
+/// `None` if there are none. The discriminant is computed with
+/// [`difference_of_products`] and the roots with the formulation that
+/// avoids cancellation. A linear equation has its single root returned
+/// twice.
+///
+/// ```
+/// use pbrt::math::quadratic;
+///
+/// assert_eq!(quadratic(1.0f64, -3.0, 2.0), Some((1.0, 2.0)));
+/// assert_eq!(quadratic(1.0f64, 0.0, 1.0), None);
+/// ```
+pub fn quadratic<T: Float>(a: T, b: T, c: T) -> Option<(T, T)> {
+    if a == T::ZERO {
+        if b == T::ZERO {
+            return None;
+        }
+        let t = -c / b;
+        return Some((t, t));
+    }
+    let discriminant = difference_of_products(b, b, T::from_f64(4.0) * a, c);
+    if discriminant < T::ZERO {
+        return None;
+    }
+    let root_discriminant = discriminant.sqrt();
+    let q = -T::HALF * (b + root_discriminant.copysign(b));
+    if q == T::ZERO {
+        // Both b and c are zero.
+        return Some((T::ZERO, T::ZERO));
+    }
+    let t0 = q / a;
+    let t1 = c / q;
+    Some(if t0 > t1 { (t1, t0) } else { (t0, t1) })
+}
+
+/// Returns intervals that are certain to contain the real roots of
+/// `a t^2 + b t + c` for any coefficients in the given intervals, ordered
+/// by their lower bounds, or `None` if there are certainly no real roots.
+/// When the discriminant may be zero the two intervals overlap.
+///
+/// ```
+/// use pbrt::math::{quadratic_interval, Interval};
+///
+/// let (t0, t1) = quadratic_interval(
+///     Interval::from(1.0f64),
+///     Interval::from_value_and_error(-3.0, 1e-9),
+///     Interval::from(2.0),
+/// )
+/// .unwrap();
+/// assert!(t0.contains(1.0) && t1.contains(2.0));
+/// assert!(t0.width() < 1e-8 && t1.width() < 1e-8);
+/// ```
+pub fn quadratic_interval<T: Float>(
+    a: Interval<T>,
+    b: Interval<T>,
+    c: Interval<T>,
+) -> Option<(Interval<T>, Interval<T>)> {
+    if a.is_exact() && a.midpoint() == T::ZERO {
+        if b.is_exact() && b.midpoint() == T::ZERO {
+            return None;
+        }
+        let t = -c / b;
+        return Some((t, t));
+    }
+    let discriminant = b.sqr() - Interval::from(T::from_f64(4.0)) * a * c;
+    if discriminant.upper_bound() < T::ZERO {
+        return None;
+    }
+    let root_discriminant = Interval::new(
+        discriminant.lower_bound().max(T::ZERO),
+        discriminant.upper_bound(),
+    )
+    .sqrt();
+    let q = if b.midpoint() < T::ZERO {
+        Interval::from(-T::HALF) * (b - root_discriminant)
+    } else {
+        Interval::from(-T::HALF) * (b + root_discriminant)
+    };
+    let t0 = q / a;
+    let t1 = c / q;
+    Some(if t0.lower_bound() > t1.lower_bound() {
+        (t1, t0)
+    } else {
+        (t0, t1)
+    })
+}
+
+/// Solves the 2x2 linear system `a x = b`, returning `None` if the matrix
+/// is nearly singular.
+///
+/// ```
+/// use pbrt::math::solve_linear_system_2x2;
+///
+/// let x = solve_linear_system_2x2([[2.0f64, 1.0], [1.0, 3.0]], [3.0, 5.0]);
+/// assert_eq!(x, Some([0.8, 1.4]));
+/// ```
+pub fn solve_linear_system_2x2<T: Float>(a: [[T; 2]; 2], b: [T; 2]) -> Option<[T; 2]> {
+    let det = difference_of_products(a[0][0], a[1][1], a[0][1], a[1][0]);
+    if det.abs() < T::from_f64(1e-10) {
+        return None;
+    }
+    let x0 = difference_of_products(a[1][1], b[0], a[0][1], b[1]) / det;
+    let x1 = difference_of_products(a[0][0], b[1], a[1][0], b[0]) / det;
+    if x0.is_nan() || x1.is_nan() {
+        return None;
+    }
+    Some([x0, x1])
+}
+
+/// Returns the error function of `x`, with an absolute error below
+/// `1.2e-7`. Small arguments use its Taylor series, which keeps the
+/// relative error small near zero; others use the Chebyshev fit to the
+/// complementary error function from Numerical Recipes.
+///
+/// ```
+/// use pbrt::math::erf;
+///
+/// assert_eq!(erf(0.0f32), 0.0);
+/// assert!((erf(0.5f64) - 0.5204999).abs() < 1e-6);
+/// ```
+pub fn erf<T: Float>(x: T) -> T {
+    let z = x.abs();
+    if z < T::HALF {
+        // 2 / sqrt(pi) * (-1)^n / (n! (2n + 1)) for n = 0..6
+        let series = [
+            std::f64::consts::FRAC_2_SQRT_PI,
+            -0.37612638903183754,
+            0.11283791670955126,
+            -0.026866170645131252,
+            0.005223977625442188,
+            -0.0008548327023450852,
+        ]
+        .map(T::from_f64);
+        return x * evaluate_polynomial(x * x, &series);
+    }
+    let t = T::ONE / z.mul_add(T::HALF, T::ONE);
+    let p = evaluate_polynomial(
+        t,
+        &[
+            -1.26551223,
+            1.00002368,
+            0.37409196,
+            0.09678418,
+            -0.18628806,
+            0.27886807,
+            -1.13520398,
+            1.48851587,
+            -0.82215223,
+            0.17087277,
+        ]
+        .map(T::from_f64),
+    );
+    let erfc = t * (p - z * z).exp();
+    (T::ONE - erfc).copysign(x)
+}
+
+/// Returns the inverse of the error function for `a` in `(-1, 1)`, using
+/// Giles' polynomial approximation as refined by Juffa, which is accurate
+/// to a few ulps in single precision.
+///
+/// ```
+/// use pbrt::math::erf_inv;
+///
+/// assert_eq!(erf_inv(0.0f32), 0.0);
+/// assert!((erf_inv(0.5f32) - 0.47693628).abs() < 1e-6);
+/// ```
+pub fn erf_inv<T: Float>(a: T) -> T {
+    let t = a
+        .mul_add(-a, T::ONE)
+        .max(T::from_f64(f64::from(f32::MIN_POSITIVE)))
+        .ln();
+    let p = if t.abs() > T::from_f64(6.125) {
+        evaluate_polynomial(
+            t,
+            &[
+                8.40016484e-1,
+                -2.64646143e-1,
+                4.83185798e-3,
+                3.02698812e-3,
+                3.93552968e-4,
+                2.84108955e-5,
+                1.22150334e-6,
+                2.93243101e-8,
+                3.03697567e-10,
+            ]
+            .map(T::from_f64),
+        )
+    } else {
+        evaluate_polynomial(
+            t,
+            &[
+                8.86226892e-1,
+                -2.32015476e-1,
+                1.15392581e-2,
+                2.31468678e-3,
+                -1.47697632e-4,
+                -5.61530760e-5,
+                1.12963626e-7,
+                1.22774793e-6,
+                1.43285448e-7,
+                5.43877832e-9,
+            ]
+            .map(T::from_f64),
+        )
+    };
+    a * p
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::functions::{
+        erf, erf_inv, evaluate_polynomial, lerp, newton_bisection, quadratic, quadratic_interval,
+        safe_acos, safe_asin, safe_sqrt, solve_linear_system_2x2,
+    };
+    use crate::math::interval::Interval;
+
+    // The error function, integrated with Simpson's rule.
+    fn reference_erf(x: f64) -> f64 {
+        let n = 2000;
+        let h = x / n as f64;
+        let f = |t: f64| (-t * t).exp();
+        let mut sum = f(0.0) + f(x);
+        for i in 1..n {
+            let weight = if i % 2 == 1 { 4.0 } else { 2.0 };
+            sum += weight * f(i as f64 * h);
+        }
+        sum * h / 3.0 * 2.0 / std::f64::consts::PI.sqrt()
+    }
+
+    #[test]
+    fn test_lerp() {
+        assert_eq!(lerp(0.0, 2.0f64, 6.0), 2.0);
+        assert_eq!(lerp(1.0, 2.0f64, 6.0), 6.0);
+        assert_eq!(lerp(0.25, 2.0f64, 6.0), 3.0);
+        assert_eq!(lerp(1.5, 2.0f32, 6.0), 8.0);
+    }
+
+    #[test]
+    fn test_safe_functions() {
+        assert_eq!(safe_sqrt(-1e-7f32), 0.0);
+        assert_eq!(safe_sqrt(9.0f32), 3.0);
+        assert_eq!(safe_asin(-1.00001f32), -std::f32::consts::FRAC_PI_2);
+        assert_eq!(safe_asin(0.5f64), 0.5f64.asin());
+        assert_eq!(safe_acos(1.00001f32), 0.0);
+        assert_eq!(safe_acos(0.5f64), 0.5f64.acos());
+    }
+
+    #[test]
+    fn test_evaluate_polynomial() {
+        assert_eq!(evaluate_polynomial::<f64>(3.0, &[]), 0.0);
+        assert_eq!(evaluate_polynomial(3.0f64, &[5.0]), 5.0);
+        assert_eq!(evaluate_polynomial(-2.0f32, &[1.0, 0.0, -1.0, 0.5]), -7.0);
+        for t in [-1.5f64, 0.0, 0.25, 4.0] {
+            let c = [0.3, -1.2, 2.0, 0.7, -0.1];
+            let expected: f64 = c
+                .iter()
+                .enumerate()
+                .map(|(i, c)| c * t.powi(i as i32))
+                .sum();
+            assert!((evaluate_polynomial(t, &c) - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_newton_bisection() {
+        // A cubic with a single root in the bracket.
+        let f = |x: f64| (x * x * x - 2.0 * x - 5.0, 3.0 * x * x - 2.0);
+        let root = newton_bisection(2.0, 3.0, f, 1e-12, 1e-12);
+        assert!(f(root).0.abs() < 1e-9);
+
+        // A function whose Newton steps overshoot the bracket.
+        let f = |x: f64| (x.atan(), 1.0 / (1.0 + x * x));
+        let root = newton_bisection(-3.0, 10.0, f, 1e-10, 1e-10);
+        assert!(root.abs() < 1e-9);
+
+        // Either endpoint may already be a root.
+        assert_eq!(
+            newton_bisection(1.0, 2.0, |x: f64| (x - 1.0, 1.0), 1e-6, 1e-6),
+            1.0
+        );
+        assert_eq!(
+            newton_bisection(0.0, 2.0, |x: f64| (x - 2.0, 1.0), 1e-6, 1e-6),
+            2.0
+        );
+
+        // A decreasing function.
+        let root = newton_bisection(0.0f32, 2.0, |x| (1.0 - x * x, -2.0 * x), 1e-6, 1e-6);
+        assert!((root - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_quadratic() {
+        assert_eq!(quadratic(1.0f64, -3.0, 2.0), Some((1.0, 2.0)));
+        assert_eq!(quadratic(-1.0f64, 3.0, -2.0), Some((1.0, 2.0)));
+        assert_eq!(quadratic(1.0f64, -2.0, 1.0), Some((1.0, 1.0)));
+        assert_eq!(quadratic(2.0f64, 0.0, 0.0), Some((0.0, 0.0)));
+        assert_eq!(quadratic(1.0f64, 0.0, -4.0), Some((-2.0, 2.0)));
+        assert_eq!(quadratic(1.0f64, 1.0, 1.0), None);
+        assert_eq!(quadratic(0.0f64, 2.0, -4.0), Some((2.0, 2.0)));
+        assert_eq!(quadratic(0.0f64, 0.0, 1.0), None);
+    }
+
+    #[test]
+    fn test_quadratic_avoids_cancellation() {
+        // The small root of t^2 - 1e8 t + 1 is about 1e-8; the textbook
+        // formula loses it entirely in single precision.
+        let (t0, t1) = quadratic(1.0f32, -1e8, 1.0).unwrap();
+        assert!((t0 - 1e-8).abs() < 1e-14);
+        assert!((t1 - 1e8).abs() < 10.0);
+    }
+
+    #[test]
+    fn test_quadratic_interval_contains_roots() {
+        let cases = [
+            (1.0f32, -3.0f32, 2.0f32),
+            (0.3, 1.7, -2.2),
+            (-2.0, 0.1, 5.0),
+            (1.0, -1e4, 1.0),
+        ];
+        for (a, b, c) in cases {
+            let (t0, t1) = quadratic(f64::from(a), f64::from(b), f64::from(c)).unwrap();
+            let ia = Interval::from_value_and_error(a, a.abs() * 1e-6);
+            let ib = Interval::from_value_and_error(b, b.abs() * 1e-6);
+            let ic = Interval::from_value_and_error(c, c.abs() * 1e-6);
+            let (r0, r1) = quadratic_interval(ia, ib, ic).unwrap();
+            assert!(r0.contains(t0 as f32), "{r0} does not contain {t0}");
+            assert!(r1.contains(t1 as f32), "{r1} does not contain {t1}");
+            assert!(r0.lower_bound() <= r1.lower_bound());
+
+            // The roots for perturbed coefficients at the interval corners
+            // are also contained.
+            for (da, db, dc) in [(1.0, 1.0, 1.0), (-1.0, 1.0, -1.0), (1.0, -1.0, -1.0)] {
+                let a = f64::from(a) * (1.0 + da * 1e-6);
+                let b = f64::from(b) * (1.0 + db * 1e-6);
+                let c = f64::from(c) * (1.0 + dc * 1e-6);
+                let (t0, t1) = quadratic(a, b, c).unwrap();
+                assert!(r0.contains(t0 as f32) && r1.contains(t1 as f32));
+            }
+        }
+    }
+
+    #[test]
+    fn test_quadratic_interval_edge_cases() {
+        let exact = |v: f64| Interval::from(v);
+        assert!(quadratic_interval(exact(1.0), exact(0.0), exact(1.0)).is_none());
+        assert!(quadratic_interval(exact(0.0), exact(0.0), exact(1.0)).is_none());
+        let (t0, t1) = quadratic_interval(exact(0.0), exact(2.0), exact(-4.0)).unwrap();
+        assert!(t0.contains(2.0) && t1.contains(2.0));
+
+        // A discriminant that may be zero gives overlapping intervals
+        // around the double root.
+        let (t0, t1) = quadratic_interval(
+            exact(1.0),
+            Interval::from_value_and_error(-2.0, 1e-6),
+            exact(1.0),
+        )
+        .unwrap();
+        assert!(t0.contains(1.0) && t1.contains(1.0));
+    }
+
+    #[test]
+    fn test_solve_linear_system_2x2() {
+        assert_eq!(
+            solve_linear_system_2x2([[1.0f64, 0.0], [0.0, 1.0]], [2.0, 3.0]),
+            Some([2.0, 3.0])
+        );
+        let a = [[0.5f64, -1.5], [2.0, 0.25]];
+        let [x0, x1] = solve_linear_system_2x2(a, [1.0, -2.0]).unwrap();
+        assert!((a[0][0] * x0 + a[0][1] * x1 - 1.0).abs() < 1e-12);
+        assert!((a[1][0] * x0 + a[1][1] * x1 + 2.0).abs() < 1e-12);
+        assert_eq!(
+            solve_linear_system_2x2([[1.0f64, 2.0], [2.0, 4.0]], [1.0, 2.0]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_erf() {
+        assert_eq!(erf(0.0f64), 0.0);
+        for i in -300..=300 {
+            let x = i as f64 / 100.0;
+            let e = erf(x);
+            assert!((e - reference_erf(x)).abs() < 1.2e-7, "erf({x}) = {e}");
+        }
+        assert_eq!(erf(f64::INFINITY), 1.0);
+    }
+
+    #[test]
+    fn test_erf_inv() {
+        assert_eq!(erf_inv(0.0f32), 0.0);
+        for i in -99..=99 {
+            let y = i as f64 / 100.0;
+            let x = erf_inv(y as f32);
+            assert_eq!(erf_inv(-y as f32), -x);
+            assert!(
+                (reference_erf(f64::from(x)) - y).abs() < 1e-6,
+                "erf({x}) != {y}"
+            );
+        }
+        // Arguments near one use the tail approximation.
+        for y in [0.9999f64, 0.999999] {
+            let x = erf_inv(y);
+            assert!((reference_erf(x) - y).abs() < 1e-9, "erf({x}) != {y}");
+        }
+    }
+}
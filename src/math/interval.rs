@@ -0,0 +1,572 @@
+//! Interval arithmetic for tracking floating-point rounding error.
+
+use crate::math::normal3::Normal3;
+use crate::math::number_traits::{Float, Number, Signed};
+use crate::math::point3::Point3;
+use crate::math::vector3::Vector3;
+
+/// A closed range of values known to contain an exact result, with
+/// arithmetic that rounds bounds outwards so that this stays true through
+/// every operation.
+///
+/// Intervals are [`Number`]s, so tuple types can have interval components
+/// to track the rounding error of geometric computations. Two intervals
+/// only compare as less or greater when they do not overlap.
+///
+/// ```
+/// use pbrt::math::Interval;
+///
+/// let a = Interval::new(1.0f32, 2.0);
+/// let b = Interval::from(0.5);
+/// let c = a * b + Interval::new(-1.0, 1.0);
+/// assert!(c.lower_bound() <= -0.5 && c.upper_bound() >= 2.0);
+/// assert!(c.contains(0.0));
+/// assert_eq!(a.to_string(), "[1, 2]");
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct Interval<T> {
+    low: T,
+    high: T,
+}
+
+impl<T: Float> Interval<T> {
+    /// Creates the interval between two bounds, in any order.
+    pub fn new(low: T, high: T) -> Self {
+        Self {
+            low: low.min(high),
+            high: low.max(high),
+        }
+    }
+
+    /// Creates an interval around `v` that contains every value within
+    /// `error` of it.
+    ///
+    /// ```
+    /// use pbrt::math::Interval;
+    ///
+    /// let i = Interval::from_value_and_error(1.0f32, 0.5);
+    /// assert!(i.contains(0.5) && i.contains(1.5));
+    /// assert!(Interval::from_value_and_error(1.0f32, 0.0).is_exact());
+    /// ```
+    pub fn from_value_and_error(v: T, error: T) -> Self {
+        if error == T::ZERO {
+            return Self { low: v, high: v };
+        }
+        Self {
+            low: (v - error).next_float_down(),
+            high: (v + error).next_float_up(),
+        }
+    }
+
+    /// Returns the lower bound.
+    pub fn lower_bound(self) -> T {
+        self.low
+    }
+
+    /// Returns the upper bound.
+    pub fn upper_bound(self) -> T {
+        self.high
+    }
+
+    /// Returns the center of the interval, the best single estimate of the
+    /// value.
+    pub fn midpoint(self) -> T {
+        (self.low + self.high) * T::HALF
+    }
+
+    /// Returns the distance between the bounds.
+    pub fn width(self) -> T {
+        self.high - self.low
+    }
+
+    /// Returns whether the interval holds a single value.
+    pub fn is_exact(self) -> bool {
+        self.low == self.high
+    }
+
+    /// Returns whether `v` is within the bounds.
+    pub fn contains(self, v: T) -> bool {
+        v >= self.low && v <= self.high
+    }
+
+    /// Returns the square. Unlike `self * self`, the result is never
+    /// negative.
+    ///
+    /// ```
+    /// use pbrt::math::Interval;
+    ///
+    /// let i = Interval::new(-1.0f64, 2.0);
+    /// assert!(i.sqr().lower_bound() >= 0.0);
+    /// assert!((i * i).lower_bound() < 0.0);
+    /// ```
+    pub fn sqr(self) -> Self {
+        let abs = self.abs();
+        Self {
+            low: (abs.low * abs.low).next_float_down().max(T::ZERO),
+            high: (abs.high * abs.high).next_float_up(),
+        }
+    }
+
+    /// Returns the square root.
+    pub fn sqrt(self) -> Self {
+        Self {
+            low: self.low.sqrt().next_float_down(),
+            high: self.high.sqrt().next_float_up(),
+        }
+    }
+
+    /// Returns the range of the sine over the interval, which must lie
+    /// within `[0, 2π]`.
+    pub fn sin(self) -> Self {
+        debug_assert!(self.low >= T::from_f64(-1e-16) && self.high <= T::from_f64(2.0001) * T::PI);
+        let mut low = self.low.sin();
+        let mut high = self.high.sin();
+        if low > high {
+            std::mem::swap(&mut low, &mut high);
+        }
+        if self.contains(T::PI * T::HALF) {
+            high = T::ONE;
+        }
+        if self.contains(T::from_f64(1.5) * T::PI) {
+            low = -T::ONE;
+        }
+        Self {
+            low: low.next_float_down().max(-T::ONE),
+            high: high.next_float_up().min(T::ONE),
+        }
+    }
+
+    /// Returns the range of the cosine over the interval, which must lie
+    /// within `[0, 2π]`.
+    pub fn cos(self) -> Self {
+        debug_assert!(self.low >= T::from_f64(-1e-16) && self.high <= T::from_f64(2.0001) * T::PI);
+        let mut low = self.low.cos();
+        let mut high = self.high.cos();
+        if low > high {
+            std::mem::swap(&mut low, &mut high);
+        }
+        if self.contains(T::PI) {
+            low = -T::ONE;
+        }
+        Self {
+            low: low.next_float_down().max(-T::ONE),
+            high: high.next_float_up().min(T::ONE),
+        }
+    }
+}
+
+impl<T: Float> From<T> for Interval<T> {
+    fn from(value: T) -> Self {
+        Self {
+            low: value,
+            high: value,
+        }
+    }
+}
+
+impl<T: Float> PartialOrd for Interval<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        if self == other {
+            Some(std::cmp::Ordering::Equal)
+        } else if self.high < other.low {
+            Some(std::cmp::Ordering::Less)
+        } else if self.low > other.high {
+            Some(std::cmp::Ordering::Greater)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Float> Number for Interval<T> {
+    const ZERO: Self = Self {
+        low: T::ZERO,
+        high: T::ZERO,
+    };
+    const ONE: Self = Self {
+        low: T::ONE,
+        high: T::ONE,
+    };
+    const MIN: Self = Self {
+        low: T::MIN,
+        high: T::MIN,
+    };
+    const MAX: Self = Self {
+        low: T::MAX,
+        high: T::MAX,
+    };
+
+    fn is_nan(&self) -> bool {
+        self.low.is_nan() || self.high.is_nan()
+    }
+
+    fn min(self, b: Self) -> Self {
+        Self {
+            low: self.low.min(b.low),
+            high: self.high.min(b.high),
+        }
+    }
+
+    fn max(self, b: Self) -> Self {
+        Self {
+            low: self.low.max(b.low),
+            high: self.high.max(b.high),
+        }
+    }
+}
+
+impl<T: Float> Signed for Interval<T> {
+    fn abs(self) -> Self {
+        if self.low >= T::ZERO {
+            self
+        } else if self.high <= T::ZERO {
+            -self
+        } else {
+            Self {
+                low: T::ZERO,
+                high: (-self.low).max(self.high),
+            }
+        }
+    }
+}
+
+impl<T: Float> std::ops::Add for Interval<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            low: (self.low + rhs.low).next_float_down(),
+            high: (self.high + rhs.high).next_float_up(),
+        }
+    }
+}
+
+impl<T: Float> std::ops::AddAssign for Interval<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Float> std::ops::Sub for Interval<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            low: (self.low - rhs.high).next_float_down(),
+            high: (self.high - rhs.low).next_float_up(),
+        }
+    }
+}
+
+impl<T: Float> std::ops::SubAssign for Interval<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Float> std::ops::Mul for Interval<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let products = [
+            self.low * rhs.low,
+            self.high * rhs.low,
+            self.low * rhs.high,
+            self.high * rhs.high,
+        ];
+        Self {
+            low: products
+                .into_iter()
+                .reduce(Number::min)
+                .unwrap()
+                .next_float_down(),
+            high: products
+                .into_iter()
+                .reduce(Number::max)
+                .unwrap()
+                .next_float_up(),
+        }
+    }
+}
+
+impl<T: Float> std::ops::MulAssign for Interval<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Float> std::ops::Div for Interval<T> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        if rhs.contains(T::ZERO) {
+            // The result is unbounded if the divisor straddles zero.
+            return Self {
+                low: T::NEG_INFINITY,
+                high: T::INFINITY,
+            };
+        }
+        let quotients = [
+            self.low / rhs.low,
+            self.high / rhs.low,
+            self.low / rhs.high,
+            self.high / rhs.high,
+        ];
+        Self {
+            low: quotients
+                .into_iter()
+                .reduce(Number::min)
+                .unwrap()
+                .next_float_down(),
+            high: quotients
+                .into_iter()
+                .reduce(Number::max)
+                .unwrap()
+                .next_float_up(),
+        }
+    }
+}
+
+impl<T: Float> std::ops::DivAssign for Interval<T> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<T: Float> std::ops::Neg for Interval<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            low: -self.high,
+            high: -self.low,
+        }
+    }
+}
+
+impl<T: Float> std::iter::Sum for Interval<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |sum, v| sum + v)
+    }
+}
+
+impl<T: Float> std::iter::Product for Interval<T> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |product, v| product * v)
+    }
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for Interval<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}, {}]", self.low, self.high)
+    }
+}
+
+macro_rules! interval_tuple_impl {
+    ($name:ident, $($components:ident),+) => {
+        impl<T: Float> std::ops::Mul<$name<Interval<T>>> for Interval<T> {
+            type Output = $name<Interval<T>>;
+
+            fn mul(self, rhs: $name<Interval<T>>) -> Self::Output {
+                Self::Output {
+                    $($components: self * rhs.$components),+
+                }
+            }
+        }
+
+        impl<T: Float> From<$name<T>> for $name<Interval<T>> {
+            fn from(value: $name<T>) -> Self {
+                Self {
+                    $($components: value.$components.into()),+
+                }
+            }
+        }
+
+        impl<T: Float> $name<Interval<T>> {
+            /// Creates a tuple of intervals around `value` with the given
+            /// error along each axis.
+            pub fn from_value_and_error(value: $name<T>, error: Vector3<T>) -> Self {
+                Self {
+                    $($components: Interval::from_value_and_error(value.$components, error.$components)),+
+                }
+            }
+
+            /// Returns the tuple of interval midpoints.
+            pub fn midpoint(self) -> $name<T> {
+                $name {
+                    $($components: self.$components.midpoint()),+
+                }
+            }
+
+            /// Returns the half-widths of the intervals.
+            pub fn error(self) -> Vector3<T> {
+                Vector3 {
+                    $($components: self.$components.width() * T::HALF),+
+                }
+            }
+
+            /// Returns whether every component is exact.
+            pub fn is_exact(self) -> bool {
+                $(self.$components.is_exact()) &&+
+            }
+        }
+    };
+}
+
+interval_tuple_impl!(Vector3, x, y, z);
+interval_tuple_impl!(Point3, x, y, z);
+interval_tuple_impl!(Normal3, x, y, z);
+
+#[cfg(test)]
+mod tests {
+    use crate::math::interval::Interval;
+    use crate::math::number_traits::Signed;
+    use crate::math::point3::Point3;
+    use crate::math::vector3::Vector3;
+
+    #[test]
+    fn test_new() {
+        let i = Interval::new(2.0, -1.0);
+        assert_eq!(i.lower_bound(), -1.0);
+        assert_eq!(i.upper_bound(), 2.0);
+        assert_eq!(i.midpoint(), 0.5);
+        assert_eq!(i.width(), 3.0);
+    }
+
+    #[test]
+    fn test_from_value_and_error() {
+        let i = Interval::from_value_and_error(1.0f32, 0.5);
+        assert!(i.lower_bound() < 0.5);
+        assert!(i.upper_bound() > 1.5);
+        assert!(Interval::from_value_and_error(1.0f32, 0.0).is_exact());
+    }
+
+    #[test]
+    fn test_add() {
+        let i = Interval::from(0.1f32) + Interval::from(0.2f32);
+        assert!(i.contains(0.1 + 0.2));
+        assert!(i.lower_bound() < i.upper_bound());
+        assert!(f64::from(i.lower_bound()) <= 0.1f64 + 0.2f64);
+    }
+
+    #[test]
+    fn test_sub() {
+        let i = Interval::new(1.0f64, 2.0) - Interval::new(0.5, 3.0);
+        assert!(i.contains(-2.0));
+        assert!(i.contains(1.5));
+        assert!(!i.contains(1.6));
+    }
+
+    #[test]
+    fn test_mul() {
+        let i = Interval::new(-2.0f32, 3.0) * Interval::new(-4.0, 1.0);
+        assert!(i.contains(-12.0));
+        assert!(i.contains(8.0));
+        assert!(!i.contains(8.01));
+        assert!(!i.contains(-12.01));
+    }
+
+    #[test]
+    fn test_div() {
+        let i = Interval::new(1.0f32, 2.0) / Interval::new(4.0, 8.0);
+        assert!(i.contains(0.125));
+        assert!(i.contains(0.5));
+        let unbounded = Interval::new(1.0f32, 2.0) / Interval::new(-1.0, 1.0);
+        assert_eq!(unbounded.lower_bound(), f32::NEG_INFINITY);
+        assert_eq!(unbounded.upper_bound(), f32::INFINITY);
+    }
+
+    #[test]
+    fn test_neg() {
+        assert_eq!(-Interval::new(-1.0, 2.0), Interval::new(-2.0, 1.0));
+    }
+
+    #[test]
+    fn test_abs() {
+        assert_eq!(Interval::new(1.0, 2.0).abs(), Interval::new(1.0, 2.0));
+        assert_eq!(Interval::new(-3.0, -2.0).abs(), Interval::new(2.0, 3.0));
+        assert_eq!(Interval::new(-3.0, 2.0).abs(), Interval::new(0.0, 3.0));
+    }
+
+    #[test]
+    fn test_sqr() {
+        let i = Interval::new(-3.0f64, 2.0).sqr();
+        assert!(i.contains(0.0));
+        assert!(i.contains(9.0));
+        assert_eq!(i.lower_bound(), 0.0);
+    }
+
+    #[test]
+    fn test_sqrt() {
+        let i = Interval::new(2.0f32, 4.0).sqrt();
+        assert!(i.contains(std::f32::consts::SQRT_2));
+        assert!(i.contains(2.0));
+    }
+
+    #[test]
+    fn test_sin() {
+        let i = Interval::new(0.1f64, 2.0).sin();
+        assert!(i.contains(0.1f64.sin()));
+        assert_eq!(i.upper_bound(), 1.0);
+        let i = Interval::new(4.0f64, 5.0).sin();
+        assert_eq!(i.lower_bound(), -1.0);
+        assert!(i.contains(4.0f64.sin()));
+    }
+
+    #[test]
+    fn test_cos() {
+        let i = Interval::new(0.5f32, 1.0).cos();
+        assert!(i.contains(0.5f32.cos()));
+        assert!(i.contains(1.0f32.cos()));
+        let i = Interval::new(3.0f32, 3.5).cos();
+        assert_eq!(i.lower_bound(), -1.0);
+    }
+
+    #[test]
+    fn test_partial_cmp() {
+        assert!(Interval::new(0.0, 1.0) < Interval::new(2.0, 3.0));
+        assert!(Interval::new(4.0, 5.0) > Interval::new(2.0, 3.0));
+        assert_eq!(
+            Interval::new(0.0, 2.0).partial_cmp(&Interval::new(1.0, 3.0)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Interval::new(-1.5, 2.0).to_string(), "[-1.5, 2]");
+    }
+
+    #[test]
+    fn test_vector3() {
+        let a = Vector3::<Interval<f32>>::from(Vector3::new(0.1, 0.2, 0.3));
+        let b = Vector3::<Interval<f32>>::from(Vector3::new(0.4, 0.5, 0.6));
+        let c = a + b * Interval::from(2.0);
+        assert!((c.midpoint() - Vector3::new(0.9, 1.2, 1.5)).length() < 1e-6);
+        assert!(c.x.contains(0.1 + 0.4 * 2.0));
+        assert!(c.error().x > 0.0);
+        assert!(!c.is_exact());
+    }
+
+    #[test]
+    fn test_point3_from_value_and_error() {
+        let p = Point3::from_value_and_error(
+            Point3::new(1.0f32, 2.0, 3.0),
+            Vector3::new(0.0, 0.25, 0.5),
+        );
+        assert_eq!(p.midpoint(), Point3::new(1.0, 2.0, 3.0));
+        assert_eq!(p.error().x, 0.0);
+        assert!(p.error().y >= 0.25);
+        assert!(p.error().z >= 0.5);
+    }
+
+    #[test]
+    fn test_point3_lerp() {
+        let a = Point3::<Interval<f64>>::from(Point3::new(0.0, 0.0, 0.0));
+        let b = Point3::<Interval<f64>>::from(Point3::new(1.0, 2.0, 3.0));
+        let p = a.lerp(b, Interval::from(0.5));
+        assert!(p.x.contains(0.5));
+        assert!(p.y.contains(1.0));
+        assert!(p.z.contains(1.5));
+    }
+}
@@ -1,25 +1,31 @@
+#[doc(hidden)]
+#[macro_export]
 macro_rules! n_tuple_component_count {
     () => { 0usize };
     ($component:ident $(, $components:ident)*) => {
-        1usize + $crate::math::macros::n_tuple_component_count!($($components),*)
+        1usize + $crate::n_tuple_component_count!($($components),*)
     };
 }
 
+#[doc(hidden)]
+#[macro_export]
 macro_rules! n_tuple_nested_call {
     ($f:expr, $s:expr, $component:ident) => {
         $s.$component
     };
     ($f:expr, $s:expr, $component:ident, $($components:ident),*) => {
-        $f($s.$component, $crate::math::macros::n_tuple_nested_call!($f, $s, $($components),*))
+        $f($s.$component, $crate::n_tuple_nested_call!($f, $s, $($components),*))
     };
 }
 
+#[doc(hidden)]
+#[macro_export]
 macro_rules! n_tuple_permute_impl {
     ($s:expr; $p:ident; $($components:tt)*) => {
-        $crate::math::macros::n_tuple_permute_impl!(@(0usize; $s; $p; $($components)*,))
+        $crate::n_tuple_permute_impl!(@(0usize; $s; $p; $($components)*,))
     };
     (@($idx:expr; $s:expr; $p:ident; $component:tt, $($components:tt)*) $($inits:tt)*) => {
-        $crate::math::macros::n_tuple_permute_impl!(
+        $crate::n_tuple_permute_impl!(
             @(1usize + $idx; $s; $p; $($components)*)
             $($inits)*
             $component: $s[$p[$idx]],
@@ -32,12 +38,14 @@ macro_rules! n_tuple_permute_impl {
     }
 }
 
+#[doc(hidden)]
+#[macro_export]
 macro_rules! n_tuple_index_impl {
     ($e:expr; [$($s:tt)+]; $($components:tt)*) => {
-        $crate::math::macros::n_tuple_index_impl!(@(0usize; $e; [$($s)+]; $($components)*,))
+        $crate::n_tuple_index_impl!(@(0usize; $e; [$($s)+]; $($components)*,))
     };
     (@($idx:expr; $e:expr; [$($s:tt)+]; $component:tt, $($components:tt)*) $($arms:tt)*) => {
-        $crate::math::macros::n_tuple_index_impl!(
+        $crate::n_tuple_index_impl!(
             @(1usize + $idx; $e; [$($s)+]; $($components)*)
             $($arms)*
             x if x == $idx => $($s)+.$component,
@@ -51,6 +59,8 @@ macro_rules! n_tuple_index_impl {
     }
 }
 
+#[doc(hidden)]
+#[macro_export]
 macro_rules! n_tuple_scalar_mul_lhs_impl {
     ($typ:ty, $name:ident, $($components:ident),+) => {
         impl std::ops::Mul<$name<$typ>> for $typ {
@@ -65,12 +75,16 @@ macro_rules! n_tuple_scalar_mul_lhs_impl {
     };
 }
 
+#[doc(hidden)]
+#[macro_export]
 macro_rules! n_tuple_fmt_string {
     ($name:ident, $component:ident, $($components:ident),*) => {
         concat!(stringify!($name), "(", stringify!({$component}) $(, ", ", stringify!({$components}))*, ")")
     };
 }
 
+#[doc(hidden)]
+#[macro_export]
 macro_rules! n_tuple_from_impl {
     ($from:ty, $to:ty, $name:ident, $($components:ident),+) => {
         impl std::convert::From<$name<$from>> for $name<$to> {
@@ -83,25 +97,319 @@ macro_rules! n_tuple_from_impl {
     };
 }
 
+/// Implements `Add` between two tuple types generated by [`n_tuple_impl!`],
+/// given the left and right operand types, the output type and the
+/// component names.
+///
+/// ```
+/// use pbrt::math::{n_tuple_add_impl, n_tuple_impl};
+///
+/// n_tuple_impl! {Position, x, y}
+/// n_tuple_impl! {Offset, x, y}
+/// n_tuple_add_impl!(Position, Offset, Position, x, y);
+///
+/// assert_eq!(Position::new(1, 2) + Offset::new(3, -1), Position::new(4, 1));
+/// ```
+#[macro_export]
+macro_rules! n_tuple_add_impl {
+    ($lhs:ident, $rhs:ident, $output:ident, $($components:ident),+) => {
+        impl<T> std::ops::Add<$rhs<T>> for $lhs<T>
+        where
+            T: $crate::math::number_traits::Number
+        {
+            type Output = $output<T>;
+
+            fn add(self, rhs: $rhs<T>) -> Self::Output {
+                debug_assert!(!rhs.has_nan());
+                Self::Output {
+                    $($components: self.$components + rhs.$components,)+
+                }
+            }
+        }
+    };
+}
+
+/// Implements `AddAssign` of the second tuple type to the first, given the
+/// component names.
+///
+/// ```
+/// use pbrt::math::{n_tuple_add_assign_impl, n_tuple_impl};
+///
+/// n_tuple_impl! {Position, x, y}
+/// n_tuple_impl! {Offset, x, y}
+/// n_tuple_add_assign_impl!(Position, Offset, x, y);
+///
+/// let mut p = Position::new(1, 2);
+/// p += Offset::new(3, -1);
+/// assert_eq!(p, Position::new(4, 1));
+/// ```
+#[macro_export]
+macro_rules! n_tuple_add_assign_impl {
+    ($lhs:ident, $rhs:ident, $($components:ident),+) => {
+        impl<T> std::ops::AddAssign<$rhs<T>> for $lhs<T>
+        where
+            T: $crate::math::number_traits::Number
+        {
+            fn add_assign(&mut self, rhs: $rhs<T>) {
+                debug_assert!(!rhs.has_nan());
+                $(self.$components += rhs.$components;)+
+            }
+        }
+    };
+}
+
+/// Implements `Sub` between two tuple types generated by [`n_tuple_impl!`],
+/// given the left and right operand types, the output type and the
+/// component names.
+///
+/// ```
+/// use pbrt::math::{n_tuple_impl, n_tuple_sub_impl};
+///
+/// n_tuple_impl! {Position, x, y}
+/// n_tuple_impl! {Offset, x, y}
+/// n_tuple_sub_impl!(Position, Position, Offset, x, y);
+///
+/// assert_eq!(Position::new(4, 1) - Position::new(1, 2), Offset::new(3, -1));
+/// ```
+#[macro_export]
+macro_rules! n_tuple_sub_impl {
+    ($lhs:ident, $rhs:ident, $output:ident, $($components:ident),+) => {
+        impl<T> std::ops::Sub<$rhs<T>> for $lhs<T>
+        where
+            T: $crate::math::number_traits::Number
+        {
+            type Output = $output<T>;
+
+            fn sub(self, rhs: $rhs<T>) -> Self::Output {
+                debug_assert!(!rhs.has_nan());
+                Self::Output {
+                    $($components: self.$components - rhs.$components,)+
+                }
+            }
+        }
+    };
+}
+
+/// Implements `SubAssign` of the second tuple type from the first, given
+/// the component names.
+///
+/// ```
+/// use pbrt::math::{n_tuple_impl, n_tuple_sub_assign_impl};
+///
+/// n_tuple_impl! {Position, x, y}
+/// n_tuple_impl! {Offset, x, y}
+/// n_tuple_sub_assign_impl!(Position, Offset, x, y);
+///
+/// let mut p = Position::new(4, 1);
+/// p -= Offset::new(3, -1);
+/// assert_eq!(p, Position::new(1, 2));
+/// ```
+#[macro_export]
+macro_rules! n_tuple_sub_assign_impl {
+    ($lhs:ident, $rhs:ident, $($components:ident),+) => {
+        impl<T> std::ops::SubAssign<$rhs<T>> for $lhs<T>
+        where
+            T: $crate::math::number_traits::Number
+        {
+            fn sub_assign(&mut self, rhs: $rhs<T>) {
+                debug_assert!(!rhs.has_nan());
+                $(self.$components -= rhs.$components;)+
+            }
+        }
+    };
+}
+
+/// Implements `From` between two tuple types with the same component names.
+///
+/// ```
+/// use pbrt::math::{n_tuple_conversion_impl, n_tuple_impl};
+///
+/// n_tuple_impl! {Position, x, y}
+/// n_tuple_impl! {Offset, x, y}
+/// n_tuple_conversion_impl!(Offset, Position, x, y);
+///
+/// assert_eq!(Position::from(Offset::new(1, 2)), Position::new(1, 2));
+/// ```
+#[macro_export]
+macro_rules! n_tuple_conversion_impl {
+    ($from:ident, $to:ident, $($components:ident),+) => {
+        impl<T> std::convert::From<$from<T>> for $to<T> {
+            fn from(value: $from<T>) -> Self {
+                Self {
+                    $($components: value.$components),+
+                }
+            }
+        }
+    };
+}
+
+/// Adds the vector operations (dot products, lengths, normalization and
+/// angles) to a tuple type generated by [`n_tuple_impl!`]. The type needs
+/// `Add` and `Sub` with itself, see [`n_tuple_add_impl!`] and
+/// [`n_tuple_sub_impl!`].
+///
+/// ```
+/// use pbrt::math::{n_tuple_add_impl, n_tuple_impl, n_tuple_sub_impl, n_tuple_vector_impl};
+///
+/// n_tuple_impl! {Offset, x, y}
+/// n_tuple_add_impl!(Offset, Offset, Offset, x, y);
+/// n_tuple_sub_impl!(Offset, Offset, Offset, x, y);
+/// n_tuple_vector_impl!(Offset, x, y);
+///
+/// let v = Offset::new(3.0, 4.0);
+/// assert_eq!(v.length(), 5.0);
+/// assert_eq!(v.normalize(), Offset::new(0.6, 0.8));
+/// ```
+#[macro_export]
+macro_rules! n_tuple_vector_impl {
+    ($name:ident, $component:ident $(, $components:ident)*) => {
+        impl<T> $name<T>
+        where
+            T: $crate::math::number_traits::Number
+        {
+            /// Returns the dot product of two tuples.
+            pub fn dot(self, rhs: Self) -> T {
+                self.$component * rhs.$component $(+ self.$components * rhs.$components)*
+            }
+
+            /// Returns the squared Euclidean length, which avoids a square root.
+            pub fn length_squared(self) -> T {
+                self.dot(self)
+            }
+        }
+
+        impl<T> $name<T>
+        where
+            T: $crate::math::number_traits::Signed
+        {
+            /// Returns the absolute value of the dot product.
+            pub fn abs_dot(self, rhs: Self) -> T {
+                self.dot(rhs).abs()
+            }
+        }
+
+        impl<T> $name<T>
+        where
+            T: $crate::math::number_traits::Float
+        {
+            /// Returns the Euclidean length.
+            pub fn length(self) -> T {
+                self.length_squared().sqrt()
+            }
+
+            /// Returns a tuple of unit length pointing the same way.
+            pub fn normalize(self) -> Self {
+                self / self.length()
+            }
+
+            /// Returns the angle in radians between two unit tuples, computed in a
+            /// way that stays accurate for nearly parallel or opposite tuples.
+            pub fn angle_between(self, b: Self) -> T {
+                use $crate::math::functions::safe_asin;
+                if self.dot(b) < T::ZERO {
+                    T::PI - T::TWO * safe_asin((self + b).length() * T::HALF)
+                } else {
+                    T::TWO * safe_asin((b - self).length() * T::HALF)
+                }
+            }
+
+            /// Removes from `self` its component along the unit tuple `w`, leaving a
+            /// tuple orthogonal to `w`.
+            pub fn gram_schmidt(self, w: Self) -> Self {
+                self - w * self.dot(w)
+            }
+
+            /// Flips `self` if needed so that it lies in the same hemisphere as `v`.
+            pub fn face_forward(self, v: Self) -> Self {
+                if self.dot(v) < T::ZERO { -self } else { self }
+            }
+        }
+    };
+}
+
+/// Adds `distance` and `distance_squared` to a point-like tuple type whose
+/// difference is a type generated with [`n_tuple_vector_impl!`].
+///
+/// ```
+/// use pbrt::math::{n_tuple_distance_impl, n_tuple_impl, n_tuple_sub_impl, Vector2};
+///
+/// n_tuple_impl! {Position, x, y}
+/// n_tuple_sub_impl!(Position, Position, Vector2, x, y);
+/// n_tuple_distance_impl!(Position);
+///
+/// assert_eq!(Position::new(1.0, 1.0).distance(Position::new(4.0, 5.0)), 5.0);
+/// ```
+#[macro_export]
+macro_rules! n_tuple_distance_impl {
+    ($name:ident) => {
+        impl<T> $name<T>
+        where
+            T: $crate::math::number_traits::Float,
+        {
+            /// Returns the distance between two points.
+            pub fn distance(self, b: Self) -> T {
+                (self - b).length()
+            }
+
+            /// Returns the squared distance between two points.
+            pub fn distance_squared(self, b: Self) -> T {
+                (self - b).length_squared()
+            }
+        }
+    };
+}
+
+/// Generates a tuple type with the given component names, generic over its
+/// component type, along with component-wise arithmetic with scalars,
+/// indexing, formatting and conversions between component types.
+///
+/// Attributes written before the name, such as doc comments, are applied to
+/// the struct. Operators between tuple types are added separately with
+/// [`n_tuple_add_impl!`] and its siblings, so that each type only gets the
+/// operations that are meaningful for it.
+///
+/// ```
+/// use pbrt::math::{n_tuple_add_impl, n_tuple_impl};
+///
+/// n_tuple_impl! {
+///     /// A color with red, green and blue components.
+///     Rgb, r, g, b
+/// }
+/// n_tuple_add_impl!(Rgb, Rgb, Rgb, r, g, b);
+///
+/// let c = Rgb::new(0.5, 0.25, 1.0) * 2.0 + Rgb::new(0.0, 0.5, 0.0);
+/// assert_eq!(c, Rgb::new(1.0, 1.0, 2.0));
+/// assert_eq!(c.max_value(), 2.0);
+/// assert_eq!(c[2], 2.0);
+/// assert_eq!(c.to_string(), "Rgb(1, 1, 2)");
+/// ```
+#[macro_export]
 macro_rules! n_tuple_impl {
-    ($name:ident, $($components:ident),+) => {
+    ($(#[$meta:meta])* $name:ident, $($components:ident),+) => {
+        $(#[$meta])*
         #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
         pub struct $name<T> {
-            $(pub $components: T),
-            +
+            $(
+                #[doc = concat!("The `", stringify!($components), "` component.")]
+                pub $components: T
+            ),+
         }
 
         impl<T> $name<T> {
-            pub const DIMENSIONS: usize = $crate::math::macros::n_tuple_component_count!($($components),+);
+            /// The number of components.
+            pub const DIMENSIONS: usize = $crate::n_tuple_component_count!($($components),+);
         }
 
         impl<T: $crate::math::number_traits::Number> $name<T> {
+            /// Creates a tuple from its components. Panics in debug builds if any
+            /// of them is NaN.
             pub fn new($($components: T),+) -> Self {
                 let v = Self { $($components),+ };
                 debug_assert!(!v.has_nan());
                 v
             }
 
+            /// Returns whether any component is NaN.
             pub fn has_nan(&self) -> bool {
                 $(self.$components.is_nan()) ||+
             }
@@ -109,6 +417,7 @@ macro_rules! n_tuple_impl {
 
         impl<T: $crate::math::number_traits::Signed> $name<T>
         {
+            /// Returns the component-wise absolute value.
             pub fn abs(self) -> Self {
                 Self {
                     $($components: self.$components.abs()),+
@@ -117,12 +426,14 @@ macro_rules! n_tuple_impl {
         }
 
         impl<T: $crate::math::number_traits::Float> $name<T> {
+            /// Returns the component-wise floor.
             pub fn floor(self) -> Self {
                 Self {
                     $($components: self.$components.floor()),+
                 }
             }
 
+            /// Returns the component-wise ceiling.
             pub fn ceil(self) -> Self {
                 Self {
                     $($components: self.$components.ceil()),+
@@ -132,9 +443,10 @@ macro_rules! n_tuple_impl {
 
         impl<T> $name<T>
         where
-            T: Copy + std::ops::Sub<Output = T> + std::ops::Mul<Self, Output = Self> + $crate::math::number_traits::Number,
+            T: std::ops::Mul<Self, Output = Self> + $crate::math::number_traits::Number,
             Self: std::ops::Add<Output = Self>
         {
+            /// Linearly interpolates between `self` at `t = 0` and `b` at `t = 1`.
             pub fn lerp(self, b: Self, t: T) -> Self {
                 (T::ONE - t) * self + t * b
             }
@@ -142,31 +454,36 @@ macro_rules! n_tuple_impl {
 
         impl<T: $crate::math::number_traits::Number> $name<T>
         {
+            /// Returns the component-wise minimum.
             pub fn min(self, b: Self) -> Self {
                 Self {
                     $($components: self.$components.min(b.$components)),+
                 }
             }
 
+            /// Returns the component-wise maximum.
             pub fn max(self, b: Self) -> Self {
                 Self {
                     $($components: self.$components.max(b.$components)),+
                 }
             }
 
+            /// Returns the smallest component.
             pub fn min_value(self) -> T {
-                $crate::math::macros::n_tuple_nested_call!($crate::math::number_traits::Number::min, self, $($components),*)
+                $crate::n_tuple_nested_call!($crate::math::number_traits::Number::min, self, $($components),*)
             }
 
+            /// Returns the largest component.
             pub fn max_value(self) -> T {
-                $crate::math::macros::n_tuple_nested_call!($crate::math::number_traits::Number::max, self, $($components),*)
+                $crate::n_tuple_nested_call!($crate::math::number_traits::Number::max, self, $($components),*)
             }
         }
 
         impl<T> $name<T>
         where
-            T: Copy + PartialOrd + $crate::math::number_traits::Number
+            T: $crate::math::number_traits::Number
         {
+            /// Returns the index of the smallest component.
             pub fn min_index(&self) -> usize {
                 let mut min = T::MAX;
                 let mut min_index = 0;
@@ -179,6 +496,7 @@ macro_rules! n_tuple_impl {
                 min_index
             }
 
+            /// Returns the index of the largest component.
             pub fn max_index(&self) -> usize {
                 let mut max = T::MIN;
                 let mut max_index = 0;
@@ -196,6 +514,7 @@ macro_rules! n_tuple_impl {
         where
             T: std::ops::Add<Output = T> + std::ops::Mul<Output = T>
         {
+            /// Returns `self * a + b` component-wise.
             pub fn mul_add(self, a: Self, b: Self) -> Self {
                 Self {
                     $($components: (self.$components * a.$components) + b.$components),+
@@ -204,8 +523,10 @@ macro_rules! n_tuple_impl {
         }
 
         impl<T: Copy> $name<T> {
-            pub fn permute(self, permutation: &[usize; $crate::math::macros::n_tuple_component_count!($($components),+)]) -> Self {
-                 $crate::math::macros::n_tuple_permute_impl!(self; permutation; $($components),+)
+            /// Returns a tuple whose `i`th component is component `permutation[i]`
+            /// of `self`.
+            pub fn permute(self, permutation: &[usize; $crate::n_tuple_component_count!($($components),+)]) -> Self {
+                 $crate::n_tuple_permute_impl!(self; permutation; $($components),+)
             }
         }
 
@@ -213,8 +534,9 @@ macro_rules! n_tuple_impl {
         where
             T: std::ops::Mul<Output = T>
         {
+            /// Returns the product of all components.
             pub fn h_prod(self) -> T {
-                $crate::math::macros::n_tuple_nested_call!(T::mul, self, $($components),+)
+                $crate::n_tuple_nested_call!(T::mul, self, $($components),+)
             }
         }
 
@@ -222,68 +544,20 @@ macro_rules! n_tuple_impl {
             type Output = T;
 
             fn index(&self, index: usize) -> &Self::Output {
-                $crate::math::macros::n_tuple_index_impl!(index; [&self]; $($components),+)
+                $crate::n_tuple_index_impl!(index; [&self]; $($components),+)
             }
 
         }
 
         impl<T> std::ops::IndexMut<usize> for $name<T> {
             fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-                $crate::math::macros::n_tuple_index_impl!(index; [&mut self]; $($components),+)
-            }
-        }
-
-        impl<T> std::ops::Add for $name<T>
-        where
-            T: std::ops::Add<Output = T> + $crate::math::number_traits::Number
-        {
-            type Output = Self;
-
-            fn add(self, rhs: Self) -> Self::Output {
-                debug_assert!(!rhs.has_nan());
-                Self::Output {
-                    $($components: self.$components + rhs.$components,)+
-                }
-            }
-        }
-
-        impl<T> std::ops::AddAssign for $name<T>
-        where
-            T: std::ops::AddAssign + $crate::math::number_traits::Number
-        {
-            fn add_assign(&mut self, rhs: Self) {
-                debug_assert!(!rhs.has_nan());
-                $(self.$components += rhs.$components;)+
-            }
-        }
-
-        impl<T> std::ops::Sub for $name<T>
-        where
-            T: std::ops::Sub<Output = T> + $crate::math::number_traits::Number
-        {
-            type Output = Self;
-
-            fn sub(self, rhs: Self) -> Self::Output {
-                debug_assert!(!rhs.has_nan());
-                Self::Output {
-                    $($components: self.$components - rhs.$components,)+
-                }
-            }
-        }
-
-        impl<T> std::ops::SubAssign for $name<T>
-        where
-            T: std::ops::SubAssign + $crate::math::number_traits::Number
-        {
-            fn sub_assign(&mut self, rhs: Self) {
-                debug_assert!(!rhs.has_nan());
-                $(self.$components -= rhs.$components;)+
+                $crate::n_tuple_index_impl!(index; [&mut self]; $($components),+)
             }
         }
 
         impl<T> std::ops::Mul<T> for $name<T>
         where
-            T: Copy + std::ops::Mul<Output = T> + $crate::math::number_traits::Number
+            T: $crate::math::number_traits::Number
         {
             type Output = Self;
 
@@ -297,7 +571,7 @@ macro_rules! n_tuple_impl {
 
         impl<T> std::ops::MulAssign<T> for $name<T>
         where
-            T: Copy + std::ops::MulAssign + $crate::math::number_traits::Number
+            T: $crate::math::number_traits::Number
         {
             fn mul_assign(&mut self, rhs: T) {
                 debug_assert!(!rhs.is_nan());
@@ -305,24 +579,24 @@ macro_rules! n_tuple_impl {
             }
         }
 
-        $crate::math::macros::n_tuple_scalar_mul_lhs_impl!(i8, $name, $($components),+);
-        $crate::math::macros::n_tuple_scalar_mul_lhs_impl!(i16, $name, $($components),+);
-        $crate::math::macros::n_tuple_scalar_mul_lhs_impl!(i32, $name, $($components),+);
-        $crate::math::macros::n_tuple_scalar_mul_lhs_impl!(i64, $name, $($components),+);
-        $crate::math::macros::n_tuple_scalar_mul_lhs_impl!(i128, $name, $($components),+);
-        $crate::math::macros::n_tuple_scalar_mul_lhs_impl!(isize, $name, $($components),+);
-        $crate::math::macros::n_tuple_scalar_mul_lhs_impl!(u8, $name, $($components),+);
-        $crate::math::macros::n_tuple_scalar_mul_lhs_impl!(u16, $name, $($components),+);
-        $crate::math::macros::n_tuple_scalar_mul_lhs_impl!(u32, $name, $($components),+);
-        $crate::math::macros::n_tuple_scalar_mul_lhs_impl!(u64, $name, $($components),+);
-        $crate::math::macros::n_tuple_scalar_mul_lhs_impl!(u128, $name, $($components),+);
-        $crate::math::macros::n_tuple_scalar_mul_lhs_impl!(usize, $name, $($components),+);
-        $crate::math::macros::n_tuple_scalar_mul_lhs_impl!(f32, $name, $($components),+);
-        $crate::math::macros::n_tuple_scalar_mul_lhs_impl!(f64, $name, $($components),+);
+        $crate::n_tuple_scalar_mul_lhs_impl!(i8, $name, $($components),+);
+        $crate::n_tuple_scalar_mul_lhs_impl!(i16, $name, $($components),+);
+        $crate::n_tuple_scalar_mul_lhs_impl!(i32, $name, $($components),+);
+        $crate::n_tuple_scalar_mul_lhs_impl!(i64, $name, $($components),+);
+        $crate::n_tuple_scalar_mul_lhs_impl!(i128, $name, $($components),+);
+        $crate::n_tuple_scalar_mul_lhs_impl!(isize, $name, $($components),+);
+        $crate::n_tuple_scalar_mul_lhs_impl!(u8, $name, $($components),+);
+        $crate::n_tuple_scalar_mul_lhs_impl!(u16, $name, $($components),+);
+        $crate::n_tuple_scalar_mul_lhs_impl!(u32, $name, $($components),+);
+        $crate::n_tuple_scalar_mul_lhs_impl!(u64, $name, $($components),+);
+        $crate::n_tuple_scalar_mul_lhs_impl!(u128, $name, $($components),+);
+        $crate::n_tuple_scalar_mul_lhs_impl!(usize, $name, $($components),+);
+        $crate::n_tuple_scalar_mul_lhs_impl!(f32, $name, $($components),+);
+        $crate::n_tuple_scalar_mul_lhs_impl!(f64, $name, $($components),+);
 
         impl<T> std::ops::Div<T> for $name<T>
         where
-            T: Copy + std::ops::Div<Output = T> + $crate::math::number_traits::Number
+            T: $crate::math::number_traits::Number
         {
             type Output = Self;
 
@@ -336,7 +610,7 @@ macro_rules! n_tuple_impl {
 
         impl<T> std::ops::DivAssign<T> for $name<T>
         where
-            T: Copy + std::ops::DivAssign + $crate::math::number_traits::Number
+            T: $crate::math::number_traits::Number
         {
             fn div_assign(&mut self, rhs: T) {
                 debug_assert!(!rhs.is_nan());
@@ -354,62 +628,285 @@ macro_rules! n_tuple_impl {
             }
         }
 
+        impl<T: $crate::random::HashBytes> $crate::random::HashBytes for $name<T> {
+            fn write_bytes(&self, buffer: &mut $crate::random::HashBuffer) {
+                $(self.$components.write_bytes(buffer);)+
+            }
+        }
+
+        impl<T> std::iter::Sum for $name<T>
+        where
+            T: $crate::math::number_traits::Number,
+            Self: std::ops::Add<Output = Self>
+        {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self { $($components: T::ZERO),+ }, |sum, v| sum + v)
+            }
+        }
+
+        impl<'a, T> std::iter::Sum<&'a $name<T>> for $name<T>
+        where
+            T: $crate::math::number_traits::Number,
+            Self: std::ops::Add<Output = Self>
+        {
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.copied().sum()
+            }
+        }
+
         impl<T: std::fmt::Display> std::fmt::Display for $name<T> {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, $crate::math::macros::n_tuple_fmt_string!($name, $($components),+), $($components = self.$components),+)
-            }
-        }
-
-        $crate::math::macros::n_tuple_from_impl!(i8, i16, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(i8, i32, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(i8, i64, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(i8, i128, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(i8, isize, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(i8, f32, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(i8, f64, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(i16, i32, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(i16, i64, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(i16, i128, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(i16, isize, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(i16, f32, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(i16, f64, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(i32, i64, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(i32, i128, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(i32, f64, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(i64, i128, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(u8, i16, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(u8, i32, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(u8, i64, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(u8, i128, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(u8, isize, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(u8, u16, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(u8, u32, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(u8, u64, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(u8, u128, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(u8, usize, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(u8, f32, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(u8, f64, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(u16, i32, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(u16, i64, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(u16, i128, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(u16, u32, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(u16, u64, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(u16, u128, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(u16, usize, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(u16, f32, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(u16, f64, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(u32, i64, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(u32, i128, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(u32, u64, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(u32, u128, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(u32, f64, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(u64, i128, $name, $($components),+);
-        $crate::math::macros::n_tuple_from_impl!(u64, u128, $name, $($components),+);
+                write!(f, $crate::n_tuple_fmt_string!($name, $($components),+), $($components = self.$components),+)
+            }
+        }
+
+        $crate::n_tuple_from_impl!(i8, i16, $name, $($components),+);
+        $crate::n_tuple_from_impl!(i8, i32, $name, $($components),+);
+        $crate::n_tuple_from_impl!(i8, i64, $name, $($components),+);
+        $crate::n_tuple_from_impl!(i8, i128, $name, $($components),+);
+        $crate::n_tuple_from_impl!(i8, isize, $name, $($components),+);
+        $crate::n_tuple_from_impl!(i8, f32, $name, $($components),+);
+        $crate::n_tuple_from_impl!(i8, f64, $name, $($components),+);
+        $crate::n_tuple_from_impl!(i16, i32, $name, $($components),+);
+        $crate::n_tuple_from_impl!(i16, i64, $name, $($components),+);
+        $crate::n_tuple_from_impl!(i16, i128, $name, $($components),+);
+        $crate::n_tuple_from_impl!(i16, isize, $name, $($components),+);
+        $crate::n_tuple_from_impl!(i16, f32, $name, $($components),+);
+        $crate::n_tuple_from_impl!(i16, f64, $name, $($components),+);
+        $crate::n_tuple_from_impl!(i32, i64, $name, $($components),+);
+        $crate::n_tuple_from_impl!(i32, i128, $name, $($components),+);
+        $crate::n_tuple_from_impl!(i32, f64, $name, $($components),+);
+        $crate::n_tuple_from_impl!(i64, i128, $name, $($components),+);
+        $crate::n_tuple_from_impl!(u8, i16, $name, $($components),+);
+        $crate::n_tuple_from_impl!(u8, i32, $name, $($components),+);
+        $crate::n_tuple_from_impl!(u8, i64, $name, $($components),+);
+        $crate::n_tuple_from_impl!(u8, i128, $name, $($components),+);
+        $crate::n_tuple_from_impl!(u8, isize, $name, $($components),+);
+        $crate::n_tuple_from_impl!(u8, u16, $name, $($components),+);
+        $crate::n_tuple_from_impl!(u8, u32, $name, $($components),+);
+        $crate::n_tuple_from_impl!(u8, u64, $name, $($components),+);
+        $crate::n_tuple_from_impl!(u8, u128, $name, $($components),+);
+        $crate::n_tuple_from_impl!(u8, usize, $name, $($components),+);
+        $crate::n_tuple_from_impl!(u8, f32, $name, $($components),+);
+        $crate::n_tuple_from_impl!(u8, f64, $name, $($components),+);
+        $crate::n_tuple_from_impl!(u16, i32, $name, $($components),+);
+        $crate::n_tuple_from_impl!(u16, i64, $name, $($components),+);
+        $crate::n_tuple_from_impl!(u16, i128, $name, $($components),+);
+        $crate::n_tuple_from_impl!(u16, u32, $name, $($components),+);
+        $crate::n_tuple_from_impl!(u16, u64, $name, $($components),+);
+        $crate::n_tuple_from_impl!(u16, u128, $name, $($components),+);
+        $crate::n_tuple_from_impl!(u16, usize, $name, $($components),+);
+        $crate::n_tuple_from_impl!(u16, f32, $name, $($components),+);
+        $crate::n_tuple_from_impl!(u16, f64, $name, $($components),+);
+        $crate::n_tuple_from_impl!(u32, i64, $name, $($components),+);
+        $crate::n_tuple_from_impl!(u32, i128, $name, $($components),+);
+        $crate::n_tuple_from_impl!(u32, u64, $name, $($components),+);
+        $crate::n_tuple_from_impl!(u32, u128, $name, $($components),+);
+        $crate::n_tuple_from_impl!(u32, f64, $name, $($components),+);
+        $crate::n_tuple_from_impl!(u64, i128, $name, $($components),+);
+        $crate::n_tuple_from_impl!(u64, u128, $name, $($components),+);
+    };
+}
+
+/// Generates an axis-aligned bounding box type over a point type and the
+/// vector type of differences of those points, given their component
+/// names. Attributes written before the name are applied to the struct.
+///
+/// ```
+/// use pbrt::math::{n_bounds_impl, Point2, Vector2};
+///
+/// n_bounds_impl! {
+///     /// A screen-space rectangle.
+///     Rect, Point2, Vector2, x, y
+/// }
+///
+/// let r = Rect::new(Point2::new(0, 0), Point2::new(4, 2));
+/// assert_eq!(r.diagonal(), Vector2::new(4, 2));
+/// assert!(r.inside(Point2::new(4, 1)));
+/// ```
+#[macro_export]
+macro_rules! n_bounds_impl {
+    ($(#[$meta:meta])* $name:ident, $point:ident, $vector:ident, $($components:ident),+) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+        pub struct $name<T> {
+            /// The corner with the smallest coordinates.
+            pub p_min: $point<T>,
+            /// The corner with the largest coordinates.
+            pub p_max: $point<T>,
+        }
+
+        impl<T: $crate::math::number_traits::Number> $name<T> {
+            /// Creates the box spanned by two corners, in any order.
+            pub fn new(p1: $point<T>, p2: $point<T>) -> Self {
+                Self {
+                    p_min: p1.min(p2),
+                    p_max: p1.max(p2),
+                }
+            }
+
+            /// Creates a box containing just one point.
+            pub fn from_point(p: $point<T>) -> Self {
+                Self { p_min: p, p_max: p }
+            }
+
+            /// Returns an empty box, with its minimum and maximum swapped so that
+            /// the union with any other box yields that box.
+            pub fn empty() -> Self {
+                Self {
+                    p_min: $point { $($components: T::MAX),+ },
+                    p_max: $point { $($components: T::MIN),+ },
+                }
+            }
+
+            /// Returns whether the box encloses no volume (or area).
+            pub fn is_empty(&self) -> bool {
+                $(self.p_min.$components >= self.p_max.$components) ||+
+            }
+
+            /// Returns whether the box is inverted along some axis, as the empty box
+            /// is.
+            pub fn is_degenerate(&self) -> bool {
+                $(self.p_min.$components > self.p_max.$components) ||+
+            }
+
+            /// Returns the smallest box containing both boxes.
+            pub fn union(self, b: Self) -> Self {
+                Self {
+                    p_min: self.p_min.min(b.p_min),
+                    p_max: self.p_max.max(b.p_max),
+                }
+            }
+
+            /// Returns the smallest box containing the box and a point.
+            pub fn union_point(self, p: $point<T>) -> Self {
+                Self {
+                    p_min: self.p_min.min(p),
+                    p_max: self.p_max.max(p),
+                }
+            }
+
+            /// Returns the overlap of two boxes. The result is degenerate if the boxes
+            /// do not overlap.
+            pub fn intersect(self, b: Self) -> Self {
+                Self {
+                    p_min: self.p_min.max(b.p_min),
+                    p_max: self.p_max.min(b.p_max),
+                }
+            }
+
+            /// Returns whether two boxes share any point, including on the boundary.
+            pub fn overlaps(&self, b: &Self) -> bool {
+                $(self.p_max.$components >= b.p_min.$components && self.p_min.$components <= b.p_max.$components) &&+
+            }
+
+            /// Returns whether `p` is inside the box or on its boundary.
+            pub fn inside(&self, p: $point<T>) -> bool {
+                $(p.$components >= self.p_min.$components && p.$components <= self.p_max.$components) &&+
+            }
+
+            /// Returns whether `p` is inside the box, counting points on the upper
+            /// boundary as outside. Useful for integer bounds.
+            pub fn inside_exclusive(&self, p: $point<T>) -> bool {
+                $(p.$components >= self.p_min.$components && p.$components < self.p_max.$components) &&+
+            }
+
+            /// Grows the box by `delta` on every side.
+            pub fn expand(self, delta: T) -> Self {
+                let delta = $vector { $($components: delta),+ };
+                Self {
+                    p_min: self.p_min - delta,
+                    p_max: self.p_max + delta,
+                }
+            }
+
+            /// Returns the vector from the minimum to the maximum corner.
+            pub fn diagonal(&self) -> $vector<T> {
+                self.p_max - self.p_min
+            }
+
+            /// Returns the index of the longest axis.
+            pub fn maximum_extent(&self) -> usize {
+                self.diagonal().max_index()
+            }
+        }
+
+        impl<T: $crate::math::number_traits::Float> $name<T> {
+            /// Returns the point at the fractional position `t` inside the box.
+            pub fn lerp(&self, t: $point<T>) -> $point<T> {
+                $point {
+                    $($components: (T::ONE - t.$components) * self.p_min.$components + t.$components * self.p_max.$components),+
+                }
+            }
+
+            /// Returns the position of `p` relative to the corners: the minimum
+            /// corner is at 0 and the maximum corner at 1 along every axis that has
+            /// any extent.
+            pub fn offset(&self, p: $point<T>) -> $vector<T> {
+                let mut o = p - self.p_min;
+                $(
+                    if self.p_max.$components > self.p_min.$components {
+                        o.$components /= self.p_max.$components - self.p_min.$components;
+                    }
+                )+
+                o
+            }
+
+            /// Returns the center and radius of a sphere enclosing the box.
+            pub fn bounding_sphere(&self) -> ($point<T>, T) {
+                let center = (self.p_min + self.p_max) * T::HALF;
+                let radius = if self.inside(center) {
+                    center.distance(self.p_max)
+                } else {
+                    T::ZERO
+                };
+                (center, radius)
+            }
+        }
+
+        impl<T: $crate::math::number_traits::Number> Default for $name<T> {
+            fn default() -> Self {
+                Self::empty()
+            }
+        }
+
+        impl<T> std::ops::Index<usize> for $name<T> {
+            type Output = $point<T>;
+
+            fn index(&self, index: usize) -> &Self::Output {
+                match index {
+                    0 => &self.p_min,
+                    1 => &self.p_max,
+                    _ => panic!("index out of bounds"),
+                }
+            }
+        }
+
+        impl<T> std::ops::IndexMut<usize> for $name<T> {
+            fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+                match index {
+                    0 => &mut self.p_min,
+                    1 => &mut self.p_max,
+                    _ => panic!("index out of bounds"),
+                }
+            }
+        }
+
+        impl<T: std::fmt::Display> std::fmt::Display for $name<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}({}, {})", stringify!($name), self.p_min, self.p_max)
+            }
+        }
     };
 }
 
+pub(crate) use n_bounds_impl;
+pub(crate) use n_tuple_add_assign_impl;
+pub(crate) use n_tuple_add_impl;
 #[allow(unused)]
 pub(crate) use n_tuple_component_count;
+pub(crate) use n_tuple_conversion_impl;
+pub(crate) use n_tuple_distance_impl;
 #[allow(unused)]
 pub(crate) use n_tuple_fmt_string;
 #[allow(unused)]
@@ -423,3 +920,6 @@ pub(crate) use n_tuple_nested_call;
 pub(crate) use n_tuple_permute_impl;
 #[allow(unused)]
 pub(crate) use n_tuple_scalar_mul_lhs_impl;
+pub(crate) use n_tuple_sub_assign_impl;
+pub(crate) use n_tuple_sub_impl;
+pub(crate) use n_tuple_vector_impl;
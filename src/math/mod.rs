@@ -1,3 +1,93 @@
+//! Geometry and numerics for rendering: tuple types, bounds, rays,
+//! transformations and the number traits they are generic over.
+//!
+//! Every type is re-exported here, so most code only needs
+//! `use pbrt::math::prelude::*;` or imports from `pbrt::math` directly.
+//!
+//! ```
+//! use pbrt::math::prelude::*;
+//!
+//! let t = Transform::translate(Vector3::new(1.0, 0.0, 0.0)) * Transform::scale(2.0, 2.0, 2.0);
+//! assert_eq!(t * Point3::new(1.0, 1.0, 1.0), Point3::new(3.0, 2.0, 2.0));
+//! ```
+
+pub mod animated_transform;
+pub mod bounds2;
+pub mod bounds3;
+pub mod compensated;
+pub mod frame;
+pub mod functions;
+pub mod interval;
 mod macros;
-mod vector3;
-mod number_traits;
\ No newline at end of file
+pub mod normal3;
+pub mod number_traits;
+pub mod octahedral_vector;
+pub mod point2;
+pub mod point3;
+pub mod quaternion;
+pub mod ray;
+pub mod spherical;
+pub mod square_matrix;
+pub mod transform;
+pub mod vector2;
+pub mod vector3;
+
+pub use crate::{
+    n_bounds_impl, n_tuple_add_assign_impl, n_tuple_add_impl, n_tuple_conversion_impl,
+    n_tuple_distance_impl, n_tuple_impl, n_tuple_sub_assign_impl, n_tuple_sub_impl,
+    n_tuple_vector_impl,
+};
+pub use animated_transform::AnimatedTransform;
+pub use bounds2::{Bounds2, Bounds2Iterator};
+pub use bounds3::Bounds3;
+pub use compensated::{
+    CompensatedFloat, KahanSum, difference_of_products, inner_product, sum_of_products, two_prod,
+    two_sum,
+};
+pub use frame::{Frame, coordinate_system};
+pub use functions::{
+    erf, erf_inv, evaluate_polynomial, lerp, newton_bisection, quadratic, quadratic_interval,
+    safe_acos, safe_asin, safe_sqrt, solve_linear_system_2x2,
+};
+pub use interval::Interval;
+pub use normal3::Normal3;
+pub use number_traits::{Float, Number, Signed};
+pub use octahedral_vector::OctahedralVector;
+pub use point2::Point2;
+pub use point3::Point3;
+pub use quaternion::Quaternion;
+pub use ray::{
+    Ray, RayDifferential, offset_ray_origin, spawn_ray, spawn_ray_between, spawn_ray_to,
+};
+pub use spherical::{
+    DirectionCone, abs_cos_theta, cos_d_phi, cos_phi, cos_theta, cos2_theta,
+    equal_area_sphere_to_square, equal_area_square_to_sphere, same_hemisphere, sin_phi, sin_theta,
+    sin2_theta, spherical_direction, spherical_phi, spherical_quad_area, spherical_theta,
+    spherical_triangle_area, tan_theta, tan2_theta, wrap_equal_area_pixel, wrap_equal_area_square,
+};
+pub use square_matrix::{SquareMatrix, linear_least_squares};
+pub use transform::Transform;
+pub use vector2::Vector2;
+pub use vector3::Vector3;
+
+/// The types and traits needed by almost every user of this module.
+///
+/// ```
+/// use pbrt::math::prelude::*;
+///
+/// fn midpoint<T: Float>(a: Point3<T>, b: Point3<T>) -> Point3<T> {
+///     a + (b - a) * T::HALF
+/// }
+///
+/// assert_eq!(
+///     midpoint(Point3::new(0.0, 2.0, 4.0), Point3::new(2.0, 2.0, 0.0)),
+///     Point3::new(1.0, 2.0, 2.0)
+/// );
+/// ```
+pub mod prelude {
+    pub use super::{
+        AnimatedTransform, Bounds2, Bounds3, DirectionCone, Float, Frame, Interval, Normal3,
+        Number, Point2, Point3, Quaternion, Ray, RayDifferential, Signed, SquareMatrix, Transform,
+        Vector2, Vector3,
+    };
+}
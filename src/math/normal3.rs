@@ -0,0 +1,98 @@
+//! Surface normals.
+
+use crate::math::macros::{
+    n_tuple_add_assign_impl, n_tuple_add_impl, n_tuple_conversion_impl, n_tuple_impl,
+    n_tuple_sub_assign_impl, n_tuple_sub_impl, n_tuple_vector_impl,
+};
+use crate::math::vector3::Vector3;
+
+n_tuple_impl! {
+    /// A surface normal. Unlike a [`Vector3`], a normal is transformed by
+    /// the inverse transpose of a transformation so that it stays
+    /// perpendicular to the surface.
+    ///
+    /// ```
+    /// use pbrt::math::{Normal3, Vector3};
+    ///
+    /// let n = Normal3::new(0.0, 0.0, 2.0).normalize();
+    /// assert_eq!(n, Normal3::new(0.0, 0.0, 1.0));
+    /// assert_eq!(n.face_forward(Normal3::new(0.0, 0.0, -1.0)), -n);
+    /// assert_eq!(Vector3::from(n), Vector3::new(0.0, 0.0, 1.0));
+    /// ```
+    Normal3, x, y, z
+}
+n_tuple_add_impl!(Normal3, Normal3, Normal3, x, y, z);
+n_tuple_add_assign_impl!(Normal3, Normal3, x, y, z);
+n_tuple_sub_impl!(Normal3, Normal3, Normal3, x, y, z);
+n_tuple_sub_assign_impl!(Normal3, Normal3, x, y, z);
+n_tuple_conversion_impl!(Vector3, Normal3, x, y, z);
+n_tuple_vector_impl!(Normal3, x, y, z);
+
+#[cfg(test)]
+mod tests {
+    use crate::math::normal3::Normal3;
+    use crate::math::vector3::Vector3;
+
+    #[test]
+    fn test_new() {
+        let n = Normal3::new(1, 2, 3);
+        assert_eq!(n.x, 1);
+        assert_eq!(n.y, 2);
+        assert_eq!(n.z, 3);
+    }
+
+    #[test]
+    fn test_add() {
+        assert_eq!(
+            Normal3::new(1, 0, 0) + Normal3::new(0, 1, 0),
+            Normal3::new(1, 1, 0)
+        );
+    }
+
+    #[test]
+    fn test_sub() {
+        assert_eq!(
+            Normal3::new(1, 0, 0) - Normal3::new(0, 1, 0),
+            Normal3::new(1, -1, 0)
+        );
+    }
+
+    #[test]
+    fn test_neg() {
+        assert_eq!(-Normal3::new(1, -2, 3), Normal3::new(-1, 2, -3));
+    }
+
+    #[test]
+    fn test_display() {
+        let n = Normal3::new(0, 1, 0);
+        assert_eq!(n.to_string(), "Normal3(0, 1, 0)");
+    }
+
+    #[test]
+    fn test_from_vector() {
+        assert_eq!(
+            Normal3::from(Vector3::new(0.0, 0.0, 1.0)),
+            Normal3::new(0.0, 0.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn test_dot() {
+        assert_eq!(Normal3::new(0, 1, 0).dot(Normal3::new(3, -2, 4)), -2);
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(
+            Normal3::new(0.0, 2.0, 0.0).normalize(),
+            Normal3::new(0.0, 1.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_face_forward() {
+        let n = Normal3::new(0.0, 1.0, 0.0);
+        let v = Normal3::from(Vector3::new(0.2, -0.9, 0.1));
+        assert_eq!(n.face_forward(v), Normal3::new(-0.0, -1.0, -0.0));
+    }
+}
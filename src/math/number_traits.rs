@@ -1,6 +1,9 @@
+//! Traits abstracting over the scalar types used by the math module.
+
 macro_rules! number_impl {
     ($typ:ty) => {
         impl Number for $typ {
+            const ZERO: Self = 0;
             const ONE: Self = 1;
             const MIN: Self = <$typ>::MIN;
             const MAX: Self = <$typ>::MAX;
@@ -27,8 +30,9 @@ macro_rules! signed_impl {
 }
 
 macro_rules! float_impl {
-    ($typ:ty) => {
+    ($typ:ident, $bits:ty) => {
         impl Number for $typ {
+            const ZERO: Self = 0.0;
             const ONE: Self = 1.0;
             const MIN: Self = <$typ>::MIN;
             const MAX: Self = <$typ>::MAX;
@@ -47,6 +51,28 @@ macro_rules! float_impl {
         }
 
         impl Float for $typ {
+            type Bits = $bits;
+
+            const TWO: Self = 2.0;
+            const HALF: Self = 0.5;
+            const PI: Self = std::$typ::consts::PI;
+            const EPSILON: Self = <$typ>::EPSILON;
+            const MACHINE_EPSILON: Self = <$typ>::EPSILON * 0.5;
+            const ONE_MINUS_EPSILON: Self = 1.0 - <$typ>::EPSILON * 0.5;
+            const INFINITY: Self = <$typ>::INFINITY;
+            const NEG_INFINITY: Self = <$typ>::NEG_INFINITY;
+            const NAN: Self = <$typ>::NAN;
+            const SIGNIFICAND_BITS: u32 = <$typ>::MANTISSA_DIGITS - 1;
+            const EXPONENT_BIAS: i32 = <$typ>::MAX_EXP - 1;
+
+            fn is_infinite(&self) -> bool {
+                <$typ>::is_infinite(*self)
+            }
+
+            fn is_finite(&self) -> bool {
+                <$typ>::is_finite(*self)
+            }
+
             fn floor(self) -> Self {
                 self.floor()
             }
@@ -54,29 +80,275 @@ macro_rules! float_impl {
             fn ceil(self) -> Self {
                 self.ceil()
             }
+
+            fn sqrt(self) -> Self {
+                self.sqrt()
+            }
+
+            fn asin(self) -> Self {
+                self.asin()
+            }
+
+            fn acos(self) -> Self {
+                self.acos()
+            }
+
+            fn sin(self) -> Self {
+                self.sin()
+            }
+
+            fn cos(self) -> Self {
+                self.cos()
+            }
+
+            fn tan(self) -> Self {
+                self.tan()
+            }
+
+            fn atan(self) -> Self {
+                self.atan()
+            }
+
+            fn atan2(self, other: Self) -> Self {
+                self.atan2(other)
+            }
+
+            fn exp(self) -> Self {
+                self.exp()
+            }
+
+            fn ln(self) -> Self {
+                self.ln()
+            }
+
+            fn powf(self, n: Self) -> Self {
+                self.powf(n)
+            }
+
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                self.mul_add(a, b)
+            }
+
+            fn copysign(self, sign: Self) -> Self {
+                self.copysign(sign)
+            }
+
+            fn next_float_up(self) -> Self {
+                self.next_up()
+            }
+
+            fn next_float_down(self) -> Self {
+                self.next_down()
+            }
+
+            fn to_bits(self) -> Self::Bits {
+                self.to_bits()
+            }
+
+            fn from_bits(bits: Self::Bits) -> Self {
+                <$typ>::from_bits(bits)
+            }
+
+            fn exponent(self) -> i32 {
+                let biased = (self.to_bits() >> Self::SIGNIFICAND_BITS) as i32;
+                (biased & (2 * Self::EXPONENT_BIAS + 1)) - Self::EXPONENT_BIAS
+            }
+
+            fn significand(self) -> Self::Bits {
+                self.to_bits() & ((1 << Self::SIGNIFICAND_BITS) - 1)
+            }
+
+            fn gamma(n: i32) -> Self {
+                let n = n as $typ;
+                n * Self::MACHINE_EPSILON / (1.0 - n * Self::MACHINE_EPSILON)
+            }
+
+            fn to_radians(self) -> Self {
+                self.to_radians()
+            }
+
+            fn to_degrees(self) -> Self {
+                self.to_degrees()
+            }
+
+            fn from_f64(v: f64) -> Self {
+                v as $typ
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
         }
     };
 }
 
-pub trait Number {
+/// The scalar types tuples, matrices and bounds are generic over: all
+/// primitive integer and floating-point types, and [`Interval`].
+///
+/// Implementing it for a custom scalar type makes all generic math types
+/// usable with that type.
+///
+/// ```
+/// use pbrt::math::{Number, Vector2};
+///
+/// fn sum_of_components<T: Number>(v: Vector2<T>) -> T {
+///     v.x + v.y
+/// }
+///
+/// assert_eq!(sum_of_components(Vector2::new(2u8, 3)), 5);
+/// assert_eq!(u16::MAX, <u16 as Number>::MAX);
+/// ```
+///
+/// [`Interval`]: crate::math::Interval
+pub trait Number:
+    Copy
+    + PartialOrd
+    + std::ops::Add<Output = Self>
+    + std::ops::Sub<Output = Self>
+    + std::ops::Mul<Output = Self>
+    + std::ops::Div<Output = Self>
+    + std::ops::AddAssign
+    + std::ops::SubAssign
+    + std::ops::MulAssign
+    + std::ops::DivAssign
+    + std::iter::Sum
+    + std::iter::Product
+{
+    /// The additive identity.
+    const ZERO: Self;
+    /// The multiplicative identity.
     const ONE: Self;
+    /// The smallest finite value.
     const MIN: Self;
+    /// The largest finite value.
     const MAX: Self;
+
+    /// Returns whether the value is NaN, which is never the case for
+    /// integers.
     fn is_nan(&self) -> bool {
         false
     }
 
+    /// Returns the smaller of two values.
     fn min(self, b: Self) -> Self;
+    /// Returns the larger of two values.
     fn max(self, b: Self) -> Self;
 }
 
-pub trait Signed: Number {
+/// A [`Number`] that can be negative.
+///
+/// ```
+/// use pbrt::math::{Signed, Vector3};
+///
+/// assert_eq!(Signed::abs(-3i32), 3);
+/// assert_eq!(Vector3::new(-1.0, 2.0, -3.0).abs(), Vector3::new(1.0, 2.0, 3.0));
+/// ```
+pub trait Signed: Number + std::ops::Neg<Output = Self> {
+    /// Returns the absolute value.
     fn abs(self) -> Self;
 }
 
-pub trait Float: Number {
+/// A floating-point [`Number`], with the transcendental functions and the
+/// bit-level access needed for robust geometric computations.
+///
+/// ```
+/// use pbrt::math::Float;
+///
+/// fn hypot<T: Float>(a: T, b: T) -> T {
+///     a.mul_add(a, b * b).sqrt()
+/// }
+///
+/// assert_eq!(hypot(3.0f32, 4.0), 5.0);
+/// assert!(1.0f64.next_float_up() > 1.0);
+/// assert_eq!(<f32 as Float>::gamma(1), f32::EPSILON / 2.0 / (1.0 - f32::EPSILON / 2.0));
+/// ```
+pub trait Float: Signed {
+    /// The unsigned integer type with the same size.
+    type Bits;
+
+    /// Two.
+    const TWO: Self;
+    /// One half.
+    const HALF: Self;
+    /// Archimedes' constant.
+    const PI: Self;
+    /// The difference between one and the next larger representable value.
+    const EPSILON: Self;
+    /// The maximum relative rounding error of an arithmetic operation, half
+    /// of [`EPSILON`](Float::EPSILON).
+    const MACHINE_EPSILON: Self;
+    /// The largest representable value less than one.
+    const ONE_MINUS_EPSILON: Self;
+    /// Positive infinity.
+    const INFINITY: Self;
+    /// Negative infinity.
+    const NEG_INFINITY: Self;
+    /// Not a number.
+    const NAN: Self;
+    /// The number of explicitly stored significand bits.
+    const SIGNIFICAND_BITS: u32;
+    /// The bias of the stored exponent.
+    const EXPONENT_BIAS: i32;
+
+    /// Returns whether the value is positive or negative infinity.
+    fn is_infinite(&self) -> bool;
+    /// Returns whether the value is neither infinite nor NaN.
+    fn is_finite(&self) -> bool;
+    /// Returns the largest integer less than or equal to the value.
     fn floor(self) -> Self;
+    /// Returns the smallest integer greater than or equal to the value.
     fn ceil(self) -> Self;
+    /// Returns the square root.
+    fn sqrt(self) -> Self;
+    /// Returns the arcsine, in radians.
+    fn asin(self) -> Self;
+    /// Returns the arccosine, in radians.
+    fn acos(self) -> Self;
+    /// Returns the sine of an angle in radians.
+    fn sin(self) -> Self;
+    /// Returns the cosine of an angle in radians.
+    fn cos(self) -> Self;
+    /// Returns the tangent of an angle in radians.
+    fn tan(self) -> Self;
+    /// Returns the arctangent, in radians.
+    fn atan(self) -> Self;
+    /// Returns the four-quadrant arctangent of `self / other`, in radians.
+    fn atan2(self, other: Self) -> Self;
+    /// Returns `e` raised to the value.
+    fn exp(self) -> Self;
+    /// Returns the natural logarithm.
+    fn ln(self) -> Self;
+    /// Raises the value to a floating-point power.
+    fn powf(self, n: Self) -> Self;
+    /// Returns `self * a + b` with a single rounding.
+    fn mul_add(self, a: Self, b: Self) -> Self;
+    /// Returns the magnitude of `self` with the sign of `sign`.
+    fn copysign(self, sign: Self) -> Self;
+    /// Returns the next larger representable value. Negative zero steps to
+    /// the smallest positive value, and infinity stays put.
+    fn next_float_up(self) -> Self;
+    /// Returns the next smaller representable value.
+    fn next_float_down(self) -> Self;
+    /// Returns the raw bit pattern.
+    fn to_bits(self) -> Self::Bits;
+    /// Creates a value from its raw bit pattern.
+    fn from_bits(bits: Self::Bits) -> Self;
+    /// Returns the unbiased exponent.
+    fn exponent(self) -> i32;
+    /// Returns the stored significand bits, without the implicit leading
+    /// one.
+    fn significand(self) -> Self::Bits;
+    /// Returns the bound `n * ε / (1 - n * ε)` on the relative error of `n`
+    /// chained roundings, with `ε` the machine epsilon.
+    fn gamma(n: i32) -> Self;
+    /// Converts degrees to radians.
+    fn to_radians(self) -> Self;
+    /// Converts radians to degrees.
+    fn to_degrees(self) -> Self;
+    /// Converts from `f64`, rounding to the nearest value.
+    fn from_f64(v: f64) -> Self;
+    /// Converts to `f64`.
+    fn to_f64(self) -> f64;
 }
 
 number_impl!(i8);
@@ -97,7 +369,92 @@ number_impl!(u32);
 number_impl!(u64);
 number_impl!(u128);
 number_impl!(usize);
-float_impl!(f32);
+float_impl!(f32, u32);
 signed_impl!(f32);
-float_impl!(f64);
+float_impl!(f64, u64);
 signed_impl!(f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::math::number_traits::{Float, Number};
+
+    #[test]
+    fn test_next_float_up() {
+        assert_eq!(Float::next_float_up(1.0f32), 1.0 + f32::EPSILON);
+        assert_eq!(Float::next_float_up(-0.0f32), f32::from_bits(1));
+        assert_eq!(Float::next_float_up(0.0f64), f64::from_bits(1));
+        assert_eq!(Float::next_float_up(f32::INFINITY), f32::INFINITY);
+        assert_eq!(Float::next_float_up(f32::MAX), f32::INFINITY);
+    }
+
+    #[test]
+    fn test_next_float_down() {
+        assert_eq!(Float::next_float_down(1.0f64), 1.0 - f64::EPSILON / 2.0);
+        assert_eq!(Float::next_float_down(0.0f32), -f32::from_bits(1));
+        assert_eq!(Float::next_float_down(f64::NEG_INFINITY), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_bits() {
+        assert_eq!(Float::to_bits(1.0f32), 0x3f80_0000);
+        assert_eq!(<f32 as Float>::from_bits(0x3f80_0000), 1.0);
+        assert_eq!(Float::to_bits(-2.0f64), 0xc000_0000_0000_0000);
+    }
+
+    #[test]
+    fn test_exponent() {
+        assert_eq!(Float::exponent(1.0f32), 0);
+        assert_eq!(Float::exponent(-8.5f32), 3);
+        assert_eq!(Float::exponent(0.25f64), -2);
+        assert_eq!(Float::exponent(1e300f64), 996);
+    }
+
+    #[test]
+    fn test_significand() {
+        assert_eq!(Float::significand(1.0f32), 0);
+        assert_eq!(Float::significand(1.5f32), 1 << 22);
+        assert_eq!(Float::significand(-1.75f64), 3 << 50);
+    }
+
+    #[test]
+    fn test_one_minus_epsilon() {
+        assert_eq!(
+            f32::ONE_MINUS_EPSILON,
+            <f32 as Float>::from_bits(0x3f7f_ffff)
+        );
+        assert_eq!(f64::ONE_MINUS_EPSILON.next_float_up(), 1.0);
+    }
+
+    #[test]
+    fn test_gamma() {
+        assert_eq!(<f32 as Float>::gamma(0), 0.0);
+        let eps = f32::MACHINE_EPSILON;
+        assert_eq!(<f32 as Float>::gamma(3), 3.0 * eps / (1.0 - 3.0 * eps));
+        assert!(<f64 as Float>::gamma(5) > 5.0 * f64::MACHINE_EPSILON);
+    }
+
+    #[test]
+    fn test_copysign() {
+        assert_eq!(Float::copysign(2.5f32, -0.0), -2.5);
+        assert_eq!(Float::copysign(-2.5f64, 1.0), 2.5);
+    }
+
+    #[test]
+    fn test_constants() {
+        assert_eq!(<i32 as Number>::ZERO, 0);
+        assert_eq!(<u8 as Number>::ONE, 1);
+        assert_eq!(<f32 as Number>::ZERO, 0.0);
+        assert_eq!(<f64 as Float>::TWO, 2.0);
+        assert_eq!(<f64 as Float>::HALF * <f64 as Float>::TWO, 1.0);
+    }
+
+    #[test]
+    fn test_generic_arithmetic() {
+        fn mean<T: Number>(values: &[T]) -> T {
+            let count = values.iter().map(|_| T::ONE).sum::<T>();
+            values.iter().copied().sum::<T>() / count
+        }
+        assert_eq!(mean(&[1, 2, 6]), 3);
+        assert_eq!(mean(&[0.5, 1.5]), 1.0);
+    }
+}
@@ -0,0 +1,98 @@
+//! Two-dimensional points.
+
+use crate::math::macros::{
+    n_tuple_add_assign_impl, n_tuple_add_impl, n_tuple_conversion_impl, n_tuple_distance_impl,
+    n_tuple_impl, n_tuple_sub_assign_impl, n_tuple_sub_impl,
+};
+use crate::math::vector2::Vector2;
+
+n_tuple_impl! {
+    /// A position in 2D space, such as a location on the film or a sample
+    /// in the unit square.
+    ///
+    /// ```
+    /// use pbrt::math::{Point2, Vector2};
+    ///
+    /// let p = Point2::new(1.0, 2.0);
+    /// assert_eq!(p + Vector2::new(1.0, 1.0), Point2::new(2.0, 3.0));
+    /// assert_eq!(Point2::new(4.0, 6.0) - p, Vector2::new(3.0, 4.0));
+    /// assert_eq!(p.distance(Point2::new(4.0, 6.0)), 5.0);
+    /// ```
+    Point2, x, y
+}
+n_tuple_add_impl!(Point2, Vector2, Point2, x, y);
+n_tuple_add_assign_impl!(Point2, Vector2, x, y);
+n_tuple_sub_impl!(Point2, Vector2, Point2, x, y);
+n_tuple_sub_assign_impl!(Point2, Vector2, x, y);
+n_tuple_sub_impl!(Point2, Point2, Vector2, x, y);
+// Adding two points is not meaningful on its own, but weighted sums of points
+// (e.g. lerp or barycentric interpolation) need it.
+n_tuple_add_impl!(Point2, Point2, Point2, x, y);
+n_tuple_conversion_impl!(Vector2, Point2, x, y);
+n_tuple_distance_impl!(Point2);
+
+#[cfg(test)]
+mod tests {
+    use crate::math::point2::Point2;
+    use crate::math::vector2::Vector2;
+
+    #[test]
+    fn test_new() {
+        let p = Point2::new(1, 2);
+        assert_eq!(p.x, 1);
+        assert_eq!(p.y, 2);
+    }
+
+    #[test]
+    fn test_add_vector() {
+        assert_eq!(Point2::new(1, 2) + Vector2::new(-1, 1), Point2::new(0, 3));
+    }
+
+    #[test]
+    fn test_add_assign_vector() {
+        let mut p = Point2::new(1, 2);
+        p += Vector2::new(-1, 1);
+        assert_eq!(p, Point2::new(0, 3));
+    }
+
+    #[test]
+    fn test_sub_vector() {
+        assert_eq!(Point2::new(1, 2) - Vector2::new(-1, 1), Point2::new(2, 1));
+    }
+
+    #[test]
+    fn test_sub_assign_vector() {
+        let mut p = Point2::new(1, 2);
+        p -= Vector2::new(-1, 1);
+        assert_eq!(p, Point2::new(2, 1));
+    }
+
+    #[test]
+    fn test_sub_point() {
+        assert_eq!(Point2::new(4, 2) - Point2::new(1, 3), Vector2::new(3, -1));
+    }
+
+    #[test]
+    fn test_permute() {
+        assert_eq!(
+            Point2::new(1.2, -0.5).permute(&[1, 0]),
+            Point2::new(-0.5, 1.2)
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let p = Point2::new(19, -4);
+        assert_eq!(p.to_string(), "Point2(19, -4)");
+    }
+
+    #[test]
+    fn test_from_vector() {
+        assert_eq!(Point2::from(Vector2::new(1, -2)), Point2::new(1, -2));
+    }
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(Point2::new(1.0, 2.0).distance(Point2::new(4.0, 6.0)), 5.0);
+    }
+}
@@ -0,0 +1,135 @@
+//! Three-dimensional points.
+
+use crate::math::macros::{
+    n_tuple_add_assign_impl, n_tuple_add_impl, n_tuple_conversion_impl, n_tuple_distance_impl,
+    n_tuple_impl, n_tuple_sub_assign_impl, n_tuple_sub_impl,
+};
+use crate::math::vector3::Vector3;
+
+n_tuple_impl! {
+    /// A position in 3D space.
+    ///
+    /// Subtracting two points gives a [`Vector3`], and vectors can be added
+    /// to or subtracted from points.
+    ///
+    /// ```
+    /// use pbrt::math::{Point3, Vector3};
+    ///
+    /// let p = Point3::new(1.0, 2.0, 3.0);
+    /// let q = p + Vector3::new(0.0, 3.0, 4.0);
+    /// assert_eq!(q - p, Vector3::new(0.0, 3.0, 4.0));
+    /// assert_eq!(p.distance(q), 5.0);
+    /// assert_eq!(p.lerp(q, 0.5), Point3::new(1.0, 3.5, 5.0));
+    /// ```
+    Point3, x, y, z
+}
+n_tuple_add_impl!(Point3, Vector3, Point3, x, y, z);
+n_tuple_add_assign_impl!(Point3, Vector3, x, y, z);
+n_tuple_sub_impl!(Point3, Vector3, Point3, x, y, z);
+n_tuple_sub_assign_impl!(Point3, Vector3, x, y, z);
+n_tuple_sub_impl!(Point3, Point3, Vector3, x, y, z);
+// Adding two points is not meaningful on its own, but weighted sums of points
+// (e.g. lerp or barycentric interpolation) need it.
+n_tuple_add_impl!(Point3, Point3, Point3, x, y, z);
+n_tuple_conversion_impl!(Vector3, Point3, x, y, z);
+n_tuple_distance_impl!(Point3);
+
+#[cfg(test)]
+mod tests {
+    use crate::math::point3::Point3;
+    use crate::math::vector3::Vector3;
+
+    #[test]
+    fn test_new() {
+        let p = Point3::new(1, 2, 3);
+        assert_eq!(p.x, 1);
+        assert_eq!(p.y, 2);
+        assert_eq!(p.z, 3);
+    }
+
+    #[test]
+    fn test_add_vector() {
+        assert_eq!(
+            Point3::new(1, 2, 3) + Vector3::new(-1, 1, 2),
+            Point3::new(0, 3, 5)
+        );
+    }
+
+    #[test]
+    fn test_add_assign_vector() {
+        let mut p = Point3::new(1, 2, 3);
+        p += Vector3::new(-1, 1, 2);
+        assert_eq!(p, Point3::new(0, 3, 5));
+    }
+
+    #[test]
+    fn test_add_point() {
+        assert_eq!(
+            Point3::new(1.0, 2.0, 3.0) * 0.5 + Point3::new(3.0, 2.0, 1.0) * 0.5,
+            Point3::new(2.0, 2.0, 2.0)
+        );
+    }
+
+    #[test]
+    fn test_sub_vector() {
+        assert_eq!(
+            Point3::new(1, 2, 3) - Vector3::new(-1, 1, 2),
+            Point3::new(2, 1, 1)
+        );
+    }
+
+    #[test]
+    fn test_sub_assign_vector() {
+        let mut p = Point3::new(1, 2, 3);
+        p -= Vector3::new(-1, 1, 2);
+        assert_eq!(p, Point3::new(2, 1, 1));
+    }
+
+    #[test]
+    fn test_sub_point() {
+        assert_eq!(
+            Point3::new(4, 2, 0) - Point3::new(1, 3, -2),
+            Vector3::new(3, -1, 2)
+        );
+    }
+
+    #[test]
+    fn test_lerp() {
+        assert_eq!(
+            Point3::new(1.0, 2.0, 4.0).lerp(Point3::new(-2.0, 1.0, 5.0), 0.2),
+            Point3::new(0.4, 1.8, 4.2)
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let p = Point3::new(19, -4, 0);
+        assert_eq!(p.to_string(), "Point3(19, -4, 0)");
+    }
+
+    #[test]
+    fn test_from_vector() {
+        assert_eq!(Point3::from(Vector3::new(1, -2, 3)), Point3::new(1, -2, 3));
+    }
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(
+            Point3::new(1.0, 2.0, 3.0).distance(Point3::new(3.0, 5.0, 9.0)),
+            7.0
+        );
+        assert_eq!(
+            Point3::new(1.0, 2.0, 3.0).distance_squared(Point3::new(3.0, 5.0, 9.0)),
+            49.0
+        );
+    }
+
+    #[test]
+    fn test_sum() {
+        let ps = [Point3::new(1.0, 2.0, 3.0), Point3::new(3.0, 0.0, -1.0)];
+        assert_eq!(
+            ps.iter().sum::<Point3<f64>>() / 2.0,
+            Point3::new(2.0, 1.0, 1.0)
+        );
+    }
+}
@@ -0,0 +1,341 @@
+//! Quaternions for representing rotations.
+
+use crate::math::functions::{safe_asin, safe_sqrt};
+use crate::math::number_traits::Float;
+use crate::math::square_matrix::SquareMatrix;
+use crate::math::transform::Transform;
+use crate::math::vector3::Vector3;
+
+/// A quaternion, used to represent and smoothly interpolate rotations.
+///
+/// ```
+/// use pbrt::math::{Quaternion, Transform, Vector3};
+///
+/// let a = Quaternion::from_transform(&Transform::rotate_z(0.0));
+/// let b = Quaternion::from_transform(&Transform::rotate_z(90.0));
+/// let halfway = a.slerp(b, 0.5).to_transform();
+/// let v = halfway * Vector3::new(1.0, 0.0, 0.0);
+/// let expected = Transform::rotate_z(45.0) * Vector3::new(1.0, 0.0, 0.0);
+/// assert!((v - expected).length() < 1e-12);
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Quaternion<T> {
+    /// The imaginary part.
+    pub v: Vector3<T>,
+    /// The real part.
+    pub w: T,
+}
+
+impl<T: Float> Quaternion<T> {
+    /// Creates a quaternion from its imaginary and real parts.
+    pub fn new(v: Vector3<T>, w: T) -> Self {
+        Self { v, w }
+    }
+
+    /// Returns the quaternion of the identity rotation.
+    pub fn identity() -> Self {
+        Self {
+            v: Vector3::new(T::ZERO, T::ZERO, T::ZERO),
+            w: T::ONE,
+        }
+    }
+
+    /// Returns the dot product, treating quaternions as 4D vectors.
+    pub fn dot(self, rhs: Self) -> T {
+        self.v.dot(rhs.v) + self.w * rhs.w
+    }
+
+    /// Returns the length, treating quaternions as 4D vectors.
+    pub fn length(self) -> T {
+        self.dot(self).sqrt()
+    }
+
+    /// Returns the quaternion scaled to unit length.
+    pub fn normalize(self) -> Self {
+        self / self.length()
+    }
+
+    /// Returns the angle in radians between two unit quaternions as 4D
+    /// vectors, which is half the angle between their rotations.
+    pub fn angle_between(self, b: Self) -> T {
+        if self.dot(b) < T::ZERO {
+            T::PI - T::TWO * safe_asin((self + b).length() * T::HALF)
+        } else {
+            T::TWO * safe_asin((b - self).length() * T::HALF)
+        }
+    }
+
+    /// Spherically interpolates between two unit quaternions, at constant
+    /// angular speed. Written in terms of `sin(x) / x` so that it stays
+    /// accurate for nearly identical rotations.
+    pub fn slerp(self, b: Self, t: T) -> Self {
+        let sin_x_over_x = |x: T| {
+            if T::ONE - x * x == T::ONE {
+                T::ONE
+            } else {
+                x.sin() / x
+            }
+        };
+        let theta = self.angle_between(b);
+        let sin_theta_over_theta = sin_x_over_x(theta);
+        self * (T::ONE - t) * sin_x_over_x((T::ONE - t) * theta) / sin_theta_over_theta
+            + b * t * sin_x_over_x(t * theta) / sin_theta_over_theta
+    }
+
+    /// Returns the rotation represented by a unit quaternion.
+    pub fn to_transform(self) -> Transform<T> {
+        let Vector3 { x, y, z } = self.v;
+        let (xx, yy, zz) = (x * x, y * y, z * z);
+        let (xy, xz, yz) = (x * y, x * z, y * z);
+        let (wx, wy, wz) = (x * self.w, y * self.w, z * self.w);
+        let m = SquareMatrix::new([
+            [
+                T::ONE - T::TWO * (yy + zz),
+                T::TWO * (xy - wz),
+                T::TWO * (xz + wy),
+                T::ZERO,
+            ],
+            [
+                T::TWO * (xy + wz),
+                T::ONE - T::TWO * (xx + zz),
+                T::TWO * (yz - wx),
+                T::ZERO,
+            ],
+            [
+                T::TWO * (xz - wy),
+                T::TWO * (yz + wx),
+                T::ONE - T::TWO * (xx + yy),
+                T::ZERO,
+            ],
+            [T::ZERO, T::ZERO, T::ZERO, T::ONE],
+        ]);
+        Transform::from_matrices(m, m.transpose())
+    }
+
+    /// Returns the unit quaternion of a transform whose upper 3x3 matrix is
+    /// a pure rotation.
+    pub fn from_transform(t: &Transform<T>) -> Self {
+        let m = t.matrix();
+        let trace = m[0][0] + m[1][1] + m[2][2];
+        if trace > T::ZERO {
+            let s = (trace + T::ONE).sqrt();
+            let w = s * T::HALF;
+            let s = T::HALF / s;
+            Self {
+                v: Vector3::new(
+                    (m[2][1] - m[1][2]) * s,
+                    (m[0][2] - m[2][0]) * s,
+                    (m[1][0] - m[0][1]) * s,
+                ),
+                w,
+            }
+        } else {
+            let next = [1, 2, 0];
+            let mut i = 0;
+            if m[1][1] > m[0][0] {
+                i = 1;
+            }
+            if m[2][2] > m[i][i] {
+                i = 2;
+            }
+            let j = next[i];
+            let k = next[j];
+            let mut s = safe_sqrt((m[i][i] - (m[j][j] + m[k][k])) + T::ONE);
+            let mut q = Vector3::new(T::ZERO, T::ZERO, T::ZERO);
+            q[i] = s * T::HALF;
+            if s != T::ZERO {
+                s = T::HALF / s;
+            }
+            q[j] = (m[j][i] + m[i][j]) * s;
+            q[k] = (m[k][i] + m[i][k]) * s;
+            Self {
+                v: q,
+                w: (m[k][j] - m[j][k]) * s,
+            }
+        }
+    }
+}
+
+impl<T: Float> std::ops::Add for Quaternion<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            v: self.v + rhs.v,
+            w: self.w + rhs.w,
+        }
+    }
+}
+
+impl<T: Float> std::ops::Sub for Quaternion<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            v: self.v - rhs.v,
+            w: self.w - rhs.w,
+        }
+    }
+}
+
+impl<T: Float> std::ops::Neg for Quaternion<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            v: -self.v,
+            w: -self.w,
+        }
+    }
+}
+
+impl<T: Float> std::ops::Mul<T> for Quaternion<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self {
+            v: self.v * rhs,
+            w: self.w * rhs,
+        }
+    }
+}
+
+impl<T: Float> std::ops::Div<T> for Quaternion<T> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Self {
+            v: self.v / rhs,
+            w: self.w / rhs,
+        }
+    }
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for Quaternion<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Quaternion({}, {}, {}, {})",
+            self.v.x, self.v.y, self.v.z, self.w
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::quaternion::Quaternion;
+    use crate::math::transform::Transform;
+    use crate::math::vector3::Vector3;
+    use crate::testing::assert_vector_near;
+
+    #[test]
+    fn test_identity() {
+        let q = Quaternion::<f64>::identity();
+        assert_eq!(q.length(), 1.0);
+        assert!(q.to_transform().is_identity());
+    }
+
+    #[test]
+    fn test_dot() {
+        let a = Quaternion::new(Vector3::new(1.0, 2.0, 3.0), 4.0);
+        let b = Quaternion::new(Vector3::new(-1.0, 0.5, 2.0), 0.25);
+        assert_eq!(a.dot(b), 7.0);
+    }
+
+    #[test]
+    fn test_normalize() {
+        let q = Quaternion::new(Vector3::new(0.0, 3.0, 0.0), 4.0).normalize();
+        assert_eq!(q, Quaternion::new(Vector3::new(0.0, 0.6, 0.0), 0.8));
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Quaternion::new(Vector3::new(1.0, 2.0, 3.0), 4.0);
+        let b = Quaternion::new(Vector3::new(0.5, 0.5, 0.5), 0.5);
+        assert_eq!(a + b, Quaternion::new(Vector3::new(1.5, 2.5, 3.5), 4.5));
+        assert_eq!(a - b, Quaternion::new(Vector3::new(0.5, 1.5, 2.5), 3.5));
+        assert_eq!(-b, Quaternion::new(Vector3::new(-0.5, -0.5, -0.5), -0.5));
+        assert_eq!(b * 2.0, Quaternion::new(Vector3::new(1.0, 1.0, 1.0), 1.0));
+        assert_eq!(a / 2.0, Quaternion::new(Vector3::new(0.5, 1.0, 1.5), 2.0));
+    }
+
+    #[test]
+    fn test_to_transform() {
+        let half_angle = 0.25 * std::f64::consts::FRAC_PI_2;
+        let q = Quaternion::new(Vector3::new(0.0, 0.0, half_angle.sin()), half_angle.cos());
+        let t = q.to_transform();
+        assert_vector_near(
+            t * Vector3::new(1.0, 0.0, 0.0),
+            Transform::rotate_z(45.0) * Vector3::new(1.0, 0.0, 0.0),
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn test_from_transform_round_trip() {
+        let rotations = [
+            Transform::rotate(30.0f64, Vector3::new(1.0, 2.0, 3.0)),
+            Transform::rotate(179.0, Vector3::new(0.0, 1.0, 0.0)),
+            Transform::rotate(180.0, Vector3::new(1.0, 0.0, 0.0)),
+            Transform::rotate(200.0, Vector3::new(-1.0, 0.3, 0.2)),
+            Transform::identity(),
+        ];
+        let v = Vector3::new(0.3, -1.2, 0.7);
+        for t in rotations {
+            let q = Quaternion::from_transform(&t);
+            assert!((q.length() - 1.0).abs() < 1e-12);
+            assert_vector_near(q.to_transform() * v, t * v, 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_angle_between() {
+        let a = Quaternion::<f64>::identity();
+        let b = Quaternion::from_transform(&Transform::rotate_x(60.0));
+        assert!((a.angle_between(b) - 30f64.to_radians()).abs() < 1e-12);
+        assert!((a.angle_between(-a) - std::f64::consts::PI).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_slerp() {
+        let a = Quaternion::from_transform(&Transform::rotate_y(10.0));
+        let b = Quaternion::from_transform(&Transform::rotate_y(90.0));
+        let v = Vector3::new(0.0, 0.0, 1.0);
+        assert_eq!(a.slerp(b, 0.0), a);
+        assert_vector_near(
+            a.slerp(b, 1.0).to_transform() * v,
+            b.to_transform() * v,
+            1e-12,
+        );
+        for (t, degrees) in [(0.25, 30.0), (0.5, 50.0), (0.75, 70.0)] {
+            let q = a.slerp(b, t);
+            assert!((q.length() - 1.0).abs() < 1e-12);
+            assert_vector_near(
+                q.to_transform() * v,
+                Transform::rotate_y(degrees) * v,
+                1e-12,
+            );
+        }
+    }
+
+    #[test]
+    fn test_slerp_nearly_identical() {
+        let a = Quaternion::from_transform(&Transform::rotate_z(1e-7f64));
+        let b = Quaternion::from_transform(&Transform::rotate_z(2e-7));
+        let q = a.slerp(b, 0.5);
+        assert!(!q.w.is_nan());
+        assert!((q.length() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            Quaternion {
+                v: Vector3::new(1, 2, 3),
+                w: 4
+            }
+            .to_string(),
+            "Quaternion(1, 2, 3, 4)"
+        );
+    }
+}
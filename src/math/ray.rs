@@ -0,0 +1,432 @@
+//! Rays and the robust spawning of rays from surfaces.
+
+use crate::math::interval::Interval;
+use crate::math::normal3::Normal3;
+use crate::math::number_traits::Float;
+use crate::math::point3::Point3;
+use crate::math::vector3::Vector3;
+
+/// A semi-infinite line `o + t * d` for `t >= 0`, at a point in time and
+/// carrying the medium its origin is in.
+///
+/// The medium is left generic since the math module knows nothing about
+/// participating media; `None` means the ray travels through vacuum.
+///
+/// ```
+/// use pbrt::math::{Point3, Ray, Vector3};
+///
+/// let r: Ray<f32> = Ray::new(Point3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 2.0, 0.0), 0.0, None);
+/// assert_eq!(r.at(1.5), Point3::new(1.0, 3.0, 0.0));
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Ray<T, M = ()> {
+    /// The origin.
+    pub o: Point3<T>,
+    /// The direction, which need not be normalized.
+    pub d: Vector3<T>,
+    /// The time the ray is traced at, for motion blur.
+    pub time: T,
+    /// The medium the origin lies in.
+    pub medium: Option<M>,
+}
+
+impl<T: Float, M> Ray<T, M> {
+    /// Creates a ray.
+    pub fn new(o: Point3<T>, d: Vector3<T>, time: T, medium: Option<M>) -> Self {
+        Self { o, d, time, medium }
+    }
+
+    /// Returns the point at parameter `t` along the ray.
+    pub fn at(&self, t: T) -> Point3<T> {
+        self.o + self.d * t
+    }
+
+    /// Returns whether the origin or direction has a NaN component.
+    pub fn has_nan(&self) -> bool {
+        self.o.has_nan() || self.d.has_nan()
+    }
+}
+
+impl<T: std::fmt::Display, M> std::fmt::Display for Ray<T, M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Ray({}, {}, {})", self.o, self.d, self.time)
+    }
+}
+
+/// A ray carrying two auxiliary rays offset by one pixel in x and y on the
+/// film, used to estimate texture footprints. Dereferences to the main
+/// [`Ray`].
+///
+/// ```
+/// use pbrt::math::{Point3, Ray, RayDifferential, Vector3};
+///
+/// let ray: Ray<f32> = Ray::new(Point3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0), 0.0, None);
+/// let mut r = RayDifferential::from(ray);
+/// r.has_differentials = true;
+/// r.rx_origin = Point3::new(1.0, 0.0, 0.0);
+/// r.ry_origin = Point3::new(0.0, 1.0, 0.0);
+/// r.rx_direction = r.d;
+/// r.ry_direction = r.d;
+/// r.scale_differentials(0.25);
+/// assert_eq!(r.rx_origin, Point3::new(0.25, 0.0, 0.0));
+/// assert_eq!(r.at(1.0), Point3::new(0.0, 0.0, 1.0));
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct RayDifferential<T, M = ()> {
+    /// The main ray.
+    pub ray: Ray<T, M>,
+    /// Whether the auxiliary rays are set.
+    pub has_differentials: bool,
+    /// The origin of the ray offset in x.
+    pub rx_origin: Point3<T>,
+    /// The origin of the ray offset in y.
+    pub ry_origin: Point3<T>,
+    /// The direction of the ray offset in x.
+    pub rx_direction: Vector3<T>,
+    /// The direction of the ray offset in y.
+    pub ry_direction: Vector3<T>,
+}
+
+impl<T: Float, M> RayDifferential<T, M> {
+    /// Creates a ray differential without auxiliary rays.
+    pub fn new(o: Point3<T>, d: Vector3<T>, time: T, medium: Option<M>) -> Self {
+        Ray::new(o, d, time, medium).into()
+    }
+
+    /// Rescales the differentials for a sample spacing of `s` pixels.
+    pub fn scale_differentials(&mut self, s: T) {
+        self.rx_origin = self.ray.o + (self.rx_origin - self.ray.o) * s;
+        self.ry_origin = self.ray.o + (self.ry_origin - self.ray.o) * s;
+        self.rx_direction = self.ray.d + (self.rx_direction - self.ray.d) * s;
+        self.ry_direction = self.ray.d + (self.ry_direction - self.ray.d) * s;
+    }
+
+    /// Returns whether the main ray or, if set, an auxiliary ray has a NaN
+    /// component.
+    pub fn has_nan(&self) -> bool {
+        self.ray.has_nan()
+            || (self.has_differentials
+                && (self.rx_origin.has_nan()
+                    || self.ry_origin.has_nan()
+                    || self.rx_direction.has_nan()
+                    || self.ry_direction.has_nan()))
+    }
+}
+
+impl<T: Float, M> From<Ray<T, M>> for RayDifferential<T, M> {
+    fn from(ray: Ray<T, M>) -> Self {
+        let zero = Vector3::new(T::ZERO, T::ZERO, T::ZERO);
+        Self {
+            ray,
+            has_differentials: false,
+            rx_origin: Point3::from(zero),
+            ry_origin: Point3::from(zero),
+            rx_direction: zero,
+            ry_direction: zero,
+        }
+    }
+}
+
+impl<T, M> std::ops::Deref for RayDifferential<T, M> {
+    type Target = Ray<T, M>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.ray
+    }
+}
+
+impl<T, M> std::ops::DerefMut for RayDifferential<T, M> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.ray
+    }
+}
+
+/// Moves a point with error bounds `pi` off the surface with normal `n`, to
+/// the side `w` points to, far enough that a ray leaving it cannot re-intersect
+/// the surface because of rounding error. The offset is along the normal, by
+/// the projection of the error box onto it, and the result is then rounded
+/// away from the surface.
+///
+/// ```
+/// use pbrt::math::{offset_ray_origin, Normal3, Point3, Vector3};
+///
+/// let p = Point3::from_value_and_error(Point3::new(0.0f32, 0.0, 0.0), Vector3::new(0.1, 0.1, 0.1));
+/// let n = Normal3::new(0.0, 1.0, 0.0);
+/// let above = offset_ray_origin(p, n, Vector3::new(1.0, 1.0, 0.0));
+/// assert!(above.y > 0.1);
+/// let below = offset_ray_origin(p, n, Vector3::new(1.0, -1.0, 0.0));
+/// assert!(below.y < -0.1);
+/// ```
+pub fn offset_ray_origin<T: Float>(
+    pi: Point3<Interval<T>>,
+    n: Normal3<T>,
+    w: Vector3<T>,
+) -> Point3<T> {
+    let d = n.abs().dot(Normal3::from(pi.error()));
+    let mut offset = Vector3::from(n) * d;
+    if w.dot(Vector3::from(n)) < T::ZERO {
+        offset = -offset;
+    }
+    let mut po = pi.midpoint() + offset;
+    for i in 0..3 {
+        if offset[i] > T::ZERO {
+            po[i] = po[i].next_float_up();
+        } else if offset[i] < T::ZERO {
+            po[i] = po[i].next_float_down();
+        }
+    }
+    po
+}
+
+/// Spawns a ray leaving a surface point with error bounds in direction `d`,
+/// with its origin offset by [`offset_ray_origin`].
+///
+/// ```
+/// use pbrt::math::{spawn_ray, Normal3, Point3, Ray, Vector3};
+///
+/// let p = Point3::from_value_and_error(Point3::new(0.0f32, 0.0, 1.0), Vector3::new(0.0, 0.0, 1e-6));
+/// let r: Ray<f32> = spawn_ray(p, Normal3::new(0.0, 0.0, 1.0), 0.0, Vector3::new(0.0, 0.0, -1.0));
+/// // The origin is moved below the error box of the surface point.
+/// assert!(r.o.z < 1.0 - 1e-6);
+/// ```
+pub fn spawn_ray<T: Float, M>(
+    pi: Point3<Interval<T>>,
+    n: Normal3<T>,
+    time: T,
+    d: Vector3<T>,
+) -> Ray<T, M> {
+    Ray::new(offset_ray_origin(pi, n, d), d, time, None)
+}
+
+/// Spawns a ray from a surface point towards `p_to`. The direction runs
+/// from the offset origin to `p_to` and is not normalized, so the ray
+/// reaches `p_to` at `t = 1`.
+///
+/// ```
+/// use pbrt::math::{spawn_ray_to, Normal3, Point3, Ray, Vector3};
+///
+/// let p = Point3::from_value_and_error(Point3::new(0.0f32, 0.0, 0.0), Vector3::new(0.0, 1e-3, 0.0));
+/// let to = Point3::new(0.0, 2.0, 0.0);
+/// let r: Ray<f32> = spawn_ray_to(p, Normal3::new(0.0, 1.0, 0.0), 0.0, to);
+/// assert!(r.o.y > 1e-3);
+/// assert!(r.at(1.0).distance(to) < 1e-6);
+/// ```
+pub fn spawn_ray_to<T: Float, M>(
+    p_from: Point3<Interval<T>>,
+    n: Normal3<T>,
+    time: T,
+    p_to: Point3<T>,
+) -> Ray<T, M> {
+    let o = offset_ray_origin(p_from, n, p_to - p_from.midpoint());
+    Ray::new(o, p_to - o, time, None)
+}
+
+/// Spawns a ray between two surface points, offsetting both ends so that it
+/// intersects neither surface. The ray reaches the offset `p_to` at `t = 1`.
+///
+/// ```
+/// use pbrt::math::{spawn_ray_between, Normal3, Point3, Ray, Vector3};
+///
+/// let error = Vector3::new(1e-3, 1e-3, 1e-3);
+/// let from = Point3::from_value_and_error(Point3::new(0.0f32, 0.0, 0.0), error);
+/// let to = Point3::from_value_and_error(Point3::new(0.0f32, 0.0, 1.0), error);
+/// let n = Normal3::new(0.0, 0.0, 1.0);
+/// let r: Ray<f32> = spawn_ray_between(from, n, 0.0, to, n);
+/// assert!(r.o.z > 1e-3 && r.at(1.0).z < 1.0 - 1e-3);
+/// ```
+pub fn spawn_ray_between<T: Float, M>(
+    p_from: Point3<Interval<T>>,
+    n_from: Normal3<T>,
+    time: T,
+    p_to: Point3<Interval<T>>,
+    n_to: Normal3<T>,
+) -> Ray<T, M> {
+    let pf = offset_ray_origin(p_from, n_from, p_to.midpoint() - p_from.midpoint());
+    let pt = offset_ray_origin(p_to, n_to, pf - p_to.midpoint());
+    Ray::new(pf, pt - pf, time, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::interval::Interval;
+    use crate::math::normal3::Normal3;
+    use crate::math::point3::Point3;
+    use crate::math::ray::{
+        Ray, RayDifferential, offset_ray_origin, spawn_ray, spawn_ray_between, spawn_ray_to,
+    };
+    use crate::math::transform::Transform;
+    use crate::math::vector3::Vector3;
+
+    #[test]
+    fn test_at() {
+        let r: Ray<f64> = Ray::new(
+            Point3::new(1.0, 2.0, 3.0),
+            Vector3::new(0.0, -1.0, 2.0),
+            0.5,
+            None,
+        );
+        assert_eq!(r.at(0.0), r.o);
+        assert_eq!(r.at(2.0), Point3::new(1.0, 0.0, 7.0));
+        assert!(!r.has_nan());
+    }
+
+    #[test]
+    fn test_medium() {
+        let r = Ray::new(
+            Point3::new(0.0, 0.0, 0.0),
+            Vector3::new(0.0, 0.0, 1.0),
+            0.0,
+            Some("fog"),
+        );
+        assert_eq!(r.medium, Some("fog"));
+    }
+
+    #[test]
+    fn test_display() {
+        let r: Ray<i32> = Ray {
+            o: Point3::new(0, 1, 2),
+            d: Vector3::new(1, 0, 0),
+            time: 3,
+            medium: None,
+        };
+        assert_eq!(r.to_string(), "Ray(Point3(0, 1, 2), Vector3(1, 0, 0), 3)");
+    }
+
+    #[test]
+    fn test_scale_differentials() {
+        let mut r: RayDifferential<f64> = RayDifferential::new(
+            Point3::new(0.0, 0.0, 0.0),
+            Vector3::new(0.0, 0.0, 1.0),
+            0.0,
+            None,
+        );
+        assert!(!r.has_differentials);
+        r.has_differentials = true;
+        r.rx_origin = Point3::new(1.0, 0.0, 0.0);
+        r.ry_origin = Point3::new(0.0, 2.0, 0.0);
+        r.rx_direction = Vector3::new(0.5, 0.0, 1.0);
+        r.ry_direction = Vector3::new(0.0, 0.25, 1.0);
+        r.scale_differentials(0.5);
+        assert_eq!(r.rx_origin, Point3::new(0.5, 0.0, 0.0));
+        assert_eq!(r.ry_origin, Point3::new(0.0, 1.0, 0.0));
+        assert_eq!(r.rx_direction, Vector3::new(0.25, 0.0, 1.0));
+        assert_eq!(r.ry_direction, Vector3::new(0.0, 0.125, 1.0));
+        // The main ray is reachable through Deref.
+        assert_eq!(r.at(2.0), Point3::new(0.0, 0.0, 2.0));
+    }
+
+    #[test]
+    fn test_offset_exact_point() {
+        let pi = Point3::<Interval<f32>>::from(Point3::new(1.0, 2.0, 3.0));
+        let n = Normal3::new(0.0, 0.0, 1.0);
+        // Without error there is nothing to offset; a hit at t = 0 is never
+        // reported by intersection routines.
+        let po = offset_ray_origin(pi, n, Vector3::new(0.0, 1.0, 0.1));
+        assert_eq!(po, Point3::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn test_offset_covers_error() {
+        let pi = Point3::from_value_and_error(
+            Point3::new(0.0f32, 0.0, 0.0),
+            Vector3::new(0.1, 0.2, 0.3),
+        );
+        let n = Normal3::new(1.0, 0.0, 0.0);
+        let po = offset_ray_origin(pi, n, Vector3::new(-1.0, 0.0, 0.0));
+        assert!(po.x < -0.1);
+        assert_eq!((po.y, po.z), (0.0, 0.0));
+    }
+
+    // The unit normal and a point of the image of the z = 0 plane under t,
+    // computed in f64 so that they are exact for all practical purposes.
+    fn exact_plane(t: &Transform<f32>) -> (Vector3<f64>, Vector3<f64>) {
+        let m = t.matrix();
+        let column =
+            |j: usize| Vector3::new(f64::from(m[0][j]), f64::from(m[1][j]), f64::from(m[2][j]));
+        (column(0).cross(column(1)).normalize(), column(3))
+    }
+
+    fn to_f64(v: Vector3<f32>) -> Vector3<f64> {
+        Vector3::new(f64::from(v.x), f64::from(v.y), f64::from(v.z))
+    }
+
+    #[test]
+    fn test_spawned_rays_leave_surface() {
+        let transforms = [
+            Transform::identity(),
+            Transform::translate(Vector3::new(1000.0, -250.0, 31.0))
+                * Transform::rotate(37.0, Vector3::new(1.0, 2.0, 3.0)),
+            Transform::rotate(89.0, Vector3::new(0.2, -1.0, 0.4))
+                * Transform::scale(3.0, 0.01, 7.0),
+            Transform::translate(Vector3::new(-0.3, 1e4, 2.0)) * Transform::rotate_x(60.0),
+        ];
+        let mut checked = 0;
+        for t in transforms {
+            let n = (t * Normal3::new(0.0f32, 0.0, 1.0)).normalize();
+            let tangent = (t * Vector3::new(1.0f32, 0.7, 0.0)).normalize();
+            let (n_exact, plane_origin) = exact_plane(&t);
+            for i in 0..50 {
+                let u = (i as f32 * 0.731).sin() * 100.0;
+                let v = (i as f32 * 1.379).cos() * 100.0;
+                let pi = t * Point3::<Interval<f32>>::from(Point3::new(u, v, 0.0));
+                // Directions barely grazing the surface from either side.
+                for elevation in [1e-3f32, -1e-3, 1e-2, -0.5] {
+                    let d = tangent + Vector3::from(n) * elevation;
+                    let n_dot_d = n_exact.dot(to_f64(d));
+                    let r: Ray<f32> = spawn_ray(pi, n, 0.0, d);
+                    let distance = n_exact.dot(to_f64(Vector3::from(r.o)) - plane_origin);
+                    // The origin is on the side the ray heads to (or exactly on
+                    // the plane when the hit point has no error along the
+                    // normal), and the ray moves away from the plane from there
+                    // on.
+                    let normal_error = n.abs().dot(Normal3::from(pi.error()));
+                    assert!(
+                        distance * n_dot_d > 0.0 || (normal_error == 0.0 && distance == 0.0),
+                        "origin {} at distance {distance} for direction {d}",
+                        r.o
+                    );
+                    checked += 1;
+                }
+            }
+        }
+        assert_eq!(checked, 4 * 50 * 4);
+    }
+
+    #[test]
+    fn test_spawn_ray_to() {
+        let pi = Point3::from_value_and_error(
+            Point3::new(0.0f32, 0.0, 0.0),
+            Vector3::new(1e-3, 1e-3, 1e-3),
+        );
+        let n = Normal3::new(0.0, 1.0, 0.0);
+        let p_to = Point3::new(2.0, 4.0, 0.0);
+        let r: Ray<f32> = spawn_ray_to(pi, n, 1.5, p_to);
+        // The origin moves by the 1e-3 error bound, but the ray still ends
+        // at p_to, to well within the size of the offset.
+        assert!(r.o.y > 1e-3);
+        assert_eq!(r.time, 1.5);
+        assert!(r.at(1.0).distance(p_to) < 1e-6);
+    }
+
+    #[test]
+    fn test_spawn_ray_between() {
+        let from = Point3::from_value_and_error(
+            Point3::new(0.0f32, 0.0, 0.0),
+            Vector3::new(1e-4, 1e-4, 1e-4),
+        );
+        let to = Point3::from_value_and_error(
+            Point3::new(0.0f32, 0.0, 10.0),
+            Vector3::new(1e-4, 1e-4, 1e-4),
+        );
+        let r: Ray<f32> = spawn_ray_between(
+            from,
+            Normal3::new(0.0, 0.0, 1.0),
+            0.0,
+            to,
+            Normal3::new(0.0, 0.0, 1.0),
+        );
+        // Both ends are pulled inside the gap between the two surfaces.
+        assert!(r.o.z > 1e-4);
+        assert!(r.at(1.0).z < 10.0 - 1e-4);
+    }
+}
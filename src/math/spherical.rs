@@ -0,0 +1,812 @@
+//! Spherical geometry: spherical coordinates, trigonometry of directions in
+//! a local shading frame, cones of directions, solid angles and the
+//! equal-area mapping between the unit square and the unit sphere.
+
+use crate::math::bounds3::Bounds3;
+use crate::math::functions::{evaluate_polynomial, safe_acos, safe_sqrt};
+use crate::math::number_traits::Float;
+use crate::math::point2::Point2;
+use crate::math::point3::Point3;
+use crate::math::transform::Transform;
+use crate::math::vector3::Vector3;
+
+/// Returns the unit vector with the given polar angle, expressed by its sine
+/// and cosine, and azimuth `phi` in radians. Out-of-range sines and cosines
+/// from roundoff are clamped to `[-1, 1]`.
+///
+/// ```
+/// use pbrt::math::{spherical_direction, Vector3};
+///
+/// let v = spherical_direction(1.0f64, 0.0, std::f64::consts::FRAC_PI_2);
+/// assert!((v - Vector3::new(0.0, 1.0, 0.0)).length() < 1e-12);
+/// ```
+pub fn spherical_direction<T: Float>(sin_theta: T, cos_theta: T, phi: T) -> Vector3<T> {
+    let sin_theta = sin_theta.max(-T::ONE).min(T::ONE);
+    Vector3::new(
+        sin_theta * phi.cos(),
+        sin_theta * phi.sin(),
+        cos_theta.max(-T::ONE).min(T::ONE),
+    )
+}
+
+/// Returns the polar angle of the unit vector `v`, in `[0, pi]`.
+///
+/// ```
+/// use pbrt::math::{spherical_theta, Vector3};
+///
+/// let theta = spherical_theta(Vector3::new(1.0f64, 0.0, 0.0));
+/// assert!((theta - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+/// ```
+pub fn spherical_theta<T: Float>(v: Vector3<T>) -> T {
+    safe_acos(v.z)
+}
+
+/// Returns the azimuth of `v`, in `[0, 2 pi)`.
+///
+/// ```
+/// use pbrt::math::{spherical_phi, Vector3};
+///
+/// let phi = spherical_phi(Vector3::new(0.0f64, -1.0, 0.0));
+/// assert!((phi - 1.5 * std::f64::consts::PI).abs() < 1e-12);
+/// ```
+pub fn spherical_phi<T: Float>(v: Vector3<T>) -> T {
+    let p = v.y.atan2(v.x);
+    if p < T::ZERO { p + T::TWO * T::PI } else { p }
+}
+
+/// Returns the cosine of the polar angle of the unit vector `w` in a local
+/// frame whose z axis is the pole.
+///
+/// ```
+/// use pbrt::math::{cos_theta, sin_theta, tan_theta, Vector3};
+///
+/// let w = Vector3::new(0.6f64, 0.0, 0.8);
+/// assert_eq!(cos_theta(w), 0.8);
+/// assert!((sin_theta(w) - 0.6).abs() < 1e-12);
+/// assert!((tan_theta(w) - 0.75).abs() < 1e-12);
+/// ```
+pub fn cos_theta<T: Float>(w: Vector3<T>) -> T {
+    w.z
+}
+
+/// Returns the squared cosine of the polar angle of `w`.
+pub fn cos2_theta<T: Float>(w: Vector3<T>) -> T {
+    w.z * w.z
+}
+
+/// Returns the absolute cosine of the polar angle of `w`.
+pub fn abs_cos_theta<T: Float>(w: Vector3<T>) -> T {
+    w.z.abs()
+}
+
+/// Returns the squared sine of the polar angle of `w`.
+pub fn sin2_theta<T: Float>(w: Vector3<T>) -> T {
+    (T::ONE - cos2_theta(w)).max(T::ZERO)
+}
+
+/// Returns the sine of the polar angle of `w`.
+pub fn sin_theta<T: Float>(w: Vector3<T>) -> T {
+    sin2_theta(w).sqrt()
+}
+
+/// Returns the tangent of the polar angle of `w`.
+pub fn tan_theta<T: Float>(w: Vector3<T>) -> T {
+    sin_theta(w) / cos_theta(w)
+}
+
+/// Returns the squared tangent of the polar angle of `w`.
+pub fn tan2_theta<T: Float>(w: Vector3<T>) -> T {
+    sin2_theta(w) / cos2_theta(w)
+}
+
+/// Returns the cosine of the azimuth of `w`, or one when `w` is along the
+/// pole and the azimuth is undefined.
+///
+/// ```
+/// use pbrt::math::{cos_phi, sin_phi, Vector3};
+///
+/// let w = Vector3::new(0.0f64, 0.6, 0.8);
+/// assert!(cos_phi(w).abs() < 1e-12);
+/// assert!((sin_phi(w) - 1.0).abs() < 1e-12);
+/// ```
+pub fn cos_phi<T: Float>(w: Vector3<T>) -> T {
+    let sin_theta = sin_theta(w);
+    if sin_theta == T::ZERO {
+        T::ONE
+    } else {
+        (w.x / sin_theta).max(-T::ONE).min(T::ONE)
+    }
+}
+
+/// Returns the sine of the azimuth of `w`, or zero when `w` is along the
+/// pole and the azimuth is undefined.
+pub fn sin_phi<T: Float>(w: Vector3<T>) -> T {
+    let sin_theta = sin_theta(w);
+    if sin_theta == T::ZERO {
+        T::ZERO
+    } else {
+        (w.y / sin_theta).max(-T::ONE).min(T::ONE)
+    }
+}
+
+/// Returns the cosine of the difference in azimuth between `wa` and `wb`.
+pub fn cos_d_phi<T: Float>(wa: Vector3<T>, wb: Vector3<T>) -> T {
+    let waxy = wa.x * wa.x + wa.y * wa.y;
+    let wbxy = wb.x * wb.x + wb.y * wb.y;
+    if waxy == T::ZERO || wbxy == T::ZERO {
+        return T::ONE;
+    }
+    ((wa.x * wb.x + wa.y * wb.y) / (waxy * wbxy).sqrt())
+        .max(-T::ONE)
+        .min(T::ONE)
+}
+
+/// Returns whether `w` and `wp` lie strictly in the same hemisphere about
+/// the z axis of the local frame.
+///
+/// ```
+/// use pbrt::math::{same_hemisphere, Vector3};
+///
+/// let w = Vector3::new(0.0f64, 0.5, 0.5);
+/// assert!(same_hemisphere(w, Vector3::new(1.0, 0.0, 0.1)));
+/// assert!(!same_hemisphere(w, Vector3::new(1.0, 0.0, -0.1)));
+/// ```
+pub fn same_hemisphere<T: Float>(w: Vector3<T>, wp: Vector3<T>) -> bool {
+    w.z * wp.z > T::ZERO
+}
+
+/// Returns the area of the spherical triangle with unit vertices `a`, `b`
+/// and `c`, which is the solid angle it subtends, using the formula of Van
+/// Oosterom and Strackee.
+///
+/// ```
+/// use pbrt::math::{spherical_triangle_area, Vector3};
+///
+/// // One octant of the sphere.
+/// let area = spherical_triangle_area(
+///     Vector3::new(1.0f64, 0.0, 0.0),
+///     Vector3::new(0.0, 1.0, 0.0),
+///     Vector3::new(0.0, 0.0, 1.0),
+/// );
+/// assert!((area - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+/// ```
+pub fn spherical_triangle_area<T: Float>(a: Vector3<T>, b: Vector3<T>, c: Vector3<T>) -> T {
+    (T::TWO
+        * a.dot(b.cross(c))
+            .atan2(T::ONE + a.dot(b) + a.dot(c) + b.dot(c)))
+    .abs()
+}
+
+/// Returns the area of the spherical quadrilateral with unit vertices `a`,
+/// `b`, `c` and `d`, given in order around its boundary, from the sum of its
+/// interior angles. Degenerate quadrilaterals have zero area.
+///
+/// ```
+/// use pbrt::math::{spherical_quad_area, Vector3};
+///
+/// // A face of a cube subtends a sixth of the sphere from its center.
+/// let [a, b, c, d] = [(1.0f64, 1.0), (-1.0, 1.0), (-1.0, -1.0), (1.0, -1.0)]
+///     .map(|(x, y)| Vector3::new(x, y, 1.0).normalize());
+/// let area = spherical_quad_area(a, b, c, d);
+/// assert!((area - 4.0 * std::f64::consts::PI / 6.0).abs() < 1e-12);
+/// ```
+pub fn spherical_quad_area<T: Float>(
+    a: Vector3<T>,
+    b: Vector3<T>,
+    c: Vector3<T>,
+    d: Vector3<T>,
+) -> T {
+    let axb = a.cross(b);
+    let bxc = b.cross(c);
+    let cxd = c.cross(d);
+    let dxa = d.cross(a);
+    if axb.length_squared() == T::ZERO
+        || bxc.length_squared() == T::ZERO
+        || cxd.length_squared() == T::ZERO
+        || dxa.length_squared() == T::ZERO
+    {
+        return T::ZERO;
+    }
+    let (axb, bxc, cxd, dxa) = (
+        axb.normalize(),
+        bxc.normalize(),
+        cxd.normalize(),
+        dxa.normalize(),
+    );
+    let alpha = dxa.angle_between(-axb);
+    let beta = axb.angle_between(-bxc);
+    let gamma = bxc.angle_between(-cxd);
+    let delta = cxd.angle_between(-dxa);
+    (alpha + beta + gamma + delta - T::TWO * T::PI).abs()
+}
+
+/// Maps a point in `[0, 1]^2` to a unit vector with Clarberg's equal-area
+/// octahedral mapping, so that uniformly distributed points map to uniformly
+/// distributed directions. The upper hemisphere maps to the diamond in the
+/// middle of the square and the lower hemisphere to its corners.
+///
+/// ```
+/// use pbrt::math::{equal_area_sphere_to_square, equal_area_square_to_sphere, Point2, Vector3};
+///
+/// assert_eq!(equal_area_square_to_sphere(Point2::new(0.5f64, 0.5)), Vector3::new(0.0, 0.0, 1.0));
+/// let p = Point2::new(0.3f64, 0.8);
+/// let q = equal_area_sphere_to_square(equal_area_square_to_sphere(p));
+/// assert!((q - p).length() < 1e-5);
+/// ```
+pub fn equal_area_square_to_sphere<T: Float>(p: Point2<T>) -> Vector3<T> {
+    // Transform p to [-1, 1]^2 and fold it into the first quadrant.
+    let u = T::TWO * p.x - T::ONE;
+    let v = T::TWO * p.y - T::ONE;
+    let up = u.abs();
+    let vp = v.abs();
+
+    // The radius is the distance from the diagonal u + v = 1, whose sign
+    // selects the hemisphere.
+    let signed_distance = T::ONE - (up + vp);
+    let d = signed_distance.abs();
+    let r = T::ONE - d;
+
+    let phi = if r == T::ZERO {
+        T::ONE
+    } else {
+        (vp - up) / r + T::ONE
+    } * T::PI
+        / T::from_f64(4.0);
+    let z = (T::ONE - r * r).copysign(signed_distance);
+
+    // Unfold back to the original quadrant.
+    let cos_phi = phi.cos().copysign(u);
+    let sin_phi = phi.sin().copysign(v);
+    let scale = r * safe_sqrt(T::TWO - r * r);
+    Vector3::new(cos_phi * scale, sin_phi * scale, z)
+}
+
+/// Maps a unit vector to a point in `[0, 1]^2`; the inverse of
+/// [`equal_area_square_to_sphere`]. The azimuth is computed with a
+/// polynomial fit to `atan`, accurate to about `1e-6`.
+pub fn equal_area_sphere_to_square<T: Float>(d: Vector3<T>) -> Point2<T> {
+    let x = d.x.abs();
+    let y = d.y.abs();
+    let z = d.z.abs();
+    let r = safe_sqrt(T::ONE - z);
+
+    // Compute 2 atan(b) / pi in the first octant, with b in [0, 1].
+    let a = x.max(y);
+    let b = x.min(y);
+    let b = if a == T::ZERO { T::ZERO } else { b / a };
+    let coefficients = [
+        4.067585662467885e-06,
+        0.6362265452740161,
+        0.006157201789828022,
+        -0.24733373328126895,
+        0.08817706647753162,
+        0.04190388180291657,
+        -0.025139097234348352,
+    ];
+    let mut phi = evaluate_polynomial(b, &coefficients.map(T::from_f64));
+    if x < y {
+        phi = T::ONE - phi;
+    }
+
+    let mut v = phi * r;
+    let mut u = r - v;
+    if d.z < T::ZERO {
+        // The lower hemisphere is mirrored across the diagonal.
+        std::mem::swap(&mut u, &mut v);
+        u = T::ONE - u;
+        v = T::ONE - v;
+    }
+    let u = u.copysign(d.x);
+    let v = v.copysign(d.y);
+    Point2::new(T::HALF * (u + T::ONE), T::HALF * (v + T::ONE))
+}
+
+/// Wraps a point just outside `[0, 1]^2` back into the square so that it
+/// maps to the direction adjacent to the nearby edge under
+/// [`equal_area_square_to_sphere`]. Crossing an edge of the square mirrors
+/// the point across that edge and flips it across the edge's midpoint.
+///
+/// ```
+/// use pbrt::math::{wrap_equal_area_square, Point2};
+///
+/// assert_eq!(wrap_equal_area_square(Point2::new(-0.25f64, 0.25)), Point2::new(0.25, 0.75));
+/// assert_eq!(wrap_equal_area_square(Point2::new(0.5f64, 1.25)), Point2::new(0.5, 0.75));
+/// ```
+pub fn wrap_equal_area_square<T: Float>(mut p: Point2<T>) -> Point2<T> {
+    if p.x < T::ZERO {
+        p.x = -p.x;
+        p.y = T::ONE - p.y;
+    } else if p.x > T::ONE {
+        p.x = T::TWO - p.x;
+        p.y = T::ONE - p.y;
+    }
+    if p.y < T::ZERO {
+        p.x = T::ONE - p.x;
+        p.y = -p.y;
+    } else if p.y > T::ONE {
+        p.x = T::ONE - p.x;
+        p.y = T::TWO - p.y;
+    }
+    p
+}
+
+/// Wraps the coordinates of a pixel just outside an image of the given
+/// resolution that is parameterized with the equal-area mapping, following
+/// the same rules as [`wrap_equal_area_square`] applied to pixel centers.
+///
+/// ```
+/// use pbrt::math::{wrap_equal_area_pixel, Point2};
+///
+/// let resolution = Point2::new(8, 8);
+/// assert_eq!(wrap_equal_area_pixel(Point2::new(-1, 2), resolution), Point2::new(0, 5));
+/// assert_eq!(wrap_equal_area_pixel(Point2::new(3, 8), resolution), Point2::new(4, 7));
+/// ```
+pub fn wrap_equal_area_pixel(mut p: Point2<i32>, resolution: Point2<i32>) -> Point2<i32> {
+    if p.x < 0 {
+        p.x = -p.x - 1;
+        p.y = resolution.y - 1 - p.y;
+    } else if p.x >= resolution.x {
+        p.x = 2 * resolution.x - 1 - p.x;
+        p.y = resolution.y - 1 - p.y;
+    }
+    if p.y < 0 {
+        p.x = resolution.x - 1 - p.x;
+        p.y = -p.y - 1;
+    } else if p.y >= resolution.y {
+        p.x = resolution.x - 1 - p.x;
+        p.y = 2 * resolution.y - 1 - p.y;
+    }
+    p
+}
+
+/// A cone of directions around a central axis, used to bound the directions
+/// from which light arrives or toward which a surface faces. A cone with an
+/// infinite `cos_theta` is empty.
+///
+/// ```
+/// use pbrt::math::{DirectionCone, Vector3};
+///
+/// let a = DirectionCone::from_direction(Vector3::new(1.0f64, 0.0, 0.0));
+/// let b = DirectionCone::from_direction(Vector3::new(0.0f64, 1.0, 0.0));
+/// let both = a.union(&b);
+/// assert!(both.inside(Vector3::new(1.0, 0.0, 0.0)));
+/// assert!(both.inside(Vector3::new(0.0, 1.0, 0.0)));
+/// assert!(!both.inside(Vector3::new(0.0, 0.0, 1.0)));
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct DirectionCone<T> {
+    /// The unit axis of the cone.
+    pub w: Vector3<T>,
+    /// The cosine of the angle between the axis and the edge of the cone.
+    pub cos_theta: T,
+}
+
+impl<T: Float> DirectionCone<T> {
+    /// Creates a cone around the direction `w`, which is normalized.
+    pub fn new(w: Vector3<T>, cos_theta: T) -> Self {
+        Self {
+            w: w.normalize(),
+            cos_theta,
+        }
+    }
+
+    /// Returns the cone holding only the direction `w`.
+    pub fn from_direction(w: Vector3<T>) -> Self {
+        Self::new(w, T::ONE)
+    }
+
+    /// Returns the cone that contains no directions.
+    pub fn empty() -> Self {
+        Self {
+            w: Vector3::new(T::ZERO, T::ZERO, T::ONE),
+            cos_theta: T::INFINITY,
+        }
+    }
+
+    /// Returns the cone that contains every direction.
+    pub fn entire_sphere() -> Self {
+        Self::new(Vector3::new(T::ZERO, T::ZERO, T::ONE), -T::ONE)
+    }
+
+    /// Returns whether the cone contains no directions.
+    pub fn is_empty(&self) -> bool {
+        self.cos_theta == T::INFINITY
+    }
+
+    /// Returns whether the direction `w` is inside the cone.
+    pub fn inside(&self, w: Vector3<T>) -> bool {
+        !self.is_empty() && self.w.dot(w.normalize()) >= self.cos_theta
+    }
+
+    /// Returns the cone of directions from `p` toward the bounding sphere of
+    /// `b`, or the entire sphere if `p` is inside that sphere.
+    ///
+    /// ```
+    /// use pbrt::math::{Bounds3, DirectionCone, Point3, Vector3};
+    ///
+    /// let b = Bounds3::new(Point3::new(-1.0f64, -1.0, 9.0), Point3::new(1.0, 1.0, 11.0));
+    /// let cone = DirectionCone::bound_subtended_directions(&b, Point3::new(0.0, 0.0, 0.0));
+    /// assert!(cone.inside(Vector3::new(1.0, 1.0, 9.0)));
+    /// assert!(!cone.inside(Vector3::new(1.0, 0.0, 0.0)));
+    /// ```
+    pub fn bound_subtended_directions(b: &Bounds3<T>, p: Point3<T>) -> Self {
+        let (center, radius) = b.bounding_sphere();
+        let distance_squared = p.distance_squared(center);
+        if distance_squared < radius * radius {
+            return Self::entire_sphere();
+        }
+        let sin2_theta_max = radius * radius / distance_squared;
+        let cos_theta_max = safe_sqrt(T::ONE - sin2_theta_max);
+        Self::new(center - p, cos_theta_max)
+    }
+
+    /// Returns the direction inside the cone closest to `wp`.
+    pub fn closest_vector_in_cone(&self, wp: Vector3<T>) -> Vector3<T> {
+        let wp = wp.normalize();
+        if wp.dot(self.w) > self.cos_theta {
+            return wp;
+        }
+        // Rotate the axis toward wp, in the plane containing both, up to the
+        // edge of the cone.
+        let perpendicular = wp.gram_schmidt(self.w);
+        let perpendicular = if perpendicular.length_squared() == T::ZERO {
+            crate::math::frame::coordinate_system(self.w).0
+        } else {
+            perpendicular.normalize()
+        };
+        let sin_theta = safe_sqrt(T::ONE - self.cos_theta * self.cos_theta);
+        self.w * self.cos_theta + perpendicular * sin_theta
+    }
+
+    /// Returns the smallest cone that contains both cones.
+    pub fn union(&self, b: &Self) -> Self {
+        if self.is_empty() {
+            return *b;
+        }
+        if b.is_empty() {
+            return *self;
+        }
+
+        // Return either cone if it contains the other.
+        let theta_a = safe_acos(self.cos_theta);
+        let theta_b = safe_acos(b.cos_theta);
+        let theta_d = self.w.angle_between(b.w);
+        if (theta_d + theta_b).min(T::PI) <= theta_a {
+            return *self;
+        }
+        if (theta_d + theta_a).min(T::PI) <= theta_b {
+            return *b;
+        }
+
+        // Otherwise spread the new cone over both, rotating the axis of self
+        // toward b.
+        let theta_o = (theta_a + theta_d + theta_b) * T::HALF;
+        if theta_o >= T::PI {
+            return Self::entire_sphere();
+        }
+        let theta_r = theta_o - theta_a;
+        let wr = self.w.cross(b.w);
+        if wr.length_squared() == T::ZERO {
+            return Self::entire_sphere();
+        }
+        let w = Transform::rotate(theta_r.to_degrees(), wr) * self.w;
+        Self::new(w, theta_o.cos())
+    }
+}
+
+impl<T: Float> Default for DirectionCone<T> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::bounds3::Bounds3;
+    use crate::math::point2::Point2;
+    use crate::math::point3::Point3;
+    use crate::math::spherical::{
+        DirectionCone, abs_cos_theta, cos_d_phi, cos_phi, cos_theta, cos2_theta,
+        equal_area_sphere_to_square, equal_area_square_to_sphere, same_hemisphere, sin_phi,
+        sin_theta, sin2_theta, spherical_direction, spherical_phi, spherical_quad_area,
+        spherical_theta, spherical_triangle_area, tan_theta, tan2_theta, wrap_equal_area_pixel,
+        wrap_equal_area_square,
+    };
+    use crate::math::vector3::Vector3;
+    use crate::testing::{assert_vector_near, directions};
+    use std::f64::consts::PI;
+
+    #[test]
+    fn test_spherical_coordinates_round_trip() {
+        for w in directions(200) {
+            let theta = spherical_theta(w);
+            let phi = spherical_phi(w);
+            assert!((0.0..=PI).contains(&theta));
+            assert!((0.0..2.0 * PI).contains(&phi));
+            // The arccosine of z resolves angles near the poles only to about
+            // 1.5e-8.
+            let tolerance = if w.z.abs() > 0.999 { 2e-8 } else { 1e-12 };
+            assert_vector_near(
+                spherical_direction(theta.sin(), theta.cos(), phi),
+                w,
+                tolerance,
+            );
+        }
+    }
+
+    #[test]
+    fn test_spherical_direction_clamps() {
+        let v = spherical_direction(1.0000001f64, 1e-9, 0.0);
+        assert_eq!(v.x, 1.0);
+        let v = spherical_direction(0.0f64, -1.0000001, 0.0);
+        assert_eq!(v.z, -1.0);
+    }
+
+    #[test]
+    fn test_shading_trigonometry() {
+        for w in directions(200) {
+            let theta = spherical_theta(w);
+            let phi = spherical_phi(w);
+            assert!((cos_theta(w) - theta.cos()).abs() < 1e-12);
+            assert!((cos2_theta(w) - theta.cos().powi(2)).abs() < 1e-12);
+            assert!((abs_cos_theta(w) - theta.cos().abs()).abs() < 1e-12);
+            assert!((sin_theta(w) - theta.sin()).abs() < 1e-12);
+            assert!((sin2_theta(w) - theta.sin().powi(2)).abs() < 1e-12);
+            if w.z.abs() > 1e-3 {
+                assert!((tan_theta(w) - theta.tan()).abs() < 1e-9);
+                assert!((tan2_theta(w) - theta.tan().powi(2)).abs() < 1e-6);
+            }
+            if sin_theta(w) > 1e-6 {
+                assert!((cos_phi(w) - phi.cos()).abs() < 1e-9);
+                assert!((sin_phi(w) - phi.sin()).abs() < 1e-9);
+            }
+        }
+        let pole = Vector3::new(0.0f64, 0.0, 1.0);
+        assert_eq!(cos_phi(pole), 1.0);
+        assert_eq!(sin_phi(pole), 0.0);
+    }
+
+    #[test]
+    fn test_cos_d_phi() {
+        let a = spherical_direction(0.5f64, 0.75f64.sqrt(), 0.3);
+        let b = spherical_direction(0.9f64, -0.19f64.sqrt(), 1.5);
+        assert!((cos_d_phi(a, b) - 1.2f64.cos()).abs() < 1e-12);
+        assert_eq!(cos_d_phi(Vector3::new(0.0, 0.0, 1.0), b), 1.0);
+    }
+
+    #[test]
+    fn test_same_hemisphere() {
+        let up = Vector3::new(0.0f64, 0.0, 1.0);
+        assert!(same_hemisphere(up, Vector3::new(0.5, 0.5, 0.1)));
+        assert!(!same_hemisphere(up, Vector3::new(0.5, 0.5, -0.1)));
+        assert!(!same_hemisphere(up, Vector3::new(1.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn test_spherical_triangle_area() {
+        let x = Vector3::new(1.0f64, 0.0, 0.0);
+        let y = Vector3::new(0.0, 1.0, 0.0);
+        let z = Vector3::new(0.0, 0.0, 1.0);
+        assert!((spherical_triangle_area(x, y, z) - PI / 2.0).abs() < 1e-12);
+        // The orientation of the vertices does not matter.
+        assert!((spherical_triangle_area(x, z, y) - PI / 2.0).abs() < 1e-12);
+        assert_eq!(spherical_triangle_area(x, x, y), 0.0);
+
+        // Half a cube face subtends a twelfth of the sphere.
+        let [a, b, c] = [(1.0, 1.0), (-1.0, 1.0), (-1.0, -1.0)]
+            .map(|(x, y)| Vector3::new(x, y, 1.0).normalize());
+        assert!((spherical_triangle_area(a, b, c) - PI / 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_spherical_quad_area() {
+        // A face of a cube subtends a sixth of the sphere.
+        let [a, b, c, d] = [(1.0, 1.0), (-1.0, 1.0), (-1.0, -1.0), (1.0, -1.0)]
+            .map(|(x, y)| Vector3::new(x, y, 1.0f64).normalize());
+        assert!((spherical_quad_area(a, b, c, d) - 2.0 * PI / 3.0).abs() < 1e-12);
+        // It agrees with splitting into triangles for an irregular quad.
+        let [a, b, c, d] = [(0.9, 1.2), (-0.7, 1.0), (-1.1, -0.6), (0.8, -0.9)]
+            .map(|(x, y)| Vector3::new(x, y, 1.0f64).normalize());
+        let triangles = spherical_triangle_area(a, b, c) + spherical_triangle_area(a, c, d);
+        assert!((spherical_quad_area(a, b, c, d) - triangles).abs() < 1e-12);
+        assert_eq!(spherical_quad_area(a, a, c, d), 0.0);
+    }
+
+    #[test]
+    fn test_equal_area_square_to_sphere() {
+        assert_eq!(
+            equal_area_square_to_sphere(Point2::new(0.5f64, 0.5)),
+            Vector3::new(0.0, 0.0, 1.0)
+        );
+        for corner in [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0), (1.0, 1.0)] {
+            let w = equal_area_square_to_sphere(Point2::new(corner.0, corner.1));
+            assert_vector_near(w, Vector3::new(0.0, 0.0, -1.0), 1e-12);
+        }
+        assert_vector_near(
+            equal_area_square_to_sphere(Point2::new(1.0f64, 0.5)),
+            Vector3::new(1.0, 0.0, 0.0),
+            1e-12,
+        );
+        let n = 64;
+        for i in 0..=n {
+            for j in 0..=n {
+                let p = Point2::new(i as f64 / n as f64, j as f64 / n as f64);
+                let w = equal_area_square_to_sphere(p);
+                assert!((w.length() - 1.0).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_equal_area_round_trip() {
+        let n = 64;
+        for i in 0..n {
+            for j in 0..n {
+                let p = Point2::new((i as f64 + 0.5) / n as f64, (j as f64 + 0.5) / n as f64);
+                let q = equal_area_sphere_to_square(equal_area_square_to_sphere(p));
+                assert!((q - p).length() < 1e-5, "{p} maps back to {q}");
+            }
+        }
+        for w in directions(200) {
+            let p = equal_area_sphere_to_square(w);
+            assert!((0.0..=1.0).contains(&p.x) && (0.0..=1.0).contains(&p.y));
+            assert_vector_near(equal_area_square_to_sphere(p), w, 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_equal_area_preserves_area() {
+        // Evenly spaced points in the square land evenly on the sphere, so
+        // the fraction falling inside a cap matches the cap's solid angle.
+        let n = 500;
+        let caps = [
+            (Vector3::new(0.0, 0.0, 1.0), 0.5),
+            (Vector3::new(1.0, 0.0, 0.0), 0.8),
+            (Vector3::new(1.0, -2.0, -0.5).normalize(), -0.3),
+        ];
+        for (axis, cos_theta_max) in caps {
+            let mut inside = 0;
+            for i in 0..n {
+                for j in 0..n {
+                    let p = Point2::new((i as f64 + 0.5) / n as f64, (j as f64 + 0.5) / n as f64);
+                    if equal_area_square_to_sphere(p).dot(axis) > cos_theta_max {
+                        inside += 1;
+                    }
+                }
+            }
+            let fraction = inside as f64 / (n * n) as f64;
+            let expected = (1.0 - cos_theta_max) / 2.0;
+            assert!(
+                (fraction - expected).abs() < 2e-3,
+                "{fraction} vs {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_wrap_equal_area_square_is_continuous() {
+        let eps = 1e-6;
+        for i in 0..=20 {
+            let t = i as f64 / 20.0;
+            let across_edges = [
+                (Point2::new(-eps, t), Point2::new(0.0, t)),
+                (Point2::new(1.0 + eps, t), Point2::new(1.0, t)),
+                (Point2::new(t, -eps), Point2::new(t, 0.0)),
+                (Point2::new(t, 1.0 + eps), Point2::new(t, 1.0)),
+            ];
+            for (outside, edge) in across_edges {
+                let wrapped = wrap_equal_area_square(outside);
+                assert!((0.0..=1.0).contains(&wrapped.x) && (0.0..=1.0).contains(&wrapped.y));
+                assert_vector_near(
+                    equal_area_square_to_sphere(wrapped),
+                    equal_area_square_to_sphere(edge),
+                    1e-5,
+                );
+            }
+        }
+        let inside = Point2::new(0.3, 0.7);
+        assert_eq!(wrap_equal_area_square(inside), inside);
+    }
+
+    #[test]
+    fn test_wrap_equal_area_pixel() {
+        let resolution = Point2::new(8, 6);
+        let center = |p: Point2<i32>| {
+            Point2::new(
+                (p.x as f64 + 0.5) / resolution.x as f64,
+                (p.y as f64 + 0.5) / resolution.y as f64,
+            )
+        };
+        for x in -1..=resolution.x {
+            for y in -1..=resolution.y {
+                let p = Point2::new(x, y);
+                let wrapped = wrap_equal_area_pixel(p, resolution);
+                assert!((0..resolution.x).contains(&wrapped.x));
+                assert!((0..resolution.y).contains(&wrapped.y));
+                let expected = wrap_equal_area_square(center(p));
+                assert!((center(wrapped) - expected).length() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_direction_cone_basics() {
+        let empty = DirectionCone::<f64>::default();
+        assert!(empty.is_empty());
+        assert!(!empty.inside(Vector3::new(0.0, 0.0, 1.0)));
+        let sphere = DirectionCone::<f64>::entire_sphere();
+        for w in directions(200) {
+            assert!(sphere.inside(w));
+        }
+        let cone = DirectionCone::new(Vector3::new(0.0, 0.0, 2.0), 0.5f64);
+        assert_eq!(cone.w, Vector3::new(0.0, 0.0, 1.0));
+        assert!(cone.inside(Vector3::new(0.0, 1.0, 1.0)));
+        assert!(!cone.inside(Vector3::new(0.0, 2.0, 1.0)));
+    }
+
+    #[test]
+    fn test_direction_cone_union() {
+        let cones = [
+            DirectionCone::new(Vector3::new(1.0f64, 0.0, 0.0), 0.9),
+            DirectionCone::new(Vector3::new(0.0, 1.0, 0.2), 0.95),
+            DirectionCone::new(Vector3::new(-1.0, 0.1, 0.0), 0.99),
+            DirectionCone::new(Vector3::new(0.3, 0.3, 1.0), 0.2),
+            DirectionCone::from_direction(Vector3::new(0.0, 0.0, -1.0)),
+            DirectionCone::empty(),
+        ];
+        for a in &cones {
+            for b in &cones {
+                let u = a.union(b);
+                for w in directions(200) {
+                    if a.inside(w) || b.inside(w) {
+                        // Allow for roundoff at the edge of the cone. Near a
+                        // cone's axis, a cosine of one only resolves angles
+                        // to about 1.5e-8.
+                        assert!(u.inside(w) || u.w.dot(w) >= u.cos_theta - 2e-8);
+                    }
+                }
+            }
+        }
+        let a = DirectionCone::from_direction(Vector3::new(1.0f64, 0.0, 0.0));
+        let b = DirectionCone::from_direction(Vector3::new(0.0f64, 1.0, 0.0));
+        let u = a.union(&b);
+        assert_vector_near(u.w, Vector3::new(1.0, 1.0, 0.0).normalize(), 1e-12);
+        assert!((u.cos_theta - (PI / 4.0).cos()).abs() < 1e-12);
+        // Opposite directions need the whole sphere.
+        let c = DirectionCone::from_direction(Vector3::new(-1.0f64, 0.0, 0.0));
+        assert_eq!(a.union(&c), DirectionCone::entire_sphere());
+    }
+
+    #[test]
+    fn test_bound_subtended_directions() {
+        let b = Bounds3::new(Point3::new(1.0f64, 2.0, 3.0), Point3::new(2.0, 4.0, 3.5));
+        let p = Point3::new(-3.0, -1.0, 0.5);
+        let cone = DirectionCone::bound_subtended_directions(&b, p);
+        for i in 0..8 {
+            assert!(cone.inside(b.corner(i) - p));
+        }
+        assert!(!cone.inside(p - b.corner(0)));
+        let inside = DirectionCone::bound_subtended_directions(&b, Point3::new(1.5, 3.0, 3.2));
+        assert_eq!(inside, DirectionCone::entire_sphere());
+    }
+
+    #[test]
+    fn test_closest_vector_in_cone() {
+        let cone = DirectionCone::new(Vector3::new(0.0f64, 0.0, 1.0), 0.5f64.sqrt());
+        let inside = Vector3::new(0.1, 0.0, 1.0);
+        assert_vector_near(
+            cone.closest_vector_in_cone(inside),
+            inside.normalize(),
+            1e-12,
+        );
+        let outside = Vector3::new(1.0, 0.0, 0.1);
+        assert_vector_near(
+            cone.closest_vector_in_cone(outside),
+            Vector3::new(1.0, 0.0, 1.0).normalize(),
+            1e-12,
+        );
+        let opposite = cone.closest_vector_in_cone(Vector3::new(0.0, 0.0, -1.0));
+        assert!((opposite.length() - 1.0).abs() < 1e-12);
+        assert!((opposite.z - 0.5f64.sqrt()).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,650 @@
+//! Fixed-size square matrices.
+
+use crate::math::compensated::{difference_of_products, inner_product};
+use crate::math::number_traits::{Float, Number};
+use crate::math::vector3::Vector3;
+
+/// An `N` by `N` matrix, stored row by row. Indexing yields a row.
+///
+/// ```
+/// use pbrt::math::SquareMatrix;
+///
+/// let m = SquareMatrix::new([[2.0, 1.0], [1.0, 1.0]]);
+/// assert_eq!(m[0][1], 1.0);
+/// assert_eq!(m.determinant(), 1.0);
+/// let inverse = m.inverse().unwrap();
+/// assert!((m * inverse).is_identity());
+/// assert_eq!(m.to_string(), "[[2, 1], [1, 1]]");
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct SquareMatrix<T, const N: usize> {
+    m: [[T; N]; N],
+}
+
+impl<T: Number, const N: usize> SquareMatrix<T, N> {
+    /// Creates a matrix from its rows.
+    pub fn new(m: [[T; N]; N]) -> Self {
+        Self { m }
+    }
+
+    /// Returns the matrix with all entries zero.
+    pub fn zero() -> Self {
+        Self {
+            m: [[T::ZERO; N]; N],
+        }
+    }
+
+    /// Returns the identity matrix.
+    pub fn identity() -> Self {
+        let mut m = [[T::ZERO; N]; N];
+        for (i, row) in m.iter_mut().enumerate() {
+            row[i] = T::ONE;
+        }
+        Self { m }
+    }
+
+    /// Returns the diagonal matrix with the given diagonal entries.
+    pub fn diag(values: [T; N]) -> Self {
+        let mut m = [[T::ZERO; N]; N];
+        for (i, row) in m.iter_mut().enumerate() {
+            row[i] = values[i];
+        }
+        Self { m }
+    }
+
+    /// Returns whether the matrix is exactly the identity.
+    pub fn is_identity(&self) -> bool {
+        *self == Self::identity()
+    }
+
+    /// Returns the transpose.
+    pub fn transpose(&self) -> Self {
+        let mut m = self.m;
+        for (i, row) in m.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = self.m[j][i];
+            }
+        }
+        Self { m }
+    }
+}
+
+impl<T: Float, const N: usize> SquareMatrix<T, N> {
+    /// Returns the determinant. Matrices up to 4x4 use closed-form
+    /// expansions with compensated products, larger ones LU decomposition.
+    pub fn determinant(&self) -> T {
+        let m = &self.m;
+        match N {
+            0 => T::ONE,
+            1 => m[0][0],
+            2 => difference_of_products(m[0][0], m[1][1], m[0][1], m[1][0]),
+            3 => {
+                let minor12 = difference_of_products(m[1][1], m[2][2], m[1][2], m[2][1]);
+                let minor02 = difference_of_products(m[1][0], m[2][2], m[1][2], m[2][0]);
+                let minor01 = difference_of_products(m[1][0], m[2][1], m[1][1], m[2][0]);
+                m[0][2].mul_add(
+                    minor01,
+                    difference_of_products(m[0][0], minor12, m[0][1], minor02),
+                )
+            }
+            4 => {
+                let [s0, s1, s2, s3, s4, s5, c0, c1, c2, c3, c4, c5] = self.minors_4x4();
+                difference_of_products(s0, c5, s1, c4)
+                    + difference_of_products(s2, c3, -s3, c2)
+                    + difference_of_products(s5, c0, s4, c1)
+            }
+            _ => self.determinant_lu(),
+        }
+    }
+
+    // Gaussian elimination with partial pivoting for matrices without a
+    // closed-form expansion.
+    fn determinant_lu(&self) -> T {
+        let mut m = self.m;
+        let mut det = T::ONE;
+        for i in 0..N {
+            let pivot = (i..N)
+                .max_by(|&a, &b| {
+                    m[a][i]
+                        .abs()
+                        .partial_cmp(&m[b][i].abs())
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .unwrap();
+            if m[pivot][i] == T::ZERO {
+                return T::ZERO;
+            }
+            if pivot != i {
+                m.swap(pivot, i);
+                det = -det;
+            }
+            det *= m[i][i];
+            let pivot_row = m[i];
+            for row in m.iter_mut().skip(i + 1) {
+                let factor = row[i] / pivot_row[i];
+                for (value, pivot_value) in row.iter_mut().zip(pivot_row).skip(i) {
+                    *value -= factor * pivot_value;
+                }
+            }
+        }
+        det
+    }
+
+    // The 2x2 minors of the upper (s) and lower (c) halves of a 4x4 matrix,
+    // shared by the determinant and the inverse.
+    fn minors_4x4(&self) -> [T; 12] {
+        let m = &self.m;
+        [
+            difference_of_products(m[0][0], m[1][1], m[1][0], m[0][1]),
+            difference_of_products(m[0][0], m[1][2], m[1][0], m[0][2]),
+            difference_of_products(m[0][0], m[1][3], m[1][0], m[0][3]),
+            difference_of_products(m[0][1], m[1][2], m[1][1], m[0][2]),
+            difference_of_products(m[0][1], m[1][3], m[1][1], m[0][3]),
+            difference_of_products(m[0][2], m[1][3], m[1][2], m[0][3]),
+            difference_of_products(m[2][0], m[3][1], m[3][0], m[2][1]),
+            difference_of_products(m[2][0], m[3][2], m[3][0], m[2][2]),
+            difference_of_products(m[2][0], m[3][3], m[3][0], m[2][3]),
+            difference_of_products(m[2][1], m[3][2], m[3][1], m[2][2]),
+            difference_of_products(m[2][1], m[3][3], m[3][1], m[2][3]),
+            difference_of_products(m[2][2], m[3][3], m[3][2], m[2][3]),
+        ]
+    }
+
+    /// Returns the inverse, or `None` if the matrix is singular.
+    ///
+    /// ```
+    /// use pbrt::math::SquareMatrix;
+    ///
+    /// let m = SquareMatrix::diag([2.0, 4.0, 0.5]);
+    /// assert_eq!(m.inverse(), Some(SquareMatrix::diag([0.5, 0.25, 2.0])));
+    /// assert_eq!(SquareMatrix::<f64, 3>::zero().inverse(), None);
+    /// ```
+    pub fn inverse(&self) -> Option<Self> {
+        match N {
+            3 => self.inverse_3x3(),
+            4 => self.inverse_4x4(),
+            _ => self.inverse_gauss_jordan(),
+        }
+    }
+
+    fn inverse_3x3(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det == T::ZERO {
+            return None;
+        }
+        let inv_det = T::ONE / det;
+        let m = &self.m;
+        let mut r = self.m;
+        r[0][0] = inv_det * difference_of_products(m[1][1], m[2][2], m[1][2], m[2][1]);
+        r[1][0] = inv_det * difference_of_products(m[1][2], m[2][0], m[1][0], m[2][2]);
+        r[2][0] = inv_det * difference_of_products(m[1][0], m[2][1], m[1][1], m[2][0]);
+        r[0][1] = inv_det * difference_of_products(m[0][2], m[2][1], m[0][1], m[2][2]);
+        r[1][1] = inv_det * difference_of_products(m[0][0], m[2][2], m[0][2], m[2][0]);
+        r[2][1] = inv_det * difference_of_products(m[0][1], m[2][0], m[0][0], m[2][1]);
+        r[0][2] = inv_det * difference_of_products(m[0][1], m[1][2], m[0][2], m[1][1]);
+        r[1][2] = inv_det * difference_of_products(m[0][2], m[1][0], m[0][0], m[1][2]);
+        r[2][2] = inv_det * difference_of_products(m[0][0], m[1][1], m[0][1], m[1][0]);
+        Some(Self { m: r })
+    }
+
+    fn inverse_4x4(&self) -> Option<Self> {
+        let [s0, s1, s2, s3, s4, s5, c0, c1, c2, c3, c4, c5] = self.minors_4x4();
+        let det = inner_product([s0, -s1, s2, s3, s5, -s4], [c5, c4, c3, c2, c0, c1]);
+        if det == T::ZERO {
+            return None;
+        }
+        let s = T::ONE / det;
+        let m = &self.m;
+        let mut r = self.m;
+        r[0][0] = s * inner_product([m[1][1], m[1][3], -m[1][2]], [c5, c3, c4]);
+        r[0][1] = s * inner_product([-m[0][1], m[0][2], -m[0][3]], [c5, c4, c3]);
+        r[0][2] = s * inner_product([m[3][1], m[3][3], -m[3][2]], [s5, s3, s4]);
+        r[0][3] = s * inner_product([-m[2][1], m[2][2], -m[2][3]], [s5, s4, s3]);
+        r[1][0] = s * inner_product([-m[1][0], m[1][2], -m[1][3]], [c5, c2, c1]);
+        r[1][1] = s * inner_product([m[0][0], m[0][3], -m[0][2]], [c5, c1, c2]);
+        r[1][2] = s * inner_product([-m[3][0], m[3][2], -m[3][3]], [s5, s2, s1]);
+        r[1][3] = s * inner_product([m[2][0], m[2][3], -m[2][2]], [s5, s1, s2]);
+        r[2][0] = s * inner_product([m[1][0], m[1][3], -m[1][1]], [c4, c0, c2]);
+        r[2][1] = s * inner_product([-m[0][0], m[0][1], -m[0][3]], [c4, c2, c0]);
+        r[2][2] = s * inner_product([m[3][0], m[3][3], -m[3][1]], [s4, s0, s2]);
+        r[2][3] = s * inner_product([-m[2][0], m[2][1], -m[2][3]], [s4, s2, s0]);
+        r[3][0] = s * inner_product([-m[1][0], m[1][1], -m[1][2]], [c3, c1, c0]);
+        r[3][1] = s * inner_product([m[0][0], m[0][2], -m[0][1]], [c3, c0, c1]);
+        r[3][2] = s * inner_product([-m[3][0], m[3][1], -m[3][2]], [s3, s1, s0]);
+        r[3][3] = s * inner_product([m[2][0], m[2][2], -m[2][1]], [s3, s0, s1]);
+        Some(Self { m: r })
+    }
+
+    // Gauss-Jordan elimination with full pivoting.
+    fn inverse_gauss_jordan(&self) -> Option<Self> {
+        let mut indxc = [0usize; N];
+        let mut indxr = [0usize; N];
+        let mut ipiv = [0u32; N];
+        let mut minv = self.m;
+        for i in 0..N {
+            let mut irow = 0;
+            let mut icol = 0;
+            let mut big = T::ZERO;
+            for j in 0..N {
+                if ipiv[j] == 1 {
+                    continue;
+                }
+                for k in 0..N {
+                    if ipiv[k] == 0 {
+                        if minv[j][k].abs() >= big {
+                            big = minv[j][k].abs();
+                            irow = j;
+                            icol = k;
+                        }
+                    } else if ipiv[k] > 1 {
+                        return None;
+                    }
+                }
+            }
+            ipiv[icol] += 1;
+            if irow != icol {
+                minv.swap(irow, icol);
+            }
+            indxr[i] = irow;
+            indxc[i] = icol;
+            if minv[icol][icol] == T::ZERO {
+                return None;
+            }
+            let pivinv = T::ONE / minv[icol][icol];
+            minv[icol][icol] = T::ONE;
+            for value in minv[icol].iter_mut() {
+                *value *= pivinv;
+            }
+            let pivot_row = minv[icol];
+            for (j, row) in minv.iter_mut().enumerate() {
+                if j != icol {
+                    let save = row[icol];
+                    row[icol] = T::ZERO;
+                    for (value, pivot_value) in row.iter_mut().zip(pivot_row) {
+                        *value -= pivot_value * save;
+                    }
+                }
+            }
+        }
+        for j in (0..N).rev() {
+            if indxr[j] != indxc[j] {
+                for row in minv.iter_mut() {
+                    row.swap(indxr[j], indxc[j]);
+                }
+            }
+        }
+        Some(Self { m: minv })
+    }
+}
+
+/// Solves the least squares problem `A * X = B` for `X`, where each row of
+/// `a` and `b` holds one observation. Returns `X` transposed so that it maps
+/// rows of `a` to rows of `b`, or `None` if `A^T * A` is singular.
+///
+/// ```
+/// use pbrt::math::linear_least_squares;
+///
+/// // Observations of a map that swaps its two coordinates.
+/// let a = [[1.0f64, 0.0], [0.0, 1.0], [1.0, 1.0]];
+/// let b = [[0.0, 1.0], [1.0, 0.0], [1.0, 1.0]];
+/// let m = linear_least_squares(&a, &b).unwrap();
+/// assert!((m[0][1] - 1.0).abs() < 1e-12 && m[0][0].abs() < 1e-12);
+/// ```
+pub fn linear_least_squares<T: Float, const N: usize>(
+    a: &[[T; N]],
+    b: &[[T; N]],
+) -> Option<SquareMatrix<T, N>> {
+    debug_assert_eq!(a.len(), b.len());
+    let mut ata = SquareMatrix::<T, N>::zero();
+    let mut atb = SquareMatrix::<T, N>::zero();
+    for i in 0..N {
+        for j in 0..N {
+            for (a_row, b_row) in a.iter().zip(b) {
+                ata[i][j] += a_row[i] * a_row[j];
+                atb[i][j] += a_row[i] * b_row[j];
+            }
+        }
+    }
+    let ata_inverse = ata.inverse()?;
+    Some((ata_inverse * atb).transpose())
+}
+
+impl<T, const N: usize> std::ops::Index<usize> for SquareMatrix<T, N> {
+    type Output = [T; N];
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.m[index]
+    }
+}
+
+impl<T, const N: usize> std::ops::IndexMut<usize> for SquareMatrix<T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.m[index]
+    }
+}
+
+impl<T: Number, const N: usize> std::ops::Add for SquareMatrix<T, N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut m = self.m;
+        for (row, rhs_row) in m.iter_mut().zip(rhs.m) {
+            for (value, rhs_value) in row.iter_mut().zip(rhs_row) {
+                *value += rhs_value;
+            }
+        }
+        Self { m }
+    }
+}
+
+impl<T: Number, const N: usize> std::ops::Mul for SquareMatrix<T, N> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let mut m = self.m;
+        for (i, row) in m.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = (0..N).map(|k| self.m[i][k] * rhs.m[k][j]).sum();
+            }
+        }
+        Self { m }
+    }
+}
+
+impl<T: Number, const N: usize> std::ops::Mul<T> for SquareMatrix<T, N> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        let mut m = self.m;
+        for row in m.iter_mut() {
+            for value in row.iter_mut() {
+                *value *= rhs;
+            }
+        }
+        Self { m }
+    }
+}
+
+impl<T: Float> std::ops::Mul<Vector3<T>> for SquareMatrix<T, 3> {
+    type Output = Vector3<T>;
+
+    fn mul(self, rhs: Vector3<T>) -> Self::Output {
+        let m = &self.m;
+        Vector3::new(
+            inner_product(m[0], [rhs.x, rhs.y, rhs.z]),
+            inner_product(m[1], [rhs.x, rhs.y, rhs.z]),
+            inner_product(m[2], [rhs.x, rhs.y, rhs.z]),
+        )
+    }
+}
+
+impl<T: std::fmt::Display, const N: usize> std::fmt::Display for SquareMatrix<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[")?;
+        for (i, row) in self.m.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "[")?;
+            for (j, value) in row.iter().enumerate() {
+                if j > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{value}")?;
+            }
+            write!(f, "]")?;
+        }
+        write!(f, "]")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::square_matrix::{SquareMatrix, linear_least_squares};
+    use crate::math::vector3::Vector3;
+
+    fn assert_near<const N: usize>(a: SquareMatrix<f64, N>, b: SquareMatrix<f64, N>) {
+        for i in 0..N {
+            for j in 0..N {
+                assert!(
+                    (a[i][j] - b[i][j]).abs() < 1e-12,
+                    "{a} differs from {b} at ({i}, {j})"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let m = SquareMatrix::new([[1, 2], [3, 4]]);
+        assert_eq!(m[0], [1, 2]);
+        assert_eq!(m[1][0], 3);
+        assert_eq!(m[1][1], 4);
+    }
+
+    #[test]
+    fn test_zero() {
+        assert_eq!(
+            SquareMatrix::<i32, 2>::zero(),
+            SquareMatrix::new([[0, 0], [0, 0]])
+        );
+    }
+
+    #[test]
+    fn test_identity() {
+        assert_eq!(
+            SquareMatrix::<f32, 3>::identity(),
+            SquareMatrix::new([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]])
+        );
+        assert!(SquareMatrix::<f32, 4>::identity().is_identity());
+        assert!(!SquareMatrix::new([[1, 0], [1, 1]]).is_identity());
+    }
+
+    #[test]
+    fn test_diag() {
+        assert_eq!(
+            SquareMatrix::diag([2, 3]),
+            SquareMatrix::new([[2, 0], [0, 3]])
+        );
+    }
+
+    #[test]
+    fn test_index_mut() {
+        let mut m = SquareMatrix::<i32, 2>::zero();
+        m[0][1] = 5;
+        m[1] = [1, 2];
+        assert_eq!(m, SquareMatrix::new([[0, 5], [1, 2]]));
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn test_index_out_of_bounds() {
+        let m = SquareMatrix::<i32, 2>::zero();
+        let _ = m[2];
+    }
+
+    #[test]
+    fn test_transpose() {
+        assert_eq!(
+            SquareMatrix::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]).transpose(),
+            SquareMatrix::new([[1, 4, 7], [2, 5, 8], [3, 6, 9]])
+        );
+    }
+
+    #[test]
+    fn test_add() {
+        assert_eq!(
+            SquareMatrix::new([[1, 2], [3, 4]]) + SquareMatrix::new([[4, 3], [2, 1]]),
+            SquareMatrix::new([[5, 5], [5, 5]])
+        );
+    }
+
+    #[test]
+    fn test_mul() {
+        assert_eq!(
+            SquareMatrix::new([[1, 2], [3, 4]]) * SquareMatrix::new([[0, 1], [1, 0]]),
+            SquareMatrix::new([[2, 1], [4, 3]])
+        );
+        let m = SquareMatrix::new([[1.0, 2.0, 0.5], [0.0, -1.0, 3.0], [2.0, 2.0, 2.0]]);
+        assert_eq!(m * SquareMatrix::identity(), m);
+    }
+
+    #[test]
+    fn test_mul_scalar() {
+        assert_eq!(
+            SquareMatrix::new([[1, 2], [3, 4]]) * 2,
+            SquareMatrix::new([[2, 4], [6, 8]])
+        );
+    }
+
+    #[test]
+    fn test_mul_vector3() {
+        let m = SquareMatrix::new([[1.0, 2.0, 0.5], [0.0, -1.0, 3.0], [2.0, 2.0, 2.0]]);
+        assert_eq!(
+            m * Vector3::new(1.0, 2.0, 4.0),
+            Vector3::new(7.0, 10.0, 14.0)
+        );
+    }
+
+    #[test]
+    fn test_determinant() {
+        assert_eq!(SquareMatrix::new([[3.0]]).determinant(), 3.0);
+        assert_eq!(
+            SquareMatrix::new([[1.0, 2.0], [3.0, 4.0]]).determinant(),
+            -2.0
+        );
+        assert_eq!(
+            SquareMatrix::new([[2.0, 0.0, 1.0], [1.0, 3.0, 2.0], [1.0, 1.0, 2.0]]).determinant(),
+            6.0
+        );
+        assert_eq!(
+            SquareMatrix::new([
+                [1.0, 0.0, 2.0, -1.0],
+                [3.0, 0.0, 0.0, 5.0],
+                [2.0, 1.0, 4.0, -3.0],
+                [1.0, 0.0, 5.0, 0.0]
+            ])
+            .determinant(),
+            30.0
+        );
+        assert_eq!(
+            SquareMatrix::new([
+                [2.0, 0.0, 0.0, 0.0, 0.0],
+                [0.0, 0.0, 3.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0, 0.0, 0.0],
+                [0.0, 0.0, 0.0, 4.0, 1.0],
+                [0.0, 0.0, 0.0, 2.0, 1.0]
+            ])
+            .determinant(),
+            -12.0
+        );
+    }
+
+    #[test]
+    fn test_determinant_singular() {
+        assert_eq!(
+            SquareMatrix::new([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 1.0, 1.0]]).determinant(),
+            0.0
+        );
+        assert_eq!(SquareMatrix::<f64, 5>::zero().determinant(), 0.0);
+    }
+
+    #[test]
+    fn test_inverse_2x2() {
+        let m = SquareMatrix::new([[4.0, 7.0], [2.0, 6.0]]);
+        assert_near(
+            m.inverse().unwrap(),
+            SquareMatrix::new([[0.6, -0.7], [-0.2, 0.4]]),
+        );
+    }
+
+    #[test]
+    fn test_inverse_3x3() {
+        let m = SquareMatrix::new([[2.0, 0.0, 1.0], [1.0, 3.0, 2.0], [1.0, 1.0, 2.0]]);
+        let inverse = m.inverse().unwrap();
+        assert_near(
+            inverse,
+            SquareMatrix::new([[4.0, 1.0, -3.0], [0.0, 3.0, -3.0], [-2.0, -2.0, 6.0]])
+                * (1.0 / 6.0),
+        );
+        assert_near(m * inverse, SquareMatrix::identity());
+        assert_near(inverse * m, SquareMatrix::identity());
+    }
+
+    #[test]
+    fn test_inverse_4x4() {
+        let m = SquareMatrix::new([
+            [1.0, 0.0, 2.0, -1.0],
+            [3.0, 0.0, 0.0, 5.0],
+            [2.0, 1.0, 4.0, -3.0],
+            [1.0, 0.0, 5.0, 0.0],
+        ]);
+        let inverse = m.inverse().unwrap();
+        assert_near(m * inverse, SquareMatrix::identity());
+        assert_near(inverse * m, SquareMatrix::identity());
+    }
+
+    #[test]
+    fn test_inverse_5x5() {
+        let m = SquareMatrix::new([
+            [2.0, 0.0, 0.0, 0.0, 1.0],
+            [0.0, 0.0, 3.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 4.0, 1.0],
+            [0.0, 1.0, 0.0, 2.0, 1.0],
+        ]);
+        let inverse = m.inverse().unwrap();
+        assert_near(m * inverse, SquareMatrix::identity());
+        assert_near(inverse * m, SquareMatrix::identity());
+    }
+
+    #[test]
+    fn test_inverse_singular() {
+        assert_eq!(SquareMatrix::new([[1.0, 2.0], [2.0, 4.0]]).inverse(), None);
+        assert_eq!(
+            SquareMatrix::new([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 1.0, 1.0]]).inverse(),
+            None
+        );
+        assert_eq!(SquareMatrix::<f32, 4>::zero().inverse(), None);
+    }
+
+    #[test]
+    fn test_inverse_identity() {
+        assert_eq!(
+            SquareMatrix::<f32, 4>::identity().inverse(),
+            Some(SquareMatrix::identity())
+        );
+    }
+
+    #[test]
+    fn test_linear_least_squares() {
+        let expected = SquareMatrix::new([[1.0, 0.5, 0.0], [0.0, 2.0, -1.0], [0.25, 0.0, 1.0]]);
+        let a = [
+            [1.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0],
+            [0.0, 0.0, 1.0],
+            [1.0, 2.0, 3.0],
+            [0.5, -1.0, 2.0],
+        ];
+        let b = a.map(|row| {
+            let v = expected * Vector3::new(row[0], row[1], row[2]);
+            [v.x, v.y, v.z]
+        });
+        assert_near(linear_least_squares(&a, &b).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_linear_least_squares_singular() {
+        let a = [[1.0, 2.0], [2.0, 4.0]];
+        let b = [[1.0, 0.0], [0.0, 1.0]];
+        assert_eq!(linear_least_squares(&a, &b), None);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            SquareMatrix::new([[1, 2], [3, 4]]).to_string(),
+            "[[1, 2], [3, 4]]"
+        );
+    }
+}
@@ -0,0 +1,860 @@
+//! Affine and projective transformations.
+
+use crate::math::bounds3::Bounds3;
+use crate::math::interval::Interval;
+use crate::math::normal3::Normal3;
+use crate::math::number_traits::Float;
+use crate::math::point3::Point3;
+use crate::math::quaternion::Quaternion;
+use crate::math::ray::{Ray, RayDifferential};
+use crate::math::square_matrix::SquareMatrix;
+use crate::math::vector3::Vector3;
+
+/// A projective transformation of 3D space, stored as a 4x4 matrix together
+/// with its inverse. Transformations are applied by multiplication, and
+/// composed right to left.
+///
+/// Rotation angles are in degrees.
+///
+/// ```
+/// use pbrt::math::{Normal3, Point3, Transform, Vector3};
+///
+/// let t = Transform::translate(Vector3::new(0.0, 0.0, 5.0)) * Transform::scale(2.0, 1.0, 1.0);
+/// assert_eq!(t * Point3::new(1.0, 1.0, 1.0), Point3::new(2.0, 1.0, 6.0));
+/// // Vectors ignore the translation.
+/// assert_eq!(t * Vector3::new(1.0, 1.0, 1.0), Vector3::new(2.0, 1.0, 1.0));
+/// // Normals stay perpendicular to the scaled surface.
+/// assert_eq!(t * Normal3::new(1.0, 1.0, 0.0), Normal3::new(0.5, 1.0, 0.0));
+/// assert_eq!(t.inverse() * Point3::new(2.0, 1.0, 6.0), Point3::new(1.0, 1.0, 1.0));
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Transform<T> {
+    m: SquareMatrix<T, 4>,
+    m_inv: SquareMatrix<T, 4>,
+}
+
+impl<T: Float> Transform<T> {
+    /// Creates a transform from its matrix, computing the inverse. A
+    /// singular matrix yields a transform whose inverse is filled with NaNs,
+    /// which is only an error if the inverse is actually used.
+    pub fn new(m: SquareMatrix<T, 4>) -> Self {
+        let m_inv = m
+            .inverse()
+            .unwrap_or_else(|| SquareMatrix::new([[T::NAN; 4]; 4]));
+        Self { m, m_inv }
+    }
+
+    /// Creates a transform from a matrix and its known inverse.
+    pub fn from_matrices(m: SquareMatrix<T, 4>, m_inv: SquareMatrix<T, 4>) -> Self {
+        Self { m, m_inv }
+    }
+
+    /// Returns the transform that changes nothing.
+    pub fn identity() -> Self {
+        Self {
+            m: SquareMatrix::identity(),
+            m_inv: SquareMatrix::identity(),
+        }
+    }
+
+    /// Returns a translation by `delta`.
+    pub fn translate(delta: Vector3<T>) -> Self {
+        let m = SquareMatrix::new([
+            [T::ONE, T::ZERO, T::ZERO, delta.x],
+            [T::ZERO, T::ONE, T::ZERO, delta.y],
+            [T::ZERO, T::ZERO, T::ONE, delta.z],
+            [T::ZERO, T::ZERO, T::ZERO, T::ONE],
+        ]);
+        let m_inv = SquareMatrix::new([
+            [T::ONE, T::ZERO, T::ZERO, -delta.x],
+            [T::ZERO, T::ONE, T::ZERO, -delta.y],
+            [T::ZERO, T::ZERO, T::ONE, -delta.z],
+            [T::ZERO, T::ZERO, T::ZERO, T::ONE],
+        ]);
+        Self { m, m_inv }
+    }
+
+    /// Returns a scaling by the given factor along each axis.
+    pub fn scale(x: T, y: T, z: T) -> Self {
+        Self {
+            m: SquareMatrix::diag([x, y, z, T::ONE]),
+            m_inv: SquareMatrix::diag([T::ONE / x, T::ONE / y, T::ONE / z, T::ONE]),
+        }
+    }
+
+    /// Returns a rotation about the x axis.
+    pub fn rotate_x(degrees: T) -> Self {
+        let (sin_theta, cos_theta) = (degrees.to_radians().sin(), degrees.to_radians().cos());
+        let m = SquareMatrix::new([
+            [T::ONE, T::ZERO, T::ZERO, T::ZERO],
+            [T::ZERO, cos_theta, -sin_theta, T::ZERO],
+            [T::ZERO, sin_theta, cos_theta, T::ZERO],
+            [T::ZERO, T::ZERO, T::ZERO, T::ONE],
+        ]);
+        Self {
+            m,
+            m_inv: m.transpose(),
+        }
+    }
+
+    /// Returns a rotation about the y axis.
+    pub fn rotate_y(degrees: T) -> Self {
+        let (sin_theta, cos_theta) = (degrees.to_radians().sin(), degrees.to_radians().cos());
+        let m = SquareMatrix::new([
+            [cos_theta, T::ZERO, sin_theta, T::ZERO],
+            [T::ZERO, T::ONE, T::ZERO, T::ZERO],
+            [-sin_theta, T::ZERO, cos_theta, T::ZERO],
+            [T::ZERO, T::ZERO, T::ZERO, T::ONE],
+        ]);
+        Self {
+            m,
+            m_inv: m.transpose(),
+        }
+    }
+
+    /// Returns a rotation about the z axis.
+    pub fn rotate_z(degrees: T) -> Self {
+        let (sin_theta, cos_theta) = (degrees.to_radians().sin(), degrees.to_radians().cos());
+        let m = SquareMatrix::new([
+            [cos_theta, -sin_theta, T::ZERO, T::ZERO],
+            [sin_theta, cos_theta, T::ZERO, T::ZERO],
+            [T::ZERO, T::ZERO, T::ONE, T::ZERO],
+            [T::ZERO, T::ZERO, T::ZERO, T::ONE],
+        ]);
+        Self {
+            m,
+            m_inv: m.transpose(),
+        }
+    }
+
+    /// Returns a rotation about an arbitrary axis.
+    ///
+    /// ```
+    /// use pbrt::math::{Transform, Vector3};
+    ///
+    /// let t = Transform::rotate(90.0, Vector3::new(0.0, 0.0, 1.0));
+    /// let v = t * Vector3::new(1.0, 0.0, 0.0);
+    /// assert!((v - Vector3::new(0.0, 1.0, 0.0)).length() < 1e-12);
+    /// ```
+    pub fn rotate(degrees: T, axis: Vector3<T>) -> Self {
+        let theta = degrees.to_radians();
+        Self::rotate_sin_cos(theta.sin(), theta.cos(), axis)
+    }
+
+    /// Returns a rotation about an arbitrary axis, given the sine and cosine
+    /// of the angle.
+    pub fn rotate_sin_cos(sin_theta: T, cos_theta: T, axis: Vector3<T>) -> Self {
+        let a = axis.normalize();
+        let mut m = SquareMatrix::identity();
+        m[0][0] = a.x * a.x + (T::ONE - a.x * a.x) * cos_theta;
+        m[0][1] = a.x * a.y * (T::ONE - cos_theta) - a.z * sin_theta;
+        m[0][2] = a.x * a.z * (T::ONE - cos_theta) + a.y * sin_theta;
+        m[1][0] = a.x * a.y * (T::ONE - cos_theta) + a.z * sin_theta;
+        m[1][1] = a.y * a.y + (T::ONE - a.y * a.y) * cos_theta;
+        m[1][2] = a.y * a.z * (T::ONE - cos_theta) - a.x * sin_theta;
+        m[2][0] = a.x * a.z * (T::ONE - cos_theta) - a.y * sin_theta;
+        m[2][1] = a.y * a.z * (T::ONE - cos_theta) + a.x * sin_theta;
+        m[2][2] = a.z * a.z + (T::ONE - a.z * a.z) * cos_theta;
+        Self {
+            m,
+            m_inv: m.transpose(),
+        }
+    }
+
+    /// Returns a rotation that takes the unit vector `from` to the unit
+    /// vector `to`, built from two reflections to stay robust when they are
+    /// (anti)parallel.
+    pub fn rotate_from_to(from: Vector3<T>, to: Vector3<T>) -> Self {
+        let threshold = T::from_f64(0.72);
+        let refl = if from.x.abs() < threshold && to.x.abs() < threshold {
+            Vector3::new(T::ONE, T::ZERO, T::ZERO)
+        } else if from.y.abs() < threshold && to.y.abs() < threshold {
+            Vector3::new(T::ZERO, T::ONE, T::ZERO)
+        } else {
+            Vector3::new(T::ZERO, T::ZERO, T::ONE)
+        };
+        let u = refl - from;
+        let v = refl - to;
+        let uu = u.dot(u);
+        let vv = v.dot(v);
+        let uv = u.dot(v);
+        let four = T::TWO * T::TWO;
+        let mut r = SquareMatrix::identity();
+        for i in 0..3 {
+            for j in 0..3 {
+                let delta = if i == j { T::ONE } else { T::ZERO };
+                r[i][j] = delta - T::TWO / uu * u[i] * u[j] - T::TWO / vv * v[i] * v[j]
+                    + four * uv / (uu * vv) * v[i] * u[j];
+            }
+        }
+        Self {
+            m: r,
+            m_inv: r.transpose(),
+        }
+    }
+
+    /// Returns the camera-from-world transform for a camera at `pos` looking
+    /// at `look`, or `None` if `up` is parallel to the viewing direction.
+    ///
+    /// ```
+    /// use pbrt::math::{Point3, Transform, Vector3};
+    ///
+    /// let pos = Point3::new(0.0, 0.0, -5.0);
+    /// let t = Transform::look_at(pos, Point3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0))
+    ///     .unwrap();
+    /// assert_eq!(t * pos, Point3::new(0.0, 0.0, 0.0));
+    /// assert_eq!(t * Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 0.0, 5.0));
+    /// ```
+    pub fn look_at(pos: Point3<T>, look: Point3<T>, up: Vector3<T>) -> Option<Self> {
+        let dir = (look - pos).normalize();
+        let right = up.normalize().cross(dir);
+        if right.length() == T::ZERO {
+            return None;
+        }
+        let right = right.normalize();
+        let new_up = dir.cross(right);
+        let world_from_camera = SquareMatrix::new([
+            [right.x, new_up.x, dir.x, pos.x],
+            [right.y, new_up.y, dir.y, pos.y],
+            [right.z, new_up.z, dir.z, pos.z],
+            [T::ZERO, T::ZERO, T::ZERO, T::ONE],
+        ]);
+        let camera_from_world = world_from_camera.inverse()?;
+        Some(Self {
+            m: camera_from_world,
+            m_inv: world_from_camera,
+        })
+    }
+
+    /// Returns the orthographic projection that maps z from `[z_near, z_far]`
+    /// to `[0, 1]`, leaving x and y alone.
+    pub fn orthographic(z_near: T, z_far: T) -> Self {
+        Self::scale(T::ONE, T::ONE, T::ONE / (z_far - z_near))
+            * Self::translate(Vector3::new(T::ZERO, T::ZERO, -z_near))
+    }
+
+    /// Returns the perspective projection with the given field of view that
+    /// maps z from `[n, f]` to `[0, 1]`.
+    pub fn perspective(fov_degrees: T, n: T, f: T) -> Self {
+        let persp = SquareMatrix::new([
+            [T::ONE, T::ZERO, T::ZERO, T::ZERO],
+            [T::ZERO, T::ONE, T::ZERO, T::ZERO],
+            [T::ZERO, T::ZERO, f / (f - n), -f * n / (f - n)],
+            [T::ZERO, T::ZERO, T::ONE, T::ZERO],
+        ]);
+        let inv_tan_angle = T::ONE / (fov_degrees.to_radians() * T::HALF).tan();
+        Self::scale(inv_tan_angle, inv_tan_angle, T::ONE) * Self::new(persp)
+    }
+
+    /// Returns the matrix.
+    pub fn matrix(&self) -> &SquareMatrix<T, 4> {
+        &self.m
+    }
+
+    /// Returns the inverse matrix.
+    pub fn inverse_matrix(&self) -> &SquareMatrix<T, 4> {
+        &self.m_inv
+    }
+
+    /// Returns the inverse transform, which is free since both matrices are
+    /// stored.
+    pub fn inverse(&self) -> Self {
+        Self {
+            m: self.m_inv,
+            m_inv: self.m,
+        }
+    }
+
+    /// Returns the transform with both matrices transposed.
+    pub fn transpose(&self) -> Self {
+        Self {
+            m: self.m.transpose(),
+            m_inv: self.m_inv.transpose(),
+        }
+    }
+
+    /// Returns whether the transform is exactly the identity.
+    pub fn is_identity(&self) -> bool {
+        self.m.is_identity()
+    }
+
+    /// Returns whether the transform changes the length of any coordinate
+    /// axis by more than a small tolerance.
+    pub fn has_scale(&self) -> bool {
+        let tolerance = T::from_f64(1e-3);
+        let la2 = (*self * Vector3::new(T::ONE, T::ZERO, T::ZERO)).length_squared();
+        let lb2 = (*self * Vector3::new(T::ZERO, T::ONE, T::ZERO)).length_squared();
+        let lc2 = (*self * Vector3::new(T::ZERO, T::ZERO, T::ONE)).length_squared();
+        (la2 - T::ONE).abs() > tolerance
+            || (lb2 - T::ONE).abs() > tolerance
+            || (lc2 - T::ONE).abs() > tolerance
+    }
+
+    /// Returns whether the transform turns a right-handed coordinate system
+    /// into a left-handed one, which flips the orientation of surfaces.
+    pub fn swaps_handedness(&self) -> bool {
+        let m = &self.m;
+        let upper = SquareMatrix::new([
+            [m[0][0], m[0][1], m[0][2]],
+            [m[1][0], m[1][1], m[1][2]],
+            [m[2][0], m[2][1], m[2][2]],
+        ]);
+        upper.determinant() < T::ZERO
+    }
+
+    /// Transforms the ray origin with error bounds and moves it forward along
+    /// the transformed direction past its error box, so that it does not end
+    /// up behind the surface it was spawned from. `t_max`, if given, is
+    /// shortened by the same amount.
+    pub fn apply_ray<M>(&self, r: Ray<T, M>, t_max: Option<&mut T>) -> Ray<T, M> {
+        let o = *self * Point3::<Interval<T>>::from(r.o);
+        let d = *self * r.d;
+        let mut origin = o.midpoint();
+        let length_squared = d.length_squared();
+        if length_squared > T::ZERO {
+            let dt = d.abs().dot(o.error()) / length_squared;
+            origin += d * dt;
+            if let Some(t_max) = t_max {
+                *t_max -= dt;
+            }
+        }
+        Ray::new(origin, d, r.time, r.medium)
+    }
+
+    /// Splits the transform into translation, rotation and scale (which may
+    /// also contain shear) such that it equals `T * R * S`. The rotation is found
+    /// by polar decomposition, averaging the matrix with its inverse transpose
+    /// until it converges.
+    pub fn decompose(&self) -> (Vector3<T>, Quaternion<T>, SquareMatrix<T, 4>) {
+        let translation = Vector3::new(self.m[0][3], self.m[1][3], self.m[2][3]);
+
+        let mut m = self.m;
+        for i in 0..3 {
+            m[i][3] = T::ZERO;
+            m[3][i] = T::ZERO;
+        }
+        m[3][3] = T::ONE;
+
+        let tolerance = T::from_f64(1e-4);
+        let mut r = m;
+        for _ in 0..100 {
+            let Some(r_inverse_transpose) = r.transpose().inverse() else {
+                break;
+            };
+            let r_next = (r + r_inverse_transpose) * T::HALF;
+            let mut norm = T::ZERO;
+            for i in 0..3 {
+                let n = (r[i][0] - r_next[i][0]).abs()
+                    + (r[i][1] - r_next[i][1]).abs()
+                    + (r[i][2] - r_next[i][2]).abs();
+                norm = norm.max(n);
+            }
+            r = r_next;
+            if norm <= tolerance {
+                break;
+            }
+        }
+        let rotation = Quaternion::from_transform(&Self::from_matrices(r, r.transpose()));
+
+        let scale = r
+            .inverse()
+            .map(|r_inverse| r_inverse * m)
+            .unwrap_or_else(SquareMatrix::identity);
+        (translation, rotation, scale)
+    }
+}
+
+impl<T: Float> Default for Transform<T> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<T: Float> std::ops::Mul for Transform<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            m: self.m * rhs.m,
+            m_inv: rhs.m_inv * self.m_inv,
+        }
+    }
+}
+
+impl<T: Float> std::ops::Mul<Point3<T>> for Transform<T> {
+    type Output = Point3<T>;
+
+    fn mul(self, p: Point3<T>) -> Self::Output {
+        let m = &self.m;
+        let xp = (m[0][0] * p.x + m[0][1] * p.y) + (m[0][2] * p.z + m[0][3]);
+        let yp = (m[1][0] * p.x + m[1][1] * p.y) + (m[1][2] * p.z + m[1][3]);
+        let zp = (m[2][0] * p.x + m[2][1] * p.y) + (m[2][2] * p.z + m[2][3]);
+        let wp = (m[3][0] * p.x + m[3][1] * p.y) + (m[3][2] * p.z + m[3][3]);
+        if wp == T::ONE {
+            Point3::new(xp, yp, zp)
+        } else {
+            Point3::new(xp, yp, zp) / wp
+        }
+    }
+}
+
+impl<T: Float> std::ops::Mul<Vector3<T>> for Transform<T> {
+    type Output = Vector3<T>;
+
+    fn mul(self, v: Vector3<T>) -> Self::Output {
+        let m = &self.m;
+        Vector3::new(
+            m[0][0] * v.x + m[0][1] * v.y + m[0][2] * v.z,
+            m[1][0] * v.x + m[1][1] * v.y + m[1][2] * v.z,
+            m[2][0] * v.x + m[2][1] * v.y + m[2][2] * v.z,
+        )
+    }
+}
+
+/// Normals transform by the inverse transpose to stay perpendicular to the
+/// transformed surface.
+impl<T: Float> std::ops::Mul<Normal3<T>> for Transform<T> {
+    type Output = Normal3<T>;
+
+    fn mul(self, n: Normal3<T>) -> Self::Output {
+        let m_inv = &self.m_inv;
+        Normal3::new(
+            m_inv[0][0] * n.x + m_inv[1][0] * n.y + m_inv[2][0] * n.z,
+            m_inv[0][1] * n.x + m_inv[1][1] * n.y + m_inv[2][1] * n.z,
+            m_inv[0][2] * n.x + m_inv[1][2] * n.y + m_inv[2][2] * n.z,
+        )
+    }
+}
+
+/// Transforms the midpoint of `p` and grows its error bounds by the rounding error
+/// of the transformation itself.
+impl<T: Float> std::ops::Mul<Point3<Interval<T>>> for Transform<T> {
+    type Output = Point3<Interval<T>>;
+
+    fn mul(self, p: Point3<Interval<T>>) -> Self::Output {
+        let m = &self.m;
+        let Point3 { x, y, z } = p.midpoint();
+        let xp = (m[0][0] * x + m[0][1] * y) + (m[0][2] * z + m[0][3]);
+        let yp = (m[1][0] * x + m[1][1] * y) + (m[1][2] * z + m[1][3]);
+        let zp = (m[2][0] * x + m[2][1] * y) + (m[2][2] * z + m[2][3]);
+        let wp = (m[3][0] * x + m[3][1] * y) + (m[3][2] * z + m[3][3]);
+
+        let gamma3 = T::gamma(3);
+        let rounding_error = |row: usize| {
+            gamma3
+                * ((m[row][0] * x).abs()
+                    + (m[row][1] * y).abs()
+                    + (m[row][2] * z).abs()
+                    + m[row][3].abs())
+        };
+        let mut error = Vector3::new(rounding_error(0), rounding_error(1), rounding_error(2));
+        if !p.is_exact() {
+            let in_error = p.error();
+            for row in 0..3 {
+                error[row] += (gamma3 + T::ONE)
+                    * (m[row][0].abs() * in_error.x
+                        + m[row][1].abs() * in_error.y
+                        + m[row][2].abs() * in_error.z);
+            }
+        }
+
+        let result = Point3::from_value_and_error(Point3::new(xp, yp, zp), error);
+        if wp == T::ONE {
+            result
+        } else {
+            result / Interval::from(wp)
+        }
+    }
+}
+
+impl<T: Float> std::ops::Mul<Vector3<Interval<T>>> for Transform<T> {
+    type Output = Vector3<Interval<T>>;
+
+    fn mul(self, v: Vector3<Interval<T>>) -> Self::Output {
+        let m = &self.m;
+        let Vector3 { x, y, z } = v.midpoint();
+        let xp = m[0][0] * x + m[0][1] * y + m[0][2] * z;
+        let yp = m[1][0] * x + m[1][1] * y + m[1][2] * z;
+        let zp = m[2][0] * x + m[2][1] * y + m[2][2] * z;
+
+        let gamma3 = T::gamma(3);
+        let rounding_error = |row: usize| {
+            gamma3 * ((m[row][0] * x).abs() + (m[row][1] * y).abs() + (m[row][2] * z).abs())
+        };
+        let mut error = Vector3::new(rounding_error(0), rounding_error(1), rounding_error(2));
+        if !v.is_exact() {
+            let in_error = v.error();
+            for row in 0..3 {
+                error[row] += (gamma3 + T::ONE)
+                    * (m[row][0].abs() * in_error.x
+                        + m[row][1].abs() * in_error.y
+                        + m[row][2].abs() * in_error.z);
+            }
+        }
+        Vector3::from_value_and_error(Vector3::new(xp, yp, zp), error)
+    }
+}
+
+impl<T: Float, M> std::ops::Mul<Ray<T, M>> for Transform<T> {
+    type Output = Ray<T, M>;
+
+    fn mul(self, r: Ray<T, M>) -> Self::Output {
+        self.apply_ray(r, None)
+    }
+}
+
+impl<T: Float, M> std::ops::Mul<RayDifferential<T, M>> for Transform<T> {
+    type Output = RayDifferential<T, M>;
+
+    fn mul(self, r: RayDifferential<T, M>) -> Self::Output {
+        RayDifferential {
+            ray: self * r.ray,
+            has_differentials: r.has_differentials,
+            rx_origin: self * r.rx_origin,
+            ry_origin: self * r.ry_origin,
+            rx_direction: self * r.rx_direction,
+            ry_direction: self * r.ry_direction,
+        }
+    }
+}
+
+impl<T: Float> std::ops::Mul<Bounds3<T>> for Transform<T> {
+    type Output = Bounds3<T>;
+
+    fn mul(self, b: Bounds3<T>) -> Self::Output {
+        (0..8).fold(Bounds3::empty(), |bounds, corner| {
+            bounds.union_point(self * b.corner(corner))
+        })
+    }
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for Transform<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Transform(m: {}, m_inv: {})", self.m, self.m_inv)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::bounds3::Bounds3;
+    use crate::math::interval::Interval;
+    use crate::math::normal3::Normal3;
+    use crate::math::point3::Point3;
+    use crate::math::ray::{Ray, RayDifferential};
+    use crate::math::square_matrix::SquareMatrix;
+    use crate::math::transform::Transform;
+    use crate::math::vector3::Vector3;
+    use crate::testing::{assert_point_near, assert_vector_near};
+
+    #[test]
+    fn test_identity() {
+        let t = Transform::<f32>::identity();
+        assert!(t.is_identity());
+        assert_eq!(t * Point3::new(1.0, 2.0, 3.0), Point3::new(1.0, 2.0, 3.0));
+        assert_eq!(Transform::default(), t);
+    }
+
+    #[test]
+    fn test_new() {
+        let m = SquareMatrix::new([
+            [2.0, 0.0, 0.0, 1.0],
+            [0.0, 4.0, 0.0, 0.0],
+            [0.0, 0.0, 8.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+        let t = Transform::new(m);
+        assert_eq!(*t.matrix(), m);
+        assert!((*t.matrix() * *t.inverse_matrix()).is_identity());
+    }
+
+    #[test]
+    fn test_new_singular() {
+        let t = Transform::new(SquareMatrix::<f32, 4>::zero());
+        assert!(t.inverse_matrix()[0][0].is_nan());
+    }
+
+    #[test]
+    fn test_translate() {
+        let t = Transform::translate(Vector3::new(1.0, -2.0, 3.0));
+        assert_eq!(t * Point3::new(1.0, 1.0, 1.0), Point3::new(2.0, -1.0, 4.0));
+        assert_eq!(t * Vector3::new(1.0, 1.0, 1.0), Vector3::new(1.0, 1.0, 1.0));
+        assert_eq!(
+            t.inverse() * Point3::new(2.0, -1.0, 4.0),
+            Point3::new(1.0, 1.0, 1.0)
+        );
+        assert!(!t.has_scale());
+    }
+
+    #[test]
+    fn test_scale() {
+        let t = Transform::scale(2.0, 4.0, -1.0);
+        assert_eq!(t * Point3::new(1.0, 1.0, 1.0), Point3::new(2.0, 4.0, -1.0));
+        assert_eq!(
+            t * Vector3::new(1.0, 1.0, 1.0),
+            Vector3::new(2.0, 4.0, -1.0)
+        );
+        assert_eq!(
+            t.inverse() * Vector3::new(2.0, 4.0, -1.0),
+            Vector3::new(1.0, 1.0, 1.0)
+        );
+        assert!(t.has_scale());
+        assert!(t.swaps_handedness());
+        assert!(!Transform::scale(2.0, 2.0, 2.0).swaps_handedness());
+    }
+
+    #[test]
+    fn test_rotate_axes() {
+        assert_vector_near(
+            Transform::rotate_x(90.0) * Vector3::new(0.0, 1.0, 0.0),
+            Vector3::new(0.0, 0.0, 1.0),
+            1e-12,
+        );
+        assert_vector_near(
+            Transform::rotate_y(90.0) * Vector3::new(0.0, 0.0, 1.0),
+            Vector3::new(1.0, 0.0, 0.0),
+            1e-12,
+        );
+        assert_vector_near(
+            Transform::rotate_z(90.0) * Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn test_rotate() {
+        let axis = Vector3::new(1.0, 1.0, 1.0);
+        let t = Transform::rotate(120.0, axis);
+        assert_vector_near(
+            t * Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+            1e-12,
+        );
+        assert_vector_near(t * axis, axis, 1e-12);
+        assert_eq!(
+            Transform::rotate(37.0, Vector3::new(1.0, 0.0, 0.0)),
+            Transform::rotate_x(37.0)
+        );
+        assert!(!t.has_scale());
+        assert!(!t.swaps_handedness());
+        let product = *t.matrix() * *t.inverse_matrix();
+        for i in 0..4 {
+            for j in 0..4 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((product[i][j] - expected).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rotate_from_to() {
+        let directions = [
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 0.0, -1.0),
+            Vector3::new(0.3, -0.4, 0.5).normalize(),
+            Vector3::new(-0.9, 0.1, 0.2).normalize(),
+            Vector3::new(0.0, 1.0, 1e-4).normalize(),
+        ];
+        for from in directions {
+            for to in directions {
+                let t = Transform::rotate_from_to(from, to);
+                assert_vector_near(t * from, to, 1e-12);
+                assert!(!t.has_scale());
+            }
+        }
+    }
+
+    #[test]
+    fn test_look_at() {
+        let pos = Point3::new(1.0, 2.0, 3.0);
+        let look = Point3::new(1.0, 2.0, 10.0);
+        let t = Transform::look_at(pos, look, Vector3::new(0.0, 1.0, 0.0)).unwrap();
+        assert_point_near(t * pos, Point3::new(0.0, 0.0, 0.0), 1e-12);
+        assert_point_near(t * look, Point3::new(0.0, 0.0, 7.0), 1e-12);
+        assert_point_near(
+            t * Point3::new(1.0, 3.0, 3.0),
+            Point3::new(0.0, 1.0, 0.0),
+            1e-12,
+        );
+        assert_point_near(
+            t.inverse() * Point3::new(0.0, 0.0, 1.0),
+            Point3::new(1.0, 2.0, 4.0),
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn test_look_at_degenerate() {
+        assert_eq!(
+            Transform::look_at(
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(0.0, 1.0, 0.0),
+                Vector3::new(0.0, 1.0, 0.0)
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_orthographic() {
+        let t = Transform::orthographic(1.0, 11.0);
+        assert_eq!(t * Point3::new(2.0, 3.0, 1.0), Point3::new(2.0, 3.0, 0.0));
+        assert_eq!(t * Point3::new(2.0, 3.0, 11.0), Point3::new(2.0, 3.0, 1.0));
+    }
+
+    #[test]
+    fn test_perspective() {
+        let t = Transform::perspective(90.0, 1.0, 100.0);
+        let near = t * Point3::new(1.0, 0.5, 1.0);
+        assert_point_near(near, Point3::new(1.0, 0.5, 0.0), 1e-12);
+        let far = t * Point3::new(100.0, 0.0, 100.0);
+        assert_point_near(far, Point3::new(1.0, 0.0, 1.0), 1e-12);
+        let p = Point3::new(0.3, -0.7, 42.0);
+        assert_point_near(t.inverse() * (t * p), p, 1e-12);
+    }
+
+    #[test]
+    fn test_compose() {
+        let t = Transform::translate(Vector3::new(1.0, 0.0, 0.0)) * Transform::scale(2.0, 2.0, 2.0);
+        assert_eq!(t * Point3::new(1.0, 1.0, 1.0), Point3::new(3.0, 2.0, 2.0));
+        assert_eq!(
+            t.inverse() * Point3::new(3.0, 2.0, 2.0),
+            Point3::new(1.0, 1.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn test_transpose() {
+        let t = Transform::translate(Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(t.transpose().matrix()[3], [1.0, 2.0, 3.0, 1.0]);
+        assert_eq!(t.transpose().transpose(), t);
+    }
+
+    #[test]
+    fn test_normal() {
+        let t = Transform::scale(1.0f64, 4.0, 1.0) * Transform::rotate_z(30.0);
+        let v = Vector3::new(1.0, 1.0, 0.0);
+        let n = Normal3::new(1.0, -1.0, 0.0);
+        assert_eq!(Vector3::from(n).dot(v), 0.0);
+        assert!(Vector3::from(t * n).dot(t * v).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_decompose() {
+        let t = Transform::translate(Vector3::new(1.0, -2.0, 3.0))
+            * Transform::rotate(40.0, Vector3::new(0.3, 1.0, -0.2))
+            * Transform::scale(2.0, 0.5, 3.0);
+        let (translation, rotation, scale) = t.decompose();
+        assert_vector_near(translation, Vector3::new(1.0, -2.0, 3.0), 1e-12);
+        let expected_rotation = Transform::rotate(40.0, Vector3::new(0.3, 1.0, -0.2));
+        let v = Vector3::new(0.5, 0.2, -1.0);
+        assert!((rotation.to_transform() * v - expected_rotation * v).length() < 1e-6);
+        for i in 0..4 {
+            for j in 0..4 {
+                let expected = SquareMatrix::diag([2.0, 0.5, 3.0, 1.0])[i][j];
+                assert!((scale[i][j] - expected).abs() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_interval_point() {
+        let t = Transform::rotate(33.0f32, Vector3::new(0.2, 1.0, -0.5))
+            * Transform::translate(Vector3::new(0.1, 2.3, -4.2));
+        let p = Point3::new(0.7f32, -1.3, 2.9);
+        let pi = t * Point3::<Interval<f32>>::from(p);
+        let m = t.matrix();
+        let p64 = Point3::new(f64::from(p.x), f64::from(p.y), f64::from(p.z));
+        let mut expected = [0.0; 3];
+        for (row, value) in expected.iter_mut().enumerate() {
+            *value = f64::from(m[row][0]) * p64.x
+                + f64::from(m[row][1]) * p64.y
+                + f64::from(m[row][2]) * p64.z
+                + f64::from(m[row][3]);
+        }
+        for (row, value) in expected.into_iter().enumerate() {
+            let bounds = pi[row];
+            assert!(f64::from(bounds.lower_bound()) <= value);
+            assert!(f64::from(bounds.upper_bound()) >= value);
+        }
+        assert!(pi.error().length() > 0.0);
+    }
+
+    #[test]
+    fn test_bounds() {
+        let b = Bounds3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 2.0, 3.0));
+        let translated = Transform::translate(Vector3::new(1.0, -1.0, 0.0)) * b;
+        assert_eq!(
+            translated,
+            Bounds3::new(Point3::new(1.0, -1.0, 0.0), Point3::new(2.0, 1.0, 3.0))
+        );
+        let rotated = Transform::rotate_z(90.0) * b;
+        assert!(rotated.p_min.distance(Point3::new(-2.0, 0.0, 0.0)) < 1e-12);
+        assert!(rotated.p_max.distance(Point3::new(0.0, 1.0, 3.0)) < 1e-12);
+    }
+
+    #[test]
+    fn test_ray() {
+        let t = Transform::translate(Vector3::new(1.0, 2.0, 3.0)) * Transform::rotate_z(90.0);
+        let r = Ray::new(
+            Point3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+            0.5,
+            Some(7),
+        );
+        let mut t_max = 10.0;
+        let tr = t.apply_ray(r, Some(&mut t_max));
+        assert_eq!(tr.time, 0.5);
+        assert_eq!(tr.medium, Some(7));
+        assert!((tr.d - Vector3::new(-1.0, 0.0, 0.0)).length() < 1e-12);
+        // The origin is pushed forward by its rounding error, and t_max
+        // shortened to match.
+        assert!(tr.o.distance(Point3::new(1.0, 3.0, 3.0)) < 1e-12);
+        assert!(t_max <= 10.0 && t_max > 10.0 - 1e-12);
+        assert!((tr.at(t_max) - (t * r.at(10.0))).length() < 1e-12);
+        assert_eq!(t * r, tr);
+    }
+
+    #[test]
+    fn test_ray_differential() {
+        let t = Transform::scale(2.0, 2.0, 2.0);
+        let mut r: RayDifferential<f64> = RayDifferential::new(
+            Point3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 0.0, 1.0),
+            0.0,
+            None,
+        );
+        r.has_differentials = true;
+        r.rx_origin = Point3::new(1.5, 0.0, 0.0);
+        r.ry_direction = Vector3::new(0.0, 0.5, 1.0);
+        let tr = t * r;
+        assert!(tr.has_differentials);
+        assert_eq!(tr.rx_origin, Point3::new(3.0, 0.0, 0.0));
+        assert_eq!(tr.ry_direction, Vector3::new(0.0, 1.0, 2.0));
+        assert_eq!(tr.d, Vector3::new(0.0, 0.0, 2.0));
+    }
+
+    #[test]
+    fn test_interval_point_with_error() {
+        let t = Transform::scale(2.0f32, 2.0, 2.0);
+        let p = Point3::from_value_and_error(
+            Point3::new(1.0f32, 1.0, 1.0),
+            Vector3::new(0.5, 0.0, 0.0),
+        );
+        let pi = t * p;
+        assert!(pi.x.contains(1.0));
+        assert!(pi.x.contains(3.0));
+        assert!(pi.y.width() < 1e-5);
+    }
+
+    #[test]
+    fn test_interval_vector() {
+        let t = Transform::rotate_y(45.0f32);
+        let v = Vector3::new(1.0f32, 2.0, 3.0);
+        let vi = t * Vector3::<Interval<f32>>::from(v);
+        let expected = t * v;
+        for i in 0..3 {
+            assert!(vi[i].contains(expected[i]));
+        }
+    }
+}
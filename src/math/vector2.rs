@@ -0,0 +1,93 @@
+//! Two-dimensional vectors.
+
+use crate::math::macros::{
+    n_tuple_add_assign_impl, n_tuple_add_impl, n_tuple_conversion_impl, n_tuple_impl,
+    n_tuple_sub_assign_impl, n_tuple_sub_impl, n_tuple_vector_impl,
+};
+use crate::math::point2::Point2;
+
+n_tuple_impl! {
+    /// A direction or displacement in 2D space.
+    ///
+    /// ```
+    /// use pbrt::math::Vector2;
+    ///
+    /// let v = Vector2::new(3.0, 4.0);
+    /// assert_eq!(v.length(), 5.0);
+    /// assert_eq!(v.dot(Vector2::new(1.0, 0.0)), 3.0);
+    /// assert_eq!(-v * 2.0, Vector2::new(-6.0, -8.0));
+    /// ```
+    Vector2, x, y
+}
+n_tuple_add_impl!(Vector2, Vector2, Vector2, x, y);
+n_tuple_add_assign_impl!(Vector2, Vector2, x, y);
+n_tuple_sub_impl!(Vector2, Vector2, Vector2, x, y);
+n_tuple_sub_assign_impl!(Vector2, Vector2, x, y);
+n_tuple_conversion_impl!(Point2, Vector2, x, y);
+n_tuple_vector_impl!(Vector2, x, y);
+
+#[cfg(test)]
+mod tests {
+    use crate::math::point2::Point2;
+    use crate::math::vector2::Vector2;
+
+    #[test]
+    fn test_new() {
+        let v = Vector2::new(1, 2);
+        assert_eq!(v.x, 1);
+        assert_eq!(v.y, 2);
+    }
+
+    #[test]
+    fn test_dimensions() {
+        assert_eq!(Vector2::<f32>::DIMENSIONS, 2);
+    }
+
+    #[test]
+    fn test_add() {
+        assert_eq!(
+            Vector2::new(1.5, 0.5) + Vector2::new(0.5, -1.0),
+            Vector2::new(2.0, -0.5)
+        );
+    }
+
+    #[test]
+    fn test_sub() {
+        assert_eq!(Vector2::new(1, 2) - Vector2::new(3, 2), Vector2::new(-2, 0));
+    }
+
+    #[test]
+    fn test_index() {
+        let v = Vector2::new(-10, 4);
+        assert_eq!(v[0], -10);
+        assert_eq!(v[1], 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn test_index_out_of_bounds() {
+        let v = Vector2::new(-10, 4);
+        let _ = v[2];
+    }
+
+    #[test]
+    fn test_display() {
+        let v = Vector2::new(19, -4);
+        assert_eq!(v.to_string(), "Vector2(19, -4)");
+    }
+
+    #[test]
+    fn test_from_point() {
+        assert_eq!(Vector2::from(Point2::new(1, -2)), Vector2::new(1, -2));
+    }
+
+    #[test]
+    fn test_dot() {
+        assert_eq!(Vector2::new(1, 2).dot(Vector2::new(-2, 4)), 6);
+    }
+
+    #[test]
+    fn test_length() {
+        assert_eq!(Vector2::new(3.0, -4.0).length(), 5.0);
+    }
+}
@@ -1,9 +1,76 @@
-use crate::math::macros::n_tuple_impl;
+//! Three-dimensional vectors.
+
+use crate::math::compensated::{difference_of_products, inner_product};
+use crate::math::macros::{
+    n_tuple_add_assign_impl, n_tuple_add_impl, n_tuple_conversion_impl, n_tuple_impl,
+    n_tuple_sub_assign_impl, n_tuple_sub_impl, n_tuple_vector_impl,
+};
+use crate::math::normal3::Normal3;
+use crate::math::number_traits::Float;
+use crate::math::point3::Point3;
+
+n_tuple_impl! {
+    /// A direction or displacement in 3D space.
+    ///
+    /// ```
+    /// use pbrt::math::Vector3;
+    ///
+    /// let a = Vector3::new(1.0, 0.0, 0.0);
+    /// let b = Vector3::new(0.0, 2.0, 0.0);
+    /// assert_eq!(a.cross(b), Vector3::new(0.0, 0.0, 2.0));
+    /// assert_eq!(a.dot(b), 0.0);
+    /// assert_eq!((a + b).length_squared(), 5.0);
+    /// assert_eq!(b.normalize(), Vector3::new(0.0, 1.0, 0.0));
+    /// ```
+    Vector3, x, y, z
+}
+n_tuple_add_impl!(Vector3, Vector3, Vector3, x, y, z);
+n_tuple_add_assign_impl!(Vector3, Vector3, x, y, z);
+n_tuple_sub_impl!(Vector3, Vector3, Vector3, x, y, z);
+n_tuple_sub_assign_impl!(Vector3, Vector3, x, y, z);
+n_tuple_conversion_impl!(Point3, Vector3, x, y, z);
+n_tuple_conversion_impl!(Normal3, Vector3, x, y, z);
+n_tuple_vector_impl!(Vector3, x, y, z);
+
+impl<T: Float> Vector3<T> {
+    /// Returns the cross product, computed with
+    /// [`difference_of_products`] to avoid catastrophic cancellation.
+    ///
+    /// ```
+    /// use pbrt::math::Vector3;
+    ///
+    /// let v = Vector3::new(1.0, 2.0, 3.0).cross(Vector3::new(4.0, 5.0, 6.0));
+    /// assert_eq!(v, Vector3::new(-3.0, 6.0, -3.0));
+    /// ```
+    pub fn cross(self, rhs: Self) -> Self {
+        Self {
+            x: difference_of_products(self.y, rhs.z, self.z, rhs.y),
+            y: difference_of_products(self.z, rhs.x, self.x, rhs.z),
+            z: difference_of_products(self.x, rhs.y, self.y, rhs.x),
+        }
+    }
 
-n_tuple_impl! {Vector3, x, y, z}
+    /// Returns the dot product computed with [`inner_product`], which stays
+    /// accurate when the products nearly cancel, unlike the plain sum in
+    /// `dot`.
+    ///
+    /// ```
+    /// use pbrt::math::Vector3;
+    ///
+    /// let a = Vector3::new(1e16f64, 1.0, -1e16);
+    /// let b = Vector3::new(1.0, 1.0, 1.0);
+    /// assert_eq!(a.compensated_dot(b), 1.0);
+    /// assert_eq!(a.dot(b), 0.0);
+    /// ```
+    pub fn compensated_dot(self, rhs: Self) -> T {
+        inner_product([self.x, self.y, self.z], [rhs.x, rhs.y, rhs.z])
+    }
+}
 
 #[cfg(test)]
 mod tests {
+    use crate::math::normal3::Normal3;
+    use crate::math::point3::Point3;
     use crate::math::vector3::Vector3;
 
     #[test]
@@ -149,7 +216,7 @@ mod tests {
     #[should_panic(expected = "index out of bounds")]
     fn test_index_out_of_bounds() {
         let v = Vector3::new(-10, 4, 42);
-        v[42];
+        let _ = v[42];
     }
 
     #[test]
@@ -243,4 +310,166 @@ mod tests {
             Vector3::new(2.0, 4.0, 6.0)
         );
     }
+
+    #[test]
+    fn test_from_point() {
+        assert_eq!(Vector3::from(Point3::new(1, -2, 3)), Vector3::new(1, -2, 3));
+    }
+
+    #[test]
+    fn test_from_normal() {
+        assert_eq!(
+            Vector3::from(Normal3::new(0.0, 1.0, 0.0)),
+            Vector3::new(0.0, 1.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_dot() {
+        assert_eq!(Vector3::new(1, 2, 3).dot(Vector3::new(-2, 0, 4)), 10);
+        assert_eq!(
+            Vector3::new(0.5, -1.0, 2.0).dot(Vector3::new(2.0, 1.0, 0.25)),
+            0.5
+        );
+    }
+
+    #[test]
+    fn test_compensated_dot() {
+        assert_eq!(
+            Vector3::new(0.5, -1.0, 2.0).compensated_dot(Vector3::new(2.0, 1.0, 0.25)),
+            0.5
+        );
+
+        // Each case cancels to a tiny exact value that the plain sum loses.
+        let e = f32::EPSILON;
+        let cases = [
+            (
+                Vector3::new(1.0 + e, 1.0, -1.0),
+                Vector3::new(1.0 - e, -0.5, 0.5),
+                -f64::from(e) * f64::from(e),
+            ),
+            (
+                Vector3::new(1e8f32, 3.0, -1e8),
+                Vector3::new(1.0, 0.1, 1.0),
+                3.0 * f64::from(0.1f32),
+            ),
+            (
+                Vector3::new(1.0 / 3.0f32, -1.0 / 3.0, 1.0),
+                Vector3::new(3.0, 3.0, 1e-7),
+                f64::from(1e-7f32),
+            ),
+        ];
+        for (a, b, exact) in cases {
+            let dot = f64::from(a.compensated_dot(b));
+            assert!(
+                (dot - exact).abs() <= exact.abs() * f64::from(f32::EPSILON),
+                "{a:?} . {b:?}: {dot} vs {exact}"
+            );
+        }
+        let (a, b, exact) = cases[0];
+        assert_ne!(f64::from(a.dot(b)), exact);
+        let (a, b, exact) = cases[1];
+        assert_ne!(f64::from(a.dot(b)), exact);
+    }
+
+    #[test]
+    fn test_abs_dot() {
+        assert_eq!(Vector3::new(1, 2, 3).abs_dot(Vector3::new(-2, 0, -4)), 14);
+    }
+
+    #[test]
+    fn test_length_squared() {
+        assert_eq!(Vector3::new(1, -2, 2).length_squared(), 9);
+    }
+
+    #[test]
+    fn test_length() {
+        assert_eq!(Vector3::new(2.0, -3.0, 6.0).length(), 7.0);
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(
+            Vector3::new(0.0, -4.0, 3.0).normalize(),
+            Vector3::new(0.0, -0.8, 0.6)
+        );
+        assert!((Vector3::new(1.3f64, -0.2, 4.1).normalize().length() - 1.0).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_cross() {
+        assert_eq!(
+            Vector3::new(1.0, 0.0, 0.0).cross(Vector3::new(0.0, 1.0, 0.0)),
+            Vector3::new(0.0, 0.0, 1.0)
+        );
+        assert_eq!(
+            Vector3::new(2.0, -1.0, 3.0).cross(Vector3::new(0.5, 4.0, -2.0)),
+            Vector3::new(-10.0, 5.5, 8.5)
+        );
+    }
+
+    #[test]
+    fn test_cross_nearly_parallel() {
+        // The naive formulation cancels to zero for the z component here.
+        let a = Vector3::new(1.0f32 + f32::EPSILON, 1.0, 0.0);
+        let b = Vector3::new(1.0f32, 1.0 - f32::EPSILON, 0.0);
+        let c = a.cross(b);
+        let expected = (1.0f64 + f64::from(f32::EPSILON)) * (1.0 - f64::from(f32::EPSILON)) - 1.0;
+        assert_eq!(c.x, 0.0);
+        assert_eq!(c.y, 0.0);
+        assert_eq!(f64::from(c.z), expected);
+    }
+
+    #[test]
+    fn test_angle_between() {
+        let x = Vector3::new(1.0, 0.0, 0.0);
+        let y = Vector3::new(0.0, 1.0, 0.0);
+        assert!((x.angle_between(y) - std::f64::consts::FRAC_PI_2).abs() < 1e-15);
+        assert!((x.angle_between(-x) - std::f64::consts::PI).abs() < 1e-15);
+        assert_eq!(x.angle_between(x), 0.0);
+    }
+
+    #[test]
+    fn test_angle_between_nearly_parallel() {
+        let a = Vector3::new(1.0f32, 0.0, 0.0);
+        let b = Vector3::new(1.0f32, 1e-4, 0.0).normalize();
+        assert!((a.angle_between(b) - 1e-4).abs() < 1e-9);
+        assert!((a.angle_between(-b) - (std::f32::consts::PI - 1e-4)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_gram_schmidt() {
+        let w = Vector3::new(0.0, 0.0, 1.0);
+        let v = Vector3::new(1.0, 2.0, 3.0).gram_schmidt(w);
+        assert_eq!(v, Vector3::new(1.0, 2.0, 0.0));
+        assert_eq!(v.dot(w), 0.0);
+    }
+
+    #[test]
+    fn test_face_forward() {
+        let n = Vector3::new(0.0, 0.0, 1.0);
+        assert_eq!(n.face_forward(Vector3::new(0.3, 0.1, 0.5)), n);
+        assert_eq!(n.face_forward(Vector3::new(0.3, 0.1, -0.5)), -n);
+    }
+
+    #[test]
+    fn test_sum() {
+        let vs = [
+            Vector3::new(1.0f32, 2.0, 3.0),
+            Vector3::new(-0.5, 0.5, 1.0),
+            Vector3::new(0.25, 0.0, -2.0),
+        ];
+        assert_eq!(
+            vs.iter().copied().sum::<Vector3<f32>>(),
+            Vector3::new(0.75, 2.5, 2.0)
+        );
+        assert_eq!(
+            vs.iter().sum::<Vector3<f32>>(),
+            Vector3::new(0.75, 2.5, 2.0)
+        );
+        assert_eq!(
+            std::iter::empty::<Vector3<i32>>().sum::<Vector3<i32>>(),
+            Vector3::new(0, 0, 0)
+        );
+    }
 }
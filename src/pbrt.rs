@@ -1 +1 @@
-fn main() {}
\ No newline at end of file
+fn main() {}
@@ -0,0 +1,418 @@
+//! Hash functions for deriving deterministic seeds and values from
+//! arbitrary inputs such as pixel coordinates and sample indices.
+
+use crate::math::number_traits::Float;
+
+/// Hashes `key` with MurmurHash64A (Appleby, SMHasher). Blocks of eight
+/// bytes are read as little-endian integers, so the result is the same on
+/// every platform.
+///
+/// ```
+/// use pbrt::random::murmur_hash64a;
+///
+/// assert_eq!(murmur_hash64a(b"", 0), 0);
+/// assert_ne!(murmur_hash64a(b"pbrt", 0), murmur_hash64a(b"pbrt", 1));
+/// ```
+pub fn murmur_hash64a(key: &[u8], seed: u64) -> u64 {
+    const M: u64 = 0xc6a4_a793_5bd1_e995;
+    const R: u32 = 47;
+    let mut h = seed ^ (key.len() as u64).wrapping_mul(M);
+
+    let mut blocks = key.chunks_exact(8);
+    for block in &mut blocks {
+        let mut k = u64::from_le_bytes(block.try_into().unwrap());
+        k = k.wrapping_mul(M);
+        k ^= k >> R;
+        k = k.wrapping_mul(M);
+        h ^= k;
+        h = h.wrapping_mul(M);
+    }
+
+    let tail = blocks.remainder();
+    if !tail.is_empty() {
+        for (i, &byte) in tail.iter().enumerate() {
+            h ^= u64::from(byte) << (8 * i);
+        }
+        h = h.wrapping_mul(M);
+    }
+
+    h ^= h >> R;
+    h = h.wrapping_mul(M);
+    h ^= h >> R;
+    h
+}
+
+/// Scrambles the bits of `v` with a bijective finalizer, so that inputs
+/// differing in a single bit give unrelated outputs. Zero maps to zero.
+///
+/// ```
+/// use pbrt::random::mix_bits;
+///
+/// assert_eq!(mix_bits(0), 0);
+/// assert!((mix_bits(1) ^ mix_bits(2)).count_ones() > 16);
+/// ```
+pub fn mix_bits(mut v: u64) -> u64 {
+    v ^= v >> 31;
+    v = v.wrapping_mul(0x7fb5_d329_728e_a185);
+    v ^= v >> 27;
+    v = v.wrapping_mul(0x81da_def4_bc2d_d44d);
+    v ^= v >> 33;
+    v
+}
+
+/// Returns element `i` of a pseudo-random permutation of `0..l` chosen by
+/// `p`, without storing the permutation (Kensler, "Correlated Multi-Jittered
+/// Sampling"). For a fixed `p`, distinct `i < l` map to distinct elements.
+///
+/// ```
+/// use pbrt::random::permutation_element;
+///
+/// let mut elements: Vec<u32> = (0..10).map(|i| permutation_element(i, 10, 1234)).collect();
+/// elements.sort();
+/// assert_eq!(elements, (0..10).collect::<Vec<_>>());
+/// ```
+pub fn permutation_element(mut i: u32, l: u32, p: u32) -> u32 {
+    debug_assert!(i < l);
+    // Work in the smallest power of two no smaller than l, and cycle-walk
+    // until the result lands in range.
+    let mut w = l - 1;
+    w |= w >> 1;
+    w |= w >> 2;
+    w |= w >> 4;
+    w |= w >> 8;
+    w |= w >> 16;
+    loop {
+        i ^= p;
+        i = i.wrapping_mul(0xe170_893d);
+        i ^= p >> 16;
+        i ^= (i & w) >> 4;
+        i ^= p >> 8;
+        i = i.wrapping_mul(0x0929_eb3f);
+        i ^= p >> 23;
+        i ^= (i & w) >> 1;
+        i = i.wrapping_mul(1 | (p >> 27));
+        i = i.wrapping_mul(0x6935_fa69);
+        i ^= (i & w) >> 11;
+        i = i.wrapping_mul(0x74dc_b303);
+        i ^= (i & w) >> 2;
+        i = i.wrapping_mul(0x9e50_1cc3);
+        i ^= (i & w) >> 2;
+        i = i.wrapping_mul(0xc860_a3df);
+        i &= w;
+        i ^= i >> 5;
+        if i < l {
+            break;
+        }
+    }
+    (i + p % l) % l
+}
+
+/// Maps a hash to a float in `[0, 1)` using its low 32 bits.
+///
+/// ```
+/// use pbrt::random::hash_to_float;
+///
+/// assert_eq!(hash_to_float::<f32>(0), 0.0);
+/// assert!(hash_to_float::<f32>(u64::MAX) < 1.0);
+/// ```
+pub fn hash_to_float<T: Float>(hash: u64) -> T {
+    // 2^-32
+    let u = f64::from(hash as u32) * 2.328_306_436_538_696_3e-10;
+    T::from_f64(u).min(T::ONE_MINUS_EPSILON)
+}
+
+/// A fixed-capacity byte buffer that the [`hash!`](crate::hash) macro
+/// collects its arguments into, to avoid allocating.
+#[derive(Clone, Debug)]
+pub struct HashBuffer {
+    bytes: [u8; Self::CAPACITY],
+    len: usize,
+}
+
+impl HashBuffer {
+    /// The maximum number of bytes the buffer holds.
+    pub const CAPACITY: usize = 256;
+
+    /// Creates an empty buffer.
+    pub fn new() -> Self {
+        Self {
+            bytes: [0; Self::CAPACITY],
+            len: 0,
+        }
+    }
+
+    /// Appends `bytes`, panicking if they do not fit.
+    pub fn extend_from_slice(&mut self, bytes: &[u8]) {
+        let end = self.len + bytes.len();
+        assert!(
+            end <= Self::CAPACITY,
+            "hashed values exceed {} bytes",
+            Self::CAPACITY
+        );
+        self.bytes[self.len..end].copy_from_slice(bytes);
+        self.len = end;
+    }
+
+    /// Returns the bytes written so far.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+impl Default for HashBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A value that can be hashed with [`hash!`](crate::hash), by writing a
+/// platform-independent byte representation of itself: integers and floats
+/// are written in little-endian order, `usize` and `isize` as 64 bits, and
+/// tuple types component by component.
+///
+/// ```
+/// use pbrt::hash;
+/// use pbrt::random::{HashBuffer, HashBytes};
+///
+/// struct Pixel {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// impl HashBytes for Pixel {
+///     fn write_bytes(&self, buffer: &mut HashBuffer) {
+///         self.x.write_bytes(buffer);
+///         self.y.write_bytes(buffer);
+///     }
+/// }
+///
+/// assert_eq!(hash!(Pixel { x: 3, y: 4 }), hash!(3i32, 4i32));
+/// ```
+pub trait HashBytes {
+    /// Appends the bytes that represent `self`.
+    fn write_bytes(&self, buffer: &mut HashBuffer);
+}
+
+macro_rules! hash_bytes_impl {
+    ($($typ:ty),+) => {
+        $(
+            impl HashBytes for $typ {
+                fn write_bytes(&self, buffer: &mut HashBuffer) {
+                    buffer.extend_from_slice(&self.to_le_bytes());
+                }
+            }
+        )+
+    };
+}
+
+hash_bytes_impl!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+impl HashBytes for usize {
+    fn write_bytes(&self, buffer: &mut HashBuffer) {
+        (*self as u64).write_bytes(buffer);
+    }
+}
+
+impl HashBytes for isize {
+    fn write_bytes(&self, buffer: &mut HashBuffer) {
+        (*self as i64).write_bytes(buffer);
+    }
+}
+
+impl HashBytes for bool {
+    fn write_bytes(&self, buffer: &mut HashBuffer) {
+        u8::from(*self).write_bytes(buffer);
+    }
+}
+
+impl<T: HashBytes + ?Sized> HashBytes for &T {
+    fn write_bytes(&self, buffer: &mut HashBuffer) {
+        (**self).write_bytes(buffer);
+    }
+}
+
+impl<T: HashBytes> HashBytes for [T] {
+    fn write_bytes(&self, buffer: &mut HashBuffer) {
+        for v in self {
+            v.write_bytes(buffer);
+        }
+    }
+}
+
+impl<T: HashBytes, const N: usize> HashBytes for [T; N] {
+    fn write_bytes(&self, buffer: &mut HashBuffer) {
+        self.as_slice().write_bytes(buffer);
+    }
+}
+
+/// Hashes any number of [`HashBytes`](crate::random::HashBytes) values
+/// together with [`murmur_hash64a`](crate::random::murmur_hash64a),
+/// returning a `u64`. The result depends on the values and their types, not
+/// on the platform, so it can seed per-pixel and per-dimension sample
+/// generators reproducibly.
+///
+/// ```
+/// use pbrt::hash;
+/// use pbrt::math::Point2;
+///
+/// let pixel = Point2::new(10, 20);
+/// let a = hash!(pixel, 0u32, 5u32);
+/// assert_eq!(a, hash!(Point2::new(10, 20), 0u32, 5u32));
+/// assert_ne!(a, hash!(pixel, 1u32, 5u32));
+/// // Values of different types have different byte representations.
+/// assert_ne!(hash!(1u32), hash!(1u64));
+/// ```
+#[macro_export]
+macro_rules! hash {
+    ($($arg:expr),+ $(,)?) => {{
+        let mut buffer = $crate::random::HashBuffer::new();
+        $($crate::random::HashBytes::write_bytes(&$arg, &mut buffer);)+
+        $crate::random::murmur_hash64a(buffer.as_bytes(), 0)
+    }};
+}
+
+/// Hashes its arguments with [`hash!`](crate::hash) and maps the result to
+/// an `f32` in `[0, 1)`, for example to jitter procedural textures
+/// reproducibly.
+///
+/// ```
+/// use pbrt::hash_float;
+///
+/// let u = hash_float!(3i32, 4i32);
+/// assert!((0.0..1.0).contains(&u));
+/// assert_eq!(u, hash_float!(3i32, 4i32));
+/// ```
+#[macro_export]
+macro_rules! hash_float {
+    ($($arg:expr),+ $(,)?) => {
+        $crate::random::hash_to_float::<f32>($crate::hash!($($arg),+))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::point2::Point2;
+    use crate::math::vector3::Vector3;
+    use crate::random::hash::{
+        HashBuffer, HashBytes, hash_to_float, mix_bits, murmur_hash64a, permutation_element,
+    };
+
+    #[test]
+    fn test_murmur_hash64a_verification() {
+        // SMHasher's verification procedure: hash keys of the bytes 0, 1,
+        // ... of every length up to 255 with seed 256 - length, then hash
+        // the concatenated results.
+        let key: Vec<u8> = (0..=255).collect();
+        let mut hashes = Vec::new();
+        for i in 0..256 {
+            let h = murmur_hash64a(&key[..i], 256 - i as u64);
+            hashes.extend_from_slice(&h.to_le_bytes());
+        }
+        let h = murmur_hash64a(&hashes, 0);
+        assert_eq!(h as u32, 0x1f0d_3804);
+    }
+
+    #[test]
+    fn test_murmur_hash64a_tail() {
+        // Every tail length contributes to the hash.
+        let key = [1u8, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+        let hashes: Vec<u64> = (0..=key.len())
+            .map(|n| murmur_hash64a(&key[..n], 0))
+            .collect();
+        for i in 0..hashes.len() {
+            for j in 0..i {
+                assert_ne!(hashes[i], hashes[j]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mix_bits() {
+        assert_eq!(mix_bits(0), 0);
+        // Flipping any input bit flips about half of the output bits.
+        let n = 1000u64;
+        let mut total = 0;
+        for v in 1..=n {
+            for bit in 0..64 {
+                total += (mix_bits(v) ^ mix_bits(v ^ (1 << bit))).count_ones();
+            }
+        }
+        let average = total as f64 / (n * 64) as f64;
+        assert!((average - 32.0).abs() < 0.5, "{average}");
+        let distinct: std::collections::HashSet<u64> = (0..10_000).map(mix_bits).collect();
+        assert_eq!(distinct.len(), 10_000);
+    }
+
+    #[test]
+    fn test_hash_matches_bytes() {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&7u32.to_le_bytes());
+        bytes.extend_from_slice(&(-2i64).to_le_bytes());
+        bytes.extend_from_slice(&1.5f32.to_le_bytes());
+        bytes.extend_from_slice(&9u64.to_le_bytes());
+        assert_eq!(
+            hash!(7u32, -2i64, 1.5f32, 9usize),
+            murmur_hash64a(&bytes, 0)
+        );
+    }
+
+    #[test]
+    fn test_hash_tuples() {
+        let v = Vector3::new(1.0f32, 2.0, 3.0);
+        assert_eq!(hash!(v), hash!(1.0f32, 2.0f32, 3.0f32));
+        assert_eq!(hash!(&v), hash!(v));
+        assert_eq!(hash!([1.0f32, 2.0, 3.0]), hash!(v));
+        let p = Point2::new(4, 5);
+        assert_eq!(hash!(p, 6u32), hash!(4i32, 5i32, 6u32));
+        assert_ne!(hash!(p, 6u32), hash!(Point2::new(5, 4), 6u32));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_hash_buffer_overflow() {
+        let mut buffer = HashBuffer::new();
+        [0u64; 33].write_bytes(&mut buffer);
+    }
+
+    #[test]
+    fn test_hash_float() {
+        assert_eq!(hash_to_float::<f64>(0), 0.0);
+        assert_eq!(hash_to_float::<f64>(1 << 31), 0.5);
+        // Only the low 32 bits are used.
+        assert_eq!(hash_to_float::<f64>(0xffff_0000_8000_0000), 0.5);
+        assert_eq!(
+            hash_to_float::<f32>(u64::from(u32::MAX)),
+            f32::from_bits(0x3f7f_ffff)
+        );
+
+        // Hashes of consecutive inputs are spread uniformly.
+        let n = 100_000;
+        let mut buckets = [0; 10];
+        for i in 0..n {
+            let u = hash_float!(i as u32, 17u32);
+            assert!((0.0..1.0).contains(&u));
+            buckets[(u * 10.0) as usize] += 1;
+        }
+        for count in buckets {
+            assert!((count as f64 - n as f64 / 10.0).abs() < n as f64 / 100.0);
+        }
+    }
+
+    #[test]
+    fn test_permutation_element() {
+        for l in 1..=70 {
+            for p in [0u32, 1, 0xdead_beef, 0x1234_5678, u32::MAX] {
+                let mut seen = vec![false; l as usize];
+                for i in 0..l {
+                    let e = permutation_element(i, l, p);
+                    assert!(!seen[e as usize], "{e} repeats for l = {l}, p = {p}");
+                    seen[e as usize] = true;
+                }
+            }
+        }
+        // Different seeds give different permutations.
+        let a: Vec<u32> = (0..16).map(|i| permutation_element(i, 16, 1)).collect();
+        let b: Vec<u32> = (0..16).map(|i| permutation_element(i, 16, 2)).collect();
+        assert_ne!(a, b);
+    }
+}
@@ -0,0 +1,18 @@
+//! Deterministic pseudo-random number generation and hashing.
+//!
+//! ```
+//! use pbrt::random::Rng;
+//!
+//! let mut rng = Rng::with_sequence(7, 0);
+//! let u: f32 = rng.uniform();
+//! assert!((0.0..1.0).contains(&u));
+//! ```
+
+pub mod hash;
+pub mod rng;
+
+pub use crate::{hash, hash_float};
+pub use hash::{
+    HashBuffer, HashBytes, hash_to_float, mix_bits, murmur_hash64a, permutation_element,
+};
+pub use rng::{Rng, Uniform};
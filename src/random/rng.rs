@@ -0,0 +1,337 @@
+//! The PCG32 pseudo-random number generator.
+
+use crate::math::number_traits::Float;
+use crate::math::vector3::Vector3;
+
+const PCG32_DEFAULT_STATE: u64 = 0x853c_49e6_748f_ea9b;
+const PCG32_DEFAULT_STREAM: u64 = 0xda3e_39cb_94b9_5bdb;
+const PCG32_MULT: u64 = 0x5851_f42d_4c95_7f2d;
+
+/// A PCG32 generator (O'Neill, "PCG: A Family of Simple Fast
+/// Space-Efficient Statistically Good Algorithms for Random Number
+/// Generation"), with 64 bits of state and one of 2^63 independent
+/// sequences. It can jump forward or backward in its sequence in
+/// logarithmic time, so that a renderer can give each pixel sample its own
+/// reproducible stream of values.
+///
+/// ```
+/// use pbrt::math::Vector3;
+/// use pbrt::random::Rng;
+///
+/// let mut rng = Rng::with_sequence(1, 0);
+/// let mut skipped = rng.clone();
+/// let values: Vec<u32> = (0..10).map(|_| rng.uniform()).collect();
+/// skipped.advance(9);
+/// assert_eq!(skipped.uniform::<u32>(), values[9]);
+/// assert_eq!(&rng - &skipped, 0);
+///
+/// let v: Vector3<f64> = rng.uniform();
+/// assert!(v.x < 1.0 && v.y < 1.0 && v.z < 1.0);
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Rng {
+    state: u64,
+    inc: u64,
+}
+
+impl Rng {
+    /// Creates a generator at the start of the default sequence.
+    pub fn new() -> Self {
+        Self {
+            state: PCG32_DEFAULT_STATE,
+            inc: PCG32_DEFAULT_STREAM,
+        }
+    }
+
+    /// Creates a generator for the given sequence, seeded with `offset`.
+    pub fn with_sequence(sequence_index: u64, offset: u64) -> Self {
+        let mut rng = Self::new();
+        rng.set_sequence(sequence_index, offset);
+        rng
+    }
+
+    /// Restarts the generator in the given sequence, seeded with `offset`.
+    /// Sequences differ only in their increment, so only the low 63 bits of
+    /// `sequence_index` matter.
+    pub fn set_sequence(&mut self, sequence_index: u64, offset: u64) {
+        self.state = 0;
+        self.inc = (sequence_index << 1) | 1;
+        self.next_u32();
+        self.state = self.state.wrapping_add(offset);
+        self.next_u32();
+    }
+
+    /// Returns a uniformly distributed value; see [`Uniform`] for the
+    /// supported types and their ranges.
+    pub fn uniform<T: Uniform>(&mut self) -> T {
+        T::uniform(self)
+    }
+
+    /// Moves the generator `delta` steps along its sequence, backward if
+    /// `delta` is negative, in time logarithmic in `|delta|` (Brown,
+    /// "Random Number Generation with Arbitrary Strides").
+    pub fn advance(&mut self, delta: i64) {
+        let mut cur_mult = PCG32_MULT;
+        let mut cur_plus = self.inc;
+        let mut acc_mult = 1u64;
+        let mut acc_plus = 0u64;
+        // Stepping backward is stepping forward almost 2^64 steps.
+        let mut delta = delta as u64;
+        while delta > 0 {
+            if delta & 1 != 0 {
+                acc_mult = acc_mult.wrapping_mul(cur_mult);
+                acc_plus = acc_plus.wrapping_mul(cur_mult).wrapping_add(cur_plus);
+            }
+            cur_plus = cur_mult.wrapping_add(1).wrapping_mul(cur_plus);
+            cur_mult = cur_mult.wrapping_mul(cur_mult);
+            delta /= 2;
+        }
+        self.state = acc_mult.wrapping_mul(self.state).wrapping_add(acc_plus);
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        let old_state = self.state;
+        self.state = old_state.wrapping_mul(PCG32_MULT).wrapping_add(self.inc);
+        let xor_shifted = (((old_state >> 18) ^ old_state) >> 27) as u32;
+        let rot = (old_state >> 59) as u32;
+        xor_shifted.rotate_right(rot)
+    }
+}
+
+impl Default for Rng {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the number of steps from `rhs` to `self`, so that advancing
+/// `rhs` by the result gives `self`. Both generators must be in the same
+/// sequence.
+impl std::ops::Sub for &Rng {
+    type Output = i64;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        assert_eq!(self.inc, rhs.inc, "generators are in different sequences");
+        let mut cur_mult = PCG32_MULT;
+        let mut cur_plus = self.inc;
+        let mut cur_state = rhs.state;
+        let mut the_bit = 1u64;
+        let mut distance = 0u64;
+        // Find the distance one bit at a time, from the lowest: bit k of the
+        // state only depends on the low k bits of the distance.
+        while self.state != cur_state {
+            if (self.state & the_bit) != (cur_state & the_bit) {
+                cur_state = cur_state.wrapping_mul(cur_mult).wrapping_add(cur_plus);
+                distance |= the_bit;
+            }
+            debug_assert_eq!(self.state & the_bit, cur_state & the_bit);
+            the_bit <<= 1;
+            cur_plus = cur_mult.wrapping_add(1).wrapping_mul(cur_plus);
+            cur_mult = cur_mult.wrapping_mul(cur_mult);
+        }
+        distance as i64
+    }
+}
+
+/// A type whose values [`Rng::uniform`] can generate.
+///
+/// Integers are uniform over their whole range. Floats are uniform in
+/// `[0, 1)`: they are multiples of `2^-32` for `f32` and `2^-64` for `f64`,
+/// clamped to [`Float::ONE_MINUS_EPSILON`] so that rounding never yields
+/// one. Vectors have each component generated in turn.
+///
+/// ```
+/// use pbrt::random::{Rng, Uniform};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Coin(bool);
+///
+/// impl Uniform for Coin {
+///     fn uniform(rng: &mut Rng) -> Self {
+///         Coin(rng.uniform::<u32>() & 1 == 1)
+///     }
+/// }
+///
+/// let mut a = Rng::new();
+/// let mut b = Rng::new();
+/// assert_eq!(a.uniform::<Coin>(), b.uniform::<Coin>());
+/// ```
+pub trait Uniform {
+    /// Returns a value generated with `rng`.
+    fn uniform(rng: &mut Rng) -> Self;
+}
+
+impl Uniform for u32 {
+    fn uniform(rng: &mut Rng) -> Self {
+        rng.next_u32()
+    }
+}
+
+impl Uniform for u64 {
+    fn uniform(rng: &mut Rng) -> Self {
+        let high = u64::from(rng.next_u32());
+        let low = u64::from(rng.next_u32());
+        (high << 32) | low
+    }
+}
+
+impl Uniform for i32 {
+    fn uniform(rng: &mut Rng) -> Self {
+        rng.next_u32() as i32
+    }
+}
+
+impl Uniform for i64 {
+    fn uniform(rng: &mut Rng) -> Self {
+        rng.uniform::<u64>() as i64
+    }
+}
+
+impl Uniform for f32 {
+    fn uniform(rng: &mut Rng) -> Self {
+        // 2^-32
+        (rng.next_u32() as f32 * 2.328_306_4e-10).min(f32::ONE_MINUS_EPSILON)
+    }
+}
+
+impl Uniform for f64 {
+    fn uniform(rng: &mut Rng) -> Self {
+        // 2^-64
+        (rng.uniform::<u64>() as f64 * 5.421_010_862_427_522e-20).min(f64::ONE_MINUS_EPSILON)
+    }
+}
+
+impl<T: Uniform> Uniform for Vector3<T> {
+    fn uniform(rng: &mut Rng) -> Self {
+        Vector3 {
+            x: rng.uniform(),
+            y: rng.uniform(),
+            z: rng.uniform(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::number_traits::Float;
+    use crate::math::vector3::Vector3;
+    use crate::random::rng::Rng;
+
+    #[test]
+    fn test_reference_output() {
+        // The first values printed by pcg32-global-demo from the reference
+        // implementation, seeded with initstate 42 and initseq 54.
+        let mut rng = Rng::with_sequence(54, 42);
+        let expected = [
+            0xa15c02b7, 0x7b47f409, 0xba1d3330, 0x83d2f293, 0xbfa4784b, 0xcbed606e,
+        ];
+        for e in expected {
+            assert_eq!(rng.uniform::<u32>(), e);
+        }
+    }
+
+    #[test]
+    fn test_default_is_deterministic() {
+        let mut a = Rng::default();
+        let mut b = Rng::new();
+        for _ in 0..100 {
+            assert_eq!(a.uniform::<u64>(), b.uniform::<u64>());
+        }
+    }
+
+    #[test]
+    fn test_sequences_differ() {
+        let mut a = Rng::with_sequence(0, 0);
+        let mut b = Rng::with_sequence(1, 0);
+        let a_values: Vec<u32> = (0..16).map(|_| a.uniform()).collect();
+        let b_values: Vec<u32> = (0..16).map(|_| b.uniform()).collect();
+        assert_ne!(a_values, b_values);
+    }
+
+    #[test]
+    fn test_advance() {
+        let mut rng = Rng::with_sequence(1234, 5678);
+        let start = rng.clone();
+        let values: Vec<u32> = (0..1000).map(|_| rng.uniform()).collect();
+        for (i, &v) in values.iter().enumerate() {
+            let mut skipped = start.clone();
+            skipped.advance(i as i64);
+            assert_eq!(skipped.uniform::<u32>(), v);
+        }
+
+        // Advancing backward undoes advancing forward.
+        let mut back = rng.clone();
+        back.advance(-1000);
+        assert_eq!(back, start);
+        let mut far = start.clone();
+        far.advance(1 << 40);
+        far.advance(-(1 << 40));
+        assert_eq!(far, start);
+    }
+
+    #[test]
+    fn test_difference() {
+        let start = Rng::with_sequence(3, 17);
+        for delta in [0i64, 1, 2, 7, 1000, 123_456_789, 1 << 40, -1, -5000] {
+            let mut rng = start.clone();
+            rng.advance(delta);
+            assert_eq!(&rng - &start, delta);
+            assert_eq!(&start - &rng, -delta);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_difference_requires_same_sequence() {
+        let _ = &Rng::with_sequence(1, 0) - &Rng::with_sequence(2, 0);
+    }
+
+    #[test]
+    fn test_uniform_floats_are_below_one() {
+        let mut rng = Rng::new();
+        let mut sum = 0.0;
+        let n = 100_000;
+        for _ in 0..n {
+            let u: f32 = rng.uniform();
+            assert!((0.0..1.0).contains(&u));
+            let v: f64 = rng.uniform();
+            assert!((0.0..1.0).contains(&v));
+            sum += v;
+        }
+        assert!((sum / n as f64 - 0.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_uniform_float_clamps_largest_value() {
+        // The largest outputs would round up to one without the clamp.
+        assert!((u32::MAX as f32 * 2.328_306_4e-10) >= 1.0);
+        assert_eq!(
+            (u32::MAX as f32 * 2.328_306_4e-10).min(f32::ONE_MINUS_EPSILON),
+            f32::ONE_MINUS_EPSILON
+        );
+    }
+
+    #[test]
+    fn test_uniform_integers() {
+        let mut a = Rng::with_sequence(9, 9);
+        let mut b = a.clone();
+        let high = u64::from(b.uniform::<u32>());
+        let low = u64::from(b.uniform::<u32>());
+        assert_eq!(a.uniform::<u64>(), (high << 32) | low);
+        let mut a = Rng::with_sequence(9, 9);
+        let mut b = a.clone();
+        assert_eq!(a.uniform::<i32>() as u32, b.uniform::<u32>());
+        assert_eq!(a.uniform::<i64>() as u64, b.uniform::<u64>());
+    }
+
+    #[test]
+    fn test_uniform_vector3() {
+        let mut a = Rng::with_sequence(2, 0);
+        let mut b = a.clone();
+        let v: Vector3<f32> = a.uniform();
+        assert_eq!(
+            v,
+            Vector3::new(b.uniform::<f32>(), b.uniform(), b.uniform())
+        );
+    }
+}
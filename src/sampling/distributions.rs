@@ -0,0 +1,770 @@
+//! Distributions tabulated from sampled functions, for importance sampling
+//! things like environment maps and image textures.
+//!
+//! Two-dimensional tables are given as slices in row-major order: the value
+//! for column `x` of row `y` of a table `nx` wide is at `y * nx + x`.
+
+use crate::math::bounds2::Bounds2;
+use crate::math::functions::lerp;
+use crate::math::number_traits::Float;
+use crate::math::point2::Point2;
+
+/// A piecewise-constant function over an interval, normalized to sample in
+/// proportion to its absolute value.
+///
+/// ```
+/// use pbrt::sampling::PiecewiseConstant1D;
+///
+/// let d = PiecewiseConstant1D::new(&[1.0, 3.0]);
+/// // A quarter of the samples land in the first half.
+/// let (x, pdf, offset) = d.sample(0.125);
+/// assert_eq!((x, pdf, offset), (0.25, 0.5, 0));
+/// let (x, pdf, offset) = d.sample(0.625);
+/// assert_eq!((x, pdf, offset), (0.75, 1.5, 1));
+/// assert_eq!(d.invert(0.75), Some(0.625));
+/// ```
+#[derive(Clone, Debug)]
+pub struct PiecewiseConstant1D {
+    func: Vec<f32>,
+    cdf: Vec<f32>,
+    min: f32,
+    max: f32,
+    func_int: f32,
+}
+
+impl PiecewiseConstant1D {
+    /// Creates the distribution of the function with the given values over
+    /// equal parts of `[0, 1]`.
+    pub fn new(f: &[f32]) -> Self {
+        Self::with_domain(f, 0.0, 1.0)
+    }
+
+    /// Creates the distribution of the function with the given values over
+    /// equal parts of `[min, max]`. A function that is zero everywhere is
+    /// sampled uniformly.
+    pub fn with_domain(f: &[f32], min: f32, max: f32) -> Self {
+        assert!(!f.is_empty(), "a distribution needs at least one value");
+        let func: Vec<f32> = f.iter().map(|v| v.abs()).collect();
+        let n = func.len();
+        let mut cdf = vec![0.0; n + 1];
+        for i in 1..=n {
+            cdf[i] = cdf[i - 1] + func[i - 1] * (max - min) / n as f32;
+        }
+        let func_int = cdf[n];
+        if func_int == 0.0 {
+            for (i, c) in cdf.iter_mut().enumerate() {
+                *c = i as f32 / n as f32;
+            }
+        } else {
+            for c in &mut cdf {
+                *c /= func_int;
+            }
+        }
+        Self {
+            func,
+            cdf,
+            min,
+            max,
+            func_int,
+        }
+    }
+
+    /// Returns the number of pieces.
+    pub fn size(&self) -> usize {
+        self.func.len()
+    }
+
+    /// Returns the integral of the function's absolute value over its
+    /// domain.
+    pub fn integral(&self) -> f32 {
+        self.func_int
+    }
+
+    /// Returns the absolute values of the pieces.
+    pub fn func(&self) -> &[f32] {
+        &self.func
+    }
+
+    /// Returns the domain as `(min, max)`.
+    pub fn domain(&self) -> (f32, f32) {
+        (self.min, self.max)
+    }
+
+    // The piece whose CDF range holds u.
+    fn find_interval(&self, u: f32) -> usize {
+        let first = self.cdf.partition_point(|&c| c <= u);
+        first.saturating_sub(1).min(self.cdf.len() - 2)
+    }
+
+    /// Samples a point in the domain, returning it with its density and the
+    /// index of the piece it lies in.
+    pub fn sample(&self, u: f32) -> (f32, f32, usize) {
+        let offset = self.find_interval(u);
+        let mut du = u - self.cdf[offset];
+        if self.cdf[offset + 1] - self.cdf[offset] > 0.0 {
+            du /= self.cdf[offset + 1] - self.cdf[offset];
+        }
+        let pdf = if self.func_int > 0.0 {
+            self.func[offset] / self.func_int
+        } else {
+            0.0
+        };
+        let x = lerp(
+            (offset as f32 + du) / self.size() as f32,
+            self.min,
+            self.max,
+        );
+        (x, pdf, offset)
+    }
+
+    /// Samples a piece with probability proportional to its value,
+    /// returning its index, its probability and `u` remapped to a fresh
+    /// uniform sample in `[0, 1)`.
+    ///
+    /// ```
+    /// use pbrt::sampling::PiecewiseConstant1D;
+    ///
+    /// let d = PiecewiseConstant1D::new(&[1.0, 3.0]);
+    /// assert_eq!(d.sample_discrete(0.625), (1, 0.75, 0.5));
+    /// ```
+    pub fn sample_discrete(&self, u: f32) -> (usize, f32, f32) {
+        let offset = self.find_interval(u);
+        let pmf = self.cdf[offset + 1] - self.cdf[offset];
+        let u_remapped = if pmf > 0.0 {
+            ((u - self.cdf[offset]) / pmf).min(f32::ONE_MINUS_EPSILON)
+        } else {
+            0.0
+        };
+        (offset, pmf, u_remapped)
+    }
+
+    /// Returns the sample that [`Self::sample`] maps to `x`, or `None` if
+    /// `x` is outside the domain.
+    pub fn invert(&self, x: f32) -> Option<f32> {
+        if x < self.min || x > self.max {
+            return None;
+        }
+        let c = (x - self.min) / (self.max - self.min) * self.size() as f32;
+        let offset = (c as usize).min(self.size() - 1);
+        let delta = c - offset as f32;
+        Some(lerp(delta, self.cdf[offset], self.cdf[offset + 1]))
+    }
+}
+
+/// A piecewise-constant function over a rectangle, sampled by first
+/// choosing `y` from the marginal distribution of its rows and then `x`
+/// from the row.
+///
+/// ```
+/// use pbrt::math::{Bounds2, Point2};
+/// use pbrt::sampling::PiecewiseConstant2D;
+///
+/// let domain = Bounds2::new(Point2::new(0.0, 0.0), Point2::new(2.0, 1.0));
+/// let d = PiecewiseConstant2D::new(&[0.0, 1.0, 2.0, 1.0], 2, 2, domain);
+/// let (p, pdf, offset) = d.sample(Point2::new(0.5, 0.5));
+/// assert_eq!(offset, Point2::new(0, 1));
+/// assert_eq!(pdf, d.pdf(p));
+/// assert_eq!(d.integral(), 2.0);
+/// let u = d.invert(p).unwrap();
+/// assert!((u.x - 0.5).abs() < 1e-6 && (u.y - 0.5).abs() < 1e-6);
+/// ```
+#[derive(Clone, Debug)]
+pub struct PiecewiseConstant2D {
+    domain: Bounds2<f32>,
+    conditional: Vec<PiecewiseConstant1D>,
+    marginal: PiecewiseConstant1D,
+}
+
+impl PiecewiseConstant2D {
+    /// Creates the distribution of the function with the given `nu` by `nv`
+    /// values over equal cells of `domain`.
+    pub fn new(func: &[f32], nu: usize, nv: usize, domain: Bounds2<f32>) -> Self {
+        assert_eq!(func.len(), nu * nv, "expected {nu} by {nv} values");
+        let (min, max) = (domain.p_min, domain.p_max);
+        let conditional: Vec<_> = func
+            .chunks(nu)
+            .map(|row| PiecewiseConstant1D::with_domain(row, min.x, max.x))
+            .collect();
+        let marginal_func: Vec<f32> = conditional.iter().map(|c| c.integral()).collect();
+        let marginal = PiecewiseConstant1D::with_domain(&marginal_func, min.y, max.y);
+        Self {
+            domain,
+            conditional,
+            marginal,
+        }
+    }
+
+    /// Returns the domain.
+    pub fn domain(&self) -> Bounds2<f32> {
+        self.domain
+    }
+
+    /// Returns the number of cells along x and y.
+    pub fn resolution(&self) -> Point2<usize> {
+        Point2 {
+            x: self.conditional[0].size(),
+            y: self.marginal.size(),
+        }
+    }
+
+    /// Returns the integral of the function's absolute value over the
+    /// domain.
+    pub fn integral(&self) -> f32 {
+        self.marginal.integral()
+    }
+
+    /// Samples a point in the domain, returning it with its density and the
+    /// cell it lies in.
+    pub fn sample(&self, u: Point2<f32>) -> (Point2<f32>, f32, Point2<i32>) {
+        let (y, pdf_y, v) = self.marginal.sample(u.y);
+        let (x, pdf_x, u) = self.conditional[v].sample(u.x);
+        (
+            Point2::new(x, y),
+            pdf_x * pdf_y,
+            Point2::new(u as i32, v as i32),
+        )
+    }
+
+    /// Returns the density of [`Self::sample`] at `p`.
+    pub fn pdf(&self, p: Point2<f32>) -> f32 {
+        let o = self.domain.offset(p);
+        let res = self.resolution();
+        let iu = ((o.x * res.x as f32).max(0.0) as usize).min(res.x - 1);
+        let iv = ((o.y * res.y as f32).max(0.0) as usize).min(res.y - 1);
+        let integral = self.marginal.integral();
+        if integral == 0.0 {
+            return 0.0;
+        }
+        self.conditional[iv].func()[iu] / integral
+    }
+
+    /// Returns the sample that [`Self::sample`] maps to `p`, or `None` if `p`
+    /// is outside the domain.
+    pub fn invert(&self, p: Point2<f32>) -> Option<Point2<f32>> {
+        let u_y = self.marginal.invert(p.y)?;
+        let o = self.domain.offset(p);
+        let rows = self.conditional.len();
+        let row = ((o.y * rows as f32).max(0.0) as usize).min(rows - 1);
+        let u_x = self.conditional[row].invert(p.x)?;
+        Some(Point2::new(u_x, u_y))
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+struct AliasBin {
+    // The probability of keeping this bin's own outcome.
+    q: f32,
+    // The outcome's probability.
+    p: f32,
+    alias: usize,
+}
+
+/// Samples from a discrete distribution in constant time with Walker's
+/// alias method: each of `n` equally likely bins holds its own outcome with
+/// some probability and one other outcome, its alias, otherwise.
+///
+/// ```
+/// use pbrt::sampling::AliasTable;
+///
+/// let table = AliasTable::new(&[1.0, 3.0]);
+/// assert_eq!(table.pmf(1), 0.75);
+/// let (index, pmf, _) = table.sample(0.9);
+/// assert_eq!((index, pmf), (1, 0.75));
+/// ```
+#[derive(Clone, Debug)]
+pub struct AliasTable {
+    bins: Vec<AliasBin>,
+}
+
+impl AliasTable {
+    /// Creates a table choosing each outcome with probability proportional
+    /// to its weight. The weights must be non-negative and not all zero.
+    pub fn new(weights: &[f32]) -> Self {
+        let sum: f64 = weights.iter().map(|&w| f64::from(w)).sum();
+        assert!(
+            sum > 0.0 && weights.iter().all(|&w| w >= 0.0),
+            "alias table weights must be non-negative and not all zero"
+        );
+        let n = weights.len();
+        let mut bins: Vec<AliasBin> = (0..n)
+            .map(|i| AliasBin {
+                q: 0.0,
+                p: (f64::from(weights[i]) / sum) as f32,
+                alias: i,
+            })
+            .collect();
+
+        // Pair bins whose scaled probability is under one with ones over it,
+        // moving the surplus of the latter into the former.
+        let (mut under, mut over) = (Vec::new(), Vec::new());
+        for (i, &w) in weights.iter().enumerate() {
+            let p_hat = f64::from(w) / sum * n as f64;
+            if p_hat < 1.0 {
+                under.push((p_hat, i));
+            } else {
+                over.push((p_hat, i));
+            }
+        }
+        while let (Some(&(p_under, i_under)), Some(&(p_over, i_over))) = (under.last(), over.last())
+        {
+            under.pop();
+            over.pop();
+            bins[i_under].q = p_under as f32;
+            bins[i_under].alias = i_over;
+            let excess = p_under + p_over - 1.0;
+            if excess < 1.0 {
+                under.push((excess, i_over));
+            } else {
+                over.push((excess, i_over));
+            }
+        }
+        // Whatever is left is within roundoff of one.
+        for (_, i) in under.into_iter().chain(over) {
+            bins[i].q = 1.0;
+            bins[i].alias = i;
+        }
+        Self { bins }
+    }
+
+    /// Returns the number of outcomes.
+    pub fn size(&self) -> usize {
+        self.bins.len()
+    }
+
+    /// Returns the probability of outcome `index`.
+    pub fn pmf(&self, index: usize) -> f32 {
+        self.bins[index].p
+    }
+
+    /// Samples an outcome, returning its index, its probability and `u`
+    /// remapped to a fresh uniform sample in `[0, 1)`.
+    pub fn sample(&self, u: f32) -> (usize, f32, f32) {
+        let n = self.size();
+        let offset = ((u * n as f32) as usize).min(n - 1);
+        let up = (u * n as f32 - offset as f32).min(f32::ONE_MINUS_EPSILON);
+        let bin = self.bins[offset];
+        if up < bin.q {
+            let u_remapped = (up / bin.q).min(f32::ONE_MINUS_EPSILON);
+            (offset, bin.p, u_remapped)
+        } else {
+            let u_remapped = ((up - bin.q) / (1.0 - bin.q)).min(f32::ONE_MINUS_EPSILON);
+            (bin.alias, self.bins[bin.alias].p, u_remapped)
+        }
+    }
+}
+
+/// A summed-area table over a grid of values, giving the integral of the
+/// piecewise-constant function they define over any rectangle in constant
+/// time. The grid covers `[0, 1]^2`.
+///
+/// ```
+/// use pbrt::math::{Bounds2, Point2};
+/// use pbrt::sampling::SummedAreaTable;
+///
+/// let sat = SummedAreaTable::new(&[1.0, 2.0, 3.0, 4.0], 2, 2);
+/// let all = Bounds2::new(Point2::new(0.0, 0.0), Point2::new(1.0, 1.0));
+/// assert_eq!(sat.integral(all), 2.5);
+/// let right = Bounds2::new(Point2::new(0.5, 0.0), Point2::new(1.0, 1.0));
+/// assert_eq!(sat.integral(right), 1.5);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SummedAreaTable {
+    // sum[y * nx + x] is the sum of the values at or below and left of
+    // (x, y), in double precision so differences of large sums keep their
+    // accuracy.
+    sum: Vec<f64>,
+    nx: usize,
+    ny: usize,
+}
+
+impl SummedAreaTable {
+    /// Creates the table for the given `nx` by `ny` values.
+    pub fn new(values: &[f32], nx: usize, ny: usize) -> Self {
+        assert_eq!(values.len(), nx * ny, "expected {nx} by {ny} values");
+        let mut sum = vec![0.0; nx * ny];
+        for y in 0..ny {
+            for x in 0..nx {
+                let mut s = f64::from(values[y * nx + x]);
+                if x > 0 {
+                    s += sum[y * nx + x - 1];
+                }
+                if y > 0 {
+                    s += sum[(y - 1) * nx + x];
+                }
+                if x > 0 && y > 0 {
+                    s -= sum[(y - 1) * nx + x - 1];
+                }
+                sum[y * nx + x] = s;
+            }
+        }
+        Self { sum, nx, ny }
+    }
+
+    /// Returns the integral over `extent`, a rectangle within `[0, 1]^2`,
+    /// of the function whose value in each cell is the cell's value.
+    pub fn integral(&self, extent: Bounds2<f32>) -> f32 {
+        let (min, max) = (extent.p_min, extent.p_max);
+        let s = (self.lookup(max.x, max.y) - self.lookup(min.x, max.y))
+            + (self.lookup(min.x, min.y) - self.lookup(max.x, min.y));
+        (s / (self.nx * self.ny) as f64).max(0.0) as f32
+    }
+
+    // The sum of the values below and left of (x, y), interpolating
+    // bilinearly between grid corners.
+    fn lookup(&self, x: f32, y: f32) -> f64 {
+        let x = f64::from(x) * self.nx as f64;
+        let y = f64::from(y) * self.ny as f64;
+        let (x0, y0) = (x as usize, y as usize);
+        let (dx, dy) = (x - x0 as f64, y - y0 as f64);
+        let v00 = self.lookup_int(x0, y0);
+        let v10 = self.lookup_int(x0 + 1, y0);
+        let v01 = self.lookup_int(x0, y0 + 1);
+        let v11 = self.lookup_int(x0 + 1, y0 + 1);
+        (1.0 - dx) * (1.0 - dy) * v00
+            + dx * (1.0 - dy) * v10
+            + (1.0 - dx) * dy * v01
+            + dx * dy * v11
+    }
+
+    // The sum of the values in the cells below and left of grid corner
+    // (x, y).
+    fn lookup_int(&self, x: usize, y: usize) -> f64 {
+        if x == 0 || y == 0 {
+            return 0.0;
+        }
+        let x = (x - 1).min(self.nx - 1);
+        let y = (y - 1).min(self.ny - 1);
+        self.sum[y * self.nx + x]
+    }
+}
+
+/// A piecewise-constant function over `[0, 1]^2` that can be sampled
+/// within any rectangle, using a [`SummedAreaTable`] to find the marginal
+/// and conditional distributions of the rectangle on the fly.
+///
+/// ```
+/// use pbrt::math::{Bounds2, Point2};
+/// use pbrt::sampling::WindowedPiecewiseConstant2D;
+///
+/// let d = WindowedPiecewiseConstant2D::new(&[1.0, 2.0, 3.0, 4.0], 2, 2);
+/// let window = Bounds2::new(Point2::new(0.5, 0.0), Point2::new(1.0, 1.0));
+/// let (p, pdf) = d.sample(Point2::new(0.3, 0.8), window).unwrap();
+/// assert!(window.inside(p));
+/// assert_eq!(pdf, d.pdf(p, window));
+/// // The window's integral is (2 + 4) / 4, so the density of the cell
+/// // holding 4 is 4 / 1.5.
+/// assert!((d.pdf(Point2::new(0.75, 0.75), window) - 8.0 / 3.0).abs() < 1e-6);
+/// ```
+#[derive(Clone, Debug)]
+pub struct WindowedPiecewiseConstant2D {
+    sat: SummedAreaTable,
+    func: Vec<f32>,
+    nx: usize,
+    ny: usize,
+}
+
+impl WindowedPiecewiseConstant2D {
+    /// Creates the distribution of the function with the given
+    /// non-negative `nx` by `ny` values.
+    pub fn new(func: &[f32], nx: usize, ny: usize) -> Self {
+        Self {
+            sat: SummedAreaTable::new(func, nx, ny),
+            func: func.to_vec(),
+            nx,
+            ny,
+        }
+    }
+
+    /// Samples a point in the rectangle `b`, returning it with its density,
+    /// or `None` if the function is zero over `b`.
+    pub fn sample(&self, u: Point2<f32>, b: Bounds2<f32>) -> Option<(Point2<f32>, f32)> {
+        let b_int = self.sat.integral(b);
+        if b_int == 0.0 {
+            return None;
+        }
+
+        // Sample x from the marginal over the window.
+        let px = |x: f32| {
+            let mut bx = b;
+            bx.p_max.x = x;
+            self.sat.integral(bx) / b_int
+        };
+        let x = sample_bisection(px, u.x, b.p_min.x, b.p_max.x, self.nx);
+
+        // Sample y from the column of cells holding x.
+        let nx = self.nx as f32;
+        let mut b_cond = Bounds2 {
+            p_min: Point2::new((x * nx).floor() / nx, b.p_min.y),
+            p_max: Point2::new((x * nx).ceil() / nx, b.p_max.y),
+        };
+        if b_cond.p_min.x == b_cond.p_max.x {
+            b_cond.p_max.x += 1.0 / nx;
+        }
+        let cond_int = self.sat.integral(b_cond);
+        if cond_int == 0.0 {
+            return None;
+        }
+        let py = |y: f32| {
+            let mut by = b_cond;
+            by.p_max.y = y;
+            self.sat.integral(by) / cond_int
+        };
+        let y = sample_bisection(py, u.y, b.p_min.y, b.p_max.y, self.ny);
+
+        let p = Point2::new(x, y);
+        Some((p, self.eval(p) / b_int))
+    }
+
+    /// Returns the density of [`Self::sample`] within `b` at `p`.
+    pub fn pdf(&self, p: Point2<f32>, b: Bounds2<f32>) -> f32 {
+        let b_int = self.sat.integral(b);
+        if b_int == 0.0 {
+            return 0.0;
+        }
+        self.eval(p) / b_int
+    }
+
+    fn eval(&self, p: Point2<f32>) -> f32 {
+        let x = ((p.x * self.nx as f32) as usize).min(self.nx - 1);
+        let y = ((p.y * self.ny as f32) as usize).min(self.ny - 1);
+        self.func[y * self.nx + x]
+    }
+}
+
+// Inverts the CDF p over [min, max] at u by bisection until the bracket
+// lies within one of n cells, where p is linear.
+fn sample_bisection(p: impl Fn(f32) -> f32, u: f32, mut min: f32, mut max: f32, n: usize) -> f32 {
+    let n = n as f32;
+    while (n * max).ceil() - (n * min).floor() > 1.0 {
+        let mid = (min + max) / 2.0;
+        if p(mid) > u {
+            max = mid;
+        } else {
+            min = mid;
+        }
+    }
+    let t = (u - p(min)) / (p(max) - p(min));
+    lerp(t, min, max).clamp(min, max)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::bounds2::Bounds2;
+    use crate::math::point2::Point2;
+    use crate::random::Rng;
+    use crate::sampling::distributions::{
+        AliasTable, PiecewiseConstant1D, PiecewiseConstant2D, SummedAreaTable,
+        WindowedPiecewiseConstant2D,
+    };
+    use crate::testing::{SIGNIFICANCE, chi2_test};
+
+    const SAMPLES: usize = 100_000;
+    // The number of histograms checked below, which share the significance
+    // level.
+    const TEST_COUNT: usize = 7;
+
+    fn assert_histogram(name: &str, observed: &[usize], probabilities: &[f64]) {
+        let observed: Vec<f64> = observed.iter().map(|&o| o as f64).collect();
+        let expected: Vec<f64> = probabilities.iter().map(|p| p * SAMPLES as f64).collect();
+        let (chi2, dof, p_value) = chi2_test(&observed, &expected);
+        assert!(
+            p_value > SIGNIFICANCE / TEST_COUNT as f64,
+            "{name}: chi2 = {chi2} with {dof} degrees of freedom, p = {p_value}"
+        );
+    }
+
+    fn unit_bounds() -> Bounds2<f32> {
+        Bounds2::new(Point2::new(0.0, 0.0), Point2::new(1.0, 1.0))
+    }
+
+    #[test]
+    fn test_piecewise_constant_1d() {
+        let f = [0.0, 1.0, 4.0, 0.5, 2.0, 0.0, 3.0, 1.5];
+        let d = PiecewiseConstant1D::with_domain(&f, -1.0, 3.0);
+        let sum: f32 = f.iter().sum();
+        assert_eq!(d.integral(), sum * 0.5);
+
+        // Sample in 32 bins, four per piece.
+        let mut rng = Rng::with_sequence(1, 0);
+        let mut observed = vec![0; 32];
+        for _ in 0..SAMPLES {
+            let (x, pdf, offset) = d.sample(rng.uniform());
+            assert!((-1.0..3.0).contains(&x));
+            assert_eq!(offset, ((x + 1.0) * 2.0) as usize);
+            assert_eq!(pdf, f[offset] / d.integral());
+            observed[((x + 1.0) * 8.0) as usize] += 1;
+        }
+        let probabilities: Vec<f64> = (0..32).map(|i| f64::from(f[i / 4] / sum / 4.0)).collect();
+        assert_histogram("1d", &observed, &probabilities);
+
+        for i in 0..100 {
+            let u = (i as f32 + 0.5) / 100.0;
+            let (x, _, _) = d.sample(u);
+            assert!((d.invert(x).unwrap() - u).abs() < 1e-5);
+        }
+        assert_eq!(d.invert(3.5), None);
+    }
+
+    #[test]
+    fn test_piecewise_constant_1d_discrete() {
+        let f = [2.0, 0.0, 1.0, 5.0];
+        let d = PiecewiseConstant1D::new(&f);
+        let mut rng = Rng::with_sequence(2, 0);
+        let mut observed = vec![0; 4];
+        let mut remapped = vec![0; 4];
+        for _ in 0..SAMPLES {
+            let (index, pmf, u) = d.sample_discrete(rng.uniform());
+            assert_eq!(pmf, f[index] / 8.0);
+            observed[index] += 1;
+            remapped[(u * 4.0) as usize] += 1;
+        }
+        assert_histogram("discrete", &observed, &[0.25, 0.0, 0.125, 0.625]);
+        assert_histogram("remapped", &remapped, &[0.25; 4]);
+    }
+
+    #[test]
+    fn test_piecewise_constant_1d_zero() {
+        // A zero function is sampled uniformly with a zero density.
+        let d = PiecewiseConstant1D::new(&[0.0; 4]);
+        assert_eq!(d.sample(0.375), (0.375, 0.0, 1));
+        assert_eq!(d.integral(), 0.0);
+    }
+
+    #[test]
+    fn test_piecewise_constant_2d() {
+        let (nu, nv) = (4, 3);
+        let f = [
+            1.0, 0.0, 2.0, 1.0, //
+            0.5, 3.0, 0.0, 0.0, //
+            2.0, 1.0, 1.0, 4.0,
+        ];
+        let domain = Bounds2::new(Point2::new(-1.0, 0.0), Point2::new(1.0, 3.0));
+        let d = PiecewiseConstant2D::new(&f, nu, nv, domain);
+        let sum: f32 = f.iter().sum();
+        assert!((d.integral() - sum * 0.5).abs() < 1e-5);
+
+        let mut rng = Rng::with_sequence(3, 0);
+        let mut observed = vec![0; nu * nv];
+        for _ in 0..SAMPLES {
+            let (p, pdf, offset) = d.sample(Point2::new(rng.uniform(), rng.uniform()));
+            let cell = offset.y as usize * nu + offset.x as usize;
+            assert!((pdf - f[cell] / d.integral()).abs() < 1e-5);
+            assert!((pdf - d.pdf(p)).abs() < 1e-5);
+            observed[cell] += 1;
+        }
+        let probabilities: Vec<f64> = f.iter().map(|&v| f64::from(v / sum)).collect();
+        assert_histogram("2d", &observed, &probabilities);
+
+        for i in 0..10 {
+            for j in 0..10 {
+                let u = Point2::new((i as f32 + 0.5) / 10.0, (j as f32 + 0.5) / 10.0);
+                let (p, _, _) = d.sample(u);
+                let v = d.invert(p).unwrap();
+                assert!((u.x - v.x).abs() < 1e-4 && (u.y - v.y).abs() < 1e-4);
+            }
+        }
+        assert_eq!(d.invert(Point2::new(0.0, 4.0)), None);
+    }
+
+    #[test]
+    fn test_alias_table() {
+        let weights = [0.5, 4.0, 0.0, 1.0, 2.5, 0.25, 7.0, 1.75];
+        let table = AliasTable::new(&weights);
+        let sum: f32 = weights.iter().sum();
+        for (i, &w) in weights.iter().enumerate() {
+            assert!((table.pmf(i) - w / sum).abs() < 1e-6);
+        }
+
+        let mut rng = Rng::with_sequence(4, 0);
+        let mut observed = vec![0; weights.len()];
+        let mut remapped = vec![0; 8];
+        for _ in 0..SAMPLES {
+            let (index, pmf, u) = table.sample(rng.uniform());
+            assert_eq!(pmf, table.pmf(index));
+            observed[index] += 1;
+            remapped[(u * 8.0) as usize] += 1;
+        }
+        let probabilities: Vec<f64> = weights.iter().map(|&w| f64::from(w / sum)).collect();
+        assert_histogram("alias", &observed, &probabilities);
+        assert_histogram("remapped", &remapped, &[0.125; 8]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_alias_table_rejects_zero_weights() {
+        AliasTable::new(&[0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_summed_area_table() {
+        let (nx, ny) = (5, 4);
+        let mut rng = Rng::with_sequence(5, 0);
+        let values: Vec<f32> = (0..nx * ny).map(|_| rng.uniform::<f32>() * 10.0).collect();
+        let sat = SummedAreaTable::new(&values, nx, ny);
+
+        // Compare against integrating the cells' overlaps directly.
+        let brute_force = |b: Bounds2<f32>| {
+            let mut sum = 0.0;
+            for y in 0..ny {
+                for x in 0..nx {
+                    let x0 = (x as f32 / nx as f32).max(b.p_min.x);
+                    let x1 = ((x + 1) as f32 / nx as f32).min(b.p_max.x);
+                    let y0 = (y as f32 / ny as f32).max(b.p_min.y);
+                    let y1 = ((y + 1) as f32 / ny as f32).min(b.p_max.y);
+                    if x1 > x0 && y1 > y0 {
+                        sum += values[y * nx + x] * (x1 - x0) * (y1 - y0);
+                    }
+                }
+            }
+            sum
+        };
+        let all: f32 = values.iter().sum::<f32>() / (nx * ny) as f32;
+        assert!((sat.integral(unit_bounds()) - all).abs() < 1e-5);
+        for _ in 0..100 {
+            let b = Bounds2::new(
+                Point2::new(rng.uniform(), rng.uniform()),
+                Point2::new(rng.uniform(), rng.uniform()),
+            );
+            let expected = brute_force(b);
+            assert!((sat.integral(b) - expected).abs() < 1e-4, "{b:?}");
+        }
+    }
+
+    #[test]
+    fn test_windowed_piecewise_constant_2d() {
+        let (nx, ny) = (8, 8);
+        let mut rng = Rng::with_sequence(6, 0);
+        let values: Vec<f32> = (0..nx * ny).map(|_| rng.uniform::<f32>()).collect();
+        let d = WindowedPiecewiseConstant2D::new(&values, nx, ny);
+
+        // A window covering cells 2..6 in x and 1..5 in y.
+        let window = Bounds2::new(Point2::new(0.25, 0.125), Point2::new(0.75, 0.625));
+        let mut observed = vec![0; 16];
+        for _ in 0..SAMPLES {
+            let u = Point2::new(rng.uniform(), rng.uniform());
+            let (p, pdf) = d.sample(u, window).unwrap();
+            assert!(window.inside(p));
+            assert!((pdf - d.pdf(p, window)).abs() < 1e-4);
+            let x = ((p.x * 8.0) as usize).clamp(2, 5) - 2;
+            let y = ((p.y * 8.0) as usize).clamp(1, 4) - 1;
+            observed[y * 4 + x] += 1;
+        }
+        let window_values: Vec<f32> = (1..5)
+            .flat_map(|y| (2..6).map(move |x| (x, y)))
+            .map(|(x, y)| values[y * nx + x])
+            .collect();
+        let sum: f32 = window_values.iter().sum();
+        let probabilities: Vec<f64> = window_values.iter().map(|&v| f64::from(v / sum)).collect();
+        assert_histogram("windowed", &observed, &probabilities);
+
+        // Over the whole domain the density matches PiecewiseConstant2D's.
+        let full = PiecewiseConstant2D::new(&values, nx, ny, unit_bounds());
+        let p = Point2::new(0.3, 0.8);
+        assert!((d.pdf(p, unit_bounds()) - full.pdf(p)).abs() < 1e-4);
+        assert!(
+            WindowedPiecewiseConstant2D::new(&[0.0; 4], 2, 2)
+                .sample(Point2::new(0.5, 0.5), unit_bounds())
+                .is_none()
+        );
+    }
+}
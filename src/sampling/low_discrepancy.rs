@@ -0,0 +1,719 @@
+//! Low-discrepancy sequences: radical inverses for the Halton sequence,
+//! Sobol' sampling, and the scrambles that randomize both.
+//!
+//! Sample values are `f32`, since the sequences are defined by integer
+//! digits whose precision `f32` already exceeds for practical sample counts.
+
+use crate::hash;
+use crate::math::number_traits::Float;
+use crate::math::point2::Point2;
+use crate::random::{mix_bits, permutation_element};
+use crate::sampling::primes::{PRIME_TABLE_SIZE, PRIMES};
+use crate::sampling::sobol_matrices::{N_SOBOL_DIMENSIONS, SOBOL_MATRICES_32, SOBOL_MATRIX_SIZE};
+
+/// Returns the radical inverse of `a` in the base `PRIMES[base_index]`:
+/// its digits mirrored about the radix point. Successive dimensions of the
+/// Halton sequence are radical inverses in successive prime bases.
+///
+/// ```
+/// use pbrt::sampling::radical_inverse;
+///
+/// // The van der Corput sequence in base 2.
+/// let values: Vec<f32> = (0..4).map(|a| radical_inverse(0, a)).collect();
+/// assert_eq!(values, [0.0, 0.5, 0.25, 0.75]);
+/// ```
+pub fn radical_inverse(base_index: usize, mut a: u64) -> f32 {
+    let base = u64::from(PRIMES[base_index]);
+    let inv_base = 1.0 / base as f32;
+    let mut reversed_digits = 0u64;
+    let mut inv_base_m = 1.0f32;
+    // Stop before reversed_digits could overflow; the remaining digits are
+    // far below f32 precision.
+    let limit = u64::MAX / base - base;
+    while a != 0 && reversed_digits < limit {
+        let next = a / base;
+        let digit = a - next * base;
+        reversed_digits = reversed_digits * base + digit;
+        inv_base_m *= inv_base;
+        a = next;
+    }
+    (reversed_digits as f32 * inv_base_m).min(f32::ONE_MINUS_EPSILON)
+}
+
+/// Returns the index whose radical inverse has the given `n_digits` digits,
+/// as an integer: the inverse of [`radical_inverse`] before its scaling to
+/// `[0, 1)`.
+///
+/// ```
+/// use pbrt::sampling::inverse_radical_inverse;
+///
+/// // 0.21 in base 3 (7 / 9) is the radical inverse of 12 in base 3 (5).
+/// assert_eq!(inverse_radical_inverse(7, 3, 2), 5);
+/// ```
+pub fn inverse_radical_inverse(mut inverse: u64, base: u32, n_digits: u32) -> u64 {
+    let base = u64::from(base);
+    let mut index = 0;
+    for _ in 0..n_digits {
+        let digit = inverse % base;
+        inverse /= base;
+        index = index * base + digit;
+    }
+    index
+}
+
+/// Random permutations of the digits in a base, one for each digit position
+/// that affects an `f32` radical inverse, for scrambling the Halton
+/// sequence.
+///
+/// ```
+/// use pbrt::sampling::DigitPermutation;
+///
+/// let permutation = DigitPermutation::new(3, 42);
+/// let mut digits: Vec<u32> = (0..3).map(|d| permutation.permute(0, d)).collect();
+/// digits.sort();
+/// assert_eq!(digits, [0, 1, 2]);
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DigitPermutation {
+    base: u32,
+    n_digits: usize,
+    permutations: Vec<u16>,
+}
+
+impl DigitPermutation {
+    /// Creates the permutations for `base`, chosen by `seed`.
+    pub fn new(base: u32, seed: u32) -> Self {
+        assert!(base > 1 && base <= u32::from(u16::MAX));
+        // Count the digits until they no longer change an f32 in [0, 1).
+        let mut n_digits = 0;
+        let inv_base = 1.0 / base as f32;
+        let mut inv_base_m = 1.0f32;
+        while 1.0 - (base - 1) as f32 * inv_base_m < 1.0 {
+            n_digits += 1;
+            inv_base_m *= inv_base;
+        }
+
+        let mut permutations = Vec::with_capacity(n_digits * base as usize);
+        for digit_index in 0..n_digits {
+            let digit_seed = hash!(base as i32, digit_index as i32, seed) as u32;
+            for digit_value in 0..base {
+                permutations.push(permutation_element(digit_value, base, digit_seed) as u16);
+            }
+        }
+        Self {
+            base,
+            n_digits,
+            permutations,
+        }
+    }
+
+    /// Returns the base whose digits are permuted.
+    pub fn base(&self) -> u32 {
+        self.base
+    }
+
+    /// Returns the number of digit positions with a permutation.
+    pub fn n_digits(&self) -> usize {
+        self.n_digits
+    }
+
+    /// Returns the permuted value of a digit at position `digit_index`,
+    /// counting from the most significant digit after the radix point.
+    pub fn permute(&self, digit_index: usize, digit_value: u32) -> u32 {
+        u32::from(self.permutations[digit_index * self.base as usize + digit_value as usize])
+    }
+}
+
+/// Returns a [`DigitPermutation`] for each prime in [`PRIMES`], chosen by
+/// `seed`, for use with [`scrambled_radical_inverse`].
+pub fn compute_radical_inverse_permutations(seed: u32) -> Vec<DigitPermutation> {
+    PRIMES[..PRIME_TABLE_SIZE]
+        .iter()
+        .map(|&base| DigitPermutation::new(base, seed))
+        .collect()
+}
+
+/// Returns the radical inverse of `a` in the base `PRIMES[base_index]` with
+/// each digit permuted by `permutation`. Trailing zero digits are permuted
+/// too, so the result is a random shift even for small `a`.
+///
+/// ```
+/// use pbrt::sampling::{scrambled_radical_inverse, DigitPermutation};
+///
+/// let permutation = DigitPermutation::new(2, 7);
+/// let u = scrambled_radical_inverse(0, 5, &permutation);
+/// assert!((0.0..1.0).contains(&u));
+/// ```
+pub fn scrambled_radical_inverse(
+    base_index: usize,
+    mut a: u64,
+    permutation: &DigitPermutation,
+) -> f32 {
+    let base = u64::from(PRIMES[base_index]);
+    debug_assert_eq!(u64::from(permutation.base()), base);
+    let inv_base = 1.0 / base as f32;
+    let mut reversed_digits = 0u64;
+    let mut inv_base_m = 1.0f32;
+    let limit = u64::MAX / base - base;
+    let mut digit_index = 0;
+    while 1.0 - (base - 1) as f32 * inv_base_m < 1.0 && reversed_digits < limit {
+        let next = a / base;
+        let digit_value = (a - next * base) as u32;
+        reversed_digits =
+            reversed_digits * base + u64::from(permutation.permute(digit_index, digit_value));
+        inv_base_m *= inv_base;
+        digit_index += 1;
+        a = next;
+    }
+    (inv_base_m * reversed_digits as f32).min(f32::ONE_MINUS_EPSILON)
+}
+
+/// Returns the radical inverse of `a` in the base `PRIMES[base_index]`
+/// with Owen scrambling: each digit is permuted by a permutation that
+/// depends on the digits before it, computed by hashing rather than stored.
+///
+/// ```
+/// use pbrt::sampling::owen_scrambled_radical_inverse;
+///
+/// // The first 9 points in base 3 still fall in distinct ninths.
+/// let mut ninths: Vec<u32> = (0..9)
+///     .map(|a| (owen_scrambled_radical_inverse(1, a, 1234) * 9.0) as u32)
+///     .collect();
+/// ninths.sort();
+/// assert_eq!(ninths, (0..9).collect::<Vec<_>>());
+/// ```
+pub fn owen_scrambled_radical_inverse(base_index: usize, mut a: u64, hash: u32) -> f32 {
+    let base = u64::from(PRIMES[base_index]);
+    let inv_base = 1.0 / base as f32;
+    let mut reversed_digits = 0u64;
+    let mut inv_base_m = 1.0f32;
+    let limit = u64::MAX / base - base;
+    while 1.0 - (base - 1) as f32 * inv_base_m < 1.0 && reversed_digits < limit {
+        let next = a / base;
+        let digit_value = (a - next * base) as u32;
+        let digit_hash = mix_bits(u64::from(hash) ^ reversed_digits) as u32;
+        let digit_value = permutation_element(digit_value, base as u32, digit_hash);
+        reversed_digits = reversed_digits * base + u64::from(digit_value);
+        inv_base_m *= inv_base;
+        a = next;
+    }
+    (inv_base_m * reversed_digits as f32).min(f32::ONE_MINUS_EPSILON)
+}
+
+/// A randomization of the 32-bit fixed-point values of a Sobol' sample.
+pub trait SobolRandomizer {
+    /// Returns the randomized value.
+    fn randomize(&self, v: u32) -> u32;
+}
+
+/// Leaves Sobol' samples unchanged.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct NoRandomizer;
+
+impl SobolRandomizer for NoRandomizer {
+    fn randomize(&self, v: u32) -> u32 {
+        v
+    }
+}
+
+/// Randomizes Sobol' samples by flipping a fixed set of bits, a random
+/// digit scramble in base 2.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct BinaryPermuteScrambler {
+    /// The bits to flip.
+    pub permutation: u32,
+}
+
+impl SobolRandomizer for BinaryPermuteScrambler {
+    fn randomize(&self, v: u32) -> u32 {
+        self.permutation ^ v
+    }
+}
+
+/// Randomizes Sobol' samples with a hash-based approximation of Owen
+/// scrambling (Burley, "Practical Hash-based Owen Scrambling"), where each
+/// bit is flipped depending only on the bits above it.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct FastOwenScrambler {
+    /// The seed selecting the scramble.
+    pub seed: u32,
+}
+
+impl SobolRandomizer for FastOwenScrambler {
+    fn randomize(&self, v: u32) -> u32 {
+        // In reversed bit order, the multiplications propagate each bit only
+        // into the less significant bits of the original value.
+        let mut v = v.reverse_bits();
+        v ^= v.wrapping_mul(0x3d20_adea);
+        v = v.wrapping_add(self.seed);
+        v = v.wrapping_mul((self.seed >> 16) | 1);
+        v ^= v.wrapping_mul(0x0552_6c56);
+        v ^= v.wrapping_mul(0x53a2_2864);
+        v.reverse_bits()
+    }
+}
+
+/// Randomizes Sobol' samples with full Owen scrambling, hashing the bits
+/// above each bit to decide whether to flip it. Slower than
+/// [`FastOwenScrambler`], but with independent flips for every prefix.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct OwenScrambler {
+    /// The seed selecting the scramble.
+    pub seed: u32,
+}
+
+impl SobolRandomizer for OwenScrambler {
+    fn randomize(&self, mut v: u32) -> u32 {
+        if self.seed & 1 != 0 {
+            v ^= 1 << 31;
+        }
+        for b in 1..32 {
+            let mask = u32::MAX << (32 - b);
+            if (mix_bits(u64::from((v & mask) ^ self.seed)) as u32) & (1 << b) != 0 {
+                v ^= 1 << (31 - b);
+            }
+        }
+        v
+    }
+}
+
+/// Returns the product of the generator matrix `c`, given by its columns,
+/// and the bits of `a`, in GF(2).
+///
+/// ```
+/// use pbrt::sampling::multiply_generator;
+///
+/// assert_eq!(multiply_generator(&[0b100, 0b010, 0b001], 0b101), 0b101);
+/// ```
+pub fn multiply_generator(c: &[u32], mut a: u64) -> u32 {
+    let mut v = 0;
+    let mut i = 0;
+    while a != 0 {
+        if a & 1 != 0 {
+            v ^= c[i];
+        }
+        a >>= 1;
+        i += 1;
+    }
+    v
+}
+
+/// Returns sample `a` of the sequence with generator matrix `c`, randomized
+/// by `randomizer` and mapped to `[0, 1)`.
+pub fn sample_generator_matrix<R: SobolRandomizer>(c: &[u32], a: u64, randomizer: R) -> f32 {
+    fixed_point_to_float(randomizer.randomize(multiply_generator(c, a)))
+}
+
+/// Returns dimension `dimension` of sample `a` of the Sobol' sequence,
+/// randomized by `randomizer`. Indices must be below `2^52`, the number of
+/// columns in the generator matrices.
+///
+/// Panics if `dimension` is not below [`N_SOBOL_DIMENSIONS`].
+///
+/// ```
+/// use pbrt::sampling::{sobol_sample, NoRandomizer};
+///
+/// let points: Vec<(f32, f32)> = (0..4)
+///     .map(|a| (sobol_sample(a, 0, NoRandomizer), sobol_sample(a, 1, NoRandomizer)))
+///     .collect();
+/// assert_eq!(points, [(0.0, 0.0), (0.5, 0.5), (0.25, 0.75), (0.75, 0.25)]);
+/// ```
+pub fn sobol_sample<R: SobolRandomizer>(a: u64, dimension: usize, randomizer: R) -> f32 {
+    assert!(
+        dimension < N_SOBOL_DIMENSIONS,
+        "Sobol' dimension {dimension} is not below {N_SOBOL_DIMENSIONS}"
+    );
+    debug_assert!(a < 1 << SOBOL_MATRIX_SIZE);
+    let start = dimension * SOBOL_MATRIX_SIZE;
+    let matrix = &SOBOL_MATRICES_32[start..start + SOBOL_MATRIX_SIZE];
+    sample_generator_matrix(matrix, a, randomizer)
+}
+
+/// Returns sample `a` of the (0,2)-sequence formed by the first two Sobol'
+/// dimensions, each randomized separately. Every aligned block of `2^m`
+/// consecutive samples is a (0,m,2)-net: each of its points falls in a
+/// different cell of any grid of `2^m` cells of size `2^-i` by `2^(i-m)`.
+///
+/// ```
+/// use pbrt::math::Point2;
+/// use pbrt::sampling::{sample_02, FastOwenScrambler};
+///
+/// let scrambles = (FastOwenScrambler { seed: 1 }, FastOwenScrambler { seed: 2 });
+/// let points: Vec<Point2<f32>> = (0..4).map(|a| sample_02(a, scrambles.0, scrambles.1)).collect();
+/// // One point in each quadrant.
+/// let mut quadrants: Vec<u32> = points
+///     .iter()
+///     .map(|p| 2 * (p.y * 2.0) as u32 + (p.x * 2.0) as u32)
+///     .collect();
+/// quadrants.sort();
+/// assert_eq!(quadrants, [0, 1, 2, 3]);
+/// ```
+pub fn sample_02<R0: SobolRandomizer, R1: SobolRandomizer>(
+    a: u64,
+    randomizer0: R0,
+    randomizer1: R1,
+) -> Point2<f32> {
+    Point2::new(
+        sobol_sample(a, 0, randomizer0),
+        sobol_sample(a, 1, randomizer1),
+    )
+}
+
+// Maps a 0.32 fixed-point value to [0, 1).
+fn fixed_point_to_float(v: u32) -> f32 {
+    // 2^-32
+    (v as f32 * 2.328_306_4e-10).min(f32::ONE_MINUS_EPSILON)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sampling::low_discrepancy::{
+        BinaryPermuteScrambler, DigitPermutation, FastOwenScrambler, NoRandomizer, OwenScrambler,
+        SobolRandomizer, compute_radical_inverse_permutations, inverse_radical_inverse,
+        multiply_generator, owen_scrambled_radical_inverse, radical_inverse, sample_02,
+        scrambled_radical_inverse, sobol_sample,
+    };
+    use crate::sampling::primes::PRIMES;
+    use crate::sampling::sobol_matrices::{
+        N_SOBOL_DIMENSIONS, SOBOL_INITIAL_DIRECTIONS, SOBOL_MATRICES_32, SOBOL_MATRIX_SIZE,
+    };
+
+    // Asserts that the values fall in distinct cells of [0, 1) split into
+    // values.len() equal intervals.
+    fn assert_stratified(values: &[f32]) {
+        let n = values.len();
+        let mut seen = vec![false; n];
+        for &v in values {
+            assert!((0.0..1.0).contains(&v));
+            let cell = (v * n as f32) as usize;
+            assert!(!seen[cell], "two of {values:?} share cell {cell}");
+            seen[cell] = true;
+        }
+    }
+
+    // Asserts that 2^m points form a (0,m,2)-net in base 2.
+    fn assert_02_net(points: &[(f32, f32)]) {
+        let m = points.len().trailing_zeros();
+        assert_eq!(points.len(), 1 << m);
+        for i in 0..=m {
+            let (nx, ny) = (1usize << i, 1usize << (m - i));
+            let mut seen = vec![false; points.len()];
+            for &(x, y) in points {
+                let cell = (y * ny as f32) as usize * nx + (x * nx as f32) as usize;
+                assert!(!seen[cell], "cell {cell} of {nx}x{ny} is hit twice");
+                seen[cell] = true;
+            }
+        }
+    }
+
+    #[test]
+    fn test_primes() {
+        assert_eq!(PRIMES[..8], [2, 3, 5, 7, 11, 13, 17, 19]);
+        for (i, &p) in PRIMES.iter().enumerate() {
+            assert!((2..p).take_while(|d| d * d <= p).all(|d| p % d != 0));
+            if i > 0 {
+                // No prime is skipped.
+                let previous = PRIMES[i - 1];
+                assert!(
+                    (previous + 1..p)
+                        .all(|n| (2..n).take_while(|d| d * d <= n).any(|d| n % d == 0))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_radical_inverse() {
+        let base2 = [0.0, 0.5, 0.25, 0.75, 0.125, 0.625, 0.375, 0.875];
+        for (a, &expected) in base2.iter().enumerate() {
+            assert_eq!(radical_inverse(0, a as u64), expected);
+        }
+        let base3 = [
+            0.0,
+            1.0 / 3.0,
+            2.0 / 3.0,
+            1.0 / 9.0,
+            4.0 / 9.0,
+            7.0 / 9.0,
+            2.0 / 9.0,
+        ];
+        for (a, &expected) in base3.iter().enumerate() {
+            assert!((radical_inverse(1, a as u64) - expected).abs() < 1e-7);
+        }
+        // Large indices and bases stay in range.
+        assert!(radical_inverse(999, u64::MAX) < 1.0);
+        assert!(radical_inverse(0, u64::MAX) < 1.0);
+    }
+
+    #[test]
+    fn test_inverse_radical_inverse() {
+        for base_index in [0, 1, 2, 10] {
+            let base = PRIMES[base_index];
+            let n_digits = 4;
+            let n = u64::from(base).pow(n_digits);
+            for a in 0..n.min(1000) {
+                let inverse = (radical_inverse(base_index, a) * n as f32).round() as u64;
+                assert_eq!(inverse_radical_inverse(inverse, base, n_digits), a);
+            }
+        }
+    }
+
+    #[test]
+    fn test_digit_permutation() {
+        for base in [2, 3, 5, 7919] {
+            let permutation = DigitPermutation::new(base, 17);
+            assert_eq!(permutation.base(), base);
+            assert!(permutation.n_digits() > 0);
+            for digit_index in 0..permutation.n_digits() {
+                let mut seen = vec![false; base as usize];
+                for digit in 0..base {
+                    seen[permutation.permute(digit_index, digit) as usize] = true;
+                }
+                assert!(seen.iter().all(|&s| s));
+            }
+        }
+        // Base 2 needs a digit for every bit of an f32 significand, and one
+        // more that can still round the result.
+        assert_eq!(DigitPermutation::new(2, 0).n_digits(), 25);
+        assert_ne!(DigitPermutation::new(5, 1), DigitPermutation::new(5, 2));
+        let permutations = compute_radical_inverse_permutations(3);
+        assert_eq!(permutations.len(), PRIMES.len());
+        assert_eq!(permutations[4], DigitPermutation::new(11, 3));
+    }
+
+    #[test]
+    fn test_scrambled_radical_inverses_are_stratified() {
+        let permutations = compute_radical_inverse_permutations(5);
+        for base_index in [0, 1, 2, 3] {
+            let base = u64::from(PRIMES[base_index]);
+            let n = base.pow(3);
+            let scrambled: Vec<f32> = (0..n)
+                .map(|a| scrambled_radical_inverse(base_index, a, &permutations[base_index]))
+                .collect();
+            assert_stratified(&scrambled);
+            let owen: Vec<f32> = (0..n)
+                .map(|a| owen_scrambled_radical_inverse(base_index, a, 0xabcd_1234))
+                .collect();
+            assert_stratified(&owen);
+            // The scrambles actually change the values.
+            assert_ne!(scrambled[1], radical_inverse(base_index, 1));
+        }
+    }
+
+    // Returns whether x^s + a_1 x^(s-1) + ... + a_(s-1) x + 1 is primitive
+    // over GF(2), that is, whether x has order 2^s - 1 modulo it.
+    fn is_primitive(s: usize, a: u32) -> bool {
+        let p = (1u64 << s) | (u64::from(a) << 1) | 1;
+        let mul_mod = |mut x: u64, mut y: u64| {
+            let mut r = 0;
+            while y != 0 {
+                if y & 1 != 0 {
+                    r ^= x;
+                }
+                y >>= 1;
+                x <<= 1;
+                if x >> s & 1 != 0 {
+                    x ^= p;
+                }
+            }
+            r
+        };
+        let pow_mod = |mut e: u64| {
+            let (mut r, mut x) = (1, if s == 1 { 1 } else { 2 });
+            while e != 0 {
+                if e & 1 != 0 {
+                    r = mul_mod(r, x);
+                }
+                x = mul_mod(x, x);
+                e >>= 1;
+            }
+            r
+        };
+        let order = (1u64 << s) - 1;
+        let factors = (2..=order)
+            .filter(|&q| order.is_multiple_of(q) && (2..q).all(|f| !q.is_multiple_of(f)));
+        pow_mod(order) == 1 && factors.into_iter().all(|q| pow_mod(order / q) != 1)
+    }
+
+    #[test]
+    fn test_sobol_matrices_follow_direction_numbers() {
+        // Rebuild every matrix from its polynomial and initial direction
+        // numbers with the standard recurrence, in 64 bits, keeping the
+        // high 32 bits of each column.
+        for (k, &column) in SOBOL_MATRICES_32[..SOBOL_MATRIX_SIZE].iter().enumerate() {
+            let expected = if k < 32 { 1u32 << (31 - k) } else { 0 };
+            assert_eq!(column, expected);
+        }
+        // The polynomials are the primitive ones, in order of degree and
+        // then of coefficients, with none skipped.
+        let mut candidates = (1..).flat_map(|s: usize| (0..1u32 << (s - 1)).map(move |a| (s, a)));
+        for (d, &(s, a, m)) in SOBOL_INITIAL_DIRECTIONS.iter().enumerate() {
+            let s = s as usize;
+            for candidate in candidates.by_ref() {
+                if candidate == (s, a) {
+                    break;
+                }
+                assert!(
+                    !is_primitive(candidate.0, candidate.1),
+                    "{candidate:?} skipped"
+                );
+            }
+            assert!(is_primitive(s, a), "dimension {} is not primitive", d + 1);
+            let mut v = [0u64; SOBOL_MATRIX_SIZE + 1];
+            for k in 1..=SOBOL_MATRIX_SIZE {
+                v[k] = if k <= s {
+                    assert!(m[k - 1] % 2 == 1 && m[k - 1] < 1 << k);
+                    u64::from(m[k - 1]) << (64 - k)
+                } else {
+                    let mut x = v[k - s] ^ (v[k - s] >> s);
+                    for j in 1..s {
+                        if (a >> (s - 1 - j)) & 1 != 0 {
+                            x ^= v[k - j];
+                        }
+                    }
+                    x
+                };
+            }
+            let start = (d + 1) * SOBOL_MATRIX_SIZE;
+            for k in 0..SOBOL_MATRIX_SIZE {
+                assert_eq!(SOBOL_MATRICES_32[start + k], (v[k + 1] >> 32) as u32);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sobol_reference_values() {
+        // The first 16 points in eight dimensions, as printed by Joe and
+        // Kuo's sobol.cc with new-joe-kuo-6.21201. That program steps
+        // through the points in Gray code order, so point k is sample
+        // k ^ (k >> 1) here.
+        #[rustfmt::skip]
+        let reference: [[f32; 8]; 16] = [
+            [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5],
+            [0.75, 0.25, 0.25, 0.25, 0.75, 0.75, 0.25, 0.75],
+            [0.25, 0.75, 0.75, 0.75, 0.25, 0.25, 0.75, 0.25],
+            [0.375, 0.375, 0.625, 0.875, 0.375, 0.125, 0.375, 0.875],
+            [0.875, 0.875, 0.125, 0.375, 0.875, 0.625, 0.875, 0.375],
+            [0.625, 0.125, 0.875, 0.625, 0.625, 0.875, 0.125, 0.125],
+            [0.125, 0.625, 0.375, 0.125, 0.125, 0.375, 0.625, 0.625],
+            [0.1875, 0.3125, 0.9375, 0.4375, 0.5625, 0.3125, 0.4375, 0.9375],
+            [0.6875, 0.8125, 0.4375, 0.9375, 0.0625, 0.8125, 0.9375, 0.4375],
+            [0.9375, 0.0625, 0.6875, 0.1875, 0.3125, 0.5625, 0.1875, 0.1875],
+            [0.4375, 0.5625, 0.1875, 0.6875, 0.8125, 0.0625, 0.6875, 0.6875],
+            [0.3125, 0.1875, 0.3125, 0.5625, 0.9375, 0.4375, 0.0625, 0.0625],
+            [0.8125, 0.6875, 0.8125, 0.0625, 0.4375, 0.9375, 0.5625, 0.5625],
+            [0.5625, 0.4375, 0.0625, 0.8125, 0.1875, 0.6875, 0.3125, 0.8125],
+            [0.0625, 0.9375, 0.5625, 0.3125, 0.6875, 0.1875, 0.8125, 0.3125],
+        ];
+        for (k, point) in reference.iter().enumerate() {
+            let a = (k ^ (k >> 1)) as u64;
+            for (dimension, &value) in point.iter().enumerate() {
+                assert_eq!(
+                    sobol_sample(a, dimension, NoRandomizer),
+                    value,
+                    "{k} {dimension}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_sobol_dimensions_are_stratified() {
+        let n = 1 << 10;
+        for dimension in 0..N_SOBOL_DIMENSIONS {
+            let values: Vec<f32> = (0..n)
+                .map(|a| sobol_sample(a, dimension, NoRandomizer))
+                .collect();
+            assert_stratified(&values);
+        }
+        // So is a later block of samples, with a scramble.
+        let values: Vec<f32> = (5 * n..6 * n)
+            .map(|a| sobol_sample(a, 17, FastOwenScrambler { seed: 9 }))
+            .collect();
+        assert_stratified(&values);
+    }
+
+    #[test]
+    #[should_panic(expected = "Sobol' dimension")]
+    fn test_sobol_sample_dimension_out_of_range() {
+        sobol_sample(1, N_SOBOL_DIMENSIONS, NoRandomizer);
+    }
+
+    #[test]
+    fn test_randomizers() {
+        assert_eq!(NoRandomizer.randomize(0x1234_5678), 0x1234_5678);
+        let permute = BinaryPermuteScrambler {
+            permutation: 0xf0f0_f0f0,
+        };
+        assert_eq!(permute.randomize(0xffff_0000), 0x0f0f_f0f0);
+
+        // Owen scrambles flip each bit based only on the bits above it, so
+        // values sharing a prefix keep sharing a (scrambled) prefix.
+        for seed in [0u32, 1, 0x9e37_79b9] {
+            let fast = FastOwenScrambler { seed };
+            let owen = OwenScrambler { seed };
+            for (a, b) in [(0x1234_5678u32, 0x1234_ffffu32), (0xabcd_0000, 0xabcd_0001)] {
+                let prefix = (a ^ b).leading_zeros();
+                let mask = !(u32::MAX >> prefix);
+                for scrambler in [&fast as &dyn SobolRandomizer, &owen] {
+                    let (sa, sb) = (scrambler.randomize(a), scrambler.randomize(b));
+                    assert_eq!(sa & mask, sb & mask);
+                    // The first differing bit still differs.
+                    assert_ne!(sa & (1 << (31 - prefix)), sb & (1 << (31 - prefix)));
+                }
+            }
+        }
+        assert_ne!(
+            OwenScrambler { seed: 1 }.randomize(0),
+            OwenScrambler { seed: 2 }.randomize(0)
+        );
+    }
+
+    #[test]
+    fn test_sample_02_nets() {
+        for m in 0..=8 {
+            let n = 1u64 << m;
+            for block in [0, 3] {
+                let points = |f: &dyn Fn(u64) -> (f32, f32)| -> Vec<(f32, f32)> {
+                    (block * n..(block + 1) * n).map(f).collect()
+                };
+                assert_02_net(&points(&|a| {
+                    let p = sample_02(a, NoRandomizer, NoRandomizer);
+                    (p.x, p.y)
+                }));
+                assert_02_net(&points(&|a| {
+                    let p = sample_02(
+                        a,
+                        BinaryPermuteScrambler {
+                            permutation: 0x1234_5678,
+                        },
+                        BinaryPermuteScrambler {
+                            permutation: 0x9abc_def0,
+                        },
+                    );
+                    (p.x, p.y)
+                }));
+                assert_02_net(&points(&|a| {
+                    let p = sample_02(
+                        a,
+                        FastOwenScrambler { seed: 3 },
+                        FastOwenScrambler { seed: 4 },
+                    );
+                    (p.x, p.y)
+                }));
+                assert_02_net(&points(&|a| {
+                    let p = sample_02(a, OwenScrambler { seed: 5 }, OwenScrambler { seed: 6 });
+                    (p.x, p.y)
+                }));
+            }
+        }
+    }
+
+    #[test]
+    fn test_multiply_generator() {
+        assert_eq!(multiply_generator(&[1, 2, 4, 8], 0), 0);
+        assert_eq!(multiply_generator(&[1, 2, 4, 8], 0b1011), 0b1011);
+        assert_eq!(multiply_generator(&[0b11, 0b01], 0b11), 0b10);
+    }
+}
@@ -0,0 +1,53 @@
+//! Sample generation: low-discrepancy sequences, the samplers built on them
+//! and the tables behind them, warps from samples to distributions, and
+//! distributions tabulated from data.
+//!
+//! ```
+//! use pbrt::sampling::{radical_inverse, sobol_sample, NoRandomizer};
+//!
+//! assert_eq!(radical_inverse(0, 3), 0.75);
+//! assert_eq!(sobol_sample(3, 1, NoRandomizer), 0.25);
+//! ```
+
+pub mod blue_noise;
+pub mod distributions;
+pub mod low_discrepancy;
+pub mod pmj02_tables;
+pub mod primes;
+pub mod samplers;
+pub mod sobol_matrices;
+pub mod warps;
+
+pub use blue_noise::blue_noise;
+pub use distributions::{
+    AliasTable, PiecewiseConstant1D, PiecewiseConstant2D, SummedAreaTable,
+    WindowedPiecewiseConstant2D,
+};
+pub use low_discrepancy::{
+    BinaryPermuteScrambler, DigitPermutation, FastOwenScrambler, NoRandomizer, OwenScrambler,
+    SobolRandomizer, compute_radical_inverse_permutations, inverse_radical_inverse,
+    multiply_generator, owen_scrambled_radical_inverse, radical_inverse, sample_02,
+    sample_generator_matrix, scrambled_radical_inverse, sobol_sample,
+};
+pub use pmj02_tables::get_pmj02bn_sample;
+pub use primes::{PRIME_TABLE_SIZE, PRIMES};
+pub use samplers::{
+    HaltonSampler, IndependentSampler, PMJ02BNSampler, PaddedSobolSampler, RandomizeStrategy,
+    Sampler, SobolSampler, StratifiedSampler, ZSobolSampler,
+};
+pub use sobol_matrices::{N_SOBOL_DIMENSIONS, SOBOL_MATRIX_SIZE};
+pub use warps::{
+    balance_heuristic, bilinear_pdf, cosine_hemisphere_pdf, exponential_pdf, ggx_d, ggx_g1,
+    ggx_visible_normal_pdf, invert_bilinear_sample, invert_cosine_hemisphere_sample,
+    invert_exponential_sample, invert_linear_sample, invert_logistic_sample, invert_normal_sample,
+    invert_spherical_rectangle_sample, invert_spherical_triangle_sample, invert_tent_sample,
+    invert_uniform_cone_sample, invert_uniform_disk_concentric_sample,
+    invert_uniform_disk_polar_sample, invert_uniform_hemisphere_sample,
+    invert_uniform_sphere_sample, invert_uniform_triangle_sample, linear_pdf, logistic_pdf,
+    normal_pdf, power_heuristic, sample_bilinear, sample_cosine_hemisphere, sample_exponential,
+    sample_ggx_visible_normal, sample_linear, sample_logistic, sample_normal,
+    sample_spherical_rectangle, sample_spherical_triangle, sample_tent, sample_uniform_cone,
+    sample_uniform_disk_concentric, sample_uniform_disk_polar, sample_uniform_hemisphere,
+    sample_uniform_sphere, sample_uniform_triangle, tent_pdf, uniform_cone_pdf,
+    uniform_hemisphere_pdf, uniform_sphere_pdf,
+};
@@ -0,0 +1,1205 @@
+//! Samplers: the sources of the sample values a renderer consumes for each
+//! pixel sample, from independent random values to low-discrepancy points.
+//!
+//! A sampler is started at a pixel sample with
+//! [`Sampler::start_pixel_sample`] and then returns successive dimensions of
+//! that sample from [`Sampler::get_1d`] and [`Sampler::get_2d`]. The values
+//! for a given pixel, sample index and dimension are deterministic, so
+//! samples can be generated in any order and on any thread.
+
+use std::sync::Arc;
+
+use crate::hash;
+use crate::math::number_traits::Float;
+use crate::math::point2::Point2;
+use crate::random::{Rng, mix_bits, permutation_element};
+use crate::sampling::blue_noise::blue_noise;
+use crate::sampling::low_discrepancy::{
+    BinaryPermuteScrambler, DigitPermutation, FastOwenScrambler, NoRandomizer, OwenScrambler,
+    compute_radical_inverse_permutations, inverse_radical_inverse, owen_scrambled_radical_inverse,
+    radical_inverse, scrambled_radical_inverse, sobol_sample,
+};
+use crate::sampling::pmj02_tables::{N_PMJ02BN_SAMPLES, N_PMJ02BN_SETS, get_pmj02bn_sample};
+use crate::sampling::primes::PRIME_TABLE_SIZE;
+use crate::sampling::sobol_matrices::{N_SOBOL_DIMENSIONS, SOBOL_MATRICES_32, SOBOL_MATRIX_SIZE};
+
+/// A generator of sample values in `[0, 1)` for the pixel samples of an
+/// image.
+///
+/// ```
+/// use pbrt::math::Point2;
+/// use pbrt::sampling::{Sampler, StratifiedSampler};
+///
+/// let mut sampler = StratifiedSampler::new(2, 2, true, 0);
+/// let mut quadrants = Vec::new();
+/// for index in 0..sampler.samples_per_pixel() {
+///     sampler.start_pixel_sample(Point2::new(3, 5), index, 0);
+///     let u = sampler.get_2d();
+///     quadrants.push(2 * (u.y * 2.0) as u32 + (u.x * 2.0) as u32);
+/// }
+/// quadrants.sort();
+/// assert_eq!(quadrants, [0, 1, 2, 3]);
+/// ```
+pub trait Sampler: Clone {
+    /// Returns the number of samples taken in each pixel.
+    fn samples_per_pixel(&self) -> usize;
+
+    /// Starts generating sample `sample_index` of pixel `p`, at dimension
+    /// `dimension`.
+    fn start_pixel_sample(&mut self, p: Point2<i32>, sample_index: usize, dimension: usize);
+
+    /// Returns the next dimension of the current sample.
+    fn get_1d(&mut self) -> f32;
+
+    /// Returns the next two dimensions of the current sample.
+    fn get_2d(&mut self) -> Point2<f32>;
+
+    /// Returns the position of the current sample within its pixel. Some
+    /// samplers draw it from dimensions reserved for the purpose, so that
+    /// pixel positions are well distributed across the image.
+    fn get_pixel_2d(&mut self) -> Point2<f32>;
+}
+
+/// How a low-discrepancy sampler randomizes its points.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum RandomizeStrategy {
+    /// The points are used as they are, so every pixel gets the same ones.
+    None,
+    /// Each digit is permuted the same way for every point.
+    PermuteDigits,
+    /// Base-2 Owen scrambling using a fast hash-based approximation.
+    #[default]
+    FastOwen,
+    /// Full Owen scrambling.
+    Owen,
+}
+
+/// Returns independent uniform random values; the baseline that other
+/// samplers improve on.
+#[derive(Clone, Debug)]
+pub struct IndependentSampler {
+    samples_per_pixel: usize,
+    seed: u32,
+    rng: Rng,
+}
+
+impl IndependentSampler {
+    /// Creates a sampler taking `samples_per_pixel` samples, chosen by
+    /// `seed`.
+    pub fn new(samples_per_pixel: usize, seed: u32) -> Self {
+        Self {
+            samples_per_pixel,
+            seed,
+            rng: Rng::new(),
+        }
+    }
+}
+
+impl Sampler for IndependentSampler {
+    fn samples_per_pixel(&self) -> usize {
+        self.samples_per_pixel
+    }
+
+    fn start_pixel_sample(&mut self, p: Point2<i32>, sample_index: usize, dimension: usize) {
+        self.rng.set_sequence(hash!(p, self.seed), 0);
+        self.rng
+            .advance((sample_index as u64 * 65536 + dimension as u64) as i64);
+    }
+
+    fn get_1d(&mut self) -> f32 {
+        self.rng.uniform()
+    }
+
+    fn get_2d(&mut self) -> Point2<f32> {
+        Point2::new(self.rng.uniform(), self.rng.uniform())
+    }
+
+    fn get_pixel_2d(&mut self) -> Point2<f32> {
+        self.get_2d()
+    }
+}
+
+/// Divides each pixel's samples among the strata of a grid, optionally
+/// jittering them within their strata. Each dimension pairs samples with
+/// strata by a different random permutation.
+#[derive(Clone, Debug)]
+pub struct StratifiedSampler {
+    x_pixel_samples: usize,
+    y_pixel_samples: usize,
+    jitter: bool,
+    seed: u32,
+    rng: Rng,
+    pixel: Point2<i32>,
+    sample_index: usize,
+    dimension: usize,
+}
+
+impl StratifiedSampler {
+    /// Creates a sampler with `x_pixel_samples` by `y_pixel_samples` strata,
+    /// placing samples randomly within them if `jitter` is set and at their
+    /// centers otherwise.
+    pub fn new(x_pixel_samples: usize, y_pixel_samples: usize, jitter: bool, seed: u32) -> Self {
+        Self {
+            x_pixel_samples,
+            y_pixel_samples,
+            jitter,
+            seed,
+            rng: Rng::new(),
+            pixel: Point2::new(0, 0),
+            sample_index: 0,
+            dimension: 0,
+        }
+    }
+
+    fn stratum(&self) -> usize {
+        let hash = hash!(self.pixel, self.dimension, self.seed);
+        permutation_element(
+            self.sample_index as u32,
+            self.samples_per_pixel() as u32,
+            hash as u32,
+        ) as usize
+    }
+
+    fn offset(&mut self) -> f32 {
+        if self.jitter { self.rng.uniform() } else { 0.5 }
+    }
+}
+
+impl Sampler for StratifiedSampler {
+    fn samples_per_pixel(&self) -> usize {
+        self.x_pixel_samples * self.y_pixel_samples
+    }
+
+    fn start_pixel_sample(&mut self, p: Point2<i32>, sample_index: usize, dimension: usize) {
+        self.pixel = p;
+        self.sample_index = sample_index;
+        self.dimension = dimension;
+        self.rng.set_sequence(hash!(p, self.seed), 0);
+        self.rng
+            .advance((sample_index as u64 * 65536 + dimension as u64) as i64);
+    }
+
+    fn get_1d(&mut self) -> f32 {
+        let stratum = self.stratum();
+        self.dimension += 1;
+        let delta = self.offset();
+        (stratum as f32 + delta) / self.samples_per_pixel() as f32
+    }
+
+    fn get_2d(&mut self) -> Point2<f32> {
+        let stratum = self.stratum();
+        self.dimension += 2;
+        let (x, y) = (
+            stratum % self.x_pixel_samples,
+            stratum / self.x_pixel_samples,
+        );
+        let dx = self.offset();
+        let dy = self.offset();
+        Point2::new(
+            (x as f32 + dx) / self.x_pixel_samples as f32,
+            (y as f32 + dy) / self.y_pixel_samples as f32,
+        )
+    }
+
+    fn get_pixel_2d(&mut self) -> Point2<f32> {
+        self.get_2d()
+    }
+}
+
+// Halton pixel positions repeat over tiles of at most this many pixels on a
+// side, keeping the sample indices small.
+const MAX_HALTON_RESOLUTION: i32 = 128;
+
+/// Samples the Halton sequence, with the first two dimensions scaled so
+/// that the sequence covers a tile of pixels and each pixel's samples are
+/// found by solving for the indices that land in it.
+///
+/// ```
+/// use pbrt::math::Point2;
+/// use pbrt::sampling::{HaltonSampler, RandomizeStrategy, Sampler};
+///
+/// let mut sampler =
+///     HaltonSampler::new(16, Point2::new(64, 64), RandomizeStrategy::Owen, 0).unwrap();
+/// sampler.start_pixel_sample(Point2::new(10, 20), 3, 0);
+/// let u = sampler.get_pixel_2d();
+/// assert!((0.0..1.0).contains(&u.x) && (0.0..1.0).contains(&u.y));
+/// ```
+#[derive(Clone, Debug)]
+pub struct HaltonSampler {
+    samples_per_pixel: usize,
+    randomize: RandomizeStrategy,
+    seed: u32,
+    digit_permutations: Option<Arc<Vec<DigitPermutation>>>,
+    base_scales: [u64; 2],
+    base_exponents: [u32; 2],
+    mult_inverse: [u64; 2],
+    halton_index: u64,
+    dimension: usize,
+}
+
+impl HaltonSampler {
+    /// Creates a sampler for an image of `full_resolution` pixels.
+    ///
+    /// Returns an error for [`RandomizeStrategy::FastOwen`]: Halton
+    /// dimensions use bases up to 7919, where the hash-based fast Owen
+    /// scramble does not apply.
+    ///
+    /// ```
+    /// use pbrt::math::Point2;
+    /// use pbrt::sampling::{HaltonSampler, RandomizeStrategy};
+    ///
+    /// let resolution = Point2::new(64, 64);
+    /// assert!(HaltonSampler::new(16, resolution, RandomizeStrategy::FastOwen, 0).is_err());
+    /// ```
+    pub fn new(
+        samples_per_pixel: usize,
+        full_resolution: Point2<i32>,
+        randomize: RandomizeStrategy,
+        seed: u32,
+    ) -> Result<Self, String> {
+        if randomize == RandomizeStrategy::FastOwen {
+            return Err("FastOwen randomization is not supported by the Halton sampler".into());
+        }
+        let digit_permutations = (randomize == RandomizeStrategy::PermuteDigits)
+            .then(|| Arc::new(compute_radical_inverse_permutations(seed)));
+
+        // Find the powers of 2 and 3 that cover the image, up to the maximum
+        // tile size.
+        let mut base_scales = [1u64; 2];
+        let mut base_exponents = [0u32; 2];
+        for (i, (base, resolution)) in [(2, full_resolution.x), (3, full_resolution.y)]
+            .into_iter()
+            .enumerate()
+        {
+            let resolution = resolution.min(MAX_HALTON_RESOLUTION);
+            while base_scales[i] < resolution as u64 {
+                base_scales[i] *= base;
+                base_exponents[i] += 1;
+            }
+        }
+        let mult_inverse = [
+            multiplicative_inverse(base_scales[1], base_scales[0]),
+            multiplicative_inverse(base_scales[0], base_scales[1]),
+        ];
+        Ok(Self {
+            samples_per_pixel,
+            randomize,
+            seed,
+            digit_permutations,
+            base_scales,
+            base_exponents,
+            mult_inverse,
+            halton_index: 0,
+            dimension: 0,
+        })
+    }
+
+    fn sample_dimension(&self, dimension: usize) -> f32 {
+        match self.randomize {
+            RandomizeStrategy::None => radical_inverse(dimension, self.halton_index),
+            RandomizeStrategy::PermuteDigits => {
+                let permutations = self.digit_permutations.as_ref().unwrap();
+                scrambled_radical_inverse(dimension, self.halton_index, &permutations[dimension])
+            }
+            RandomizeStrategy::Owen => {
+                let hash = hash!(dimension, self.seed) as u32;
+                owen_scrambled_radical_inverse(dimension, self.halton_index, hash)
+            }
+            RandomizeStrategy::FastOwen => unreachable!("rejected by HaltonSampler::new"),
+        }
+    }
+}
+
+impl Sampler for HaltonSampler {
+    fn samples_per_pixel(&self) -> usize {
+        self.samples_per_pixel
+    }
+
+    fn start_pixel_sample(&mut self, p: Point2<i32>, sample_index: usize, dimension: usize) {
+        // The first sample of the pixel is the index whose first two
+        // dimensions, scaled by base_scales, fall in it: by the Chinese
+        // remainder theorem, one of every base_scales[0] * base_scales[1].
+        self.halton_index = 0;
+        let sample_stride = self.base_scales[0] * self.base_scales[1];
+        if sample_stride > 1 {
+            let pm = [
+                p.x.rem_euclid(MAX_HALTON_RESOLUTION) as u64,
+                p.y.rem_euclid(MAX_HALTON_RESOLUTION) as u64,
+            ];
+            for (i, base) in [2, 3].into_iter().enumerate() {
+                let dim_offset = inverse_radical_inverse(pm[i], base, self.base_exponents[i]);
+                self.halton_index = self.halton_index.wrapping_add(
+                    dim_offset * (sample_stride / self.base_scales[i]) * self.mult_inverse[i],
+                );
+            }
+            self.halton_index %= sample_stride;
+        }
+        self.halton_index += sample_index as u64 * sample_stride;
+        self.dimension = dimension.max(2);
+    }
+
+    fn get_1d(&mut self) -> f32 {
+        if self.dimension >= PRIME_TABLE_SIZE {
+            self.dimension = 2;
+        }
+        let dimension = self.dimension;
+        self.dimension += 1;
+        self.sample_dimension(dimension)
+    }
+
+    fn get_2d(&mut self) -> Point2<f32> {
+        if self.dimension + 1 >= PRIME_TABLE_SIZE {
+            self.dimension = 2;
+        }
+        let dimension = self.dimension;
+        self.dimension += 2;
+        Point2::new(
+            self.sample_dimension(dimension),
+            self.sample_dimension(dimension + 1),
+        )
+    }
+
+    fn get_pixel_2d(&mut self) -> Point2<f32> {
+        // Drop the digits that select the pixel, leaving the position in it.
+        Point2::new(
+            radical_inverse(0, self.halton_index >> self.base_exponents[0]),
+            radical_inverse(1, self.halton_index / self.base_scales[1]),
+        )
+    }
+}
+
+/// Samples independent Sobol' point sets in each pair of dimensions, with
+/// the sample indices shuffled differently in each pair so that the
+/// dimensions are not correlated. The number of samples per pixel is
+/// rounded up to a power of two.
+#[derive(Clone, Debug)]
+pub struct PaddedSobolSampler {
+    samples_per_pixel: usize,
+    seed: u32,
+    randomize: RandomizeStrategy,
+    pixel: Point2<i32>,
+    sample_index: usize,
+    dimension: usize,
+}
+
+impl PaddedSobolSampler {
+    /// Creates a sampler taking at least `samples_per_pixel` samples.
+    pub fn new(samples_per_pixel: usize, randomize: RandomizeStrategy, seed: u32) -> Self {
+        Self {
+            samples_per_pixel: samples_per_pixel.next_power_of_two(),
+            seed,
+            randomize,
+            pixel: Point2::new(0, 0),
+            sample_index: 0,
+            dimension: 0,
+        }
+    }
+
+    fn permuted_index(&self) -> (u64, u64) {
+        let hash = hash!(self.pixel, self.dimension, self.seed);
+        let index = permutation_element(
+            self.sample_index as u32,
+            self.samples_per_pixel as u32,
+            hash as u32,
+        );
+        (u64::from(index), hash)
+    }
+
+    fn sample_dimension(&self, dimension: usize, a: u64, hash: u32) -> f32 {
+        sobol_sample_randomized(a, dimension, self.randomize, hash)
+    }
+}
+
+impl Sampler for PaddedSobolSampler {
+    fn samples_per_pixel(&self) -> usize {
+        self.samples_per_pixel
+    }
+
+    fn start_pixel_sample(&mut self, p: Point2<i32>, sample_index: usize, dimension: usize) {
+        self.pixel = p;
+        self.sample_index = sample_index;
+        self.dimension = dimension;
+    }
+
+    fn get_1d(&mut self) -> f32 {
+        let (index, hash) = self.permuted_index();
+        self.dimension += 1;
+        self.sample_dimension(0, index, (hash >> 32) as u32)
+    }
+
+    fn get_2d(&mut self) -> Point2<f32> {
+        let (index, hash) = self.permuted_index();
+        self.dimension += 2;
+        Point2::new(
+            self.sample_dimension(0, index, hash as u32),
+            self.sample_dimension(1, index, (hash >> 32) as u32),
+        )
+    }
+
+    fn get_pixel_2d(&mut self) -> Point2<f32> {
+        self.get_2d()
+    }
+}
+
+/// Samples a single Sobol' sequence over the whole image, with its first
+/// two dimensions scaled to the image so that each pixel's samples are the
+/// points of the sequence that land in it. The number of samples per pixel
+/// is rounded up to a power of two. Past the last of the
+/// [`N_SOBOL_DIMENSIONS`] dimensions, sampling starts over at dimension 2.
+///
+/// ```
+/// use pbrt::math::Point2;
+/// use pbrt::sampling::{RandomizeStrategy, Sampler, SobolSampler};
+///
+/// let mut sampler = SobolSampler::new(4, Point2::new(8, 8), RandomizeStrategy::None, 0);
+/// let mut quadrants = Vec::new();
+/// for index in 0..4 {
+///     sampler.start_pixel_sample(Point2::new(5, 2), index, 0);
+///     let u = sampler.get_pixel_2d();
+///     quadrants.push(2 * (u.y * 2.0) as u32 + (u.x * 2.0) as u32);
+/// }
+/// quadrants.sort();
+/// assert_eq!(quadrants, [0, 1, 2, 3]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SobolSampler {
+    samples_per_pixel: usize,
+    seed: u32,
+    randomize: RandomizeStrategy,
+    scale: i32,
+    interval_map: Arc<SobolIntervalMap>,
+    pixel: Point2<i32>,
+    dimension: usize,
+    sobol_index: u64,
+}
+
+impl SobolSampler {
+    /// Creates a sampler for an image of `full_resolution` pixels taking at
+    /// least `samples_per_pixel` samples.
+    pub fn new(
+        samples_per_pixel: usize,
+        full_resolution: Point2<i32>,
+        randomize: RandomizeStrategy,
+        seed: u32,
+    ) -> Self {
+        let scale = (full_resolution.x.max(full_resolution.y).max(1) as u32).next_power_of_two();
+        Self {
+            samples_per_pixel: samples_per_pixel.next_power_of_two(),
+            seed,
+            randomize,
+            scale: scale as i32,
+            interval_map: Arc::new(SobolIntervalMap::new(scale.trailing_zeros())),
+            pixel: Point2::new(0, 0),
+            dimension: 0,
+            sobol_index: 0,
+        }
+    }
+
+    fn sample_dimension(&self, dimension: usize) -> f32 {
+        let hash = hash!(dimension, self.seed) as u32;
+        sobol_sample_randomized(self.sobol_index, dimension, self.randomize, hash)
+    }
+}
+
+impl Sampler for SobolSampler {
+    fn samples_per_pixel(&self) -> usize {
+        self.samples_per_pixel
+    }
+
+    fn start_pixel_sample(&mut self, p: Point2<i32>, sample_index: usize, dimension: usize) {
+        self.pixel = p;
+        self.dimension = dimension.max(2);
+        self.sobol_index = self.interval_map.index(sample_index as u64, p);
+    }
+
+    fn get_1d(&mut self) -> f32 {
+        if self.dimension >= N_SOBOL_DIMENSIONS {
+            self.dimension = 2;
+        }
+        let dimension = self.dimension;
+        self.dimension += 1;
+        self.sample_dimension(dimension)
+    }
+
+    fn get_2d(&mut self) -> Point2<f32> {
+        if self.dimension + 1 >= N_SOBOL_DIMENSIONS {
+            self.dimension = 2;
+        }
+        let dimension = self.dimension;
+        self.dimension += 2;
+        Point2::new(
+            self.sample_dimension(dimension),
+            self.sample_dimension(dimension + 1),
+        )
+    }
+
+    fn get_pixel_2d(&mut self) -> Point2<f32> {
+        let u = [
+            sobol_sample(self.sobol_index, 0, NoRandomizer),
+            sobol_sample(self.sobol_index, 1, NoRandomizer),
+        ];
+        let pixel = [self.pixel.x, self.pixel.y];
+        let [x, y] = [0, 1].map(|i| {
+            (u[i] * self.scale as f32 - pixel[i] as f32).clamp(0.0, f32::ONE_MINUS_EPSILON)
+        });
+        Point2::new(x, y)
+    }
+}
+
+// Finds the index of a pixel's samples in a Sobol' sequence whose first two
+// dimensions are scaled by 2^m. Within each block of 2^2m consecutive
+// indices, the (0,2m,2)-net property puts exactly one point in each pixel,
+// so the low 2m index bits follow from the pixel by inverting a linear map
+// over GF(2), after accounting for the block's contribution.
+#[derive(Debug)]
+struct SobolIntervalMap {
+    m: u32,
+    // The pixel bits contributed by each index bit from 2m up.
+    frame_columns: Vec<u64>,
+    // The index bits selecting each bit of the pixel, (x << m) | y.
+    inverse_columns: Vec<u64>,
+}
+
+impl SobolIntervalMap {
+    fn new(m: u32) -> Self {
+        assert!(m <= 16, "image too large for the Sobol' sampler");
+        let n = 2 * m as usize;
+        let column = |c: usize| {
+            if m == 0 {
+                return 0;
+            }
+            let x = u64::from(SOBOL_MATRICES_32[c]) >> (32 - m);
+            let y = u64::from(SOBOL_MATRICES_32[SOBOL_MATRIX_SIZE + c]) >> (32 - m);
+            (x << m) | y
+        };
+        let frame_columns = (n..SOBOL_MATRIX_SIZE).map(column).collect();
+
+        // Gauss-Jordan elimination on the rows of [A | I], where column c
+        // of A holds the pixel bits set by index bit c.
+        let mut rows: Vec<(u64, u64)> = (0..n)
+            .map(|r| {
+                let a = (0..n).fold(0, |row, c| row | ((column(c) >> r) & 1) << c);
+                (a, 1 << r)
+            })
+            .collect();
+        for c in 0..n {
+            let pivot = (c..n)
+                .find(|&r| rows[r].0 & (1 << c) != 0)
+                .expect("Sobol' pixel map is singular");
+            rows.swap(c, pivot);
+            for r in 0..n {
+                if r != c && rows[r].0 & (1 << c) != 0 {
+                    rows[r].0 ^= rows[c].0;
+                    rows[r].1 ^= rows[c].1;
+                }
+            }
+        }
+        // Row c of the inverse now gives index bit c in terms of pixel
+        // bits; transpose it into columns indexed by pixel bit.
+        let inverse_columns = (0..n)
+            .map(|r| (0..n).fold(0, |column, c| column | ((rows[c].1 >> r) & 1) << c))
+            .collect();
+        Self {
+            m,
+            frame_columns,
+            inverse_columns,
+        }
+    }
+
+    fn index(&self, frame: u64, p: Point2<i32>) -> u64 {
+        if self.m == 0 {
+            return frame;
+        }
+        let m2 = 2 * self.m;
+        debug_assert!(frame < 1 << (SOBOL_MATRIX_SIZE as u32 - m2));
+        let mut delta = 0;
+        let mut bits = frame;
+        let mut c = 0;
+        while bits != 0 {
+            if bits & 1 != 0 {
+                delta ^= self.frame_columns[c];
+            }
+            bits >>= 1;
+            c += 1;
+        }
+        let mask = (1 << self.m) - 1;
+        let mut b = (((p.x as u64 & mask) << self.m) | (p.y as u64 & mask)) ^ delta;
+        let mut index = frame << m2;
+        let mut c = 0;
+        while b != 0 {
+            if b & 1 != 0 {
+                index ^= self.inverse_columns[c];
+            }
+            b >>= 1;
+            c += 1;
+        }
+        index
+    }
+}
+
+/// Samples Sobol' points with the pixel samples ordered along a Morton
+/// (Z-order) curve and shuffled by hashed base-4 digit permutations
+/// (Ahmed and Wonka, "Screen-Space Blue-Noise Diffusion of Monte Carlo
+/// Sampling Error via Hierarchical Ordering of Pixels"). Neighbouring
+/// pixels get complementary parts of a single well-stratified point set,
+/// so their errors are negatively correlated and look like blue noise. The
+/// number of samples per pixel is rounded up to a power of two.
+#[derive(Clone, Debug)]
+pub struct ZSobolSampler {
+    randomize: RandomizeStrategy,
+    seed: u32,
+    log2_samples_per_pixel: u32,
+    n_base4_digits: u32,
+    morton_index: u64,
+    dimension: usize,
+}
+
+impl ZSobolSampler {
+    /// Creates a sampler for an image of `full_resolution` pixels taking at
+    /// least `samples_per_pixel` samples.
+    pub fn new(
+        samples_per_pixel: usize,
+        full_resolution: Point2<i32>,
+        randomize: RandomizeStrategy,
+        seed: u32,
+    ) -> Self {
+        let log2_samples_per_pixel = samples_per_pixel.next_power_of_two().trailing_zeros();
+        let resolution =
+            (full_resolution.x.max(full_resolution.y).max(1) as u32).next_power_of_two();
+        let log4_samples_per_pixel = log2_samples_per_pixel.div_ceil(2);
+        Self {
+            randomize,
+            seed,
+            log2_samples_per_pixel,
+            n_base4_digits: resolution.trailing_zeros() + log4_samples_per_pixel,
+            morton_index: 0,
+            dimension: 0,
+        }
+    }
+
+    // Shuffles the base-4 digits of the Morton index, each by a permutation
+    // hashed from the digits above it, to decorrelate dimensions.
+    fn sample_index(&self) -> u64 {
+        #[rustfmt::skip]
+        const PERMUTATIONS: [[u8; 4]; 24] = [
+            [0, 1, 2, 3], [0, 1, 3, 2], [0, 2, 1, 3], [0, 2, 3, 1], [0, 3, 2, 1], [0, 3, 1, 2],
+            [1, 0, 2, 3], [1, 0, 3, 2], [1, 2, 0, 3], [1, 2, 3, 0], [1, 3, 2, 0], [1, 3, 0, 2],
+            [2, 1, 0, 3], [2, 1, 3, 0], [2, 0, 1, 3], [2, 0, 3, 1], [2, 3, 0, 1], [2, 3, 1, 0],
+            [3, 1, 2, 0], [3, 1, 0, 2], [3, 2, 1, 0], [3, 2, 0, 1], [3, 0, 2, 1], [3, 0, 1, 2],
+        ];
+        let dimension_hash = 0x5555_5555u64.wrapping_mul(self.dimension as u64);
+        let mut sample_index = 0;
+        // With an odd power of two samples, the lowest digit is binary.
+        let pow2_samples = self.log2_samples_per_pixel & 1 != 0;
+        let last_digit = u32::from(pow2_samples);
+        for i in (last_digit..self.n_base4_digits).rev() {
+            let digit_shift = 2 * i - u32::from(pow2_samples);
+            let digit = (self.morton_index >> digit_shift) & 3;
+            let higher_digits = self.morton_index >> (digit_shift + 2);
+            let p = (mix_bits(higher_digits ^ dimension_hash) >> 24) % 24;
+            let digit = u64::from(PERMUTATIONS[p as usize][digit as usize]);
+            sample_index |= digit << digit_shift;
+        }
+        if pow2_samples {
+            let digit = self.morton_index & 1;
+            sample_index |= digit ^ (mix_bits((self.morton_index >> 1) ^ dimension_hash) & 1);
+        }
+        sample_index
+    }
+}
+
+impl Sampler for ZSobolSampler {
+    fn samples_per_pixel(&self) -> usize {
+        1 << self.log2_samples_per_pixel
+    }
+
+    fn start_pixel_sample(&mut self, p: Point2<i32>, sample_index: usize, dimension: usize) {
+        self.dimension = dimension;
+        self.morton_index = (encode_morton2(p.x as u32, p.y as u32) << self.log2_samples_per_pixel)
+            | sample_index as u64;
+    }
+
+    fn get_1d(&mut self) -> f32 {
+        let sample_index = self.sample_index();
+        self.dimension += 1;
+        let hash = hash!(self.dimension, self.seed) as u32;
+        sobol_sample_randomized(sample_index, 0, self.randomize, hash)
+    }
+
+    fn get_2d(&mut self) -> Point2<f32> {
+        let sample_index = self.sample_index();
+        self.dimension += 2;
+        let hash = hash!(self.dimension, self.seed);
+        Point2::new(
+            sobol_sample_randomized(sample_index, 0, self.randomize, hash as u32),
+            sobol_sample_randomized(sample_index, 1, self.randomize, (hash >> 32) as u32),
+        )
+    }
+
+    fn get_pixel_2d(&mut self) -> Point2<f32> {
+        self.get_2d()
+    }
+}
+
+/// Samples progressive multi-jittered (0,2) point sets with blue-noise
+/// spacing (see [`crate::sampling::pmj02_tables`]). Pixel positions are
+/// taken from one large set spread over a tile of pixels; other dimensions
+/// use the smaller sets, rotated per pixel by blue-noise offsets. Results
+/// are best with a power of four samples per pixel.
+#[derive(Clone, Debug)]
+pub struct PMJ02BNSampler {
+    samples_per_pixel: usize,
+    seed: u32,
+    pixel_tile_size: i32,
+    pixel_samples: Arc<Vec<Point2<f32>>>,
+    pixel: Point2<i32>,
+    sample_index: usize,
+    dimension: usize,
+}
+
+impl PMJ02BNSampler {
+    /// Creates a sampler taking `samples_per_pixel` samples.
+    ///
+    /// Returns an error if `samples_per_pixel` is zero or exceeds
+    /// [`N_PMJ02BN_SAMPLES`], the size of the sample sets.
+    ///
+    /// ```
+    /// use pbrt::sampling::PMJ02BNSampler;
+    /// use pbrt::sampling::pmj02_tables::N_PMJ02BN_SAMPLES;
+    ///
+    /// assert!(PMJ02BNSampler::new(16, 0).is_ok());
+    /// assert!(PMJ02BNSampler::new(N_PMJ02BN_SAMPLES + 1, 0).is_err());
+    /// ```
+    pub fn new(samples_per_pixel: usize, seed: u32) -> Result<Self, String> {
+        if !(1..=N_PMJ02BN_SAMPLES).contains(&samples_per_pixel) {
+            return Err(format!(
+                "{samples_per_pixel} samples per pixel requested, but the PMJ02BN sampler \
+                 supports 1 to {N_PMJ02BN_SAMPLES}"
+            ));
+        }
+        // Spread the first set over a tile holding all of its points.
+        let log4 = |n: usize| n.next_power_of_two().trailing_zeros().div_ceil(2);
+        let pixel_tile_size = 1 << (log4(N_PMJ02BN_SAMPLES) - log4(samples_per_pixel));
+        let n_pixels = (pixel_tile_size * pixel_tile_size) as usize;
+        let mut pixel_samples = vec![Point2::new(0.0, 0.0); n_pixels * samples_per_pixel];
+        let mut n_stored = vec![0; n_pixels];
+        for i in 0..N_PMJ02BN_SAMPLES {
+            let p = get_pmj02bn_sample(0, i);
+            let (x, y) = (p.x * pixel_tile_size as f32, p.y * pixel_tile_size as f32);
+            let pixel_offset = x as usize + y as usize * pixel_tile_size as usize;
+            // With a power of four samples per pixel, every pixel gets
+            // exactly that many; otherwise the extras are dropped.
+            if n_stored[pixel_offset] == samples_per_pixel {
+                continue;
+            }
+            pixel_samples[pixel_offset * samples_per_pixel + n_stored[pixel_offset]] =
+                Point2::new(x - x.floor(), y - y.floor());
+            n_stored[pixel_offset] += 1;
+        }
+        Ok(Self {
+            samples_per_pixel,
+            seed,
+            pixel_tile_size,
+            pixel_samples: Arc::new(pixel_samples),
+            pixel: Point2::new(0, 0),
+            sample_index: 0,
+            dimension: 0,
+        })
+    }
+}
+
+impl Sampler for PMJ02BNSampler {
+    fn samples_per_pixel(&self) -> usize {
+        self.samples_per_pixel
+    }
+
+    fn start_pixel_sample(&mut self, p: Point2<i32>, sample_index: usize, dimension: usize) {
+        self.pixel = p;
+        self.sample_index = sample_index;
+        self.dimension = dimension.max(2);
+    }
+
+    fn get_1d(&mut self) -> f32 {
+        let hash = hash!(self.pixel, self.dimension, self.seed);
+        let index = permutation_element(
+            self.sample_index as u32,
+            self.samples_per_pixel as u32,
+            hash as u32,
+        );
+        let delta = blue_noise(self.dimension, self.pixel);
+        self.dimension += 1;
+        ((index as f32 + delta) / self.samples_per_pixel as f32).min(f32::ONE_MINUS_EPSILON)
+    }
+
+    fn get_2d(&mut self) -> Point2<f32> {
+        // Once every set is in use, reuse them with shuffled indices.
+        let mut index = self.sample_index;
+        let pmj_instance = self.dimension / 2;
+        if pmj_instance >= N_PMJ02BN_SETS {
+            let hash = hash!(self.pixel, self.dimension, self.seed);
+            index = permutation_element(
+                self.sample_index as u32,
+                self.samples_per_pixel as u32,
+                hash as u32,
+            ) as usize;
+        }
+        let u = get_pmj02bn_sample(pmj_instance % N_PMJ02BN_SETS, index);
+        // Cranley-Patterson rotation by a per-pixel blue-noise offset.
+        let rotate = |u: f32, delta: f32| {
+            let u = u + delta;
+            (if u >= 1.0 { u - 1.0 } else { u }).min(f32::ONE_MINUS_EPSILON)
+        };
+        let x = rotate(u.x, blue_noise(self.dimension, self.pixel));
+        let y = rotate(u.y, blue_noise(self.dimension + 1, self.pixel));
+        self.dimension += 2;
+        Point2::new(x, y)
+    }
+
+    fn get_pixel_2d(&mut self) -> Point2<f32> {
+        let px = self.pixel.x.rem_euclid(self.pixel_tile_size);
+        let py = self.pixel.y.rem_euclid(self.pixel_tile_size);
+        let offset = (px + py * self.pixel_tile_size) as usize * self.samples_per_pixel;
+        self.pixel_samples[offset + self.sample_index]
+    }
+}
+
+// Samples a Sobol' dimension randomized by the given strategy and seed.
+fn sobol_sample_randomized(
+    a: u64,
+    dimension: usize,
+    randomize: RandomizeStrategy,
+    hash: u32,
+) -> f32 {
+    match randomize {
+        RandomizeStrategy::None => sobol_sample(a, dimension, NoRandomizer),
+        RandomizeStrategy::PermuteDigits => {
+            sobol_sample(a, dimension, BinaryPermuteScrambler { permutation: hash })
+        }
+        RandomizeStrategy::FastOwen => sobol_sample(a, dimension, FastOwenScrambler { seed: hash }),
+        RandomizeStrategy::Owen => sobol_sample(a, dimension, OwenScrambler { seed: hash }),
+    }
+}
+
+// Returns the inverse of a modulo n, for coprime a and n.
+fn multiplicative_inverse(a: u64, n: u64) -> u64 {
+    // Extended Euclid, tracking the coefficients of a.
+    let (mut r0, mut r1) = (a as i64, n as i64);
+    let (mut s0, mut s1) = (1i64, 0i64);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (s0, s1) = (s1, s0 - q * s1);
+    }
+    debug_assert_eq!(r0, 1);
+    s0.rem_euclid(n as i64) as u64
+}
+
+// Interleaves the bits of x and y, with those of x in the even positions.
+fn encode_morton2(x: u32, y: u32) -> u64 {
+    fn spread(v: u32) -> u64 {
+        let mut v = u64::from(v);
+        v = (v | (v << 16)) & 0x0000_ffff_0000_ffff;
+        v = (v | (v << 8)) & 0x00ff_00ff_00ff_00ff;
+        v = (v | (v << 4)) & 0x0f0f_0f0f_0f0f_0f0f;
+        v = (v | (v << 2)) & 0x3333_3333_3333_3333;
+        (v | (v << 1)) & 0x5555_5555_5555_5555
+    }
+    (spread(y) << 1) | spread(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::point2::Point2;
+    use crate::sampling::low_discrepancy::{NoRandomizer, radical_inverse, sobol_sample};
+    use crate::sampling::samplers::{
+        HaltonSampler, IndependentSampler, PMJ02BNSampler, PaddedSobolSampler, RandomizeStrategy,
+        Sampler, SobolSampler, StratifiedSampler, ZSobolSampler, encode_morton2,
+        multiplicative_inverse,
+    };
+
+    const RESOLUTION: Point2<i32> = Point2 { x: 16, y: 16 };
+
+    type Integrand = fn(f64, f64) -> f64;
+
+    // Calls `check` with one of each sampler, named.
+    fn for_each_sampler(
+        samples_per_pixel: usize,
+        mut check: impl FnMut(&str, &mut dyn FnMut(Point2<i32>, usize) -> Vec<f32>),
+    ) {
+        fn values<S: Sampler>(sampler: &mut S, p: Point2<i32>, index: usize) -> Vec<f32> {
+            sampler.start_pixel_sample(p, index, 0);
+            let pixel = sampler.get_pixel_2d();
+            let mut v = vec![pixel.x, pixel.y];
+            for _ in 0..4 {
+                let u = sampler.get_2d();
+                v.extend([u.x, u.y, sampler.get_1d()]);
+            }
+            v
+        }
+        let n = (samples_per_pixel as f64).sqrt() as usize;
+        let mut independent = IndependentSampler::new(samples_per_pixel, 1);
+        check("independent", &mut |p, i| values(&mut independent, p, i));
+        let mut stratified = StratifiedSampler::new(n, n, true, 2);
+        check("stratified", &mut |p, i| values(&mut stratified, p, i));
+        for randomize in [
+            RandomizeStrategy::None,
+            RandomizeStrategy::PermuteDigits,
+            RandomizeStrategy::FastOwen,
+            RandomizeStrategy::Owen,
+        ] {
+            if randomize != RandomizeStrategy::FastOwen {
+                let mut halton =
+                    HaltonSampler::new(samples_per_pixel, RESOLUTION, randomize, 3).unwrap();
+                check(&format!("halton {randomize:?}"), &mut |p, i| {
+                    values(&mut halton, p, i)
+                });
+            }
+            let mut padded = PaddedSobolSampler::new(samples_per_pixel, randomize, 4);
+            check(&format!("padded sobol {randomize:?}"), &mut |p, i| {
+                values(&mut padded, p, i)
+            });
+            let mut sobol = SobolSampler::new(samples_per_pixel, RESOLUTION, randomize, 5);
+            check(&format!("sobol {randomize:?}"), &mut |p, i| {
+                values(&mut sobol, p, i)
+            });
+            let mut zsobol = ZSobolSampler::new(samples_per_pixel, RESOLUTION, randomize, 6);
+            check(&format!("zsobol {randomize:?}"), &mut |p, i| {
+                values(&mut zsobol, p, i)
+            });
+        }
+        let mut pmj = PMJ02BNSampler::new(samples_per_pixel, 7).unwrap();
+        check("pmj02bn", &mut |p, i| values(&mut pmj, p, i));
+    }
+
+    // Asserts that n^2 points have one point in each cell of an n by n grid.
+    fn assert_grid_stratified(points: &[(f32, f32)]) {
+        let n = (points.len() as f64).sqrt() as usize;
+        let mut seen = vec![false; n * n];
+        for &(x, y) in points {
+            let cell = (y * n as f32) as usize * n + (x * n as f32) as usize;
+            assert!(!seen[cell], "{points:?} is not stratified");
+            seen[cell] = true;
+        }
+    }
+
+    // Returns the star discrepancy of a 2D point set: the largest difference
+    // between the fraction of points in a box anchored at the origin and the
+    // box's area.
+    fn star_discrepancy(points: &[(f64, f64)]) -> f64 {
+        let n = points.len() as f64;
+        let xs: Vec<f64> = points.iter().map(|p| p.0).chain([1.0]).collect();
+        let ys: Vec<f64> = points.iter().map(|p| p.1).chain([1.0]).collect();
+        let mut discrepancy = 0.0f64;
+        for &x in &xs {
+            for &y in &ys {
+                let open = points.iter().filter(|p| p.0 < x && p.1 < y).count() as f64;
+                let closed = points.iter().filter(|p| p.0 <= x && p.1 <= y).count() as f64;
+                discrepancy = discrepancy.max(x * y - open / n).max(closed / n - x * y);
+            }
+        }
+        discrepancy
+    }
+
+    #[test]
+    fn test_values_are_in_range_and_deterministic() {
+        for_each_sampler(16, |name, values| {
+            for p in [Point2::new(0, 0), Point2::new(7, 3), Point2::new(15, 15)] {
+                for index in 0..16 {
+                    let v = values(p, index);
+                    assert!(v.iter().all(|u| (0.0..1.0).contains(u)), "{name}: {v:?}");
+                    assert_eq!(v, values(p, index), "{name}");
+                }
+            }
+            // Different pixels get different samples.
+            assert_ne!(
+                values(Point2::new(1, 2), 0),
+                values(Point2::new(2, 1), 0),
+                "{name}"
+            );
+        });
+    }
+
+    #[test]
+    fn test_clone_continues_identically() {
+        let mut sampler = ZSobolSampler::new(16, RESOLUTION, RandomizeStrategy::Owen, 0);
+        sampler.start_pixel_sample(Point2::new(3, 4), 5, 0);
+        sampler.get_2d();
+        let mut clone = sampler.clone();
+        assert_eq!(sampler.get_2d(), clone.get_2d());
+        assert_eq!(sampler.get_1d(), clone.get_1d());
+    }
+
+    #[test]
+    fn test_pixel_samples_are_stratified() {
+        // Every sampler but the independent one and Halton, whose pixel
+        // samples are spread over the image, stratifies each pixel's
+        // positions.
+        for_each_sampler(16, |name, values| {
+            if name == "independent" || name.starts_with("halton") {
+                return;
+            }
+            for p in [Point2::new(0, 0), Point2::new(5, 9)] {
+                let points: Vec<(f32, f32)> = (0..16)
+                    .map(|i| {
+                        let v = values(p, i);
+                        (v[0], v[1])
+                    })
+                    .collect();
+                assert_grid_stratified(&points);
+            }
+        });
+    }
+
+    #[test]
+    fn test_padded_dimensions_are_stratified() {
+        for_each_sampler(16, |name, values| {
+            if !["stratified", "padded", "zsobol"]
+                .iter()
+                .any(|s| name.starts_with(s))
+            {
+                return;
+            }
+            let p = Point2::new(2, 3);
+            let samples: Vec<Vec<f32>> = (0..16).map(|i| values(p, i)).collect();
+            for d in 0..4 {
+                let points: Vec<(f32, f32)> = samples
+                    .iter()
+                    .map(|v| (v[2 + 3 * d], v[3 + 3 * d]))
+                    .collect();
+                assert_grid_stratified(&points);
+                let mut strata: Vec<usize> = samples
+                    .iter()
+                    .map(|v| (v[4 + 3 * d] * 16.0) as usize)
+                    .collect();
+                strata.sort();
+                assert_eq!(strata, (0..16).collect::<Vec<_>>(), "{name}");
+            }
+        });
+    }
+
+    #[test]
+    fn test_halton_pixel_samples_land_in_pixel() {
+        let mut sampler =
+            HaltonSampler::new(8, Point2::new(20, 10), RandomizeStrategy::None, 0).unwrap();
+        for p in [Point2::new(0, 0), Point2::new(19, 9), Point2::new(7, 3)] {
+            for index in 0..8 {
+                sampler.start_pixel_sample(p, index, 0);
+                let h = sampler.halton_index;
+                let x = radical_inverse(0, h) * sampler.base_scales[0] as f32;
+                let y = radical_inverse(1, h) * sampler.base_scales[1] as f32;
+                assert_eq!((x as i32, y as i32), (p.x, p.y));
+                let u = sampler.get_pixel_2d();
+                assert!((x.fract() - u.x).abs() < 1e-4 && (y.fract() - u.y).abs() < 1e-4);
+            }
+        }
+    }
+
+    #[test]
+    fn test_halton_rejects_fast_owen() {
+        let resolution = Point2::new(16, 16);
+        let err = HaltonSampler::new(4, resolution, RandomizeStrategy::FastOwen, 7).unwrap_err();
+        assert!(err.contains("FastOwen"), "{err}");
+        assert!(HaltonSampler::new(4, resolution, RandomizeStrategy::Owen, 7).is_ok());
+    }
+
+    #[test]
+    fn test_sobol_pixel_samples_land_in_pixel() {
+        let mut sampler = SobolSampler::new(8, Point2::new(5, 7), RandomizeStrategy::None, 0);
+        assert_eq!(sampler.scale, 8);
+        let mut indices = Vec::new();
+        for y in 0..8 {
+            for x in 0..8 {
+                for index in 0..8 {
+                    sampler.start_pixel_sample(Point2::new(x, y), index, 0);
+                    let a = sampler.sobol_index;
+                    let u = (
+                        sobol_sample(a, 0, NoRandomizer),
+                        sobol_sample(a, 1, NoRandomizer),
+                    );
+                    assert_eq!(((u.0 * 8.0) as i32, (u.1 * 8.0) as i32), (x, y));
+                    indices.push(a);
+                }
+            }
+        }
+        // Together, the pixels use exactly the first 512 points.
+        indices.sort();
+        assert_eq!(indices, (0..512).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_helpers() {
+        assert_eq!(multiplicative_inverse(3, 8), 3);
+        assert_eq!(multiplicative_inverse(27, 64), 19);
+        assert_eq!(multiplicative_inverse(64, 27), 19);
+        assert_eq!(encode_morton2(0b11, 0b00), 0b0101);
+        assert_eq!(encode_morton2(0b00, 0b11), 0b1010);
+        assert_eq!(encode_morton2(u32::MAX, 0), 0x5555_5555_5555_5555);
+    }
+
+    #[test]
+    fn test_discrepancy_and_integration_error() {
+        // Integrands over the unit square with known integrals: smooth,
+        // discontinuous and peaked.
+        let integrands: [(Integrand, f64); 3] = [
+            (|x, y| x * y, 0.25),
+            (
+                |x, y| f64::from(u8::from(x * x + y * y < 1.0)),
+                std::f64::consts::FRAC_PI_4,
+            ),
+            (|x, y| (-(x * x + y * y)).exp(), 0.557_746_285_351_034),
+        ];
+        let pixels: Vec<Point2<i32>> = (0..16)
+            .map(|i| Point2::new(i % 4 * 3, i / 4 * 3 + 1))
+            .collect();
+        let mut results = Vec::new();
+        for_each_sampler(64, |name, values| {
+            let mut discrepancy = 0.0;
+            let mut squared_errors = [0.0; 3];
+            for &p in &pixels {
+                let samples: Vec<Vec<f32>> = (0..64).map(|i| values(p, i)).collect();
+                // Dimensions 2 and 3 are the first after the pixel position.
+                let points: Vec<(f64, f64)> = samples
+                    .iter()
+                    .map(|v| (f64::from(v[2]), f64::from(v[3])))
+                    .collect();
+                discrepancy += star_discrepancy(&points) / pixels.len() as f64;
+                for (j, (f, integral)) in integrands.iter().enumerate() {
+                    let estimate = points.iter().map(|&(x, y)| f(x, y)).sum::<f64>() / 64.0;
+                    squared_errors[j] += (estimate - integral).powi(2) / pixels.len() as f64;
+                }
+            }
+            results.push((name.to_string(), discrepancy, squared_errors.map(f64::sqrt)));
+        });
+
+        let (_, random_discrepancy, random_errors) = results[0].clone();
+        assert!(random_discrepancy > 0.08, "{random_discrepancy}");
+        for (name, discrepancy, errors) in &results[1..] {
+            // Unrandomized samples are well distributed, but their errors
+            // are not independent between pixels (padded and Z-order Sobol'
+            // repeat the same points in every pixel), so they are only held
+            // to the discrepancy bound.
+            assert!(
+                *discrepancy < random_discrepancy * 0.6,
+                "{name}: {discrepancy}"
+            );
+            if name.ends_with("None") {
+                continue;
+            }
+            // The discontinuous integrand converges more slowly for every
+            // sampler.
+            for (j, bound) in [0.4, 0.75, 0.4].into_iter().enumerate() {
+                assert!(
+                    errors[j] < random_errors[j] * bound,
+                    "{name}: {errors:?} vs {random_errors:?}"
+                );
+            }
+        }
+        // Owen-scrambled Sobol' points converge faster than jittered ones on
+        // smooth integrands.
+        let errors = |name: &str| results.iter().find(|r| r.0 == name).unwrap().2;
+        for name in ["padded sobol Owen", "zsobol Owen", "padded sobol FastOwen"] {
+            assert!(errors(name)[0] < errors("stratified")[0] * 0.5, "{name}");
+            assert!(errors(name)[2] < errors("stratified")[2] * 0.5, "{name}");
+        }
+    }
+}
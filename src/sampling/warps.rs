@@ -0,0 +1,1351 @@
+//! Warps from uniform samples in `[0, 1)` or `[0, 1)^2` to common
+//! distributions, each with its PDF and, where it exists, the inverse that
+//! maps a value back to the sample that produced it.
+//!
+//! PDFs are with respect to the measure of the warped domain: length for 1D
+//! distributions, area for the disk, unit square and triangle, and solid
+//! angle for directions.
+
+use crate::math::compensated::{difference_of_products, sum_of_products};
+use crate::math::frame::Frame;
+use crate::math::functions::{erf, erf_inv, lerp, safe_sqrt};
+use crate::math::number_traits::Float;
+use crate::math::point2::Point2;
+use crate::math::point3::Point3;
+use crate::math::spherical::{cos_phi, cos2_theta, sin_phi, tan2_theta};
+use crate::math::vector3::Vector3;
+
+fn clamp<T: Float>(x: T, lo: T, hi: T) -> T {
+    x.max(lo).min(hi)
+}
+
+fn two_pi<T: Float>() -> T {
+    T::TWO * T::PI
+}
+
+/// Samples `x` in `[0, 1)` with density proportional to the linear function
+/// with values `a` at 0 and `b` at 1, which must not both be zero.
+///
+/// ```
+/// use pbrt::sampling::{invert_linear_sample, linear_pdf, sample_linear};
+///
+/// let x = sample_linear(0.3f64, 1.0, 3.0);
+/// assert!((invert_linear_sample(x, 1.0, 3.0) - 0.3).abs() < 1e-12);
+/// assert_eq!(linear_pdf(0.5f64, 1.0, 3.0), 1.0);
+/// ```
+pub fn sample_linear<T: Float>(u: T, a: T, b: T) -> T {
+    if u == T::ZERO && a == T::ZERO {
+        return T::ZERO;
+    }
+    // The root of the quadratic CDF, in a form that is stable for a = b.
+    let x = u * (a + b) / (a + lerp(u, a * a, b * b).sqrt());
+    x.min(T::ONE_MINUS_EPSILON)
+}
+
+/// Returns the density of [`sample_linear`] at `x`.
+pub fn linear_pdf<T: Float>(x: T, a: T, b: T) -> T {
+    if x < T::ZERO || x > T::ONE {
+        return T::ZERO;
+    }
+    T::TWO * lerp(x, a, b) / (a + b)
+}
+
+/// Returns the sample that [`sample_linear`] maps to `x`.
+pub fn invert_linear_sample<T: Float>(x: T, a: T, b: T) -> T {
+    x * (a * (T::TWO - x) + b * x) / (a + b)
+}
+
+/// Samples the tent function of radius `r`, which falls linearly from its
+/// peak at zero to zero at `-r` and `r`.
+///
+/// ```
+/// use pbrt::sampling::{invert_tent_sample, sample_tent, tent_pdf};
+///
+/// assert_eq!(sample_tent(0.5f64, 2.0), 0.0);
+/// let x = sample_tent(0.2f64, 2.0);
+/// assert!(x < 0.0 && (invert_tent_sample(x, 2.0) - 0.2).abs() < 1e-12);
+/// assert_eq!(tent_pdf(0.0f64, 2.0), 0.5);
+/// ```
+pub fn sample_tent<T: Float>(u: T, r: T) -> T {
+    // Pick a side, then reuse u to sample it.
+    if u < T::HALF {
+        let u = (u * T::TWO).min(T::ONE_MINUS_EPSILON);
+        -r + r * sample_linear(u, T::ZERO, T::ONE)
+    } else {
+        let u = ((u - T::HALF) * T::TWO).min(T::ONE_MINUS_EPSILON);
+        r * sample_linear(u, T::ONE, T::ZERO)
+    }
+}
+
+/// Returns the density of [`sample_tent`] at `x`.
+pub fn tent_pdf<T: Float>(x: T, r: T) -> T {
+    if x.abs() >= r {
+        return T::ZERO;
+    }
+    T::ONE / r - x.abs() / (r * r)
+}
+
+/// Returns the sample that [`sample_tent`] maps to `x`.
+pub fn invert_tent_sample<T: Float>(x: T, r: T) -> T {
+    if x <= T::ZERO {
+        (T::ONE - invert_linear_sample(-x / r, T::ONE, T::ZERO)) * T::HALF
+    } else {
+        T::HALF + invert_linear_sample(x / r, T::ONE, T::ZERO) * T::HALF
+    }
+}
+
+/// Samples the exponential distribution with rate `a`, with density
+/// `a e^(-a x)` for `x >= 0`.
+///
+/// ```
+/// use pbrt::sampling::{exponential_pdf, invert_exponential_sample, sample_exponential};
+///
+/// let x = sample_exponential(0.75f64, 2.0);
+/// assert!((x - 4.0f64.ln() / 2.0).abs() < 1e-12);
+/// assert!((invert_exponential_sample(x, 2.0) - 0.75).abs() < 1e-12);
+/// assert_eq!(exponential_pdf(0.0f64, 2.0), 2.0);
+/// ```
+pub fn sample_exponential<T: Float>(u: T, a: T) -> T {
+    -(T::ONE - u).ln() / a
+}
+
+/// Returns the density of [`sample_exponential`] at `x`.
+pub fn exponential_pdf<T: Float>(x: T, a: T) -> T {
+    a * (-a * x).exp()
+}
+
+/// Returns the sample that [`sample_exponential`] maps to `x`.
+pub fn invert_exponential_sample<T: Float>(x: T, a: T) -> T {
+    T::ONE - (-a * x).exp()
+}
+
+/// Samples the normal distribution with mean `mu` and standard deviation
+/// `sigma`.
+///
+/// ```
+/// use pbrt::sampling::{invert_normal_sample, normal_pdf, sample_normal};
+///
+/// assert_eq!(sample_normal(0.5f32, 1.0, 2.0), 1.0);
+/// let x = sample_normal(0.9f32, 1.0, 2.0);
+/// assert!((invert_normal_sample(x, 1.0, 2.0) - 0.9).abs() < 1e-5);
+/// assert!((normal_pdf(1.0f32, 1.0, 2.0) - 0.19947114).abs() < 1e-6);
+/// ```
+pub fn sample_normal<T: Float>(u: T, mu: T, sigma: T) -> T {
+    mu + T::TWO.sqrt() * sigma * erf_inv(T::TWO * u - T::ONE)
+}
+
+/// Returns the density of [`sample_normal`] at `x`.
+pub fn normal_pdf<T: Float>(x: T, mu: T, sigma: T) -> T {
+    let d = x - mu;
+    T::ONE / (two_pi::<T>() * sigma * sigma).sqrt() * (-(d * d) / (T::TWO * sigma * sigma)).exp()
+}
+
+/// Returns the sample that [`sample_normal`] maps to `x`.
+pub fn invert_normal_sample<T: Float>(x: T, mu: T, sigma: T) -> T {
+    T::HALF * (T::ONE + erf((x - mu) / (sigma * T::TWO.sqrt())))
+}
+
+/// Samples the logistic distribution with scale `s`, a bell curve like the
+/// normal distribution but with a CDF and inverse in closed form.
+///
+/// ```
+/// use pbrt::sampling::{invert_logistic_sample, logistic_pdf, sample_logistic};
+///
+/// assert_eq!(sample_logistic(0.5f64, 1.5), 0.0);
+/// let x = sample_logistic(0.1f64, 1.5);
+/// assert!((invert_logistic_sample(x, 1.5) - 0.1).abs() < 1e-12);
+/// assert_eq!(logistic_pdf(0.0f64, 0.5), 0.5);
+/// ```
+pub fn sample_logistic<T: Float>(u: T, s: T) -> T {
+    -s * (T::ONE / u - T::ONE).ln()
+}
+
+/// Returns the density of [`sample_logistic`] at `x`.
+pub fn logistic_pdf<T: Float>(x: T, s: T) -> T {
+    // Using |x| keeps the exponential from overflowing.
+    let e = (-x.abs() / s).exp();
+    e / (s * (T::ONE + e) * (T::ONE + e))
+}
+
+/// Returns the sample that [`sample_logistic`] maps to `x`.
+pub fn invert_logistic_sample<T: Float>(x: T, s: T) -> T {
+    T::ONE / (T::ONE + (-x / s).exp())
+}
+
+/// Samples a point in the unit square with density proportional to the
+/// bilinear interpolation of the corner values `w`, given in the order
+/// `(0, 0)`, `(1, 0)`, `(0, 1)`, `(1, 1)`. This is the distribution of
+/// points on a bilinear patch weighted by per-vertex values.
+///
+/// ```
+/// use pbrt::math::Point2;
+/// use pbrt::sampling::{bilinear_pdf, invert_bilinear_sample, sample_bilinear};
+///
+/// let w = [1.0f64, 2.0, 3.0, 4.0];
+/// let p = sample_bilinear(Point2::new(0.25, 0.75), w);
+/// let u = invert_bilinear_sample(p, w);
+/// assert!((u.x - 0.25).abs() < 1e-12 && (u.y - 0.75).abs() < 1e-12);
+/// assert_eq!(bilinear_pdf(Point2::new(0.5, 0.5), w), 1.0);
+/// ```
+pub fn sample_bilinear<T: Float>(u: Point2<T>, w: [T; 4]) -> Point2<T> {
+    // Sample y from the marginal, then x from the conditional at y.
+    let y = sample_linear(u.y, w[0] + w[1], w[2] + w[3]);
+    let x = sample_linear(u.x, lerp(y, w[0], w[2]), lerp(y, w[1], w[3]));
+    Point2::new(x, y)
+}
+
+/// Returns the density of [`sample_bilinear`] at `p`.
+pub fn bilinear_pdf<T: Float>(p: Point2<T>, w: [T; 4]) -> T {
+    if p.x < T::ZERO || p.x > T::ONE || p.y < T::ZERO || p.y > T::ONE {
+        return T::ZERO;
+    }
+    let sum = w[0] + w[1] + w[2] + w[3];
+    if sum == T::ZERO {
+        return T::ONE;
+    }
+    let value = lerp(p.y, lerp(p.x, w[0], w[1]), lerp(p.x, w[2], w[3]));
+    T::TWO * T::TWO * value / sum
+}
+
+/// Returns the sample that [`sample_bilinear`] maps to `p`.
+pub fn invert_bilinear_sample<T: Float>(p: Point2<T>, w: [T; 4]) -> Point2<T> {
+    Point2::new(
+        invert_linear_sample(p.x, lerp(p.y, w[0], w[2]), lerp(p.y, w[1], w[3])),
+        invert_linear_sample(p.y, w[0] + w[1], w[2] + w[3]),
+    )
+}
+
+/// Samples a point uniformly in the unit disk by mapping the sample to
+/// polar coordinates. The mapping distorts areas, so stratification of the
+/// samples is not preserved as well as by
+/// [`sample_uniform_disk_concentric`]. The density is `1 / pi`.
+///
+/// ```
+/// use pbrt::math::Point2;
+/// use pbrt::sampling::{invert_uniform_disk_polar_sample, sample_uniform_disk_polar};
+///
+/// let p = sample_uniform_disk_polar(Point2::new(0.25f64, 0.5));
+/// assert!((p.x + 0.5).abs() < 1e-12 && p.y.abs() < 1e-12);
+/// let u = invert_uniform_disk_polar_sample(p);
+/// assert!((u.x - 0.25).abs() < 1e-12 && (u.y - 0.5).abs() < 1e-12);
+/// ```
+pub fn sample_uniform_disk_polar<T: Float>(u: Point2<T>) -> Point2<T> {
+    let r = u.x.sqrt();
+    let theta = two_pi::<T>() * u.y;
+    Point2::new(r * theta.cos(), r * theta.sin())
+}
+
+/// Returns the sample that [`sample_uniform_disk_polar`] maps to `p`.
+pub fn invert_uniform_disk_polar_sample<T: Float>(p: Point2<T>) -> Point2<T> {
+    let mut phi = p.y.atan2(p.x);
+    if phi < T::ZERO {
+        phi += two_pi::<T>();
+    }
+    Point2::new(p.x * p.x + p.y * p.y, phi / two_pi::<T>())
+}
+
+/// Samples a point uniformly in the unit disk with Shirley and Chiu's
+/// concentric mapping, which maps concentric squares to concentric circles
+/// and so keeps stratified samples well distributed. The density is
+/// `1 / pi`.
+///
+/// ```
+/// use pbrt::math::Point2;
+/// use pbrt::sampling::{invert_uniform_disk_concentric_sample, sample_uniform_disk_concentric};
+///
+/// // The middle of each edge of the square maps to the circle.
+/// let p = sample_uniform_disk_concentric(Point2::new(1.0f64, 0.5));
+/// assert_eq!(p, Point2::new(1.0, 0.0));
+/// let q = sample_uniform_disk_concentric(Point2::new(0.2f64, 0.9));
+/// let u = invert_uniform_disk_concentric_sample(q);
+/// assert!((u.x - 0.2).abs() < 1e-12 && (u.y - 0.9).abs() < 1e-12);
+/// ```
+pub fn sample_uniform_disk_concentric<T: Float>(u: Point2<T>) -> Point2<T> {
+    let x = T::TWO * u.x - T::ONE;
+    let y = T::TWO * u.y - T::ONE;
+    if x == T::ZERO && y == T::ZERO {
+        return Point2::new(T::ZERO, T::ZERO);
+    }
+    let quarter_pi = T::PI / (T::TWO * T::TWO);
+    let (r, theta) = if x.abs() > y.abs() {
+        (x, quarter_pi * (y / x))
+    } else {
+        (y, T::PI * T::HALF - quarter_pi * (x / y))
+    };
+    Point2::new(r * theta.cos(), r * theta.sin())
+}
+
+/// Returns the sample that [`sample_uniform_disk_concentric`] maps to `p`.
+pub fn invert_uniform_disk_concentric_sample<T: Float>(p: Point2<T>) -> Point2<T> {
+    let quarter_pi = T::PI / (T::TWO * T::TWO);
+    let half_pi = T::PI * T::HALF;
+    let theta = p.y.atan2(p.x);
+    let r = (p.x * p.x + p.y * p.y).sqrt();
+    let (x, y) = if theta.abs() < quarter_pi || theta.abs() > T::from_f64(3.0) * quarter_pi {
+        // The left or right wedge, where |x| > |y| in the square.
+        let r = r.copysign(p.x);
+        let y = if p.x < T::ZERO {
+            if p.y < T::ZERO {
+                (T::PI + theta) * r / quarter_pi
+            } else {
+                (theta - T::PI) * r / quarter_pi
+            }
+        } else {
+            theta * r / quarter_pi
+        };
+        (r, y)
+    } else {
+        let r = r.copysign(p.y);
+        let x = if p.y < T::ZERO {
+            -(half_pi + theta) * r / quarter_pi
+        } else {
+            (half_pi - theta) * r / quarter_pi
+        };
+        (x, r)
+    };
+    Point2::new((x + T::ONE) * T::HALF, (y + T::ONE) * T::HALF)
+}
+
+/// Samples barycentric coordinates uniformly over a triangle, with a
+/// mapping that keeps stratified samples well distributed (Heitz, "A
+/// Low-Distortion Map Between Triangle and Square"). The density is the
+/// reciprocal of the triangle's area.
+///
+/// ```
+/// use pbrt::math::Point2;
+/// use pbrt::sampling::{invert_uniform_triangle_sample, sample_uniform_triangle};
+///
+/// let b = sample_uniform_triangle(Point2::new(0.25f64, 0.5));
+/// assert_eq!(b, [0.125, 0.375, 0.5]);
+/// assert_eq!(invert_uniform_triangle_sample(b), Point2::new(0.25, 0.5));
+/// ```
+pub fn sample_uniform_triangle<T: Float>(u: Point2<T>) -> [T; 3] {
+    let (b0, b1) = if u.x < u.y {
+        let b0 = u.x * T::HALF;
+        (b0, u.y - b0)
+    } else {
+        let b1 = u.y * T::HALF;
+        (u.x - b1, b1)
+    };
+    [b0, b1, T::ONE - b0 - b1]
+}
+
+/// Returns the sample that [`sample_uniform_triangle`] maps to the
+/// barycentric coordinates `b`.
+pub fn invert_uniform_triangle_sample<T: Float>(b: [T; 3]) -> Point2<T> {
+    if b[0] > b[1] {
+        Point2::new(b[0] + b[1], T::TWO * b[1])
+    } else {
+        Point2::new(T::TWO * b[0], b[1] + b[0])
+    }
+}
+
+/// Samples a direction uniformly over the hemisphere around +z. The
+/// density is `1 / (2 pi)`.
+///
+/// ```
+/// use pbrt::math::Point2;
+/// use pbrt::sampling::{invert_uniform_hemisphere_sample, sample_uniform_hemisphere};
+///
+/// let w = sample_uniform_hemisphere(Point2::new(0.3f64, 0.6));
+/// assert!((w.z - 0.3).abs() < 1e-12 && (w.length() - 1.0).abs() < 1e-12);
+/// let u = invert_uniform_hemisphere_sample(w);
+/// assert!((u.x - 0.3).abs() < 1e-12 && (u.y - 0.6).abs() < 1e-12);
+/// ```
+pub fn sample_uniform_hemisphere<T: Float>(u: Point2<T>) -> Vector3<T> {
+    let z = u.x;
+    let r = safe_sqrt(T::ONE - z * z);
+    let phi = two_pi::<T>() * u.y;
+    Vector3 {
+        x: r * phi.cos(),
+        y: r * phi.sin(),
+        z,
+    }
+}
+
+/// Returns the density of [`sample_uniform_hemisphere`].
+pub fn uniform_hemisphere_pdf<T: Float>() -> T {
+    T::ONE / two_pi::<T>()
+}
+
+/// Returns the sample that [`sample_uniform_hemisphere`] maps to `w`.
+pub fn invert_uniform_hemisphere_sample<T: Float>(w: Vector3<T>) -> Point2<T> {
+    Point2::new(w.z, direction_phi(w) / two_pi::<T>())
+}
+
+/// Samples a direction uniformly over the unit sphere. The density is
+/// `1 / (4 pi)`.
+///
+/// ```
+/// use pbrt::math::Point2;
+/// use pbrt::sampling::{invert_uniform_sphere_sample, sample_uniform_sphere};
+///
+/// let w = sample_uniform_sphere(Point2::new(0.75f64, 0.25));
+/// assert!((w.z + 0.5).abs() < 1e-12 && (w.length() - 1.0).abs() < 1e-12);
+/// let u = invert_uniform_sphere_sample(w);
+/// assert!((u.x - 0.75).abs() < 1e-12 && (u.y - 0.25).abs() < 1e-12);
+/// ```
+pub fn sample_uniform_sphere<T: Float>(u: Point2<T>) -> Vector3<T> {
+    let z = T::ONE - T::TWO * u.x;
+    let r = safe_sqrt(T::ONE - z * z);
+    let phi = two_pi::<T>() * u.y;
+    Vector3 {
+        x: r * phi.cos(),
+        y: r * phi.sin(),
+        z,
+    }
+}
+
+/// Returns the density of [`sample_uniform_sphere`].
+pub fn uniform_sphere_pdf<T: Float>() -> T {
+    T::ONE / (T::TWO * two_pi::<T>())
+}
+
+/// Returns the sample that [`sample_uniform_sphere`] maps to `w`.
+pub fn invert_uniform_sphere_sample<T: Float>(w: Vector3<T>) -> Point2<T> {
+    Point2::new((T::ONE - w.z) * T::HALF, direction_phi(w) / two_pi::<T>())
+}
+
+/// Samples a direction over the hemisphere around +z with density
+/// proportional to its cosine with +z, by projecting a uniform disk sample
+/// up to the hemisphere (Malley's method).
+///
+/// ```
+/// use pbrt::math::{Point2, Vector3};
+/// use pbrt::sampling::{cosine_hemisphere_pdf, sample_cosine_hemisphere};
+///
+/// let w = sample_cosine_hemisphere(Point2::new(0.5f64, 0.5));
+/// assert_eq!(w, Vector3::new(0.0, 0.0, 1.0));
+/// assert_eq!(cosine_hemisphere_pdf(w.z), std::f64::consts::FRAC_1_PI);
+/// ```
+pub fn sample_cosine_hemisphere<T: Float>(u: Point2<T>) -> Vector3<T> {
+    let d = sample_uniform_disk_concentric(u);
+    let z = safe_sqrt(T::ONE - d.x * d.x - d.y * d.y);
+    Vector3 { x: d.x, y: d.y, z }
+}
+
+/// Returns the density of [`sample_cosine_hemisphere`] for a direction
+/// with the given cosine.
+pub fn cosine_hemisphere_pdf<T: Float>(cos_theta: T) -> T {
+    cos_theta / T::PI
+}
+
+/// Returns the sample that [`sample_cosine_hemisphere`] maps to `w`.
+pub fn invert_cosine_hemisphere_sample<T: Float>(w: Vector3<T>) -> Point2<T> {
+    invert_uniform_disk_concentric_sample(Point2::new(w.x, w.y))
+}
+
+/// Samples a direction uniformly within the cone around +z whose half
+/// angle has cosine `cos_theta_max`.
+///
+/// ```
+/// use pbrt::math::Point2;
+/// use pbrt::sampling::{invert_uniform_cone_sample, sample_uniform_cone, uniform_cone_pdf};
+///
+/// let w = sample_uniform_cone(Point2::new(0.5f64, 0.3), 0.8);
+/// assert!((w.z - 0.9).abs() < 1e-12);
+/// let u = invert_uniform_cone_sample(w, 0.8);
+/// assert!((u.x - 0.5).abs() < 1e-12 && (u.y - 0.3).abs() < 1e-12);
+/// assert!((uniform_cone_pdf(0.8f64) * 0.4 * std::f64::consts::PI - 1.0).abs() < 1e-12);
+/// ```
+pub fn sample_uniform_cone<T: Float>(u: Point2<T>, cos_theta_max: T) -> Vector3<T> {
+    let cos_theta = (T::ONE - u.x) + u.x * cos_theta_max;
+    let sin_theta = safe_sqrt(T::ONE - cos_theta * cos_theta);
+    let phi = u.y * two_pi::<T>();
+    Vector3 {
+        x: sin_theta * phi.cos(),
+        y: sin_theta * phi.sin(),
+        z: cos_theta,
+    }
+}
+
+/// Returns the density of [`sample_uniform_cone`], the reciprocal of the
+/// cone's solid angle.
+pub fn uniform_cone_pdf<T: Float>(cos_theta_max: T) -> T {
+    T::ONE / (two_pi::<T>() * (T::ONE - cos_theta_max))
+}
+
+/// Returns the sample that [`sample_uniform_cone`] maps to `w`.
+pub fn invert_uniform_cone_sample<T: Float>(w: Vector3<T>, cos_theta_max: T) -> Point2<T> {
+    Point2::new(
+        (T::ONE - w.z) / (T::ONE - cos_theta_max),
+        direction_phi(w) / two_pi::<T>(),
+    )
+}
+
+// The azimuth of w in [0, 2 pi).
+fn direction_phi<T: Float>(w: Vector3<T>) -> T {
+    let phi = w.y.atan2(w.x);
+    if phi < T::ZERO {
+        phi + two_pi::<T>()
+    } else {
+        phi
+    }
+}
+
+// The unit vectors from a point to a triangle's vertices, the normals of
+// the great circles through its edges ab, bc and ca, and its angles at a, b
+// and c.
+type SphericalTriangle<T> = ([Vector3<T>; 3], [Vector3<T>; 3], [T; 3]);
+
+// The spherical triangle seen from p, or None if it is degenerate.
+fn spherical_triangle<T: Float>(v: [Point3<T>; 3], p: Point3<T>) -> Option<SphericalTriangle<T>> {
+    let [a, b, c] = v.map(|v| (v - p).normalize());
+    let normals = [a.cross(b), b.cross(c), c.cross(a)];
+    if normals.iter().any(|n| n.length_squared() == T::ZERO) {
+        return None;
+    }
+    let [n_ab, n_bc, n_ca] = normals.map(|n| n.normalize());
+    let alpha = n_ab.angle_between(-n_ca);
+    let beta = n_bc.angle_between(-n_ab);
+    let gamma = n_ca.angle_between(-n_bc);
+    Some(([a, b, c], [n_ab, n_bc, n_ca], [alpha, beta, gamma]))
+}
+
+/// Samples a direction uniformly over the solid angle subtended at `p` by
+/// the triangle `v` (Arvo, "Stratified Sampling of Spherical Triangles").
+/// Returns the barycentric coordinates of the point the direction hits and
+/// the density, the reciprocal of the solid angle, or `None` if the
+/// triangle is degenerate as seen from `p`.
+///
+/// ```
+/// use pbrt::math::{Point2, Point3};
+/// use pbrt::sampling::sample_spherical_triangle;
+///
+/// let v = [
+///     Point3::new(1.0f64, 0.0, 0.0),
+///     Point3::new(0.0, 1.0, 0.0),
+///     Point3::new(0.0, 0.0, 1.0),
+/// ];
+/// let (b, pdf) = sample_spherical_triangle(v, Point3::new(0.0, 0.0, 0.0), Point2::new(0.5, 0.5))
+///     .unwrap();
+/// // The triangle covers an octant of the sphere.
+/// assert!((pdf - 2.0 / std::f64::consts::PI).abs() < 1e-12);
+/// assert!((b[0] + b[1] + b[2] - 1.0).abs() < 1e-12);
+/// ```
+pub fn sample_spherical_triangle<T: Float>(
+    v: [Point3<T>; 3],
+    p: Point3<T>,
+    u: Point2<T>,
+) -> Option<([T; 3], T)> {
+    let ([a, b, c], _, [alpha, beta, gamma]) = spherical_triangle(v, p)?;
+
+    // Sample the area A' of the sub-triangle (a, b, c') uniformly.
+    let a_pi = alpha + beta + gamma;
+    let ap_pi = lerp(u.x, T::PI, a_pi);
+    let area = a_pi - T::PI;
+    let pdf = if area <= T::ZERO {
+        T::ZERO
+    } else {
+        T::ONE / area
+    };
+
+    // Find cos(beta') for the vertex c' along the arc from a to c that
+    // gives the sampled area.
+    let (cos_alpha, sin_alpha) = (alpha.cos(), alpha.sin());
+    let sin_phi = ap_pi.sin() * cos_alpha - ap_pi.cos() * sin_alpha;
+    let cos_phi = ap_pi.cos() * cos_alpha + ap_pi.sin() * sin_alpha;
+    let k1 = cos_phi + cos_alpha;
+    let k2 = sin_phi - sin_alpha * a.dot(b);
+    let cos_bp = (k2 + difference_of_products(k2, cos_phi, k1, sin_phi) * cos_alpha)
+        / (sum_of_products(k2, sin_phi, k1, cos_phi) * sin_alpha);
+    // Triangles covering nearly a hemisphere can push this out of range.
+    let cos_bp = clamp(cos_bp, -T::ONE, T::ONE);
+    let sin_bp = safe_sqrt(T::ONE - cos_bp * cos_bp);
+    let cp = a * cos_bp + c.gram_schmidt(a).normalize() * sin_bp;
+
+    // Sample a direction along the arc from b to c'.
+    let cos_theta = T::ONE - u.y * (T::ONE - cp.dot(b));
+    let sin_theta = safe_sqrt(T::ONE - cos_theta * cos_theta);
+    let w = b * cos_theta + cp.gram_schmidt(b).normalize() * sin_theta;
+
+    // Intersect the direction with the triangle for its barycentrics.
+    let e1 = v[1] - v[0];
+    let e2 = v[2] - v[0];
+    let s1 = w.cross(e2);
+    let divisor = s1.dot(e1);
+    if divisor == T::ZERO {
+        // The triangle covers nearly the whole hemisphere.
+        let third = T::ONE / T::from_f64(3.0);
+        return Some(([third; 3], pdf));
+    }
+    let s = p - v[0];
+    let mut b1 = clamp(s.dot(s1) / divisor, T::ZERO, T::ONE);
+    let mut b2 = clamp(w.dot(s.cross(e1)) / divisor, T::ZERO, T::ONE);
+    if b1 + b2 > T::ONE {
+        let sum = b1 + b2;
+        b1 /= sum;
+        b2 /= sum;
+    }
+    Some(([T::ONE - b1 - b2, b1, b2], pdf))
+}
+
+/// Returns the sample that [`sample_spherical_triangle`] maps to the
+/// direction `w` from `p`, or `None` if the triangle is degenerate as seen
+/// from `p`.
+pub fn invert_spherical_triangle_sample<T: Float>(
+    v: [Point3<T>; 3],
+    p: Point3<T>,
+    w: Vector3<T>,
+) -> Option<Point2<T>> {
+    let ([a, b, c], [n_ab, _, _], [alpha, beta, gamma]) = spherical_triangle(v, p)?;
+
+    // Find the vertex c' where the arc from b through w meets the arc from
+    // a to c.
+    let w = w.normalize();
+    let mut cp = b.cross(w).cross(c.cross(a)).normalize();
+    if cp.dot(a + c) < T::ZERO {
+        cp = -cp;
+    }
+
+    // The first sample is the fraction of the area in the sub-triangle
+    // (a, b, c').
+    let u0 = if a.dot(cp) > T::from_f64(0.99999847691) {
+        // c' is within 0.1 degrees of a.
+        T::ZERO
+    } else {
+        let n_cpb = cp.cross(b);
+        let n_acp = a.cross(cp);
+        if n_cpb.length_squared() == T::ZERO || n_acp.length_squared() == T::ZERO {
+            return Some(Point2::new(T::HALF, T::HALF));
+        }
+        let (n_cpb, n_acp) = (n_cpb.normalize(), n_acp.normalize());
+        let ap = alpha + n_ab.angle_between(n_cpb) + n_acp.angle_between(-n_cpb) - T::PI;
+        ap / (alpha + beta + gamma - T::PI)
+    };
+    let u1 = (T::ONE - w.dot(b)) / (T::ONE - cp.dot(b));
+    Some(Point2::new(
+        clamp(u0, T::ZERO, T::ONE),
+        clamp(u1, T::ZERO, T::ONE),
+    ))
+}
+
+// The frame and local coordinates of a rectangle as seen from p_ref: the
+// rectangle spans [x0, x1] x [y0, y1] in the plane z = z0 < 0.
+struct LocalRectangle<T> {
+    frame: Frame<T>,
+    x0: T,
+    x1: T,
+    y0: T,
+    y1: T,
+    z0: T,
+}
+
+impl<T: Float> LocalRectangle<T> {
+    fn new(p_ref: Point3<T>, s: Point3<T>, ex: Vector3<T>, ey: Vector3<T>) -> Self {
+        let (exl, eyl) = (ex.length(), ey.length());
+        let mut frame = Frame::from_xy(ex / exl, ey / eyl);
+        let d = frame.to_local(s - p_ref);
+        let mut z0 = d.z;
+        // Flip z to point away from the rectangle.
+        if z0 > T::ZERO {
+            frame.z = -frame.z;
+            z0 = -z0;
+        }
+        Self {
+            frame,
+            x0: d.x,
+            x1: d.x + exl,
+            y0: d.y,
+            y1: d.y + eyl,
+            z0,
+        }
+    }
+
+    // The interior angles of the spherical rectangle spanning [x0, x1] in
+    // x, and the z components of the normals of its lower and upper edges.
+    fn angles(&self, x0: T, x1: T) -> ([T; 4], T, T) {
+        let (y0, y1, z0) = (self.y0, self.y1, self.z0);
+        let v00 = Vector3::new(x0, y0, z0);
+        let v01 = Vector3::new(x0, y1, z0);
+        let v10 = Vector3::new(x1, y0, z0);
+        let v11 = Vector3::new(x1, y1, z0);
+        let n0 = v00.cross(v10).normalize();
+        let n1 = v10.cross(v11).normalize();
+        let n2 = v11.cross(v01).normalize();
+        let n3 = v01.cross(v00).normalize();
+        let g = [
+            (-n0).angle_between(n1),
+            (-n1).angle_between(n2),
+            (-n2).angle_between(n3),
+            (-n3).angle_between(n0),
+        ];
+        (g, n0.z, n2.z)
+    }
+}
+
+/// Samples a point on the parallelogram with corner `s` and edges `ex` and
+/// `ey`, which must be perpendicular, uniformly over the solid angle it
+/// subtends at `p_ref` (Ureña et al., "An Area-Preserving Parametrization
+/// for Spherical Rectangles"). Returns the point and the density, the
+/// reciprocal of the solid angle. Rectangles subtending tiny solid angles
+/// are sampled uniformly by area instead, where the two nearly agree.
+///
+/// ```
+/// use pbrt::math::{Point2, Point3, Vector3};
+/// use pbrt::sampling::{invert_spherical_rectangle_sample, sample_spherical_rectangle};
+///
+/// let s = Point3::new(-1.0f64, -1.0, 1.0);
+/// let (ex, ey) = (Vector3::new(2.0, 0.0, 0.0), Vector3::new(0.0, 2.0, 0.0));
+/// let p_ref = Point3::new(0.0, 0.0, 0.0);
+/// let (p, pdf) = sample_spherical_rectangle(p_ref, s, ex, ey, Point2::new(0.5, 0.5));
+/// assert!(p.x.abs() < 1e-9 && p.y.abs() < 1e-9 && p.z == 1.0);
+/// // The square is a face of a cube around p_ref: a sixth of the sphere.
+/// assert!((pdf * 4.0 * std::f64::consts::PI / 6.0 - 1.0).abs() < 1e-9);
+/// let u = invert_spherical_rectangle_sample(p_ref, s, ex, ey, p);
+/// assert!((u.x - 0.5).abs() < 1e-9 && (u.y - 0.5).abs() < 1e-9);
+/// ```
+pub fn sample_spherical_rectangle<T: Float>(
+    p_ref: Point3<T>,
+    s: Point3<T>,
+    ex: Vector3<T>,
+    ey: Vector3<T>,
+    u: Point2<T>,
+) -> (Point3<T>, T) {
+    let rect = LocalRectangle::new(p_ref, s, ex, ey);
+    let (
+        LocalRectangle {
+            x0, x1, y0, y1, z0, ..
+        },
+        frame,
+    ) = (&rect, rect.frame);
+    let ([g0, g1, g2, g3], b0, b1) = rect.angles(*x0, *x1);
+    let solid_angle = g0 + g1 + g2 + g3 - T::TWO * T::PI;
+    if solid_angle <= T::ZERO {
+        return (s + ex * u.x + ey * u.y, T::ZERO);
+    }
+    let pdf = T::ONE / solid_angle;
+    if solid_angle < T::from_f64(1e-3) {
+        return (s + ex * u.x + ey * u.y, pdf);
+    }
+
+    // Sample x from the marginal distribution of solid angle.
+    let au = u.x * solid_angle - (g2 + g3);
+    let fu = (au.cos() * b0 - b1) / au.sin();
+    let cu = (T::ONE / (fu * fu + b0 * b0).sqrt()).copysign(fu);
+    let cu = clamp(cu, -T::ONE_MINUS_EPSILON, T::ONE_MINUS_EPSILON);
+    let xu = clamp(-(cu * *z0) / safe_sqrt(T::ONE - cu * cu), *x0, *x1);
+
+    // Sample y uniformly in solid angle along the line at xu.
+    let dd = (xu * xu + *z0 * *z0).sqrt();
+    let h0 = *y0 / (dd * dd + *y0 * *y0).sqrt();
+    let h1 = *y1 / (dd * dd + *y1 * *y1).sqrt();
+    let hv = h0 + u.y * (h1 - h0);
+    let yv = if hv * hv < T::ONE - T::from_f64(1e-6) {
+        hv * dd / (T::ONE - hv * hv).sqrt()
+    } else {
+        *y1
+    };
+    (p_ref + frame.from_local(Vector3::new(xu, yv, *z0)), pdf)
+}
+
+/// Returns the sample that [`sample_spherical_rectangle`] maps to the point
+/// `p_rect` on the rectangle.
+pub fn invert_spherical_rectangle_sample<T: Float>(
+    p_ref: Point3<T>,
+    s: Point3<T>,
+    ex: Vector3<T>,
+    ey: Vector3<T>,
+    p_rect: Point3<T>,
+) -> Point2<T> {
+    let rect = LocalRectangle::new(p_ref, s, ex, ey);
+    let ([g0, g1, g2, g3], _, _) = rect.angles(rect.x0, rect.x1);
+    let solid_angle = g0 + g1 + g2 + g3 - T::TWO * T::PI;
+    if solid_angle < T::from_f64(1e-3) {
+        let pq = p_rect - s;
+        return Point2::new(
+            pq.dot(ex) / ex.length_squared(),
+            pq.dot(ey) / ey.length_squared(),
+        );
+    }
+    let v = rect.frame.to_local(p_rect - p_ref);
+    let xu = clamp(v.x, rect.x0, rect.x1);
+
+    // Since x is sampled from its marginal, the first sample is the
+    // fraction of the solid angle left of xu.
+    let u0 = if xu <= rect.x0 {
+        T::ZERO
+    } else {
+        let ([g0, g1, g2, g3], _, _) = rect.angles(rect.x0, xu);
+        (g0 + g1 + g2 + g3 - T::TWO * T::PI) / solid_angle
+    };
+
+    // y is linear in h = y / sqrt(dd^2 + y^2) along the line at xu.
+    let dd2 = xu * xu + rect.z0 * rect.z0;
+    let h = |y: T| y / (dd2 + y * y).sqrt();
+    let (h0, h1) = (h(rect.y0), h(rect.y1));
+    let u1 = (h(v.y) - h0) / (h1 - h0);
+    Point2::new(clamp(u0, T::ZERO, T::ONE), clamp(u1, T::ZERO, T::ONE))
+}
+
+/// Returns the Trowbridge–Reitz (GGX) microfacet distribution with
+/// roughness `alpha_x` and `alpha_y` along x and y, for the microfacet
+/// normal `wm` in a local frame with the surface normal along +z: the
+/// density of microfacet area with that normal per unit of macrosurface
+/// area and solid angle.
+///
+/// ```
+/// use pbrt::math::Vector3;
+/// use pbrt::sampling::ggx_d;
+///
+/// let alpha = 0.5f64;
+/// let d = ggx_d(Vector3::new(0.0, 0.0, 1.0), alpha, alpha);
+/// assert!((d - 1.0 / (std::f64::consts::PI * alpha * alpha)).abs() < 1e-12);
+/// ```
+pub fn ggx_d<T: Float>(wm: Vector3<T>, alpha_x: T, alpha_y: T) -> T {
+    let tan2 = tan2_theta(wm);
+    if tan2.is_infinite() || tan2.is_nan() {
+        return T::ZERO;
+    }
+    let cos4 = cos2_theta(wm) * cos2_theta(wm);
+    if cos4 < T::from_f64(1e-16) {
+        return T::ZERO;
+    }
+    let (cx, sy) = (cos_phi(wm) / alpha_x, sin_phi(wm) / alpha_y);
+    let e = tan2 * (cx * cx + sy * sy);
+    T::ONE / (T::PI * alpha_x * alpha_y * cos4 * (T::ONE + e) * (T::ONE + e))
+}
+
+fn ggx_lambda<T: Float>(w: Vector3<T>, alpha_x: T, alpha_y: T) -> T {
+    let tan2 = tan2_theta(w);
+    if tan2.is_infinite() || tan2.is_nan() {
+        return T::ZERO;
+    }
+    let (cx, sy) = (cos_phi(w) * alpha_x, sin_phi(w) * alpha_y);
+    let alpha2 = cx * cx + sy * sy;
+    ((T::ONE + alpha2 * tan2).sqrt() - T::ONE) * T::HALF
+}
+
+/// Returns Smith's masking function for the Trowbridge–Reitz distribution:
+/// the fraction of microfacets facing `w` that are visible from it.
+pub fn ggx_g1<T: Float>(w: Vector3<T>, alpha_x: T, alpha_y: T) -> T {
+    T::ONE / (T::ONE + ggx_lambda(w, alpha_x, alpha_y))
+}
+
+/// Samples a microfacet normal of the Trowbridge–Reitz distribution from
+/// those visible from the direction `w`, in proportion to their projected
+/// area (Heitz, "Sampling the GGX Distribution of Visible Normals"). The
+/// density is [`ggx_visible_normal_pdf`].
+///
+/// ```
+/// use pbrt::math::{Point2, Vector3};
+/// use pbrt::sampling::{ggx_visible_normal_pdf, sample_ggx_visible_normal};
+///
+/// let w = Vector3::new(0.6f64, 0.0, 0.8);
+/// let wm = sample_ggx_visible_normal(w, 0.3, 0.3, Point2::new(0.4, 0.7));
+/// assert!(wm.z > 0.0 && wm.dot(w) > 0.0);
+/// assert!(ggx_visible_normal_pdf(w, wm, 0.3, 0.3) > 0.0);
+/// ```
+pub fn sample_ggx_visible_normal<T: Float>(
+    w: Vector3<T>,
+    alpha_x: T,
+    alpha_y: T,
+    u: Point2<T>,
+) -> Vector3<T> {
+    // Stretch w to the configuration where the roughness is one.
+    let mut wh = Vector3 {
+        x: alpha_x * w.x,
+        y: alpha_y * w.y,
+        z: w.z,
+    }
+    .normalize();
+    if wh.z < T::ZERO {
+        wh = -wh;
+    }
+    let t1 = if wh.z < T::from_f64(0.99999) {
+        Vector3 {
+            x: T::ZERO,
+            y: T::ZERO,
+            z: T::ONE,
+        }
+        .cross(wh)
+        .normalize()
+    } else {
+        Vector3 {
+            x: T::ONE,
+            y: T::ZERO,
+            z: T::ZERO,
+        }
+    };
+    let t2 = wh.cross(t1);
+
+    // Sample the projected hemisphere: a disk whose lower half is squashed
+    // by the projection.
+    let mut p = sample_uniform_disk_polar(u);
+    let h = (T::ONE - p.x * p.x).sqrt();
+    p.y = lerp((T::ONE + wh.z) * T::HALF, h, p.y);
+    let pz = safe_sqrt(T::ONE - p.x * p.x - p.y * p.y);
+    let nh = t1 * p.x + t2 * p.y + wh * pz;
+
+    // Unstretch back to the original roughness.
+    Vector3 {
+        x: alpha_x * nh.x,
+        y: alpha_y * nh.y,
+        z: nh.z.max(T::from_f64(1e-6)),
+    }
+    .normalize()
+}
+
+/// Returns the density of [`sample_ggx_visible_normal`] for the
+/// microfacet normal `wm` seen from `w`, with respect to solid angle. It is
+/// zero for normals facing away from `w`.
+pub fn ggx_visible_normal_pdf<T: Float>(
+    w: Vector3<T>,
+    wm: Vector3<T>,
+    alpha_x: T,
+    alpha_y: T,
+) -> T {
+    // Normals facing away from w are hidden from it.
+    let cos = w.dot(wm).max(T::ZERO);
+    ggx_g1(w, alpha_x, alpha_y) / w.z.abs() * ggx_d(wm, alpha_x, alpha_y) * cos
+}
+
+/// Returns the balance heuristic weight for a sample from strategy `f`,
+/// which took `nf` samples with density `f_pdf`, when strategy `g` took
+/// `ng` samples with density `g_pdf` (Veach, "Optimally Combining Sampling
+/// Techniques for Monte Carlo Rendering").
+///
+/// ```
+/// use pbrt::sampling::{balance_heuristic, power_heuristic};
+///
+/// assert_eq!(balance_heuristic(1, 3.0f64, 1, 1.0), 0.75);
+/// assert_eq!(power_heuristic(1, 3.0f64, 1, 1.0), 0.9);
+/// ```
+pub fn balance_heuristic<T: Float>(nf: u32, f_pdf: T, ng: u32, g_pdf: T) -> T {
+    let f = T::from_f64(f64::from(nf)) * f_pdf;
+    let g = T::from_f64(f64::from(ng)) * g_pdf;
+    f / (f + g)
+}
+
+/// Returns the power heuristic weight, with exponent two, for a sample
+/// from strategy `f`; see [`balance_heuristic`]. It reduces variance
+/// further when one strategy is much better than the other.
+pub fn power_heuristic<T: Float>(nf: u32, f_pdf: T, ng: u32, g_pdf: T) -> T {
+    let f = T::from_f64(f64::from(nf)) * f_pdf;
+    let g = T::from_f64(f64::from(ng)) * g_pdf;
+    if (f * f).is_infinite() {
+        return T::ONE;
+    }
+    f * f / (f * f + g * g)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use crate::math::bounds2::Bounds2;
+    use crate::math::functions::erf;
+    use crate::math::point2::Point2;
+    use crate::math::point3::Point3;
+    use crate::math::spherical::spherical_triangle_area;
+    use crate::math::vector3::Vector3;
+    use crate::random::Rng;
+    use crate::sampling::warps::*;
+    use crate::testing::{Chi2Test, Domain, cdf_from_pdf, ks_test};
+
+    // The number of statistical tests below, which share the significance
+    // level.
+    const TEST_COUNT: usize = 21;
+
+    fn unit_square() -> Bounds2<f64> {
+        Bounds2::new(Point2::new(0.0, 0.0), Point2::new(1.0, 1.0))
+    }
+
+    fn assert_round_trip(name: &str, invert: impl Fn(Point2<f64>) -> Point2<f64>) {
+        for i in 0..16 {
+            for j in 0..16 {
+                let u = Point2::new((i as f64 + 0.5) / 16.0, (j as f64 + 0.5) / 16.0);
+                let v = invert(u);
+                assert!(
+                    (u.x - v.x).abs() < 1e-6 && (u.y - v.y).abs() < 1e-6,
+                    "{name}: {u:?} came back as {v:?}"
+                );
+            }
+        }
+    }
+
+    // Checks a 1D warp with both a chi-squared test over [min, max], where
+    // the tails go to the overflow cell, and a Kolmogorov–Smirnov test
+    // against the CDF of its density.
+    fn test_1d(
+        name: &str,
+        (min, max): (f64, f64),
+        sample: impl Fn(f64) -> f64,
+        pdf: impl Fn(f64) -> f64 + Copy,
+    ) {
+        Chi2Test::interval(min, max)
+            .resolution(80, 1)
+            .test_count(TEST_COUNT)
+            .run(
+                name,
+                |u| {
+                    let x = sample(u.x);
+                    Some((Vector3::new(x, 0.5, 0.0), pdf(x)))
+                },
+                |w| pdf(w.x),
+            )
+            .unwrap();
+        let mut rng = Rng::with_sequence(11, 0);
+        let samples = (0..20_000).map(|_| sample(rng.uniform())).collect();
+        // Widen the range so that the tails' mass is negligible.
+        let width = max - min;
+        let cdf = cdf_from_pdf(pdf, min - 4.0 * width, max + 4.0 * width);
+        ks_test(name, samples, cdf, TEST_COUNT).unwrap();
+    }
+
+    #[test]
+    fn test_one_dimensional_warps() {
+        test_1d(
+            "linear",
+            (0.0, 1.0),
+            |u| sample_linear(u, 1.0, 3.0),
+            |x| linear_pdf(x, 1.0, 3.0),
+        );
+        test_1d(
+            "tent",
+            (-1.5, 1.5),
+            |u| sample_tent(u, 1.5),
+            |x| tent_pdf(x, 1.5),
+        );
+        test_1d(
+            "exponential",
+            (0.0, 4.0),
+            |u| sample_exponential(u, 1.5),
+            |x| {
+                if x < 0.0 {
+                    0.0
+                } else {
+                    exponential_pdf(x, 1.5)
+                }
+            },
+        );
+        test_1d(
+            "normal",
+            (-4.0, 6.0),
+            |u| sample_normal(u, 1.0, 1.5),
+            |x| normal_pdf(x, 1.0, 1.5),
+        );
+        test_1d(
+            "logistic",
+            (-5.0, 5.0),
+            |u| sample_logistic(u, 0.7),
+            |x| logistic_pdf(x, 0.7),
+        );
+    }
+
+    #[test]
+    fn test_one_dimensional_inverses() {
+        for i in 0..64 {
+            let u = (i as f64 + 0.5) / 64.0;
+            let checks = [
+                invert_linear_sample(sample_linear(u, 0.0, 2.0), 0.0, 2.0),
+                invert_linear_sample(sample_linear(u, 3.0, 1.0), 3.0, 1.0),
+                invert_tent_sample(sample_tent(u, 2.0), 2.0),
+                invert_exponential_sample(sample_exponential(u, 0.5), 0.5),
+                invert_normal_sample(sample_normal(u, -1.0, 0.5), -1.0, 0.5),
+                invert_logistic_sample(sample_logistic(u, 2.0), 2.0),
+            ];
+            for (k, v) in checks.into_iter().enumerate() {
+                assert!((u - v).abs() < 1e-6, "check {k}: {u} came back as {v}");
+            }
+        }
+        // The normal CDF via erf agrees with its known values.
+        assert!((invert_normal_sample(1.0f64, 0.0, 1.0) - 0.841_344_746).abs() < 1e-6);
+        assert!((erf(1.0f64) - 0.842_700_793).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_bilinear() {
+        let w = [0.2, 1.5, 0.7, 3.0];
+        Chi2Test::rectangle(unit_square())
+            .test_count(TEST_COUNT)
+            .run(
+                "bilinear",
+                |u| {
+                    let p = sample_bilinear(u, w);
+                    Some((Vector3::new(p.x, p.y, 0.0), bilinear_pdf(p, w)))
+                },
+                |v| bilinear_pdf(Point2::new(v.x, v.y), w),
+            )
+            .unwrap();
+        assert_round_trip("bilinear", |u| {
+            invert_bilinear_sample(sample_bilinear(u, w), w)
+        });
+    }
+
+    #[test]
+    fn test_disks() {
+        // Binning by r^2 and phi / (2 pi) gives bins of equal area, and a
+        // uniform density of one with respect to them.
+        let polar = |p: Point2<f64>| {
+            let phi = p.y.atan2(p.x);
+            let phi = if phi < 0.0 { phi + 2.0 * PI } else { phi };
+            Some((
+                Vector3::new(p.x * p.x + p.y * p.y, phi / (2.0 * PI), 0.0),
+                1.0,
+            ))
+        };
+        let test = Chi2Test::rectangle(unit_square()).test_count(TEST_COUNT);
+        test.run(
+            "disk polar",
+            |u| polar(sample_uniform_disk_polar(u)),
+            |_| 1.0,
+        )
+        .unwrap();
+        test.run(
+            "disk concentric",
+            |u| polar(sample_uniform_disk_concentric(u)),
+            |_| 1.0,
+        )
+        .unwrap();
+        assert_round_trip("disk polar", |u| {
+            invert_uniform_disk_polar_sample(sample_uniform_disk_polar(u))
+        });
+        assert_round_trip("disk concentric", |u| {
+            invert_uniform_disk_concentric_sample(sample_uniform_disk_concentric(u))
+        });
+    }
+
+    // Coordinates in which a triangle's area is smooth: t = b0 + b1 and
+    // s = b0 / t, with db0 db1 = t dt ds.
+    fn triangle_coordinates(b: [f64; 3]) -> Vector3<f64> {
+        let t = b[0] + b[1];
+        Vector3::new(t, if t > 0.0 { b[0] / t } else { 0.0 }, 0.0)
+    }
+
+    #[test]
+    fn test_uniform_triangle() {
+        Chi2Test::rectangle(unit_square())
+            .test_count(TEST_COUNT)
+            .run(
+                "triangle",
+                |u| {
+                    let c = triangle_coordinates(sample_uniform_triangle(u));
+                    Some((c, 2.0 * c.x))
+                },
+                |c| 2.0 * c.x,
+            )
+            .unwrap();
+        assert_round_trip("triangle", |u| {
+            invert_uniform_triangle_sample(sample_uniform_triangle(u))
+        });
+    }
+
+    #[test]
+    fn test_directions() {
+        let hemisphere = Chi2Test::hemisphere().test_count(TEST_COUNT);
+        hemisphere
+            .run(
+                "hemisphere",
+                |u| Some((sample_uniform_hemisphere(u), uniform_hemisphere_pdf())),
+                |_| uniform_hemisphere_pdf(),
+            )
+            .unwrap();
+        Chi2Test::sphere()
+            .test_count(TEST_COUNT)
+            .run(
+                "sphere",
+                |u| Some((sample_uniform_sphere(u), uniform_sphere_pdf())),
+                |_| uniform_sphere_pdf(),
+            )
+            .unwrap();
+        hemisphere
+            .run(
+                "cosine hemisphere",
+                |u| {
+                    let w = sample_cosine_hemisphere(u);
+                    Some((w, cosine_hemisphere_pdf(w.z)))
+                },
+                |w| cosine_hemisphere_pdf(w.z),
+            )
+            .unwrap();
+        // Bin edges fall on the cone's edge at z = 0.7.
+        let cone_pdf = |w: Vector3<f64>| {
+            if w.z >= 0.7 {
+                uniform_cone_pdf(0.7)
+            } else {
+                0.0
+            }
+        };
+        hemisphere
+            .clone()
+            .resolution(30, 48)
+            .run(
+                "cone",
+                |u| {
+                    let w = sample_uniform_cone(u, 0.7);
+                    Some((w, uniform_cone_pdf(0.7)))
+                },
+                cone_pdf,
+            )
+            .unwrap();
+        assert_round_trip("hemisphere", |u| {
+            invert_uniform_hemisphere_sample(sample_uniform_hemisphere(u))
+        });
+        assert_round_trip("sphere", |u| {
+            invert_uniform_sphere_sample(sample_uniform_sphere(u))
+        });
+        assert_round_trip("cosine hemisphere", |u| {
+            invert_cosine_hemisphere_sample(sample_cosine_hemisphere(u))
+        });
+        assert_round_trip("cone", |u| {
+            invert_uniform_cone_sample(sample_uniform_cone(u, 0.7), 0.7)
+        });
+    }
+
+    #[test]
+    fn test_spherical_triangle() {
+        let v = [
+            Point3::new(0.3, -0.5, 1.0),
+            Point3::new(1.2, 0.4, 0.8),
+            Point3::new(-0.4, 0.9, 1.3),
+        ];
+        let p = Point3::new(0.1, 0.0, -0.2);
+        let point = |b: [f64; 3]| {
+            Point3::new(
+                b[0] * v[0].x + b[1] * v[1].x + b[2] * v[2].x,
+                b[0] * v[0].y + b[1] * v[1].y + b[2] * v[2].y,
+                b[0] * v[0].z + b[1] * v[1].z + b[2] * v[2].z,
+            )
+        };
+        let n = (v[1] - v[0]).cross(v[2] - v[0]);
+        let double_area = n.length();
+        let n = n.normalize();
+        let solid_angle = spherical_triangle_area(
+            (v[0] - p).normalize(),
+            (v[1] - p).normalize(),
+            (v[2] - p).normalize(),
+        );
+
+        // Convert densities per solid angle to ones in triangle
+        // coordinates.
+        let to_coordinates = |c: Vector3<f64>, pdf: f64| {
+            let b = [c.x * c.y, c.x * (1.0 - c.y), 1.0 - c.x];
+            let d = point(b) - p;
+            let cos = n.abs_dot(d.normalize());
+            pdf * cos / d.length_squared() * double_area * c.x
+        };
+        Chi2Test::rectangle(unit_square())
+            .test_count(TEST_COUNT)
+            .run(
+                "spherical triangle",
+                |u| {
+                    let (b, pdf) = sample_spherical_triangle(v, p, u)?;
+                    let c = triangle_coordinates(b);
+                    Some((c, to_coordinates(c, pdf)))
+                },
+                |c| to_coordinates(c, 1.0 / solid_angle),
+            )
+            .unwrap();
+        assert_round_trip("spherical triangle", |u| {
+            let (b, _) = sample_spherical_triangle(v, p, u).unwrap();
+            invert_spherical_triangle_sample(v, p, point(b) - p).unwrap()
+        });
+        assert!(sample_spherical_triangle([v[0], v[0], v[2]], p, Point2::new(0.5, 0.5)).is_none());
+    }
+
+    #[test]
+    fn test_spherical_rectangle() {
+        let s = Point3::new(-1.0, -0.5, 1.5);
+        let ex = Vector3::new(1.5, 0.0, 0.5);
+        let ey = Vector3::new(0.0, 1.2, 0.0);
+        let p_ref = Point3::new(0.2, -0.3, 0.0);
+        let n = ex.cross(ey).normalize();
+        let coordinates = |q: Point3<f64>| {
+            let d = q - s;
+            Point2::new(
+                d.dot(ex) / ex.length_squared(),
+                d.dot(ey) / ey.length_squared(),
+            )
+        };
+        // Convert densities per solid angle to ones per unit of the
+        // rectangle's coordinates.
+        let to_coordinates = |c: Vector3<f64>, pdf: f64| {
+            let d = s + ex * c.x + ey * c.y - p_ref;
+            let cos = n.abs_dot(d.normalize());
+            pdf * cos / d.length_squared() * ex.length() * ey.length()
+        };
+        let solid_angle = spherical_quad_solid_angle(p_ref, s, ex, ey);
+        Chi2Test::rectangle(unit_square())
+            .test_count(TEST_COUNT)
+            .run(
+                "spherical rectangle",
+                |u| {
+                    let (q, pdf) = sample_spherical_rectangle(p_ref, s, ex, ey, u);
+                    let c = coordinates(q);
+                    let c = Vector3::new(c.x, c.y, 0.0);
+                    Some((c, to_coordinates(c, pdf)))
+                },
+                |c| to_coordinates(c, 1.0 / solid_angle),
+            )
+            .unwrap();
+        assert_round_trip("spherical rectangle", |u| {
+            let (q, _) = sample_spherical_rectangle(p_ref, s, ex, ey, u);
+            invert_spherical_rectangle_sample(p_ref, s, ex, ey, q)
+        });
+
+        // Far away, the rectangle is sampled by area, and its solid angle is
+        // its projected area over the squared distance to its center.
+        let far = Point3::new(0.0, 0.0, -200.0);
+        let (q, pdf) = sample_spherical_rectangle(far, s, ex, ey, Point2::new(0.25, 0.75));
+        let u = coordinates(q);
+        assert!((u.x - 0.25).abs() < 1e-9 && (u.y - 0.75).abs() < 1e-9);
+        let area_pdf = 1.0 / (ex.length() * ey.length());
+        let d = s + ex * 0.5 + ey * 0.5 - far;
+        let expected = area_pdf * d.length_squared() / n.abs_dot(d.normalize());
+        assert!((pdf / expected - 1.0).abs() < 1e-3);
+    }
+
+    // The solid angle of a rectangle, from the areas of the two spherical
+    // triangles it splits into.
+    fn spherical_quad_solid_angle(
+        p: Point3<f64>,
+        s: Point3<f64>,
+        ex: Vector3<f64>,
+        ey: Vector3<f64>,
+    ) -> f64 {
+        let a = (s - p).normalize();
+        let b = (s + ex - p).normalize();
+        let c = (s + ex + ey - p).normalize();
+        let d = (s + ey - p).normalize();
+        spherical_triangle_area(a, b, c) + spherical_triangle_area(a, c, d)
+    }
+
+    #[test]
+    fn test_ggx() {
+        let (ax, ay) = (0.3, 0.6);
+        // Microfacets project to the macrosurface's area.
+        let projected = Domain::Hemisphere.integrate(|wm| ggx_d(wm, ax, ay) * wm.z);
+        assert!((projected - 1.0).abs() < 1e-3, "{projected}");
+
+        let w = Vector3::new(0.4, -0.3, 0.5).normalize();
+        let pdf = |wm: Vector3<f64>| ggx_visible_normal_pdf(w, wm, ax, ay);
+        let total = Domain::Hemisphere.integrate(pdf);
+        assert!((total - 1.0).abs() < 1e-3, "{total}");
+        Chi2Test::hemisphere()
+            .resolution(24, 48)
+            .test_count(TEST_COUNT)
+            .run(
+                "ggx visible normals",
+                |u| {
+                    let wm = sample_ggx_visible_normal(w, ax, ay, u);
+                    Some((wm, pdf(wm)))
+                },
+                pdf,
+            )
+            .unwrap();
+        // Seen from straight above, every normal is visible.
+        assert_eq!(ggx_g1(Vector3::new(0.0, 0.0, 1.0), ax, ay), 1.0);
+    }
+
+    #[test]
+    fn test_heuristics() {
+        for (f, g) in [(0.5f64, 2.0), (3.0, 0.1), (1.0, 1.0)] {
+            let balance = balance_heuristic(2, f, 1, g) + balance_heuristic(1, g, 2, f);
+            let power = power_heuristic(2, f, 1, g) + power_heuristic(1, g, 2, f);
+            assert!((balance - 1.0).abs() < 1e-12 && (power - 1.0).abs() < 1e-12);
+        }
+        assert_eq!(power_heuristic(1, f64::INFINITY, 1, 1.0), 1.0);
+        assert_eq!(balance_heuristic(1, 0.0f64, 1, 2.0), 0.0);
+    }
+}
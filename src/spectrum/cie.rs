@@ -0,0 +1,287 @@
+//! Colorimetric tables: the CIE 1931 standard observer's color matching
+//! functions and the CIE daylight basis functions.
+//!
+//! The matching functions are the CIE's 5nm table of the 1931 2° standard
+//! observer (CIE 015:2004), interpolated to 1nm with the Sprague
+//! interpolation that CIE 167 recommends for resampling its tables; the
+//! few tiny negative overshoots in the tails are clamped to zero. The
+//! samples of [`CIE_Y`] sum to [`CIE_Y_INTEGRAL`] to within one part in
+//! 10⁶.
+//!
+//! The daylight basis functions `S0`, `S1` and `S2` are the CIE's, from
+//! 300nm to 830nm in steps of 10nm, and [`cie_daylight`] combines them into
+//! the daylight spectrum of a given temperature.
+
+use crate::spectrum::{LAMBDA_MAX, LAMBDA_MIN};
+
+/// The number of samples in the color matching functions: one per
+/// nanometer from [`LAMBDA_MIN`] to [`LAMBDA_MAX`].
+pub const N_CIE_SAMPLES: usize = (LAMBDA_MAX - LAMBDA_MIN) as usize + 1;
+
+/// The integral of the luminous efficiency function [`CIE_Y`] over the
+/// visible range, which normalizes spectra so that a constant spectrum of
+/// one has a `Y` of one.
+pub const CIE_Y_INTEGRAL: f32 = 106.856895;
+
+/// The CIE 1931 `x` color matching function, one sample per nanometer
+/// from [`LAMBDA_MIN`].
+#[rustfmt::skip]
+pub const CIE_X: [f32; 471] = [
+    0.0001299, 0.00014755734, 0.00016577574, 0.0001851903, 0.00020700294, 0.0002321, 0.00026063342,
+    0.0002930796, 0.00032957568, 0.00037007497, 0.0004149, 0.00046458415, 0.000519208,
+    0.00058134063, 0.00065467966, 0.0007416, 0.0008435541, 0.00096401345, 0.0010972423,
+    0.0012334209, 0.001368, 0.0015047673, 0.0016446216, 0.0018008542, 0.0019933798, 0.002236,
+    0.0025323292, 0.0028910758, 0.0033046496, 0.0037573113, 0.004243, 0.004768864, 0.005333869,
+    0.0059717856, 0.0067332624, 0.00765, 0.00873428, 0.010021954, 0.011446307, 0.012893965, 0.01431,
+    0.015733622, 0.017172042, 0.01876476, 0.020722438, 0.02319, 0.026185824, 0.029766016,
+    0.033896446, 0.03848936, 0.04351, 0.049017742, 0.055038128, 0.06170003, 0.069188416, 0.07763,
+    0.086979724, 0.097157955, 0.108320095, 0.12069896, 0.13438, 0.14925855, 0.16530953, 0.182035,
+    0.1986954, 0.21477, 0.23021907, 0.24493164, 0.25880843, 0.2718125, 0.2839, 0.294966, 0.30492762,
+    0.3137996, 0.3216425, 0.3285, 0.33435842, 0.33922237, 0.34312978, 0.34613174, 0.34828,
+    0.34961003, 0.35015595, 0.3500073, 0.34927818, 0.34806, 0.3463798, 0.34425673, 0.341783,
+    0.3390737, 0.3362, 0.33317116, 0.3300153, 0.32664374, 0.32290548, 0.3187, 0.31403527,
+    0.30889207, 0.30328357, 0.2972482, 0.2908, 0.28393975, 0.27671462, 0.2689724, 0.2604743, 0.2511,
+    0.24091117, 0.22990583, 0.21837312, 0.20675689, 0.19536, 0.18418583, 0.1732932, 0.16268247,
+    0.15229344, 0.1421, 0.1321725, 0.12256392, 0.113277666, 0.10430261, 0.09564, 0.08730821,
+    0.07931047, 0.07170313, 0.0645669, 0.05795001, 0.05184697, 0.046266887, 0.041155685, 0.0364236,
+    0.03201, 0.027920496, 0.024149198, 0.020689184, 0.017540207, 0.0147, 0.012161264, 0.009922144,
+    0.007972784, 0.006300304, 0.0049, 0.003780608, 0.002950864, 0.00242824, 0.002236816, 0.0024,
+    0.002930672, 0.00383992, 0.005169968, 0.006976256, 0.0093, 0.012149088, 0.015531008,
+    0.019468687, 0.023987088, 0.0291, 0.034807406, 0.041111328, 0.047984127, 0.055381726, 0.06327,
+    0.071637005, 0.08046133, 0.089733645, 0.099451244, 0.1096, 0.12015621, 0.13110821, 0.14238054,
+    0.15386821, 0.1655, 0.17726816, 0.18915264, 0.20116977, 0.2133605, 0.2257499, 0.23832175,
+    0.25106722, 0.2639911, 0.27710053, 0.2904, 0.3038896, 0.31757155, 0.33143985, 0.34548458,
+    0.3597, 0.37408662, 0.388641, 0.40337497, 0.41830775, 0.4334499, 0.44879377, 0.4643326,
+    0.48006067, 0.49596992, 0.5120501, 0.5282922, 0.54468834, 0.5612112, 0.5778246, 0.5945,
+    0.61122406, 0.6279776, 0.64475673, 0.66156584, 0.6784, 0.6952363, 0.71205515, 0.7288279,
+    0.74551994, 0.7621, 0.7785445, 0.79482514, 0.81092256, 0.8268216, 0.8425, 0.8579272, 0.87307775,
+    0.88789874, 0.90232366, 0.9163, 0.9298029, 0.9428027, 0.9552786, 0.9672168, 0.9786, 0.9893917,
+    0.99954975, 1.0090774, 1.0179955, 1.0263, 1.0339612, 1.0409743, 1.047213, 1.052494, 1.0567,
+    1.0598241, 1.0618242, 1.0627898, 1.0628834, 1.0622, 1.06071, 1.0584266, 1.0552472, 1.0510046,
+    1.0456, 1.0390612, 1.0313836, 1.0226572, 1.0130296, 1.0026, 0.9913603, 0.97932243, 0.9664897,
+    0.95285016, 0.9384, 0.923179, 0.90724206, 0.89053196, 0.87294745, 0.8544499, 0.8351092,
+    0.8149759, 0.7941891, 0.77294344, 0.7514, 0.72960395, 0.70759195, 0.68556273, 0.6637739, 0.6424,
+    0.6214773, 0.60107434, 0.58111095, 0.5614204, 0.5419, 0.5226019, 0.50355214, 0.4847496,
+    0.46619663, 0.4479, 0.42986688, 0.412104, 0.39464352, 0.37753025, 0.3608, 0.3444603, 0.32851633,
+    0.3130091, 0.29799232, 0.2835, 0.2695352, 0.2561083, 0.24319103, 0.23073296, 0.2187, 0.2070976,
+    0.19592464, 0.18517248, 0.17483312, 0.1649, 0.15536784, 0.14623168, 0.13749072, 0.12914656,
+    0.1212, 0.11364363, 0.10646568, 0.09968293, 0.09332362, 0.0874, 0.08189533, 0.07679677,
+    0.07207477, 0.06768789, 0.0636, 0.059799727, 0.05627981, 0.052982368, 0.04982973, 0.04677,
+    0.04379523, 0.04088669, 0.038070142, 0.03539736, 0.0329, 0.03056264, 0.028378056, 0.026343316,
+    0.024452502, 0.0227, 0.021083174, 0.019598437, 0.018236972, 0.016987534, 0.01584, 0.0147891585,
+    0.013830431, 0.012950011, 0.012130506, 0.01135916, 0.010631461, 0.009941282, 0.009288794,
+    0.008678157, 0.008110916, 0.007582211, 0.007088691, 0.0066275345, 0.006195621, 0.005790346,
+    0.0054100165, 0.0050528683, 0.0047176518, 0.0044035036, 0.004109457, 0.003834046, 0.0035758878,
+    0.0033343225, 0.0031089957, 0.002899327, 0.00270428, 0.0025230004, 0.002354282, 0.0021967264,
+    0.00204919, 0.0019110262, 0.0017815519, 0.001660188, 0.001546478, 0.001439971, 0.001340106,
+    0.0012463533, 0.0011584822, 0.0010764044, 0.000999949, 0.0009287361, 0.00086244446,
+    0.0008007718, 0.0007434102, 0.000690079, 0.0006405333, 0.0005945236, 0.00055186794,
+    0.00051242224, 0.000476021, 0.00044244924, 0.00041151472, 0.00038299718, 0.00035666174,
+    0.000332301, 0.00030976348, 0.0002889029, 0.00026955985, 0.00025157924, 0.000234826,
+    0.0002191858, 0.00020454652, 0.00019084878, 0.00017806372, 0.000166151, 0.00015502996,
+    0.00014463018, 0.00013491268, 0.0001258509, 0.000117413, 0.00010955194, 0.00010222686,
+    9.539805e-5, 8.9025874e-5, 8.3075e-5, 7.751534e-5, 7.231704e-5, 6.74597e-5, 6.292859e-5,
+    5.8707e-5, 5.4771423e-5, 5.1100822e-5, 4.767759e-5, 4.4485525e-5, 4.1509e-5, 3.8732596e-5,
+    3.6142093e-5, 3.37237e-5, 3.1464286e-5, 2.9352e-5, 2.7375896e-5, 2.5525149e-5, 2.3793746e-5,
+    2.217827e-5, 2.0674e-5, 1.9272662e-5, 1.7967122e-5, 1.6750773e-5, 1.5617064e-5, 1.456e-5,
+    1.3574339e-5, 1.26549885e-5, 1.1797639e-5, 1.0998544e-5, 1.0254e-5, 9.559955e-6, 8.912646e-6,
+    8.309002e-6, 7.746333e-6, 7.222e-6, 6.7331503e-6, 6.2771214e-6, 5.8517967e-6, 5.45536e-6,
+    5.086e-6, 4.7416975e-6, 4.420581e-6, 4.121136e-6, 3.842043e-6, 3.582e-6, 3.3395904e-6,
+    3.113496e-6, 2.9026496e-6, 2.7061233e-6, 2.523e-6, 2.3520327e-6, 2.191797e-6, 2.0422813e-6,
+    1.9040663e-6, 1.777e-6, 1.659507e-6, 1.5501774e-6, 1.4471473e-6, 1.3481433e-6, 1.251e-6,
+];
+
+/// The CIE 1931 `y` color matching function, which is also the
+/// photopic luminous efficiency function.
+#[rustfmt::skip]
+pub const CIE_Y: [f32; 471] = [
+    3.917e-6, 4.445439e-6, 4.9922755e-6, 5.5775777e-6, 6.227845e-6, 6.965e-6, 7.802064e-6,
+    8.756368e-6, 9.835866e-6, 1.104378e-5, 1.239e-5, 1.3891858e-5, 1.5560445e-5, 1.7437016e-5,
+    1.9577068e-5, 2.202e-5, 2.4810575e-5, 2.803532e-5, 3.1584583e-5, 3.526545e-5, 3.9e-5,
+    4.288784e-5, 4.6968144e-5, 5.1558447e-5, 5.712491e-5, 6.4e-5, 7.228346e-5, 8.218109e-5,
+    9.358256e-5, 0.00010621603, 0.00012, 0.000135112, 0.0001515664, 0.0001700688, 0.0001916592,
+    0.000217, 0.0002464864, 0.0002811008, 0.0003191872, 0.0003579216, 0.000396, 0.0004345072,
+    0.0004736912, 0.0005174272, 0.0005715232, 0.00064, 0.00072376, 0.0008250512, 0.0009420624,
+    0.0010708816, 0.00121, 0.0013627008, 0.0015316544, 0.0017208, 0.0019353057, 0.00218,
+    0.002456368, 0.002764432, 0.003115136, 0.00352384, 0.004, 0.004543424, 0.005156656, 0.005830288,
+    0.00654824, 0.0073, 0.00808736, 0.008909728, 0.009767776, 0.010664064, 0.0116, 0.012573536,
+    0.013582816, 0.014629056, 0.015714496, 0.01684, 0.018005695, 0.019213855, 0.020456417,
+    0.021720735, 0.023, 0.024297217, 0.025612736, 0.026957696, 0.028349375, 0.0298, 0.03131027,
+    0.032882817, 0.03452064, 0.036225665, 0.038, 0.03984656, 0.04176752, 0.04376528, 0.04584224,
+    0.048, 0.050241567, 0.05257232, 0.054983873, 0.057461984, 0.06, 0.06260483, 0.06528083,
+    0.06804227, 0.07090979, 0.0739, 0.077017345, 0.080266595, 0.08366416, 0.08723037, 0.09098,
+    0.09491648, 0.0990432, 0.10336448, 0.1078832, 0.1126, 0.11752355, 0.12267152, 0.12800604,
+    0.13346586, 0.13902, 0.14468944, 0.15048254, 0.15645914, 0.16270934, 0.1693, 0.17624217,
+    0.18355529, 0.19127001, 0.1994161, 0.20802, 0.21711238, 0.22673097, 0.23686701, 0.24749152,
+    0.2586, 0.27020925, 0.2823038, 0.29501548, 0.30854252, 0.323, 0.33836395, 0.35465086, 0.3717144,
+    0.38931715, 0.4073, 0.4256557, 0.44432545, 0.463368, 0.48290896, 0.503, 0.5235483, 0.5444856,
+    0.5656845, 0.5869722, 0.6082, 0.62932, 0.6503013, 0.67092174, 0.69088626, 0.71, 0.72823274,
+    0.74551016, 0.7619556, 0.77780384, 0.7932, 0.8080941, 0.8224824, 0.83631593, 0.84950584, 0.862,
+    0.8738204, 0.88497156, 0.8954903, 0.9054363, 0.9148501, 0.9237315, 0.9320891, 0.93992347,
+    0.9472275, 0.954, 0.96025544, 0.96600664, 0.97126025, 0.9760218, 0.9803, 0.9841033, 0.98743546,
+    0.99032766, 0.99282306, 0.9949501, 0.99670905, 0.9981041, 0.99912786, 0.9997641, 1.0, 0.9998397,
+    0.99928904, 0.9983219, 0.9969, 0.995, 0.99262464, 0.9897695, 0.9864572, 0.9827257, 0.9786,
+    0.9740784, 0.9691664, 0.9638576, 0.9581376, 0.952, 0.9454533, 0.9385016, 0.9311611, 0.92345506,
+    0.9154, 0.90700257, 0.8982755, 0.8892101, 0.879787, 0.87, 0.8598659, 0.8493973, 0.8386222,
+    0.8275792, 0.8163, 0.8047968, 0.79308224, 0.78118765, 0.76915073, 0.757, 0.7447498, 0.73241806,
+    0.7200048, 0.7074995, 0.6949, 0.6822211, 0.6694728, 0.6566725, 0.6438426, 0.631, 0.6181528,
+    0.60531247, 0.59247774, 0.5796406, 0.5668, 0.5539651, 0.54113936, 0.528348, 0.515627, 0.503,
+    0.4904656, 0.4780256, 0.4656752, 0.4534032, 0.4412, 0.42907232, 0.4170336, 0.40504447,
+    0.39304417, 0.381, 0.36892927, 0.3568397, 0.3447773, 0.3328125, 0.321, 0.30934495, 0.29785088,
+    0.2865792, 0.27561152, 0.265, 0.25474912, 0.2448752, 0.23533729, 0.22606176, 0.217, 0.20816287,
+    0.19955169, 0.19115809, 0.18297568, 0.175, 0.16722624, 0.15964928, 0.15227713, 0.14512417,
+    0.1382, 0.13150112, 0.12502432, 0.11877632, 0.11276672, 0.107, 0.10147325, 0.09618576,
+    0.09112387, 0.08626694, 0.0816, 0.07712115, 0.07282643, 0.06871075, 0.06476995, 0.061,
+    0.057396512, 0.05395571, 0.05067443, 0.04754995, 0.04458, 0.04176051, 0.03908563, 0.036561154,
+    0.034197792, 0.032, 0.029960545, 0.028073952, 0.02632864, 0.024708608, 0.0232, 0.021798288,
+    0.0205004, 0.019285232, 0.018124703, 0.017, 0.01590791, 0.014841336, 0.013809723, 0.0128320865,
+    0.01192, 0.011067687, 0.010272435, 0.009532808, 0.008846133, 0.00821, 0.007623398, 0.00708492,
+    0.00659133, 0.006138579, 0.005723, 0.005342603, 0.0049956497, 0.004677136, 0.0043807905,
+    0.004102, 0.0038390944, 0.003589832, 0.0033542416, 0.0031337873, 0.002929, 0.002738112,
+    0.0025598975, 0.0023933472, 0.0022373567, 0.002091, 0.001953656, 0.0018246832, 0.0017036304,
+    0.0015901856, 0.001484, 0.001384544, 0.0012913184, 0.0012040848, 0.0011227152, 0.001047,
+    0.000976565, 0.0009111016, 0.0008501742, 0.0007932781, 0.00074, 0.00069010654, 0.00064335106,
+    0.0005995243, 0.0005584616, 0.00052, 0.00048393695, 0.00045008113, 0.0004183493, 0.00038870945,
+    0.0003611, 0.00033538367, 0.00031144448, 0.00028917327, 0.00026845888, 0.0002492, 0.00023130816,
+    0.00021469328, 0.0001992896, 0.00018504512, 0.0001719, 0.00015977664, 0.0001486056,
+    0.00013830735, 0.00012879712, 0.00012, 0.00011186128, 0.00010432816, 9.734304e-5, 9.084992e-5,
+    8.48e-5, 7.9152e-5, 7.386544e-5, 6.891888e-5, 6.430192e-5, 6.0e-5, 5.5984e-5, 5.222848e-5,
+    4.871936e-5, 4.544704e-5, 4.24e-5, 3.9561262e-5, 3.691608e-5, 3.4450095e-5, 3.2148993e-5,
+    3.0e-5, 2.7992255e-5, 2.6114976e-5, 2.4360816e-5, 2.2724496e-5, 2.12e-5, 1.977884e-5,
+    1.8453416e-5, 1.7217351e-5, 1.6064769e-5, 1.499e-5, 1.3987483e-5, 1.3052088e-5, 1.21788125e-5,
+    1.1362881e-5, 1.06e-5, 9.886216e-6, 9.217612e-6, 8.592066e-6, 8.008415e-6, 7.465e-6,
+    6.9588286e-6, 6.4873298e-6, 6.0480943e-6, 5.6387153e-6, 5.257e-6, 4.901064e-6, 4.5690704e-6,
+    4.2594606e-6, 3.9708834e-6, 3.702e-6, 3.4513455e-6, 3.2175567e-6, 2.999536e-6, 2.7963313e-6,
+    2.607e-6, 2.4305104e-6, 2.265904e-6, 2.1124015e-6, 1.9693232e-6, 1.836e-6, 1.7117056e-6,
+    1.5957615e-6, 1.4876337e-6, 1.3868656e-6, 1.293e-6, 1.2055216e-6, 1.1239536e-6, 1.0479056e-6,
+    9.770336e-7, 9.11e-7, 8.4935544e-7, 7.915868e-7, 7.376821e-7, 6.878403e-7, 6.42e-7, 5.99594e-7,
+    5.6011396e-7, 5.228912e-7, 4.871118e-7, 4.52e-7,
+];
+
+/// The CIE 1931 `z` color matching function.
+#[rustfmt::skip]
+pub const CIE_Z: [f32; 471] = [
+    0.0006061, 0.00068896153, 0.00077445764, 0.00086557196, 0.00096801855, 0.001086, 0.0012201883,
+    0.0013728143, 0.0015445161, 0.0017350666, 0.001946, 0.0021798252, 0.002436875, 0.0027294757,
+    0.0030753887, 0.003486, 0.00396817, 0.0045384043, 0.005169247, 0.0058137206, 0.006450001,
+    0.00709613, 0.0077564083, 0.008493935, 0.00940327, 0.01054999, 0.011951317, 0.01364866,
+    0.015606195, 0.01774949, 0.02005001, 0.022541994, 0.025221031, 0.028247109, 0.031859986,
+    0.03621, 0.041356545, 0.047471106, 0.05423727, 0.061116874, 0.06785001, 0.07462511, 0.08147895,
+    0.08907615, 0.09841943, 0.1102, 0.124510445, 0.14162423, 0.16137992, 0.18335922, 0.2074,
+    0.23379736, 0.26268783, 0.29469073, 0.33069, 0.3713, 0.41632095, 0.4653803, 0.51927525,
+    0.5791946, 0.6456, 0.7180002, 0.7962938, 0.87809503, 0.9598469, 1.0390501, 1.1155267, 1.1887462,
+    1.2582631, 1.3239431, 1.3856, 1.4427286, 1.4949433, 1.5422593, 1.58488, 1.62296, 1.6564472,
+    1.685365, 1.7099173, 1.7303895, 1.74706, 1.7600976, 1.7696661, 1.77623, 1.7803844, 1.7826,
+    1.783, 1.7816662, 1.7790593, 1.7757586, 1.77211, 1.7681247, 1.7639002, 1.7589679, 1.7525495,
+    1.7441, 1.7335957, 1.7208822, 1.7059036, 1.6886969, 1.6692, 1.6473634, 1.6233795, 1.5963277,
+    1.5648142, 1.5281, 1.4864597, 1.4398268, 1.3897071, 1.3384469, 1.28764, 1.2372757, 1.1876514,
+    1.1387451, 1.0902106, 1.0419, 0.99418545, 0.94733137, 0.90144867, 0.8566225, 0.8129501,
+    0.7705349, 0.72944033, 0.68986535, 0.652064, 0.6162, 0.5822878, 0.55036795, 0.5203328,
+    0.49198452, 0.46518, 0.43992084, 0.41617882, 0.39388123, 0.37294716, 0.3533, 0.33486435,
+    0.31755108, 0.30132067, 0.28615412, 0.272, 0.25878295, 0.2464669, 0.23481505, 0.22349916,
+    0.2123, 0.20120393, 0.1901628, 0.17923369, 0.16854896, 0.1582, 0.14815208, 0.13838407,
+    0.12897968, 0.120058484, 0.1117, 0.10389826, 0.096652955, 0.08996846, 0.08383908, 0.07824999,
+    0.07319268, 0.0686639, 0.064576, 0.060801942, 0.05725001, 0.05390825, 0.050754808, 0.047760326,
+    0.04490168, 0.04216, 0.039514158, 0.036943197, 0.034458, 0.03208504, 0.02984, 0.02771176,
+    0.02569408, 0.02378656, 0.02198888, 0.0203, 0.018717743, 0.01723976, 0.015863135, 0.014584432,
+    0.0134, 0.012306672, 0.011301376, 0.01037816, 0.009529743, 0.008749999, 0.008035359,
+    0.0073818387, 0.006785519, 0.006242799, 0.005749999, 0.005303439, 0.0048999195, 0.0045347996,
+    0.00420288, 0.0039, 0.00362368, 0.0033711996, 0.0031415194, 0.002934639, 0.002749999,
+    0.0025853592, 0.0024387192, 0.0023092795, 0.0021966398, 0.0021, 0.00201768, 0.00194808,
+    0.0018896799, 0.0018408799, 0.0018, 0.0017658401, 0.0017376804, 0.0017119207, 0.0016837609,
+    0.001650001, 0.0016105609, 0.0015651208, 0.0015140804, 0.0014586402, 0.0014, 0.0013376799,
+    0.0012704799, 0.00120368, 0.00114528, 0.0011, 0.0010672, 0.00104808, 0.00103656, 0.00102264,
+    0.001, 0.000969664, 0.00093088, 0.000886496, 0.000841792, 0.0008, 0.00076032, 0.000723296,
+    0.000686592, 0.000646208, 0.0006, 0.000548816, 0.000492272, 0.000434608, 0.000382464, 0.00034,
+    0.000306528, 0.000282368, 0.000265488, 0.000252208, 0.00024, 0.000229408, 0.00022068799,
+    0.00021236799, 0.00020252798, 0.00019, 0.00017467202, 0.00015604807, 0.0001357441, 0.0001164801,
+    0.0001, 8.5951826e-5, 7.438356e-5, 6.497528e-5, 5.7007066e-5, 4.9999e-5, 4.4127064e-5,
+    3.9455274e-5, 3.574356e-5, 3.2671825e-5, 3.0e-5, 2.7664097e-5, 2.5584113e-5, 2.3664074e-5,
+    2.1824026e-5, 2.0e-5, 1.8143986e-5, 1.6223985e-5, 1.4223992e-5, 1.2143998e-5, 1.0e-5, 7.776e-6,
+    5.424e-6, 3.152e-6, 1.28e-6, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.28e-7, 1.44e-7, 8.0e-8, 1.6e-8,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+];
+
+/// The wavelength of the first sample of the daylight basis functions.
+pub const CIE_S_LAMBDA_MIN: f32 = 300.0;
+
+/// The spacing of the samples of the daylight basis functions.
+pub const CIE_S_LAMBDA_STEP: f32 = 10.0;
+
+/// The mean of the CIE daylight spectra.
+#[rustfmt::skip]
+pub const CIE_S0: [f32; 54] = [
+    0.04, 6.0, 29.6, 55.3, 57.3, 61.8, 61.5, 68.8, 63.4, 65.8, 94.8, 104.8, 105.9, 96.8, 113.9,
+    125.6, 125.5, 121.3, 121.3, 113.5, 113.1, 110.8, 106.5, 108.8, 105.3, 104.4, 1.0e2, 96.0, 95.1,
+    89.1, 90.5, 90.3, 88.4, 84.0, 85.1, 81.9, 82.6, 84.9, 81.3, 71.9, 74.3, 76.4, 63.3, 71.7, 77.0,
+    65.2, 47.7, 68.6, 65.0, 66.0, 61.0, 53.3, 58.9, 61.9,
+];
+
+/// The first characteristic vector of the CIE daylight spectra, which
+/// varies them from yellow to blue.
+#[rustfmt::skip]
+pub const CIE_S1: [f32; 54] = [
+    0.02, 4.5, 22.4, 42.0, 40.6, 41.6, 38.0, 42.4, 38.5, 35.0, 43.4, 46.3, 43.9, 37.1, 36.7, 35.9,
+    32.6, 27.9, 24.3, 20.1, 16.2, 13.2, 8.6, 6.1, 4.2, 1.9, 0.0, -1.6, -3.5, -3.5, -5.8, -7.2, -8.6,
+    -9.5, -10.9, -10.7, -12.0, -14.0, -13.6, -12.0, -13.3, -12.9, -10.6, -11.6, -12.2, -10.2, -7.8,
+    -11.2, -10.4, -10.6, -9.7, -8.3, -9.3, -9.8,
+];
+
+/// The second characteristic vector of the CIE daylight spectra, which
+/// varies them from pink to green.
+#[rustfmt::skip]
+pub const CIE_S2: [f32; 54] = [
+    0.0, 2.0, 4.0, 8.5, 7.8, 6.7, 5.3, 6.1, 3.0, 1.2, -1.1, -0.5, -0.7, -1.2, -2.6, -2.9, -2.8,
+    -2.6, -2.6, -1.8, -1.5, -1.3, -1.2, -1.0, -0.5, -0.3, 0.0, 0.2, 0.5, 2.1, 3.2, 4.1, 4.7, 5.1,
+    6.7, 7.3, 8.6, 9.8, 10.2, 8.3, 9.6, 8.5, 7.0, 7.6, 8.0, 6.7, 5.2, 7.4, 6.8, 7.0, 6.4, 5.5, 6.1,
+    6.5,
+];
+
+/// Returns the CIE daylight spectrum with the correlated color temperature
+/// `cct`, in kelvin, sampled at the wavelengths of [`CIE_S0`].
+pub fn cie_daylight(cct: f32) -> [f32; 54] {
+    // The chromaticity of daylight at the temperature.
+    let t = f64::from(cct);
+    let x = if t <= 7000.0 {
+        -4.607e9 / (t * t * t) + 2.9678e6 / (t * t) + 0.09911e3 / t + 0.244063
+    } else {
+        -2.0064e9 / (t * t * t) + 1.9018e6 / (t * t) + 0.24748e3 / t + 0.23704
+    };
+    let y = -3.0 * x * x + 2.87 * x - 0.275;
+    // The weights of the characteristic vectors that give it.
+    let m = 0.0241 + 0.2562 * x - 0.7341 * y;
+    let m1 = ((-1.3515 - 1.7703 * x + 5.9114 * y) / m) as f32;
+    let m2 = ((0.0300 - 31.4424 * x + 30.0717 * y) / m) as f32;
+    std::array::from_fn(|i| CIE_S0[i] + m1 * CIE_S1[i] + m2 * CIE_S2[i])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::spectrum::cie::{CIE_X, CIE_Y, CIE_Y_INTEGRAL, CIE_Z};
+
+    #[test]
+    fn test_matching_functions_match_cie_table() {
+        // Entries of the CIE's 5nm table, which the interpolation keeps.
+        for (lambda, x, y, z) in [
+            (360, 0.000_129_9, 0.000_003_917, 0.000_606_1),
+            (450, 0.336_2, 0.038, 1.772_11),
+            (555, 0.512_050_1, 1.0, 0.005_749_999),
+            (600, 1.062_2, 0.631, 0.000_8),
+            (830, 0.000_001_251, 0.000_000_452, 0.0),
+        ] {
+            let i = lambda - 360;
+            assert_eq!((CIE_X[i], CIE_Y[i], CIE_Z[i]), (x, y, z));
+        }
+        let sum: f32 = CIE_Y.iter().sum();
+        assert!((sum / CIE_Y_INTEGRAL - 1.0).abs() < 1e-5);
+        assert!(CIE_X.iter().chain(&CIE_Y).chain(&CIE_Z).all(|&v| v >= 0.0));
+    }
+}
@@ -0,0 +1,254 @@
+//! Tristimulus colors and the sigmoid-polynomial spectra that RGB colors are
+//! lifted to.
+
+use std::ops::{Add, AddAssign, Div, DivAssign, Index, Mul, MulAssign, Sub, SubAssign};
+
+use crate::spectrum::{LAMBDA_MAX, LAMBDA_MIN};
+
+/// A color given by its red, green and blue components in some
+/// [`RGBColorSpace`](crate::spectrum::RGBColorSpace).
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct RGB {
+    /// The red component.
+    pub r: f32,
+    /// The green component.
+    pub g: f32,
+    /// The blue component.
+    pub b: f32,
+}
+
+impl RGB {
+    /// Creates a color from its components.
+    pub fn new(r: f32, g: f32, b: f32) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Returns the mean of the components.
+    pub fn average(&self) -> f32 {
+        (self.r + self.g + self.b) / 3.0
+    }
+
+    /// Returns the largest component.
+    pub fn max_component(&self) -> f32 {
+        self.r.max(self.g).max(self.b)
+    }
+
+    /// Returns the smallest component.
+    pub fn min_component(&self) -> f32 {
+        self.r.min(self.g).min(self.b)
+    }
+
+    /// Returns the components clamped to be non-negative.
+    pub fn clamp_zero(self) -> Self {
+        Self::new(self.r.max(0.0), self.g.max(0.0), self.b.max(0.0))
+    }
+}
+
+/// A color given by its CIE 1931 XYZ tristimulus values; `y` is the
+/// luminance.
+///
+/// ```
+/// use pbrt::spectrum::XYZ;
+///
+/// let c = XYZ::from_xyy(0.3127, 0.3290, 1.0);
+/// let (x, y) = c.xy();
+/// assert!((x - 0.3127).abs() < 1e-6 && (y - 0.3290).abs() < 1e-6);
+/// assert_eq!(c.y, 1.0);
+/// ```
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct XYZ {
+    /// The `X` tristimulus value.
+    pub x: f32,
+    /// The `Y` tristimulus value, the luminance.
+    pub y: f32,
+    /// The `Z` tristimulus value.
+    pub z: f32,
+}
+
+impl XYZ {
+    /// Creates a color from its tristimulus values.
+    pub fn new(x: f32, y: f32, z: f32) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Creates a color from its chromaticity `(x, y)` and luminance `Y`.
+    pub fn from_xyy(x: f32, y: f32, luminance: f32) -> Self {
+        if y == 0.0 {
+            return Self::default();
+        }
+        Self::new(x * luminance / y, luminance, (1.0 - x - y) * luminance / y)
+    }
+
+    /// Returns the chromaticity `(x, y)` of the color.
+    pub fn xy(&self) -> (f32, f32) {
+        let sum = self.x + self.y + self.z;
+        (self.x / sum, self.y / sum)
+    }
+
+    /// Returns the mean of the tristimulus values.
+    pub fn average(&self) -> f32 {
+        (self.x + self.y + self.z) / 3.0
+    }
+}
+
+macro_rules! color_ops_impl {
+    ($t:ident, $a:ident, $b:ident, $c:ident) => {
+        color_ops_impl!(@op $t, $a, $b, $c, Add, add, AddAssign, add_assign, +);
+        color_ops_impl!(@op $t, $a, $b, $c, Sub, sub, SubAssign, sub_assign, -);
+        color_ops_impl!(@op $t, $a, $b, $c, Mul, mul, MulAssign, mul_assign, *);
+        color_ops_impl!(@op $t, $a, $b, $c, Div, div, DivAssign, div_assign, /);
+
+        impl Mul<$t> for f32 {
+            type Output = $t;
+
+            fn mul(self, rhs: $t) -> $t {
+                rhs * self
+            }
+        }
+
+        impl Index<usize> for $t {
+            type Output = f32;
+
+            fn index(&self, i: usize) -> &f32 {
+                match i {
+                    0 => &self.$a,
+                    1 => &self.$b,
+                    2 => &self.$c,
+                    _ => panic!("color component index {i} out of range"),
+                }
+            }
+        }
+    };
+    (@op $t:ident, $a:ident, $b:ident, $c:ident,
+     $op:ident, $fn:ident, $op_assign:ident, $fn_assign:ident, $sym:tt) => {
+        impl $op for $t {
+            type Output = Self;
+
+            fn $fn(self, rhs: Self) -> Self {
+                Self::new(self.$a $sym rhs.$a, self.$b $sym rhs.$b, self.$c $sym rhs.$c)
+            }
+        }
+
+        impl $op<f32> for $t {
+            type Output = Self;
+
+            fn $fn(self, rhs: f32) -> Self {
+                Self::new(self.$a $sym rhs, self.$b $sym rhs, self.$c $sym rhs)
+            }
+        }
+
+        impl $op_assign for $t {
+            fn $fn_assign(&mut self, rhs: Self) {
+                *self = *self $sym rhs;
+            }
+        }
+
+        impl $op_assign<f32> for $t {
+            fn $fn_assign(&mut self, rhs: f32) {
+                *self = *self $sym rhs;
+            }
+        }
+    };
+}
+
+color_ops_impl!(RGB, r, g, b);
+color_ops_impl!(XYZ, x, y, z);
+
+/// A smooth spectrum `s(λ) = S(c0 λ² + c1 λ + c2)`, with the sigmoid
+/// `S(x) = 1/2 + x / (2 √(1 + x²))` keeping it within `[0, 1]`.
+///
+/// Three coefficients are enough to match any RGB color inside the gamut
+/// of a color space, which is how RGB reflectances become spectra.
+///
+/// ```
+/// use pbrt::spectrum::RGBSigmoidPolynomial;
+///
+/// let s = RGBSigmoidPolynomial::new(0.0, 0.0, 0.0);
+/// assert_eq!(s.evaluate(550.0), 0.5);
+/// let peak = RGBSigmoidPolynomial::new(-1e-3, 1.1, -300.0);
+/// assert!(peak.max_value() >= peak.evaluate(550.0));
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct RGBSigmoidPolynomial {
+    c0: f32,
+    c1: f32,
+    c2: f32,
+}
+
+impl RGBSigmoidPolynomial {
+    /// Creates the spectrum with the polynomial coefficients `c0`, `c1` and
+    /// `c2`, for wavelengths in nanometers.
+    pub fn new(c0: f32, c1: f32, c2: f32) -> Self {
+        Self { c0, c1, c2 }
+    }
+
+    /// Returns the value at `lambda`, in nanometers.
+    pub fn evaluate(&self, lambda: f32) -> f32 {
+        sigmoid((self.c0 * lambda + self.c1) * lambda + self.c2)
+    }
+
+    /// Returns the largest value over the visible range.
+    pub fn max_value(&self) -> f32 {
+        let mut result = self.evaluate(LAMBDA_MIN).max(self.evaluate(LAMBDA_MAX));
+        let lambda = -self.c1 / (2.0 * self.c0);
+        if (LAMBDA_MIN..=LAMBDA_MAX).contains(&lambda) {
+            result = result.max(self.evaluate(lambda));
+        }
+        result
+    }
+}
+
+fn sigmoid(x: f32) -> f32 {
+    if x.is_infinite() {
+        return if x > 0.0 { 1.0 } else { 0.0 };
+    }
+    0.5 + x / (2.0 * (1.0 + x * x).sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::spectrum::color::{RGB, RGBSigmoidPolynomial, XYZ};
+    use crate::spectrum::{LAMBDA_MAX, LAMBDA_MIN};
+
+    #[test]
+    fn test_color_ops() {
+        let a = RGB::new(1.0, 2.0, 3.0);
+        assert_eq!(a + a, 2.0 * a);
+        assert_eq!(a / 2.0 * RGB::new(2.0, 2.0, 2.0), a);
+        assert_eq!((a[0], a[1], a[2]), (1.0, 2.0, 3.0));
+        assert_eq!(a.max_component(), 3.0);
+        assert_eq!(
+            RGB::new(-1.0, 0.5, 0.0).clamp_zero(),
+            RGB::new(0.0, 0.5, 0.0)
+        );
+        let mut c = XYZ::new(1.0, 1.0, 1.0);
+        c -= XYZ::new(0.5, 0.0, 0.25);
+        assert_eq!(c, XYZ::new(0.5, 1.0, 0.75));
+        assert_eq!(XYZ::from_xyy(0.3, 0.0, 1.0), XYZ::default());
+    }
+
+    #[test]
+    fn test_sigmoid_polynomial() {
+        // Extreme coefficients saturate rather than overflow.
+        assert_eq!(
+            RGBSigmoidPolynomial::new(0.0, 0.0, f32::INFINITY).evaluate(500.0),
+            1.0
+        );
+        assert_eq!(
+            RGBSigmoidPolynomial::new(0.0, 0.0, f32::NEG_INFINITY).evaluate(500.0),
+            0.0
+        );
+        // A downward parabola peaking at 512nm, where it is zero; the
+        // coefficients are exact so that the vertex is too.
+        let s = RGBSigmoidPolynomial::new(-1.0 / 1024.0, 1.0, -256.0);
+        assert_eq!(s.max_value(), 0.5);
+        assert_eq!(s.evaluate(512.0), 0.5);
+        assert!(s.max_value() > s.evaluate(LAMBDA_MIN).max(s.evaluate(LAMBDA_MAX)));
+        for lambda in (360..=830).step_by(10) {
+            let v = s.evaluate(lambda as f32);
+            assert!((0.0..=s.max_value()).contains(&v));
+        }
+    }
+}
@@ -0,0 +1,464 @@
+//! RGB color spaces, and the conversion of RGB colors to spectra.
+
+use std::sync::{Arc, OnceLock};
+
+use crate::math::functions::lerp;
+use crate::math::point2::Point2;
+use crate::math::square_matrix::SquareMatrix;
+use crate::math::vector3::Vector3;
+use crate::spectrum::color::{RGB, RGBSigmoidPolynomial, XYZ};
+use crate::spectrum::illuminants::{std_illuminant_d, std_illuminant_d65};
+use crate::spectrum::spectra::{DenselySampledSpectrum, Spectrum, spectrum_to_xyz};
+use crate::spectrum::{LAMBDA_MAX, LAMBDA_MIN};
+
+/// An RGB color space, given by the chromaticities of its primaries and
+/// its illuminant, whose color is the white point `(1, 1, 1)`.
+///
+/// ```
+/// use pbrt::spectrum::{RGB, RGBColorSpace, XYZ};
+///
+/// let srgb = RGBColorSpace::srgb();
+/// let white = srgb.to_rgb(XYZ::from_xyy(srgb.w().x, srgb.w().y, 1.0));
+/// assert!((white.r - 1.0).abs() < 1e-4 && (white.g - 1.0).abs() < 1e-4);
+/// let red = srgb.to_xyz(RGB::new(1.0, 0.0, 0.0));
+/// let (x, y) = red.xy();
+/// assert!((x - 0.64).abs() < 1e-4 && (y - 0.33).abs() < 1e-4);
+/// ```
+#[derive(Debug)]
+pub struct RGBColorSpace {
+    r: Point2<f32>,
+    g: Point2<f32>,
+    b: Point2<f32>,
+    w: Point2<f32>,
+    illuminant: Arc<DenselySampledSpectrum>,
+    xyz_from_rgb: SquareMatrix<f32, 3>,
+    rgb_from_xyz: SquareMatrix<f32, 3>,
+    table: &'static RGBToSpectrumTable,
+}
+
+impl RGBColorSpace {
+    /// Creates the color space with the primaries of chromaticities `r`, `g`
+    /// and `b` and the illuminant `illuminant`, which converts colors to
+    /// spectra with `table`.
+    pub fn new(
+        r: Point2<f32>,
+        g: Point2<f32>,
+        b: Point2<f32>,
+        illuminant: &Spectrum,
+        table: &'static RGBToSpectrumTable,
+    ) -> Self {
+        let white = spectrum_to_xyz(illuminant);
+        let (wx, wy) = white.xy();
+        let [rx, gx, bx] = [r, g, b].map(|p| XYZ::from_xyy(p.x, p.y, 1.0));
+        let rgb = SquareMatrix::new([[rx.x, gx.x, bx.x], [rx.y, gx.y, bx.y], [rx.z, gx.z, bx.z]]);
+        // Scale the primaries so that they add up to the white point.
+        let c = rgb.inverse().expect("color space primaries are collinear")
+            * Vector3::new(white.x, white.y, white.z);
+        let xyz_from_rgb = rgb * SquareMatrix::diag([c.x, c.y, c.z]);
+        let rgb_from_xyz = xyz_from_rgb
+            .inverse()
+            .expect("color space primaries are collinear");
+        let illuminant = Arc::new(DenselySampledSpectrum::new(
+            illuminant,
+            LAMBDA_MIN as i32,
+            LAMBDA_MAX as i32,
+        ));
+        Self {
+            r,
+            g,
+            b,
+            w: Point2::new(wx, wy),
+            illuminant,
+            xyz_from_rgb,
+            rgb_from_xyz,
+            table,
+        }
+    }
+
+    /// Returns the sRGB color space, with the D65 illuminant.
+    pub fn srgb() -> &'static RGBColorSpace {
+        static SRGB: OnceLock<RGBColorSpace> = OnceLock::new();
+        SRGB.get_or_init(|| {
+            RGBColorSpace::new(
+                Point2::new(0.64, 0.33),
+                Point2::new(0.3, 0.6),
+                Point2::new(0.15, 0.06),
+                &std_illuminant_d65(),
+                RGBToSpectrumTable::srgb(),
+            )
+        })
+    }
+
+    /// Returns the DCI-P3 color space of digital cinema, with the D65
+    /// illuminant.
+    pub fn dci_p3() -> &'static RGBColorSpace {
+        static DCI_P3: OnceLock<RGBColorSpace> = OnceLock::new();
+        DCI_P3.get_or_init(|| {
+            RGBColorSpace::new(
+                Point2::new(0.68, 0.32),
+                Point2::new(0.265, 0.69),
+                Point2::new(0.15, 0.06),
+                &std_illuminant_d65(),
+                RGBToSpectrumTable::dci_p3(),
+            )
+        })
+    }
+
+    /// Returns the ITU-R Rec. 2020 color space of ultra-high-definition
+    /// television, with the D65 illuminant.
+    pub fn rec2020() -> &'static RGBColorSpace {
+        static REC2020: OnceLock<RGBColorSpace> = OnceLock::new();
+        REC2020.get_or_init(|| {
+            RGBColorSpace::new(
+                Point2::new(0.708, 0.292),
+                Point2::new(0.17, 0.797),
+                Point2::new(0.131, 0.046),
+                &std_illuminant_d65(),
+                RGBToSpectrumTable::rec2020(),
+            )
+        })
+    }
+
+    /// Returns the ACES2065-1 color space, whose primaries enclose all
+    /// visible colors, with the ACES D60 illuminant.
+    pub fn aces2065_1() -> &'static RGBColorSpace {
+        static ACES2065_1: OnceLock<RGBColorSpace> = OnceLock::new();
+        ACES2065_1.get_or_init(|| {
+            RGBColorSpace::new(
+                Point2::new(0.7347, 0.2653),
+                Point2::new(0.0, 1.0),
+                Point2::new(0.0001, -0.077),
+                &std_illuminant_d(6000.0),
+                RGBToSpectrumTable::aces2065_1(),
+            )
+        })
+    }
+
+    /// Returns the chromaticity of the red primary.
+    pub fn r(&self) -> Point2<f32> {
+        self.r
+    }
+
+    /// Returns the chromaticity of the green primary.
+    pub fn g(&self) -> Point2<f32> {
+        self.g
+    }
+
+    /// Returns the chromaticity of the blue primary.
+    pub fn b(&self) -> Point2<f32> {
+        self.b
+    }
+
+    /// Returns the chromaticity of the white point.
+    pub fn w(&self) -> Point2<f32> {
+        self.w
+    }
+
+    /// Returns the illuminant, tabulated over the visible range.
+    pub fn illuminant(&self) -> &Arc<DenselySampledSpectrum> {
+        &self.illuminant
+    }
+
+    /// Returns the matrix from RGB to XYZ.
+    pub fn xyz_from_rgb(&self) -> SquareMatrix<f32, 3> {
+        self.xyz_from_rgb
+    }
+
+    /// Returns the matrix from XYZ to RGB.
+    pub fn rgb_from_xyz(&self) -> SquareMatrix<f32, 3> {
+        self.rgb_from_xyz
+    }
+
+    /// Converts an XYZ color to this color space.
+    pub fn to_rgb(&self, xyz: XYZ) -> RGB {
+        let v = self.rgb_from_xyz * Vector3::new(xyz.x, xyz.y, xyz.z);
+        RGB::new(v.x, v.y, v.z)
+    }
+
+    /// Converts a color in this color space to XYZ.
+    pub fn to_xyz(&self, rgb: RGB) -> XYZ {
+        let v = self.xyz_from_rgb * Vector3::new(rgb.r, rgb.g, rgb.b);
+        XYZ::new(v.x, v.y, v.z)
+    }
+
+    /// Returns the smooth reflectance spectrum that has the color `rgb`,
+    /// with components in `[0, 1]`, under this color space's illuminant.
+    ///
+    /// The coefficients are interpolated from the color space's
+    /// [`RGBToSpectrumTable`]. Colors on the boundary of the gamut can only
+    /// be approached, since the spectra are smooth.
+    pub fn to_rgb_coeffs(&self, rgb: RGB) -> RGBSigmoidPolynomial {
+        self.table.evaluate(rgb)
+    }
+}
+
+/// Sigmoid polynomial coefficients tabulated over the RGB cube, which
+/// convert the colors of a color space to reflectance spectra.
+///
+/// As in pbrt-v4, the tables are fitted ahead of time, by the build
+/// script, for each color space that [`RGBColorSpace`] provides.
+///
+/// A color is located by its largest component `z`, which selects one of
+/// three tables and the position along its last axis, and by the ratios
+/// of the other two components to `z`, which give the other two
+/// positions. The nodes along `z` are spaced more densely near 0 and 1,
+/// where the coefficients change fastest.
+///
+/// ```
+/// use pbrt::spectrum::{RGB, RGBToSpectrumTable};
+///
+/// // A reddish color gives a spectrum that rises towards long wavelengths.
+/// let s = RGBToSpectrumTable::srgb().evaluate(RGB::new(0.8, 0.2, 0.1));
+/// assert!(s.evaluate(650.0) > 0.5 && s.evaluate(450.0) < 0.2);
+/// ```
+pub struct RGBToSpectrumTable {
+    res: usize,
+    z_nodes: Vec<f32>,
+    /// The coefficients, indexed by the largest component, then the two
+    /// ratios and `z`.
+    coeffs: Vec<[f32; 3]>,
+}
+
+impl RGBToSpectrumTable {
+    /// Returns the table for [`RGBColorSpace::srgb`].
+    pub fn srgb() -> &'static RGBToSpectrumTable {
+        static SRGB: OnceLock<RGBToSpectrumTable> = OnceLock::new();
+        SRGB.get_or_init(|| {
+            Self::from_bytes(include_bytes!(concat!(
+                env!("OUT_DIR"),
+                "/rgbspectrum_srgb.bin"
+            )))
+        })
+    }
+
+    /// Returns the table for [`RGBColorSpace::dci_p3`].
+    pub fn dci_p3() -> &'static RGBToSpectrumTable {
+        static DCI_P3: OnceLock<RGBToSpectrumTable> = OnceLock::new();
+        DCI_P3.get_or_init(|| {
+            Self::from_bytes(include_bytes!(concat!(
+                env!("OUT_DIR"),
+                "/rgbspectrum_dci_p3.bin"
+            )))
+        })
+    }
+
+    /// Returns the table for [`RGBColorSpace::rec2020`].
+    pub fn rec2020() -> &'static RGBToSpectrumTable {
+        static REC2020: OnceLock<RGBToSpectrumTable> = OnceLock::new();
+        REC2020.get_or_init(|| {
+            Self::from_bytes(include_bytes!(concat!(
+                env!("OUT_DIR"),
+                "/rgbspectrum_rec2020.bin"
+            )))
+        })
+    }
+
+    /// Returns the table for [`RGBColorSpace::aces2065_1`].
+    pub fn aces2065_1() -> &'static RGBToSpectrumTable {
+        static ACES2065_1: OnceLock<RGBToSpectrumTable> = OnceLock::new();
+        ACES2065_1.get_or_init(|| {
+            Self::from_bytes(include_bytes!(concat!(
+                env!("OUT_DIR"),
+                "/rgbspectrum_aces2065_1.bin"
+            )))
+        })
+    }
+
+    /// Reads a table in the format that the build script writes.
+    fn from_bytes(bytes: &[u8]) -> Self {
+        let (res, data) = bytes.split_at(4);
+        let res = u32::from_le_bytes(res.try_into().unwrap()) as usize;
+        let mut values = data
+            .chunks_exact(4)
+            .map(|b| f32::from_le_bytes(b.try_into().unwrap()));
+        let z_nodes: Vec<f32> = values.by_ref().take(res).collect();
+        let coeffs: Vec<[f32; 3]> =
+            std::iter::from_fn(|| Some([values.next()?, values.next()?, values.next()?])).collect();
+        assert_eq!(coeffs.len(), 3 * res * res * res, "truncated table");
+        Self {
+            res,
+            z_nodes,
+            coeffs,
+        }
+    }
+
+    /// Returns the coefficients of the smooth reflectance spectrum that has
+    /// the color `rgb`, with components in `[0, 1]`.
+    pub fn evaluate(&self, rgb: RGB) -> RGBSigmoidPolynomial {
+        assert!(
+            rgb.max_component() <= 1.0 && rgb.min_component() >= 0.0,
+            "color {rgb:?} outside [0, 1]"
+        );
+        if rgb.r == rgb.g && rgb.g == rgb.b {
+            // A constant spectrum, with the sigmoid inverted by hand.
+            let v = rgb.r;
+            return RGBSigmoidPolynomial::new(0.0, 0.0, (v - 0.5) / (v * (1.0 - v)).sqrt());
+        }
+        let res = self.res;
+        let maxc = if rgb.r > rgb.g {
+            if rgb.r > rgb.b { 0 } else { 2 }
+        } else if rgb.g > rgb.b {
+            1
+        } else {
+            2
+        };
+        let z = rgb[maxc];
+        let x = rgb[(maxc + 1) % 3] * (res - 1) as f32 / z;
+        let y = rgb[(maxc + 2) % 3] * (res - 1) as f32 / z;
+        let xi = (x as usize).min(res - 2);
+        let yi = (y as usize).min(res - 2);
+        let zi = self
+            .z_nodes
+            .partition_point(|&node| node <= z)
+            .clamp(1, res - 1)
+            - 1;
+        let (dx, dy) = (x - xi as f32, y - yi as f32);
+        let dz = (z - self.z_nodes[zi]) / (self.z_nodes[zi + 1] - self.z_nodes[zi]);
+        let co = |i: usize, j: usize, k: usize, c: usize| {
+            self.coeffs[((maxc * res + yi + j) * res + xi + i) * res + zi + k][c]
+        };
+        let c = [0, 1, 2].map(|c| {
+            lerp(
+                dz,
+                lerp(
+                    dy,
+                    lerp(dx, co(0, 0, 0, c), co(1, 0, 0, c)),
+                    lerp(dx, co(0, 1, 0, c), co(1, 1, 0, c)),
+                ),
+                lerp(
+                    dy,
+                    lerp(dx, co(0, 0, 1, c), co(1, 0, 1, c)),
+                    lerp(dx, co(0, 1, 1, c), co(1, 1, 1, c)),
+                ),
+            )
+        });
+        RGBSigmoidPolynomial::new(c[0], c[1], c[2])
+    }
+}
+
+impl std::fmt::Debug for RGBToSpectrumTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RGBToSpectrumTable")
+            .field("res", &self.res)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::point2::Point2;
+    use crate::spectrum::color::{RGB, XYZ};
+    use crate::spectrum::color_space::{RGBColorSpace, RGBToSpectrumTable};
+    use crate::spectrum::illuminants::std_illuminant_d50;
+    use crate::spectrum::spectra::{cie_x, cie_y, cie_z};
+    use crate::spectrum::{LAMBDA_MAX, LAMBDA_MIN};
+
+    #[test]
+    fn test_srgb_matrices() {
+        let cs = RGBColorSpace::srgb();
+        // The standard sRGB matrix, which the white point computed from the
+        // D65 spectrum should reproduce closely.
+        let expected = [
+            [0.4124, 0.3576, 0.1805],
+            [0.2126, 0.7152, 0.0722],
+            [0.0193, 0.1192, 0.9505],
+        ];
+        let m = cs.xyz_from_rgb();
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((m[i][j] - expected[i][j]).abs() < 2e-3, "{m}");
+            }
+        }
+        let rgb = RGB::new(0.2, 0.7, 0.4);
+        let back = cs.to_rgb(cs.to_xyz(rgb));
+        for c in 0..3 {
+            assert!((back[c] - rgb[c]).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_white_point() {
+        let cs = RGBColorSpace::new(
+            Point2::new(0.7347, 0.2653),
+            Point2::new(0.1596, 0.8404),
+            Point2::new(0.0366, 0.0001),
+            &std_illuminant_d50(),
+            // Only used to convert colors to spectra, which is not tested.
+            RGBToSpectrumTable::srgb(),
+        );
+        assert!((cs.w().x - 0.3457).abs() < 1e-3 && (cs.w().y - 0.3585).abs() < 1e-3);
+        let white = cs.to_rgb(XYZ::from_xyy(cs.w().x, cs.w().y, 1.0));
+        for c in 0..3 {
+            assert!((white[c] - 1.0).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_rgb_coeffs_gray() {
+        let cs = RGBColorSpace::srgb();
+        for v in [0.0, 0.25, 0.5, 1.0] {
+            let s = cs.to_rgb_coeffs(RGB::new(v, v, v));
+            assert!((s.evaluate(400.0) - v).abs() < 1e-6);
+            assert!((s.evaluate(700.0) - v).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_named_color_spaces() {
+        let aces = RGBColorSpace::aces2065_1();
+        assert!((aces.w().x - 0.32168).abs() < 1e-3 && (aces.w().y - 0.33767).abs() < 1e-3);
+        for cs in [RGBColorSpace::dci_p3(), RGBColorSpace::rec2020()] {
+            assert!((cs.w().x - 0.3127).abs() < 1e-3 && (cs.w().y - 0.3290).abs() < 1e-3);
+        }
+        let (x, y) = RGBColorSpace::rec2020()
+            .to_xyz(RGB::new(0.0, 1.0, 0.0))
+            .xy();
+        assert!((x - 0.17).abs() < 1e-4 && (y - 0.797).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_rgb_coeffs_round_trip() {
+        // The reflectance spectra that the tables give, lit by the color
+        // space's illuminant, have the colors they were looked up with.
+        // The colors are given in sRGB, since saturated colors in the wider
+        // spaces may not be the colors of any reflectance.
+        let srgb = RGBColorSpace::srgb();
+        let colors = [
+            RGB::new(0.3, 0.55, 0.2),
+            RGB::new(0.8, 0.2, 0.1),
+            RGB::new(0.05, 0.1, 0.6),
+            RGB::new(0.9, 0.85, 0.7),
+            RGB::new(0.02, 0.01, 0.015),
+            RGB::new(0.5, 0.5, 0.49),
+        ];
+        for cs in [
+            RGBColorSpace::srgb(),
+            RGBColorSpace::dci_p3(),
+            RGBColorSpace::rec2020(),
+            RGBColorSpace::aces2065_1(),
+        ] {
+            for rgb in colors {
+                let rgb = cs.to_rgb(srgb.to_xyz(rgb));
+                let s = cs.to_rgb_coeffs(rgb);
+                let (mut xyz, mut y_sum) = ([0.0; 3], 0.0);
+                for lambda in LAMBDA_MIN as i32..=LAMBDA_MAX as i32 {
+                    let lambda = lambda as f32;
+                    let illum = f64::from(cs.illuminant().evaluate(lambda));
+                    let v = f64::from(s.evaluate(lambda)) * illum;
+                    for (c, cmf) in xyz.iter_mut().zip([cie_x(), cie_y(), cie_z()]) {
+                        *c += v * f64::from(cmf.evaluate(lambda));
+                    }
+                    y_sum += illum * f64::from(cie_y().evaluate(lambda));
+                }
+                let [x, y, z] = xyz.map(|c| (c / y_sum) as f32);
+                let back = cs.to_rgb(XYZ::new(x, y, z));
+                for c in 0..3 {
+                    assert!(
+                        (back[c] - rgb[c]).abs() < 2e-3,
+                        "{rgb:?} came back as {back:?}"
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,49 @@
+//! Spectral representation of light and reflectance.
+//!
+//! Light transport is computed at a handful of wavelengths at a time: a
+//! [`SampledWavelengths`] picks them for each camera ray and
+//! [`SampledSpectrum`] carries values at them through the computation.
+//! Scene descriptions give spectra as [`Spectrum`]s, which can be evaluated
+//! at any wavelength, and colors are converted to and from spectra through
+//! an [`RGBColorSpace`].
+//!
+//! ```
+//! use pbrt::spectrum::{BlackbodySpectrum, SampledWavelengths, Spectrum};
+//!
+//! let lambda = SampledWavelengths::sample_uniform(0.5);
+//! let s = Spectrum::from(BlackbodySpectrum::new(6500.0)).sample(&lambda);
+//! assert!(s.max_value() <= 1.0 && s.min_value() > 0.0);
+//! ```
+
+pub mod cie;
+pub mod color;
+pub mod color_space;
+pub mod illuminants;
+pub mod named;
+pub mod sampled;
+pub mod spectra;
+
+/// The shortest wavelength, in nanometers, that spectra are defined over.
+pub const LAMBDA_MIN: f32 = 360.0;
+
+/// The longest wavelength, in nanometers, that spectra are defined over.
+pub const LAMBDA_MAX: f32 = 830.0;
+
+/// The number of wavelengths a [`SampledSpectrum`] holds.
+pub const N_SPECTRUM_SAMPLES: usize = 4;
+
+pub use cie::{CIE_Y_INTEGRAL, N_CIE_SAMPLES};
+pub use color::{RGB, RGBSigmoidPolynomial, XYZ};
+pub use color_space::{RGBColorSpace, RGBToSpectrumTable};
+pub use illuminants::{
+    std_illuminant_a, std_illuminant_d, std_illuminant_d50, std_illuminant_d65, std_illuminant_f,
+};
+pub use sampled::{
+    SampledSpectrum, SampledWavelengths, safe_div, sample_visible_wavelengths,
+    visible_wavelengths_pdf,
+};
+pub use spectra::{
+    BlackbodySpectrum, ConstantSpectrum, DenselySampledSpectrum, PiecewiseLinearSpectrum,
+    RGBAlbedoSpectrum, RGBIlluminantSpectrum, RGBUnboundedSpectrum, Spectrum, blackbody, cie_x,
+    cie_y, cie_z, get_named, inner_product, spectrum_to_xyz,
+};
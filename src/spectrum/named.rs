@@ -0,0 +1,316 @@
+//! The optical constants behind the named spectra of
+//! [`get_named`](crate::spectrum::spectra::get_named).
+//!
+//! - Ag, Al, Au and Cu use the measured complex refractive indices that
+//!   pbrt tabulates, from 298nm to 886nm.
+//! - Glasses use the manufacturers' Sellmeier coefficients (Schott, and
+//!   Malitson's for fused silica), which match the catalog refractive
+//!   indices to a few parts in 10⁵. They are sampled every 5nm over the
+//!   visible range, as are the two dielectrics below.
+//! - MgO uses the dispersion formula of Stephens and Malitson (1952), and
+//!   TiO₂ the ordinary index of rutile from DeVore (1951). Only their `eta`
+//!   is provided: there is no `metal-MgO-k` or `metal-TiO2-k`.
+//!
+//! pbrt-v4's measured tables for brass (CuZn), for the absorption of MgO
+//! and TiO₂, and for the camera sensor response curves are not included,
+//! so those names are not found.
+
+use crate::spectrum::spectra::{PiecewiseLinearSpectrum, Spectrum};
+use crate::spectrum::{LAMBDA_MAX, LAMBDA_MIN};
+
+/// The spacing, in nanometers, at which the models are sampled.
+const SAMPLE_SPACING: usize = 5;
+
+/// Samples `f`, a function of the wavelength in micrometers, over the
+/// visible range.
+fn sample(f: impl Fn(f64) -> f64) -> Spectrum {
+    let (lambdas, values) = (LAMBDA_MIN as i32..=LAMBDA_MAX as i32)
+        .step_by(SAMPLE_SPACING)
+        .map(|lambda| (lambda as f32, f(f64::from(lambda) * 1e-3) as f32))
+        .unzip();
+    PiecewiseLinearSpectrum::new(lambdas, values).into()
+}
+
+/// The Sellmeier coefficients `B` and `C`, with `C` in μm², of a glass.
+type Sellmeier = ([f64; 3], [f64; 3]);
+
+/// Returns the refractive index given by the Sellmeier equation
+/// `n² = 1 + Σ Bᵢ λ² / (λ² − Cᵢ)` at `lambda` micrometers.
+fn sellmeier((b, c): &Sellmeier, lambda: f64) -> f64 {
+    let l2 = lambda * lambda;
+    let n2 = 1.0 + (0..3).map(|i| b[i] * l2 / (l2 - c[i])).sum::<f64>();
+    n2.sqrt()
+}
+
+const GLASS_BK7: Sellmeier = (
+    [1.039_612_12, 0.231_792_344, 1.010_469_45],
+    [0.006_000_698_67, 0.020_017_914_4, 103.560_653],
+);
+
+const GLASS_BAF10: Sellmeier = (
+    [1.585_149_5, 0.143_559_385, 1.085_212_69],
+    [0.009_266_812_82, 0.042_448_980_5, 105.613_573],
+);
+
+const GLASS_FK51A: Sellmeier = (
+    [0.971_247_817, 0.216_901_417, 0.904_651_666],
+    [0.004_723_019_95, 0.015_357_561_2, 168.681_33],
+);
+
+const GLASS_LASF9: Sellmeier = (
+    [2.000_295_47, 0.298_926_886, 1.806_918_43],
+    [0.012_142_601_7, 0.053_873_623_6, 156.530_829],
+);
+
+const GLASS_SF5: Sellmeier = (
+    [1.524_818_89, 0.187_085_527, 1.427_290_15],
+    [0.011_254_756, 0.058_899_539_2, 129.141_675],
+);
+
+const GLASS_SF10: Sellmeier = (
+    [1.621_539_02, 0.256_287_842, 1.644_475_52],
+    [0.012_224_145_7, 0.059_573_677_5, 147.468_793],
+);
+
+const GLASS_SF11: Sellmeier = (
+    [1.737_596_95, 0.313_747_346, 1.898_781_01],
+    [0.013_188_707, 0.062_306_814_2, 155.236_29],
+);
+
+const FUSED_SILICA: Sellmeier = (
+    [0.696_166_3, 0.407_942_6, 0.897_479_4],
+    [
+        0.068_404_3 * 0.068_404_3,
+        0.116_241_4 * 0.116_241_4,
+        9.896_161 * 9.896_161,
+    ],
+);
+
+// The complex refractive indices of the metals, as interleaved wavelengths
+// in nanometers and values, as tabulated by pbrt.
+
+#[rustfmt::skip]
+const METAL_AG_ETA: [f32; 112] = [
+    298.75705, 1.519, 302.40042, 1.496, 306.13376, 1.4325, 309.96045, 1.323, 313.884, 1.142062,
+    317.90814, 0.932, 322.03683, 0.719062, 326.27414, 0.526, 330.62448, 0.388125, 335.09238, 0.294,
+    339.68268, 0.253313, 344.40048, 0.238, 349.25122, 0.221438, 354.2405, 0.209,
+    359.37442, 0.194813, 364.65933, 0.186, 370.10202, 0.192063, 375.70963, 0.2, 381.48978, 0.198063,
+    387.45056, 0.192, 393.60055, 0.182, 399.94898, 0.173, 406.5055, 0.172625, 413.28058, 0.173,
+    420.28534, 0.166688, 427.53165, 0.16, 435.0322, 0.1585, 442.80063, 0.157, 450.85156, 0.151063,
+    459.20065, 0.144, 467.86484, 0.137313, 476.8622, 0.132, 486.21246, 0.13025, 495.9367, 0.13,
+    506.05786, 0.129938, 516.60077, 0.13, 527.5922, 0.130063, 539.06165, 0.129, 551.0408, 0.124375,
+    563.56445, 0.12, 576.6706, 0.119313, 590.4008, 0.121, 604.80084, 0.1255, 619.9209, 0.131,
+    635.8163, 0.136125, 652.5483, 0.14, 670.18475, 0.140063, 688.80096, 0.14, 708.481, 0.144313,
+    729.31867, 0.148, 751.41925, 0.145875, 774.9011, 0.143, 799.89795, 0.142563, 826.56116, 0.145,
+    855.0633, 0.151938, 885.60126, 0.163,
+];
+
+#[rustfmt::skip]
+const METAL_AG_K: [f32; 112] = [
+    298.75705, 1.08, 302.40042, 0.882, 306.13376, 0.761063, 309.96045, 0.647, 313.884, 0.550875,
+    317.90814, 0.504, 322.03683, 0.554375, 326.27414, 0.663, 330.62448, 0.818563, 335.09238, 0.986,
+    339.68268, 1.120687, 344.40048, 1.24, 349.25122, 1.34525, 354.2405, 1.44, 359.37442, 1.53375,
+    364.65933, 1.61, 370.10202, 1.641875, 375.70963, 1.67, 381.48978, 1.735, 387.45056, 1.81,
+    393.60055, 1.87875, 399.94898, 1.95, 406.5055, 2.029375, 413.28058, 2.11, 420.28534, 2.18625,
+    427.53165, 2.26, 435.0322, 2.329375, 442.80063, 2.4, 450.85156, 2.47875, 459.20065, 2.56,
+    467.86484, 2.64, 476.8622, 2.72, 486.21246, 2.798125, 495.9367, 2.88, 506.05786, 2.97375,
+    516.60077, 3.07, 527.5922, 3.159375, 539.06165, 3.25, 551.0408, 3.348125, 563.56445, 3.45,
+    576.6706, 3.55375, 590.4008, 3.66, 604.80084, 3.76625, 619.9209, 3.88, 635.8163, 4.010625,
+    652.5483, 4.15, 670.18475, 4.293125, 688.80096, 4.44, 708.481, 4.58625, 729.31867, 4.74,
+    751.41925, 4.908125, 774.9011, 5.09, 799.89795, 5.28875, 826.56116, 5.5, 855.0633, 5.720624,
+    885.60126, 5.95,
+];
+
+#[rustfmt::skip]
+const METAL_AL_ETA: [f32; 112] = [
+    298.75705, 0.273375, 302.40042, 0.28, 306.13376, 0.286813, 309.96045, 0.294, 313.884, 0.301875,
+    317.90814, 0.31, 322.03683, 0.317875, 326.27414, 0.326, 330.62448, 0.33375, 335.09238, 0.342,
+    339.68268, 0.35125, 344.40048, 0.36, 349.25122, 0.368375, 354.2405, 0.377, 359.37442, 0.387375,
+    364.65933, 0.398, 370.10202, 0.407, 375.70963, 0.416, 381.48978, 0.431063, 387.45056, 0.446,
+    393.60055, 0.45425, 399.94898, 0.465, 406.5055, 0.489375, 413.28058, 0.511, 420.28534, 0.52,
+    427.53165, 0.531, 435.0322, 0.557625, 442.80063, 0.583, 450.85156, 0.599, 459.20065, 0.617,
+    467.86484, 0.649375, 476.8622, 0.678, 486.21246, 0.69575, 495.9367, 0.716, 506.05786, 0.7475,
+    516.60077, 0.782, 527.5922, 0.826, 539.06165, 0.871, 551.0408, 0.909, 563.56445, 0.951,
+    576.6706, 1.004375, 590.4008, 1.06, 604.80084, 1.125, 619.9209, 1.19, 635.8163, 1.26125,
+    652.5483, 1.33, 670.18475, 1.3975, 688.80096, 1.47, 708.481, 1.545, 729.31867, 1.61,
+    751.41925, 1.66, 774.9011, 1.71, 799.89795, 1.7575, 826.56116, 1.8, 855.0633, 1.80875,
+    885.60126, 1.8,
+];
+
+// 6.28 is a measurement, not an approximation of τ.
+#[allow(clippy::approx_constant)]
+#[rustfmt::skip]
+const METAL_AL_K: [f32; 112] = [
+    298.75705, 3.59375, 302.40042, 3.64, 306.13376, 3.689375, 309.96045, 3.74, 313.884, 3.789375,
+    317.90814, 3.84, 322.03683, 3.894375, 326.27414, 3.95, 330.62448, 4.005, 335.09238, 4.06,
+    339.68268, 4.11375, 344.40048, 4.17, 349.25122, 4.23375, 354.2405, 4.3, 359.37442, 4.365,
+    364.65933, 4.43, 370.10202, 4.49375, 375.70963, 4.56, 381.48978, 4.63375, 387.45056, 4.71,
+    393.60055, 4.784375, 399.94898, 4.86, 406.5055, 4.938125, 413.28058, 5.02, 420.28534, 5.10875,
+    427.53165, 5.2, 435.0322, 5.29, 442.80063, 5.38, 450.85156, 5.48, 459.20065, 5.58,
+    467.86484, 5.69, 476.8622, 5.8, 486.21246, 5.915, 495.9367, 6.03, 506.05786, 6.15,
+    516.60077, 6.28, 527.5922, 6.42, 539.06165, 6.55, 551.0408, 6.7, 563.56445, 6.85, 576.6706, 7.0,
+    590.4008, 7.15, 604.80084, 7.310625, 619.9209, 7.48, 635.8163, 7.65, 652.5483, 7.82,
+    670.18475, 8.010625, 688.80096, 8.21, 708.481, 8.39, 729.31867, 8.57, 751.41925, 8.62125,
+    774.9011, 8.6, 799.89795, 8.45, 826.56116, 8.31, 855.0633, 8.2375, 885.60126, 8.21,
+];
+
+#[rustfmt::skip]
+const METAL_AU_ETA: [f32; 112] = [
+    298.75705, 1.795, 302.40042, 1.812, 306.13376, 1.822625, 309.96045, 1.83, 313.884, 1.837125,
+    317.90814, 1.84, 322.03683, 1.83425, 326.27414, 1.824, 330.62448, 1.812, 335.09238, 1.798,
+    339.68268, 1.782, 344.40048, 1.766, 349.25122, 1.7525, 354.2405, 1.74, 359.37442, 1.727625,
+    364.65933, 1.716, 370.10202, 1.705875, 375.70963, 1.696, 381.48978, 1.68475, 387.45056, 1.674,
+    393.60055, 1.666, 399.94898, 1.658, 406.5055, 1.64725, 413.28058, 1.636, 420.28534, 1.628,
+    427.53165, 1.616, 435.0322, 1.59625, 442.80063, 1.562, 450.85156, 1.502125, 459.20065, 1.426,
+    467.86484, 1.345875, 476.8622, 1.242, 486.21246, 1.08675, 495.9367, 0.916, 506.05786, 0.7545,
+    516.60077, 0.608, 527.5922, 0.49175, 539.06165, 0.402, 551.0408, 0.3455, 563.56445, 0.306,
+    576.6706, 0.267625, 590.4008, 0.236, 604.80084, 0.212375, 619.9209, 0.194, 635.8163, 0.17775,
+    652.5483, 0.166, 670.18475, 0.161, 688.80096, 0.16, 708.481, 0.160875, 729.31867, 0.164,
+    751.41925, 0.1695, 774.9011, 0.176, 799.89795, 0.181375, 826.56116, 0.188, 855.0633, 0.198125,
+    885.60126, 0.21,
+];
+
+#[rustfmt::skip]
+const METAL_AU_K: [f32; 112] = [
+    298.75705, 1.920375, 302.40042, 1.92, 306.13376, 1.918875, 309.96045, 1.916, 313.884, 1.911375,
+    317.90814, 1.904, 322.03683, 1.891375, 326.27414, 1.878, 330.62448, 1.86825, 335.09238, 1.86,
+    339.68268, 1.85175, 344.40048, 1.846, 349.25122, 1.84525, 354.2405, 1.848, 359.37442, 1.852375,
+    364.65933, 1.862, 370.10202, 1.883, 375.70963, 1.906, 381.48978, 1.9225, 387.45056, 1.936,
+    393.60055, 1.94775, 399.94898, 1.956, 406.5055, 1.959375, 413.28058, 1.958, 420.28534, 1.951375,
+    427.53165, 1.94, 435.0322, 1.9245, 442.80063, 1.904, 450.85156, 1.875875, 459.20065, 1.846,
+    467.86484, 1.814625, 476.8622, 1.796, 486.21246, 1.797375, 495.9367, 1.84, 506.05786, 1.9565,
+    516.60077, 2.12, 527.5922, 2.32625, 539.06165, 2.54, 551.0408, 2.730625, 563.56445, 2.88,
+    576.6706, 2.940625, 590.4008, 2.97, 604.80084, 3.015, 619.9209, 3.06, 635.8163, 3.07,
+    652.5483, 3.15, 670.18475, 3.445812, 688.80096, 3.8, 708.481, 4.087687, 729.31867, 4.357,
+    751.41925, 4.610188, 774.9011, 4.86, 799.89795, 5.125813, 826.56116, 5.39, 855.0633, 5.63125,
+    885.60126, 5.88,
+];
+
+#[rustfmt::skip]
+const METAL_CU_ETA: [f32; 112] = [
+    298.75705, 1.400313, 302.40042, 1.38, 306.13376, 1.358438, 309.96045, 1.34, 313.884, 1.329063,
+    317.90814, 1.325, 322.03683, 1.3325, 326.27414, 1.34, 330.62448, 1.334375, 335.09238, 1.325,
+    339.68268, 1.317812, 344.40048, 1.31, 349.25122, 1.300313, 354.2405, 1.29, 359.37442, 1.281563,
+    364.65933, 1.27, 370.10202, 1.249062, 375.70963, 1.225, 381.48978, 1.2, 387.45056, 1.18,
+    393.60055, 1.174375, 399.94898, 1.175, 406.5055, 1.1775, 413.28058, 1.18, 420.28534, 1.178125,
+    427.53165, 1.175, 435.0322, 1.172812, 442.80063, 1.17, 450.85156, 1.165312, 459.20065, 1.16,
+    467.86484, 1.155312, 476.8622, 1.15, 486.21246, 1.142812, 495.9367, 1.135, 506.05786, 1.131562,
+    516.60077, 1.12, 527.5922, 1.092437, 539.06165, 1.04, 551.0408, 0.950375, 563.56445, 0.826,
+    576.6706, 0.645875, 590.4008, 0.468, 604.80084, 0.35125, 619.9209, 0.272, 635.8163, 0.230813,
+    652.5483, 0.214, 670.18475, 0.20925, 688.80096, 0.213, 708.481, 0.21625, 729.31867, 0.223,
+    751.41925, 0.2365, 774.9011, 0.25, 799.89795, 0.254188, 826.56116, 0.26, 855.0633, 0.28,
+    885.60126, 0.3,
+];
+
+#[rustfmt::skip]
+const METAL_CU_K: [f32; 112] = [
+    298.75705, 1.662125, 302.40042, 1.687, 306.13376, 1.703313, 309.96045, 1.72, 313.884, 1.744563,
+    317.90814, 1.77, 322.03683, 1.791625, 326.27414, 1.81, 330.62448, 1.822125, 335.09238, 1.834,
+    339.68268, 1.85175, 344.40048, 1.872, 349.25122, 1.89425, 354.2405, 1.916, 359.37442, 1.931688,
+    364.65933, 1.95, 370.10202, 1.972438, 375.70963, 2.015, 381.48978, 2.121562, 387.45056, 2.21,
+    393.60055, 2.177188, 399.94898, 2.13, 406.5055, 2.160063, 413.28058, 2.21, 420.28534, 2.249938,
+    427.53165, 2.289, 435.0322, 2.326, 442.80063, 2.362, 450.85156, 2.397625, 459.20065, 2.433,
+    467.86484, 2.469187, 476.8622, 2.504, 486.21246, 2.535875, 495.9367, 2.564, 506.05786, 2.589625,
+    516.60077, 2.605, 527.5922, 2.595562, 539.06165, 2.583, 551.0408, 2.5765, 563.56445, 2.599,
+    576.6706, 2.678062, 590.4008, 2.809, 604.80084, 3.01075, 619.9209, 3.24, 635.8163, 3.458187,
+    652.5483, 3.67, 670.18475, 3.863125, 688.80096, 4.05, 708.481, 4.239563, 729.31867, 4.43,
+    751.41925, 4.619563, 774.9011, 4.817, 799.89795, 5.034125, 826.56116, 5.26, 855.0633, 5.485625,
+    885.60126, 5.717,
+];
+/// Returns the refractive index of MgO at `lambda` micrometers.
+fn mgo(lambda: f64) -> f64 {
+    let l2 = lambda * lambda;
+    (2.956_362 - 0.010_623_87 * l2 - 0.000_020_4 * l2 * l2 + 0.021_957_7 / (l2 - 0.014_283_22))
+        .sqrt()
+}
+
+/// Returns the ordinary refractive index of rutile TiO₂ at `lambda`
+/// micrometers.
+fn tio2(lambda: f64) -> f64 {
+    (5.913 + 0.2441 / (lambda * lambda - 0.0803)).sqrt()
+}
+
+/// Returns the named spectra of materials, by name.
+pub(crate) fn material_spectra() -> Vec<(&'static str, Spectrum)> {
+    let mut spectra = Vec::new();
+    for (name, glass) in [
+        ("glass-BK7", GLASS_BK7),
+        ("glass-BAF10", GLASS_BAF10),
+        ("glass-FK51A", GLASS_FK51A),
+        ("glass-LASF9", GLASS_LASF9),
+        ("glass-F5", GLASS_SF5),
+        ("glass-F10", GLASS_SF10),
+        ("glass-F11", GLASS_SF11),
+        ("glass-fused-silica", FUSED_SILICA),
+    ] {
+        spectra.push((name, sample(|lambda| sellmeier(&glass, lambda))));
+    }
+    for (name, data) in [
+        ("metal-Ag-eta", &METAL_AG_ETA),
+        ("metal-Ag-k", &METAL_AG_K),
+        ("metal-Al-eta", &METAL_AL_ETA),
+        ("metal-Al-k", &METAL_AL_K),
+        ("metal-Au-eta", &METAL_AU_ETA),
+        ("metal-Au-k", &METAL_AU_K),
+        ("metal-Cu-eta", &METAL_CU_ETA),
+        ("metal-Cu-k", &METAL_CU_K),
+    ] {
+        spectra.push((
+            name,
+            PiecewiseLinearSpectrum::from_interleaved(data, false).into(),
+        ));
+    }
+    spectra.push(("metal-MgO-eta", sample(mgo)));
+    spectra.push(("metal-TiO2-eta", sample(tio2)));
+    spectra
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::spectrum::named::{
+        FUSED_SILICA, GLASS_BAF10, GLASS_BK7, GLASS_SF11, METAL_AG_ETA, METAL_AG_K, METAL_AL_ETA,
+        METAL_AL_K, METAL_AU_ETA, METAL_AU_K, METAL_CU_ETA, METAL_CU_K, mgo, sellmeier,
+    };
+    use crate::spectrum::spectra::PiecewiseLinearSpectrum;
+
+    #[test]
+    fn test_glass_catalog_indices() {
+        // Indices at the helium d line, and Abbe numbers, from the catalogs.
+        for (glass, n_d, abbe) in [
+            (GLASS_BK7, 1.5168, 64.17),
+            (GLASS_BAF10, 1.67003, 47.11),
+            (GLASS_SF11, 1.78472, 25.68),
+            (FUSED_SILICA, 1.45846, 67.82),
+        ] {
+            let n = |lambda| sellmeier(&glass, lambda);
+            assert!((n(0.587_56) - n_d).abs() < 1e-4);
+            let v = (n(0.587_56) - 1.0) / (n(0.486_13) - n(0.656_27));
+            assert!((v - abbe).abs() < 0.1, "{v}");
+        }
+        assert!((mgo(0.5893) - 1.7355).abs() < 3e-3);
+    }
+
+    #[test]
+    fn test_metal_colors() {
+        let reflectance = |data: &[f32], k: &[f32], lambda| {
+            let eta = PiecewiseLinearSpectrum::from_interleaved(data, false).evaluate(lambda);
+            let k = PiecewiseLinearSpectrum::from_interleaved(k, false).evaluate(lambda);
+            ((eta - 1.0) * (eta - 1.0) + k * k) / ((eta + 1.0) * (eta + 1.0) + k * k)
+        };
+        // Gold and copper reflect red far better than blue.
+        for (eta, k) in [(&METAL_AU_ETA, &METAL_AU_K), (&METAL_CU_ETA, &METAL_CU_K)] {
+            assert!(reflectance(eta, k, 450.0) < 0.6 && reflectance(eta, k, 650.0) > 0.85);
+        }
+        // Silver and aluminum are bright and nearly neutral.
+        for (eta, k) in [(&METAL_AG_ETA, &METAL_AG_K), (&METAL_AL_ETA, &METAL_AL_K)] {
+            for lambda in [450.0, 550.0, 650.0] {
+                assert!(reflectance(eta, k, lambda) > 0.85);
+            }
+        }
+        // The tables interpolate linearly between the tabulated wavelengths.
+        let eta = PiecewiseLinearSpectrum::from_interleaved(&METAL_CU_ETA, false);
+        assert_eq!(eta.evaluate(METAL_CU_ETA[82]), 0.468);
+        let mid = (METAL_CU_ETA[82] + METAL_CU_ETA[84]) / 2.0;
+        assert!((eta.evaluate(mid) - (0.468 + 0.35125) / 2.0).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,422 @@
+//! Spectra sampled at a few wavelengths, and the choice of those
+//! wavelengths.
+
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+
+use crate::spectrum::color::{RGB, XYZ};
+use crate::spectrum::color_space::RGBColorSpace;
+use crate::spectrum::spectra::{cie_x, cie_y, cie_z};
+use crate::spectrum::{CIE_Y_INTEGRAL, LAMBDA_MAX, LAMBDA_MIN, N_SPECTRUM_SAMPLES};
+
+/// The values of a spectral quantity at the wavelengths of a
+/// [`SampledWavelengths`]. Arithmetic is per wavelength.
+///
+/// ```
+/// use pbrt::spectrum::{SampledSpectrum, safe_div};
+///
+/// let a = SampledSpectrum::from_array([1.0, 2.0, 3.0, 4.0]);
+/// let b = SampledSpectrum::new(2.0);
+/// assert_eq!(a * b - b, SampledSpectrum::from_array([0.0, 2.0, 4.0, 6.0]));
+/// assert_eq!(a.average(), 2.5);
+/// assert_eq!(a.max_value(), 4.0);
+/// let zero = SampledSpectrum::new(0.0);
+/// assert_eq!(safe_div(a, zero), zero);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct SampledSpectrum {
+    values: [f32; N_SPECTRUM_SAMPLES],
+}
+
+impl SampledSpectrum {
+    /// Creates a spectrum with the value `c` at every wavelength.
+    pub fn new(c: f32) -> Self {
+        Self {
+            values: [c; N_SPECTRUM_SAMPLES],
+        }
+    }
+
+    /// Creates a spectrum from its values at each wavelength.
+    pub fn from_array(values: [f32; N_SPECTRUM_SAMPLES]) -> Self {
+        Self { values }
+    }
+
+    /// Returns the values at each wavelength.
+    pub fn values(&self) -> [f32; N_SPECTRUM_SAMPLES] {
+        self.values
+    }
+
+    /// Returns whether any value is nonzero.
+    pub fn is_nonzero(&self) -> bool {
+        self.values.iter().any(|&v| v != 0.0)
+    }
+
+    /// Returns the largest value.
+    pub fn max_value(&self) -> f32 {
+        self.values
+            .iter()
+            .copied()
+            .fold(f32::NEG_INFINITY, f32::max)
+    }
+
+    /// Returns the smallest value.
+    pub fn min_value(&self) -> f32 {
+        self.values.iter().copied().fold(f32::INFINITY, f32::min)
+    }
+
+    /// Returns the mean of the values.
+    pub fn average(&self) -> f32 {
+        self.values.iter().sum::<f32>() / N_SPECTRUM_SAMPLES as f32
+    }
+
+    /// Returns whether any value is NaN.
+    pub fn has_nan(&self) -> bool {
+        self.values.iter().any(|v| v.is_nan())
+    }
+
+    /// Applies `f` to each value.
+    pub fn map(self, f: impl Fn(f32) -> f32) -> Self {
+        Self {
+            values: self.values.map(f),
+        }
+    }
+
+    /// Returns the square root of each value.
+    pub fn sqrt(self) -> Self {
+        self.map(f32::sqrt)
+    }
+
+    /// Returns `e` raised to each value.
+    pub fn exp(self) -> Self {
+        self.map(f32::exp)
+    }
+
+    /// Returns the values with negative ones replaced by zero.
+    pub fn clamp_zero(self) -> Self {
+        self.map(|v| v.max(0.0))
+    }
+
+    /// Returns the values clamped to `[min, max]`.
+    pub fn clamp(self, min: f32, max: f32) -> Self {
+        self.map(|v| v.clamp(min, max))
+    }
+
+    /// Returns the Monte Carlo estimate of the color of the spectrum from
+    /// its values at the wavelengths `lambda`.
+    pub fn to_xyz(&self, lambda: &SampledWavelengths) -> XYZ {
+        let pdf = lambda.pdf();
+        let x = safe_div(cie_x().sample(lambda) * *self, pdf).average();
+        let y = safe_div(cie_y().sample(lambda) * *self, pdf).average();
+        let z = safe_div(cie_z().sample(lambda) * *self, pdf).average();
+        XYZ::new(x, y, z) / CIE_Y_INTEGRAL
+    }
+
+    /// Returns the estimate of the luminance `Y` of the spectrum.
+    pub fn y(&self, lambda: &SampledWavelengths) -> f32 {
+        let pdf = lambda.pdf();
+        safe_div(cie_y().sample(lambda) * *self, pdf).average() / CIE_Y_INTEGRAL
+    }
+
+    /// Returns the estimate of the color of the spectrum in the color space
+    /// `cs`.
+    pub fn to_rgb(&self, lambda: &SampledWavelengths, cs: &RGBColorSpace) -> RGB {
+        cs.to_rgb(self.to_xyz(lambda))
+    }
+}
+
+/// Divides `a` by `b` per wavelength, giving zero where `b` is zero.
+pub fn safe_div(a: SampledSpectrum, b: SampledSpectrum) -> SampledSpectrum {
+    let mut r = a;
+    for (r, &b) in r.values.iter_mut().zip(&b.values) {
+        *r = if b != 0.0 { *r / b } else { 0.0 };
+    }
+    r
+}
+
+impl Index<usize> for SampledSpectrum {
+    type Output = f32;
+
+    fn index(&self, i: usize) -> &f32 {
+        &self.values[i]
+    }
+}
+
+impl IndexMut<usize> for SampledSpectrum {
+    fn index_mut(&mut self, i: usize) -> &mut f32 {
+        &mut self.values[i]
+    }
+}
+
+macro_rules! sampled_spectrum_op_impl {
+    ($op:ident, $fn:ident, $op_assign:ident, $fn_assign:ident, $sym:tt) => {
+        impl $op for SampledSpectrum {
+            type Output = Self;
+
+            fn $fn(self, rhs: Self) -> Self {
+                Self {
+                    values: std::array::from_fn(|i| self.values[i] $sym rhs.values[i]),
+                }
+            }
+        }
+
+        impl $op<f32> for SampledSpectrum {
+            type Output = Self;
+
+            fn $fn(self, rhs: f32) -> Self {
+                self.map(|a| a $sym rhs)
+            }
+        }
+
+        impl $op_assign for SampledSpectrum {
+            fn $fn_assign(&mut self, rhs: Self) {
+                *self = *self $sym rhs;
+            }
+        }
+
+        impl $op_assign<f32> for SampledSpectrum {
+            fn $fn_assign(&mut self, rhs: f32) {
+                *self = *self $sym rhs;
+            }
+        }
+    };
+}
+
+sampled_spectrum_op_impl!(Add, add, AddAssign, add_assign, +);
+sampled_spectrum_op_impl!(Sub, sub, SubAssign, sub_assign, -);
+sampled_spectrum_op_impl!(Mul, mul, MulAssign, mul_assign, *);
+sampled_spectrum_op_impl!(Div, div, DivAssign, div_assign, /);
+
+impl Mul<SampledSpectrum> for f32 {
+    type Output = SampledSpectrum;
+
+    fn mul(self, rhs: SampledSpectrum) -> SampledSpectrum {
+        rhs * self
+    }
+}
+
+impl Neg for SampledSpectrum {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.map(|v| -v)
+    }
+}
+
+/// Returns the density of [`sample_visible_wavelengths`], which follows the
+/// eye's sensitivity so that the wavelengths that matter most for the
+/// image are sampled most.
+pub fn visible_wavelengths_pdf(lambda: f32) -> f32 {
+    if !(LAMBDA_MIN..=LAMBDA_MAX).contains(&lambda) {
+        return 0.0;
+    }
+    let c = (0.0072 * (lambda - 538.0)).cosh();
+    0.003_939_804 / (c * c)
+}
+
+/// Samples a visible wavelength, in nanometers, from
+/// [`visible_wavelengths_pdf`].
+///
+/// ```
+/// use pbrt::spectrum::sample_visible_wavelengths;
+///
+/// let lambda = sample_visible_wavelengths(0.5);
+/// assert!(lambda > 530.0 && lambda < 560.0);
+/// assert!((sample_visible_wavelengths(0.0) - 360.0).abs() < 0.1);
+/// ```
+pub fn sample_visible_wavelengths(u: f32) -> f32 {
+    538.0 - 138.888_89 * (0.856_910_6 - 1.827_502 * u).atanh()
+}
+
+/// The wavelengths, in nanometers, that a camera ray carries, with the
+/// densities they were sampled with.
+///
+/// The wavelengths are stratified: they are evenly spaced from one drawn
+/// at random, wrapping around the sampled range. Effects such as
+/// dispersion, whose paths depend on the wavelength, keep only the first
+/// with [`Self::terminate_secondary`].
+///
+/// ```
+/// use pbrt::spectrum::SampledWavelengths;
+///
+/// let mut lambda = SampledWavelengths::sample_uniform_range(0.5, 400.0, 800.0);
+/// assert_eq!([lambda[0], lambda[1], lambda[2]], [600.0, 700.0, 800.0]);
+/// // The last wavelength wraps around to the start of the range.
+/// assert_eq!(lambda[3], 500.0);
+/// assert_eq!(lambda.pdf()[1], 1.0 / 400.0);
+/// lambda.terminate_secondary();
+/// assert!(lambda.secondary_terminated());
+/// assert_eq!(lambda.pdf().values(), [1.0 / 1600.0, 0.0, 0.0, 0.0]);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SampledWavelengths {
+    lambda: [f32; N_SPECTRUM_SAMPLES],
+    pdf: [f32; N_SPECTRUM_SAMPLES],
+}
+
+impl SampledWavelengths {
+    /// Samples wavelengths uniformly over the visible range.
+    pub fn sample_uniform(u: f32) -> Self {
+        Self::sample_uniform_range(u, LAMBDA_MIN, LAMBDA_MAX)
+    }
+
+    /// Samples wavelengths uniformly over `[lambda_min, lambda_max]`.
+    pub fn sample_uniform_range(u: f32, lambda_min: f32, lambda_max: f32) -> Self {
+        let mut lambda = [0.0; N_SPECTRUM_SAMPLES];
+        lambda[0] = (1.0 - u) * lambda_min + u * lambda_max;
+        let delta = (lambda_max - lambda_min) / N_SPECTRUM_SAMPLES as f32;
+        for i in 1..N_SPECTRUM_SAMPLES {
+            lambda[i] = lambda[i - 1] + delta;
+            if lambda[i] > lambda_max {
+                lambda[i] = lambda_min + (lambda[i] - lambda_max);
+            }
+        }
+        Self {
+            lambda,
+            pdf: [1.0 / (lambda_max - lambda_min); N_SPECTRUM_SAMPLES],
+        }
+    }
+
+    /// Samples wavelengths in proportion to the eye's sensitivity, which
+    /// reduces color noise compared to [`Self::sample_uniform`].
+    pub fn sample_visible(u: f32) -> Self {
+        let mut lambda = [0.0; N_SPECTRUM_SAMPLES];
+        let mut pdf = [0.0; N_SPECTRUM_SAMPLES];
+        for i in 0..N_SPECTRUM_SAMPLES {
+            let mut up = u + i as f32 / N_SPECTRUM_SAMPLES as f32;
+            if up > 1.0 {
+                up -= 1.0;
+            }
+            lambda[i] = sample_visible_wavelengths(up);
+            pdf[i] = visible_wavelengths_pdf(lambda[i]);
+        }
+        Self { lambda, pdf }
+    }
+
+    /// Returns the densities the wavelengths were sampled with.
+    pub fn pdf(&self) -> SampledSpectrum {
+        SampledSpectrum::from_array(self.pdf)
+    }
+
+    /// Keeps only the first wavelength, for paths that can only follow one.
+    /// Its density is divided by the number of wavelengths, since it now
+    /// stands for all of them.
+    pub fn terminate_secondary(&mut self) {
+        if self.secondary_terminated() {
+            return;
+        }
+        for pdf in &mut self.pdf[1..] {
+            *pdf = 0.0;
+        }
+        self.pdf[0] /= N_SPECTRUM_SAMPLES as f32;
+    }
+
+    /// Returns whether [`Self::terminate_secondary`] has been called.
+    pub fn secondary_terminated(&self) -> bool {
+        self.pdf[1..].iter().all(|&pdf| pdf == 0.0)
+    }
+}
+
+impl Index<usize> for SampledWavelengths {
+    type Output = f32;
+
+    fn index(&self, i: usize) -> &f32 {
+        &self.lambda[i]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::random::Rng;
+    use crate::spectrum::sampled::{
+        SampledSpectrum, SampledWavelengths, safe_div, sample_visible_wavelengths,
+        visible_wavelengths_pdf,
+    };
+    use crate::spectrum::spectra::{ConstantSpectrum, Spectrum};
+    use crate::spectrum::{LAMBDA_MAX, LAMBDA_MIN, N_SPECTRUM_SAMPLES};
+    use crate::testing::{cdf_from_pdf, ks_test};
+
+    #[test]
+    fn test_arithmetic() {
+        let a = SampledSpectrum::from_array([1.0, -2.0, 0.5, 4.0]);
+        let b = SampledSpectrum::from_array([2.0, 2.0, 0.0, 1.0]);
+        assert_eq!(a + b, SampledSpectrum::from_array([3.0, 0.0, 0.5, 5.0]));
+        assert_eq!(2.0 * a, a * 2.0);
+        assert_eq!(
+            -a / 2.0,
+            SampledSpectrum::from_array([-0.5, 1.0, -0.25, -2.0])
+        );
+        assert_eq!(
+            safe_div(a, b),
+            SampledSpectrum::from_array([0.5, -1.0, 0.0, 4.0])
+        );
+        assert_eq!(a.clamp_zero().min_value(), 0.0);
+        assert_eq!(b.sqrt()[3], 1.0);
+        assert_eq!(SampledSpectrum::new(0.0).exp(), SampledSpectrum::new(1.0));
+        let mut c = a;
+        c *= b;
+        c -= a;
+        assert_eq!(c, a * b - a);
+        assert!(!SampledSpectrum::default().is_nonzero());
+        assert!(b.is_nonzero());
+    }
+
+    #[test]
+    fn test_sample_uniform() {
+        let lambda = SampledWavelengths::sample_uniform(0.9);
+        let delta = (LAMBDA_MAX - LAMBDA_MIN) / N_SPECTRUM_SAMPLES as f32;
+        for i in 0..N_SPECTRUM_SAMPLES {
+            assert!((LAMBDA_MIN..=LAMBDA_MAX).contains(&lambda[i]));
+            // Evenly spaced, modulo the range.
+            let offset = (lambda[i] - lambda[0]).rem_euclid(LAMBDA_MAX - LAMBDA_MIN);
+            assert!((offset - i as f32 * delta).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_sample_visible() {
+        let cdf = cdf_from_pdf(
+            |l| f64::from(visible_wavelengths_pdf(l as f32)),
+            f64::from(LAMBDA_MIN),
+            f64::from(LAMBDA_MAX),
+        );
+        // The density integrates to one over the visible range.
+        assert!((cdf(f64::from(LAMBDA_MAX) - 1e-9) - 1.0).abs() < 1e-4);
+        let mut rng = Rng::with_sequence(1, 0);
+        let samples = (0..20_000)
+            .map(|_| f64::from(sample_visible_wavelengths(rng.uniform())))
+            .collect();
+        ks_test("visible wavelengths", samples, cdf, 1).unwrap();
+
+        let lambda = SampledWavelengths::sample_visible(0.3);
+        for i in 0..N_SPECTRUM_SAMPLES {
+            assert_eq!(lambda.pdf()[i], visible_wavelengths_pdf(lambda[i]));
+        }
+    }
+
+    #[test]
+    fn test_to_xyz_estimates() {
+        // A constant spectrum of one has a luminance of one, so the average
+        // of many estimates must be close to it.
+        let s = Spectrum::from(ConstantSpectrum::new(1.0));
+        let mut rng = Rng::with_sequence(2, 0);
+        let n = 10_000;
+        let mut y = 0.0;
+        for _ in 0..n {
+            let lambda = SampledWavelengths::sample_visible(rng.uniform());
+            y += s.sample(&lambda).y(&lambda);
+        }
+        assert!((y / n as f32 - 1.0).abs() < 0.01, "{}", y / n as f32);
+    }
+
+    #[test]
+    fn test_terminate_secondary() {
+        let mut lambda = SampledWavelengths::sample_visible(0.7);
+        let pdf = lambda.pdf()[0];
+        assert!(!lambda.secondary_terminated());
+        lambda.terminate_secondary();
+        lambda.terminate_secondary();
+        assert_eq!(lambda.pdf()[0], pdf / N_SPECTRUM_SAMPLES as f32);
+        assert!(lambda.secondary_terminated());
+    }
+}
@@ -0,0 +1,683 @@
+//! Spectra defined over all wavelengths, as given by scene descriptions.
+
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+
+use crate::spectrum::cie::{CIE_X, CIE_Y, CIE_Z};
+use crate::spectrum::color::{RGB, RGBSigmoidPolynomial, XYZ};
+use crate::spectrum::color_space::RGBColorSpace;
+use crate::spectrum::illuminants::{
+    std_illuminant_a, std_illuminant_d, std_illuminant_d50, std_illuminant_d65, std_illuminant_f,
+};
+use crate::spectrum::named::material_spectra;
+use crate::spectrum::sampled::{SampledSpectrum, SampledWavelengths};
+use crate::spectrum::{CIE_Y_INTEGRAL, LAMBDA_MAX, LAMBDA_MIN, N_SPECTRUM_SAMPLES};
+
+/// A spectral distribution, such as an emission spectrum or a reflectance,
+/// that can be evaluated at any wavelength in nanometers.
+///
+/// ```
+/// use pbrt::spectrum::{ConstantSpectrum, PiecewiseLinearSpectrum, Spectrum};
+///
+/// let c = Spectrum::from(ConstantSpectrum::new(0.5));
+/// assert_eq!(c.evaluate(700.0), 0.5);
+/// let ramp = Spectrum::from(PiecewiseLinearSpectrum::new(vec![400.0, 600.0], vec![0.0, 1.0]));
+/// assert_eq!(ramp.evaluate(450.0), 0.25);
+/// assert_eq!(ramp.evaluate(300.0), 0.0);
+/// assert_eq!(ramp.max_value(), 1.0);
+/// ```
+#[derive(Clone, Debug)]
+pub enum Spectrum {
+    /// The same value at every wavelength.
+    Constant(ConstantSpectrum),
+    /// Values tabulated at every nanometer.
+    DenselySampled(DenselySampledSpectrum),
+    /// Values interpolated linearly between arbitrary wavelengths.
+    PiecewiseLinear(PiecewiseLinearSpectrum),
+    /// The emission of a blackbody, normalized to a peak of one.
+    Blackbody(BlackbodySpectrum),
+    /// A reflectance given by an RGB color.
+    RGBAlbedo(RGBAlbedoSpectrum),
+    /// A spectrum given by an RGB color with components that may exceed one.
+    RGBUnbounded(RGBUnboundedSpectrum),
+    /// An emission spectrum given by an RGB color, relative to the
+    /// illuminant of its color space.
+    RGBIlluminant(RGBIlluminantSpectrum),
+}
+
+impl Spectrum {
+    /// Returns the value at `lambda`, in nanometers.
+    pub fn evaluate(&self, lambda: f32) -> f32 {
+        match self {
+            Spectrum::Constant(s) => s.evaluate(lambda),
+            Spectrum::DenselySampled(s) => s.evaluate(lambda),
+            Spectrum::PiecewiseLinear(s) => s.evaluate(lambda),
+            Spectrum::Blackbody(s) => s.evaluate(lambda),
+            Spectrum::RGBAlbedo(s) => s.evaluate(lambda),
+            Spectrum::RGBUnbounded(s) => s.evaluate(lambda),
+            Spectrum::RGBIlluminant(s) => s.evaluate(lambda),
+        }
+    }
+
+    /// Returns an upper bound on the values over the visible range.
+    pub fn max_value(&self) -> f32 {
+        match self {
+            Spectrum::Constant(s) => s.max_value(),
+            Spectrum::DenselySampled(s) => s.max_value(),
+            Spectrum::PiecewiseLinear(s) => s.max_value(),
+            Spectrum::Blackbody(s) => s.max_value(),
+            Spectrum::RGBAlbedo(s) => s.max_value(),
+            Spectrum::RGBUnbounded(s) => s.max_value(),
+            Spectrum::RGBIlluminant(s) => s.max_value(),
+        }
+    }
+
+    /// Returns the values at the wavelengths `lambda`.
+    pub fn sample(&self, lambda: &SampledWavelengths) -> SampledSpectrum {
+        let mut s = SampledSpectrum::default();
+        for i in 0..N_SPECTRUM_SAMPLES {
+            s[i] = self.evaluate(lambda[i]);
+        }
+        s
+    }
+}
+
+macro_rules! spectrum_from_impl {
+    ($($variant:ident($t:ident)),*) => {
+        $(
+            impl From<$t> for Spectrum {
+                fn from(s: $t) -> Self {
+                    Spectrum::$variant(s)
+                }
+            }
+        )*
+    };
+}
+
+spectrum_from_impl!(
+    Constant(ConstantSpectrum),
+    DenselySampled(DenselySampledSpectrum),
+    PiecewiseLinear(PiecewiseLinearSpectrum),
+    Blackbody(BlackbodySpectrum),
+    RGBAlbedo(RGBAlbedoSpectrum),
+    RGBUnbounded(RGBUnboundedSpectrum),
+    RGBIlluminant(RGBIlluminantSpectrum)
+);
+
+/// A spectrum with the same value at every wavelength.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ConstantSpectrum {
+    c: f32,
+}
+
+impl ConstantSpectrum {
+    /// Creates the spectrum with the value `c`.
+    pub fn new(c: f32) -> Self {
+        Self { c }
+    }
+
+    /// Returns the value, whatever the wavelength.
+    pub fn evaluate(&self, _lambda: f32) -> f32 {
+        self.c
+    }
+
+    /// Returns the value.
+    pub fn max_value(&self) -> f32 {
+        self.c
+    }
+}
+
+/// A spectrum tabulated at every integer wavelength of a range, for fast
+/// lookups of spectra that are expensive to evaluate.
+///
+/// ```
+/// use pbrt::spectrum::{BlackbodySpectrum, DenselySampledSpectrum, Spectrum};
+///
+/// let b = Spectrum::from(BlackbodySpectrum::new(3000.0));
+/// let d = DenselySampledSpectrum::new(&b, 360, 830);
+/// assert_eq!(d.evaluate(500.0), b.evaluate(500.0));
+/// assert_eq!(d.evaluate(500.3), b.evaluate(500.0));
+/// assert_eq!(d.evaluate(900.0), 0.0);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct DenselySampledSpectrum {
+    lambda_min: i32,
+    values: Vec<f32>,
+}
+
+impl DenselySampledSpectrum {
+    /// Tabulates `s` from `lambda_min` to `lambda_max`, inclusive.
+    pub fn new(s: &Spectrum, lambda_min: i32, lambda_max: i32) -> Self {
+        let values = (lambda_min..=lambda_max)
+            .map(|lambda| s.evaluate(lambda as f32))
+            .collect();
+        Self { lambda_min, values }
+    }
+
+    /// Creates the spectrum from values at successive integer wavelengths
+    /// starting at `lambda_min`.
+    pub fn from_values(lambda_min: i32, values: Vec<f32>) -> Self {
+        Self { lambda_min, values }
+    }
+
+    /// Returns the value at the integer wavelength nearest to `lambda`, or
+    /// zero outside the tabulated range.
+    pub fn evaluate(&self, lambda: f32) -> f32 {
+        let offset = lambda.round() as i64 - i64::from(self.lambda_min);
+        if offset < 0 || offset >= self.values.len() as i64 {
+            return 0.0;
+        }
+        self.values[offset as usize]
+    }
+
+    /// Returns the largest tabulated value.
+    pub fn max_value(&self) -> f32 {
+        self.values
+            .iter()
+            .copied()
+            .fold(f32::NEG_INFINITY, f32::max)
+    }
+
+    /// Multiplies every value by `s`.
+    pub fn scale(&mut self, s: f32) {
+        for v in &mut self.values {
+            *v *= s;
+        }
+    }
+}
+
+/// A spectrum interpolated linearly between values at increasing
+/// wavelengths, and zero outside them; the usual form of measured data.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PiecewiseLinearSpectrum {
+    lambdas: Vec<f32>,
+    values: Vec<f32>,
+}
+
+impl PiecewiseLinearSpectrum {
+    /// Creates the spectrum with the given `values` at the increasing
+    /// wavelengths `lambdas`.
+    pub fn new(lambdas: Vec<f32>, values: Vec<f32>) -> Self {
+        assert_eq!(lambdas.len(), values.len());
+        assert!(
+            lambdas.windows(2).all(|w| w[0] < w[1]),
+            "wavelengths must be increasing"
+        );
+        Self { lambdas, values }
+    }
+
+    /// Creates the spectrum from `(wavelength, value)` pairs laid out one
+    /// after the other, as measured data is usually given.
+    ///
+    /// The first and last values are extended to cover the visible range.
+    /// If `normalize` is set, the spectrum is scaled to a luminance `Y` of
+    /// one, as is done for illuminants so that only their color matters.
+    ///
+    /// ```
+    /// use pbrt::spectrum::{PiecewiseLinearSpectrum, Spectrum, spectrum_to_xyz};
+    ///
+    /// let s = PiecewiseLinearSpectrum::from_interleaved(&[400.0, 2.0, 700.0, 3.0], true);
+    /// let s = Spectrum::from(s);
+    /// assert!((spectrum_to_xyz(&s).y - 1.0).abs() < 1e-4);
+    /// assert!(s.evaluate(380.0) > 0.0);
+    /// ```
+    pub fn from_interleaved(data: &[f32], normalize: bool) -> Self {
+        assert!(data.len().is_multiple_of(2) && !data.is_empty());
+        let mut lambdas = Vec::with_capacity(data.len() / 2 + 2);
+        let mut values = Vec::with_capacity(data.len() / 2 + 2);
+        if data[0] > LAMBDA_MIN {
+            lambdas.push(LAMBDA_MIN - 1.0);
+            values.push(data[1]);
+        }
+        for pair in data.chunks_exact(2) {
+            lambdas.push(pair[0]);
+            values.push(pair[1]);
+        }
+        if lambdas[lambdas.len() - 1] < LAMBDA_MAX {
+            lambdas.push(LAMBDA_MAX + 1.0);
+            values.push(values[values.len() - 1]);
+        }
+        let mut spec = Self::new(lambdas, values);
+        if normalize {
+            let y = inner_product(&Spectrum::PiecewiseLinear(spec.clone()), cie_y());
+            spec.scale(CIE_Y_INTEGRAL / y);
+        }
+        spec
+    }
+
+    /// Returns the value at `lambda`, interpolating between the nearest
+    /// tabulated wavelengths.
+    pub fn evaluate(&self, lambda: f32) -> f32 {
+        let (Some(&first), Some(&last)) = (self.lambdas.first(), self.lambdas.last()) else {
+            return 0.0;
+        };
+        if lambda < first || lambda > last {
+            return 0.0;
+        }
+        let i = self
+            .lambdas
+            .partition_point(|&l| l <= lambda)
+            .clamp(1, self.lambdas.len() - 1)
+            - 1;
+        if i + 1 == self.lambdas.len() {
+            return self.values[i];
+        }
+        let t = (lambda - self.lambdas[i]) / (self.lambdas[i + 1] - self.lambdas[i]);
+        (1.0 - t) * self.values[i] + t * self.values[i + 1]
+    }
+
+    /// Returns the largest tabulated value.
+    pub fn max_value(&self) -> f32 {
+        self.values
+            .iter()
+            .copied()
+            .fold(f32::NEG_INFINITY, f32::max)
+    }
+
+    /// Multiplies every value by `s`.
+    pub fn scale(&mut self, s: f32) {
+        for v in &mut self.values {
+            *v *= s;
+        }
+    }
+}
+
+/// The emission of a blackbody at a temperature, scaled so that its peak is
+/// one; the color of the emission then depends only on the temperature.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BlackbodySpectrum {
+    t: f32,
+    normalization_factor: f32,
+}
+
+impl BlackbodySpectrum {
+    /// Creates the spectrum of a blackbody at `t` kelvin.
+    pub fn new(t: f32) -> Self {
+        // Wien's displacement law gives the wavelength of the peak.
+        let lambda_max = 2.897_772e-3 / t;
+        Self {
+            t,
+            normalization_factor: 1.0 / blackbody(lambda_max * 1e9, t),
+        }
+    }
+
+    /// Returns the value at `lambda`, in nanometers.
+    pub fn evaluate(&self, lambda: f32) -> f32 {
+        blackbody(lambda, self.t) * self.normalization_factor
+    }
+
+    /// Returns one, the peak value.
+    pub fn max_value(&self) -> f32 {
+        1.0
+    }
+}
+
+/// Returns the spectral radiance, in W/(m² sr m), emitted by a blackbody at
+/// `t` kelvin at the wavelength `lambda` in nanometers, by Planck's law.
+///
+/// ```
+/// use pbrt::spectrum::blackbody;
+///
+/// // The peak moves to shorter wavelengths as the temperature rises.
+/// assert!(blackbody(500.0, 6000.0) > blackbody(800.0, 6000.0));
+/// assert!(blackbody(500.0, 3000.0) < blackbody(800.0, 3000.0));
+/// assert_eq!(blackbody(500.0, 0.0), 0.0);
+/// ```
+pub fn blackbody(lambda: f32, t: f32) -> f32 {
+    if t <= 0.0 {
+        return 0.0;
+    }
+    const C: f64 = 299_792_458.0;
+    const H: f64 = 6.626_069_57e-34;
+    const KB: f64 = 1.380_648_8e-23;
+    let l = f64::from(lambda) * 1e-9;
+    let le = (2.0 * H * C * C) / (l.powi(5) * ((H * C / (l * KB * f64::from(t))).exp() - 1.0));
+    le as f32
+}
+
+/// A reflectance given by an RGB color with components in `[0, 1]`, as the
+/// smooth spectrum that has that color under the color space's illuminant.
+///
+/// ```
+/// use pbrt::spectrum::{RGB, RGBAlbedoSpectrum, RGBColorSpace};
+///
+/// let s = RGBAlbedoSpectrum::new(RGBColorSpace::srgb(), RGB::new(0.8, 0.2, 0.1));
+/// assert!(s.evaluate(650.0) > s.evaluate(450.0));
+/// assert!(s.max_value() <= 1.0);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RGBAlbedoSpectrum {
+    rsp: RGBSigmoidPolynomial,
+}
+
+impl RGBAlbedoSpectrum {
+    /// Creates the reflectance with the color `rgb` in the color space
+    /// `cs`.
+    pub fn new(cs: &RGBColorSpace, rgb: RGB) -> Self {
+        assert!(
+            rgb.max_component() <= 1.0 && rgb.min_component() >= 0.0,
+            "albedo {rgb:?} outside [0, 1]"
+        );
+        Self {
+            rsp: cs.to_rgb_coeffs(rgb),
+        }
+    }
+
+    /// Returns the value at `lambda`, in nanometers.
+    pub fn evaluate(&self, lambda: f32) -> f32 {
+        self.rsp.evaluate(lambda)
+    }
+
+    /// Returns the largest value over the visible range.
+    pub fn max_value(&self) -> f32 {
+        self.rsp.max_value()
+    }
+}
+
+/// A spectrum given by an RGB color with arbitrary non-negative components:
+/// a bounded spectrum for the color scaled into `[0, 1]`, scaled back up.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RGBUnboundedSpectrum {
+    scale: f32,
+    rsp: RGBSigmoidPolynomial,
+}
+
+impl RGBUnboundedSpectrum {
+    /// Creates the spectrum with the color `rgb` in the color space `cs`.
+    pub fn new(cs: &RGBColorSpace, rgb: RGB) -> Self {
+        let (scale, rsp) = scaled_coeffs(cs, rgb);
+        Self { scale, rsp }
+    }
+
+    /// Returns the value at `lambda`, in nanometers.
+    pub fn evaluate(&self, lambda: f32) -> f32 {
+        self.scale * self.rsp.evaluate(lambda)
+    }
+
+    /// Returns the largest value over the visible range.
+    pub fn max_value(&self) -> f32 {
+        self.scale * self.rsp.max_value()
+    }
+}
+
+/// An emission spectrum given by an RGB color: the spectrum for the color,
+/// times the illuminant of the color space, so that white light has the
+/// color space's white point.
+///
+/// ```
+/// use pbrt::spectrum::{RGB, RGBColorSpace, RGBIlluminantSpectrum, Spectrum, spectrum_to_xyz};
+///
+/// let cs = RGBColorSpace::srgb();
+/// let s = Spectrum::from(RGBIlluminantSpectrum::new(cs, RGB::new(1.0, 1.0, 1.0)));
+/// let rgb = cs.to_rgb(spectrum_to_xyz(&s));
+/// assert!((rgb.r - 1.0).abs() < 1e-3 && (rgb.b - 1.0).abs() < 1e-3);
+/// ```
+#[derive(Clone, Debug)]
+pub struct RGBIlluminantSpectrum {
+    scale: f32,
+    rsp: RGBSigmoidPolynomial,
+    illuminant: Arc<DenselySampledSpectrum>,
+}
+
+impl RGBIlluminantSpectrum {
+    /// Creates the emission spectrum with the color `rgb` in the color
+    /// space `cs`.
+    pub fn new(cs: &RGBColorSpace, rgb: RGB) -> Self {
+        let (scale, rsp) = scaled_coeffs(cs, rgb);
+        Self {
+            scale,
+            rsp,
+            illuminant: cs.illuminant().clone(),
+        }
+    }
+
+    /// Returns the value at `lambda`, in nanometers.
+    pub fn evaluate(&self, lambda: f32) -> f32 {
+        self.scale * self.rsp.evaluate(lambda) * self.illuminant.evaluate(lambda)
+    }
+
+    /// Returns an upper bound on the values over the visible range.
+    pub fn max_value(&self) -> f32 {
+        self.scale * self.rsp.max_value() * self.illuminant.max_value()
+    }
+}
+
+/// Splits `rgb` into a scale and the polynomial for the color scaled so its
+/// largest component is one half, which leaves room for the sigmoid to
+/// reach the color.
+fn scaled_coeffs(cs: &RGBColorSpace, rgb: RGB) -> (f32, RGBSigmoidPolynomial) {
+    let scale = 2.0 * rgb.max_component();
+    let rgb = if scale > 0.0 {
+        rgb / scale
+    } else {
+        RGB::default()
+    };
+    (scale, cs.to_rgb_coeffs(rgb))
+}
+
+/// Returns the spectrum that scene descriptions refer to as `name`, using
+/// pbrt-v4's names, or `None` if there is no such spectrum.
+///
+/// The names are those of the standard illuminants (`stdillum-A`,
+/// `stdillum-D50`, `stdillum-D65`, and `stdillum-F1` to `stdillum-F12`)
+/// and of the ACES white, `illum-acesD60`, of glass refractive indices
+/// (`glass-BK7`, `glass-BAF10`, `glass-FK51A`, `glass-LASF9`, the dense
+/// flints `glass-F5`, `glass-F10` and `glass-F11`, and
+/// `glass-fused-silica`), and of the complex refractive indices of
+/// conductors (`metal-Ag-eta` and `metal-Ag-k`, and likewise for Al, Au
+/// and Cu), and the indices of the dielectrics `metal-MgO-eta` and
+/// `metal-TiO2-eta`. See [`named`](crate::spectrum::named) for how the
+/// material spectra are obtained and what is missing.
+///
+/// ```
+/// use pbrt::spectrum::spectra::get_named;
+///
+/// let eta = get_named("metal-Au-eta").unwrap();
+/// let k = get_named("metal-Au-k").unwrap();
+/// // Gold's index is far lower in the red than in the blue.
+/// assert!(eta.evaluate(650.0) < eta.evaluate(450.0));
+/// assert!(k.evaluate(650.0) > 3.0);
+/// assert!((get_named("glass-BK7").unwrap().evaluate(588.0) - 1.5168).abs() < 1e-3);
+/// assert!(get_named("stdillum-F11").is_some());
+/// assert!(get_named("metal-Unobtainium-eta").is_none());
+/// ```
+pub fn get_named(name: &str) -> Option<&'static Spectrum> {
+    static NAMED: OnceLock<HashMap<&'static str, Spectrum>> = OnceLock::new();
+    NAMED
+        .get_or_init(|| {
+            let mut named: HashMap<_, _> = material_spectra().into_iter().collect();
+            named.insert("stdillum-A", std_illuminant_a());
+            named.insert("stdillum-D50", std_illuminant_d50());
+            named.insert("stdillum-D65", std_illuminant_d65());
+            let fluorescent = [
+                "stdillum-F1",
+                "stdillum-F2",
+                "stdillum-F3",
+                "stdillum-F4",
+                "stdillum-F5",
+                "stdillum-F6",
+                "stdillum-F7",
+                "stdillum-F8",
+                "stdillum-F9",
+                "stdillum-F10",
+                "stdillum-F11",
+                "stdillum-F12",
+            ];
+            for (n, name) in (1..).zip(fluorescent) {
+                named.insert(name, std_illuminant_f(n).expect("CIE illuminant F1 to F12"));
+            }
+            // The ACES white point is that of daylight at 6000K.
+            named.insert("illum-acesD60", std_illuminant_d(6000.0));
+            named
+        })
+        .get(name)
+}
+
+/// Returns the CIE 1931 `x̄` color matching function.
+pub fn cie_x() -> &'static Spectrum {
+    static X: OnceLock<Spectrum> = OnceLock::new();
+    X.get_or_init(|| cie_spectrum(&CIE_X))
+}
+
+/// Returns the CIE 1931 `ȳ` color matching function, the luminous
+/// efficiency of the eye.
+pub fn cie_y() -> &'static Spectrum {
+    static Y: OnceLock<Spectrum> = OnceLock::new();
+    Y.get_or_init(|| cie_spectrum(&CIE_Y))
+}
+
+/// Returns the CIE 1931 `z̄` color matching function.
+pub fn cie_z() -> &'static Spectrum {
+    static Z: OnceLock<Spectrum> = OnceLock::new();
+    Z.get_or_init(|| cie_spectrum(&CIE_Z))
+}
+
+fn cie_spectrum(values: &[f32]) -> Spectrum {
+    DenselySampledSpectrum::from_values(LAMBDA_MIN as i32, values.to_vec()).into()
+}
+
+/// Returns the integral of the product of `f` and `g` over the visible
+/// range, summed at every nanometer.
+pub fn inner_product(f: &Spectrum, g: &Spectrum) -> f32 {
+    (LAMBDA_MIN as i32..=LAMBDA_MAX as i32)
+        .map(|lambda| f.evaluate(lambda as f32) * g.evaluate(lambda as f32))
+        .sum()
+}
+
+/// Returns the XYZ color of `s`, scaled so that a constant spectrum of one
+/// has a luminance `Y` of one.
+///
+/// ```
+/// use pbrt::spectrum::{ConstantSpectrum, Spectrum, spectrum_to_xyz};
+///
+/// let xyz = spectrum_to_xyz(&Spectrum::from(ConstantSpectrum::new(1.0)));
+/// assert!((xyz.y - 1.0).abs() < 1e-5);
+/// ```
+pub fn spectrum_to_xyz(s: &Spectrum) -> XYZ {
+    XYZ::new(
+        inner_product(cie_x(), s),
+        inner_product(cie_y(), s),
+        inner_product(cie_z(), s),
+    ) / CIE_Y_INTEGRAL
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::spectrum::color::{RGB, XYZ};
+    use crate::spectrum::color_space::RGBColorSpace;
+    use crate::spectrum::spectra::{
+        BlackbodySpectrum, PiecewiseLinearSpectrum, RGBAlbedoSpectrum, RGBUnboundedSpectrum,
+        Spectrum, blackbody, cie_x, cie_y, cie_z, get_named, spectrum_to_xyz,
+    };
+
+    #[test]
+    fn test_blackbody_normalized() {
+        for t in [1000.0, 2700.0, 5500.0, 9000.0] {
+            let b = BlackbodySpectrum::new(t);
+            let peak = 2.897_772e6 / t;
+            assert!((b.evaluate(peak) - 1.0).abs() < 1e-4);
+            for lambda in (300..900).step_by(7) {
+                assert!(b.evaluate(lambda as f32) <= 1.0 + 1e-5);
+            }
+        }
+        // Planck's law, against a value computed independently.
+        let l = blackbody(550.0, 5000.0);
+        assert!((l / 1.271_301e13 - 1.0).abs() < 1e-4, "{l}");
+    }
+
+    #[test]
+    fn test_piecewise_linear() {
+        let s = PiecewiseLinearSpectrum::new(vec![400.0, 500.0, 700.0], vec![1.0, 3.0, 2.0]);
+        assert_eq!(s.evaluate(400.0), 1.0);
+        assert_eq!(s.evaluate(450.0), 2.0);
+        assert_eq!(s.evaluate(600.0), 2.5);
+        assert_eq!(s.evaluate(700.0), 2.0);
+        assert_eq!(s.evaluate(700.5), 0.0);
+        assert_eq!(
+            PiecewiseLinearSpectrum::new(vec![], vec![]).evaluate(500.0),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_rgb_albedo_round_trip() {
+        let cs = RGBColorSpace::srgb();
+        for rgb in [
+            RGB::new(0.5, 0.5, 0.5),
+            RGB::new(0.8, 0.3, 0.2),
+            RGB::new(0.1, 0.6, 0.3),
+            RGB::new(0.2, 0.25, 0.7),
+            RGB::new(0.9, 0.85, 0.1),
+            RGB::new(0.0, 0.0, 0.0),
+            RGB::new(1.0, 1.0, 1.0),
+        ] {
+            let s = Spectrum::from(RGBAlbedoSpectrum::new(cs, rgb));
+            // Reflectances are seen under the color space's illuminant.
+            let illum = cs.illuminant();
+            let (mut xyz, mut y_illum) = (XYZ::default(), 0.0);
+            for lambda in 360..=830 {
+                let lambda = lambda as f32;
+                let lit = s.evaluate(lambda) * illum.evaluate(lambda);
+                xyz += XYZ::new(
+                    cie_x().evaluate(lambda),
+                    cie_y().evaluate(lambda),
+                    cie_z().evaluate(lambda),
+                ) * lit;
+                y_illum += cie_y().evaluate(lambda) * illum.evaluate(lambda);
+            }
+            let xyz = xyz / y_illum;
+            let back = cs.to_rgb(xyz);
+            for c in 0..3 {
+                assert!((back[c] - rgb[c]).abs() < 2e-3, "{rgb:?} -> {back:?}");
+            }
+            assert!(s.max_value() <= 1.0);
+        }
+    }
+
+    #[test]
+    fn test_rgb_unbounded() {
+        let cs = RGBColorSpace::srgb();
+        // The spectrum of the color scaled to a largest component of one
+        // half, scaled back up.
+        let rgb = RGB::new(4.0, 2.0, 1.0);
+        let s = RGBUnboundedSpectrum::new(cs, rgb);
+        let bounded = RGBAlbedoSpectrum::new(cs, rgb / 8.0);
+        for lambda in [400.0, 550.0, 700.0] {
+            assert!((s.evaluate(lambda) - 8.0 * bounded.evaluate(lambda)).abs() < 1e-5);
+        }
+        assert!(s.max_value() <= 8.0);
+        assert!(s.evaluate(650.0) > 2.0 * s.evaluate(450.0));
+        let black = Spectrum::from(RGBUnboundedSpectrum::new(cs, RGB::default()));
+        assert_eq!(spectrum_to_xyz(&black).y, 0.0);
+    }
+
+    #[test]
+    fn test_named_illuminants() {
+        for n in 1..=12 {
+            let xyz = spectrum_to_xyz(get_named(&format!("stdillum-F{n}")).unwrap());
+            assert!((xyz.y - 1.0).abs() < 1e-4);
+        }
+        let (x, y) = spectrum_to_xyz(get_named("illum-acesD60").unwrap()).xy();
+        assert!((x - 0.32168).abs() < 1e-3 && (y - 0.33767).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_named_materials() {
+        for metal in ["Ag", "Al", "Au", "Cu"] {
+            assert!(get_named(&format!("metal-{metal}-eta")).is_some());
+            assert!(get_named(&format!("metal-{metal}-k")).is_some());
+        }
+        let mgo = get_named("metal-MgO-eta").unwrap();
+        assert!((mgo.evaluate(590.0) - 1.7355).abs() < 3e-3);
+        assert!(get_named("metal-TiO2-eta").unwrap().evaluate(550.0) > 2.5);
+        // Measured data that is not shipped is not made up.
+        for name in [
+            "metal-MgO-k",
+            "metal-TiO2-k",
+            "metal-CuZn-eta",
+            "canon_eos_100d_r",
+        ] {
+            assert!(get_named(name).is_none(), "{name}");
+        }
+    }
+}
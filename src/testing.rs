@@ -0,0 +1,540 @@
+//! Statistical tests for sampling routines, shared by the tests throughout
+//! the crate.
+//!
+//! [`Chi2Test`] draws samples from a routine, bins them over the unit sphere
+//! or a rectangle, integrates the density the routine claims over each bin
+//! and compares the two histograms with Pearson's chi-squared test.
+//! [`ks_test`] compares one-dimensional samples with a CDF directly. Both
+//! split their significance level between the tests of a suite (Bonferroni
+//! correction), so that a suite of correct routines passes with high
+//! probability while a density that is off by a few percent fails.
+//!
+//! Setting the `PBRT_CHI2_DUMP` environment variable to a directory makes
+//! [`Chi2Test`] write the observed and expected histograms there as PFM
+//! images, to see where a failing routine goes wrong.
+//!
+//! The module also holds the fixtures and assertions that the geometry
+//! tests share.
+
+use std::f64::consts::PI;
+use std::fs;
+use std::path::Path;
+
+use crate::math::bounds2::Bounds2;
+use crate::math::point2::Point2;
+use crate::math::point3::Point3;
+use crate::math::vector3::Vector3;
+use crate::random::Rng;
+
+/// Asserts that `a` and `b` differ by less than `tolerance`.
+pub(crate) fn assert_vector_near(a: Vector3<f64>, b: Vector3<f64>, tolerance: f64) {
+    assert!((a - b).length() < tolerance, "{a} differs from {b}");
+}
+
+/// Asserts that `a` and `b` are less than `tolerance` apart.
+pub(crate) fn assert_point_near(a: Point3<f64>, b: Point3<f64>, tolerance: f64) {
+    assert!(a.distance(b) < tolerance, "{a} differs from {b}");
+}
+
+/// Returns `count` unit vectors spread evenly over the sphere on a
+/// Fibonacci lattice, followed by the axes and directions right next to
+/// them, where constructions on the sphere tend to switch branches.
+pub(crate) fn directions(count: usize) -> Vec<Vector3<f64>> {
+    let golden_angle = PI * (3.0 - 5f64.sqrt());
+    let mut directions: Vec<_> = (0..count)
+        .map(|i| {
+            let z = 1.0 - 2.0 * (i as f64 + 0.5) / count as f64;
+            let r = (1.0 - z * z).sqrt();
+            let phi = golden_angle * i as f64;
+            Vector3::new(r * phi.cos(), r * phi.sin(), z)
+        })
+        .collect();
+    for pole in [1.0, -1.0] {
+        for offset in [0.0, 1e-12, -1e-8, 1e-4] {
+            directions.push(Vector3::new(offset, -offset, pole).normalize());
+            directions.push(Vector3::new(pole, offset, offset).normalize());
+        }
+    }
+    directions.extend([
+        Vector3::new(1.0, 0.0, -0.0),
+        Vector3::new(0.0, 1.0, 0.0),
+        Vector3::new(0.0, -1.0, 0.0),
+    ]);
+    directions
+}
+
+/// The overall significance level of a suite of tests.
+pub(crate) const SIGNIFICANCE: f64 = 0.01;
+
+/// The smallest expected count a histogram cell may have; cells below it
+/// are pooled.
+const MIN_EXPECTED: f64 = 5.0;
+
+/// Integration points per bin along each axis.
+const SUBDIVISIONS: usize = 6;
+
+/// Where a [`Chi2Test`] bins its samples.
+#[derive(Copy, Clone, Debug)]
+pub(crate) enum Domain {
+    /// Unit directions, binned by `cos(theta)` and `phi`, which gives bins
+    /// of equal solid angle. Densities are per unit solid angle.
+    Sphere,
+    /// Unit directions in the hemisphere around +z, binned like
+    /// [`Domain::Sphere`]. Directions below it go to an extra cell holding
+    /// the probability the hemisphere misses.
+    Hemisphere,
+    /// Points in a rectangle of the plane, given as vectors whose `z` is
+    /// ignored. Densities are per unit area; samples outside the rectangle
+    /// go to an extra cell holding the probability the rectangle misses.
+    Rectangle(Bounds2<f64>),
+}
+
+impl Domain {
+    // The ranges of the two binned coordinates.
+    fn ranges(&self) -> [(f64, f64); 2] {
+        match self {
+            Domain::Sphere => [(-1.0, 1.0), (0.0, 2.0 * PI)],
+            Domain::Hemisphere => [(0.0, 1.0), (0.0, 2.0 * PI)],
+            Domain::Rectangle(b) => [(b.p_min.x, b.p_max.x), (b.p_min.y, b.p_max.y)],
+        }
+    }
+
+    fn coordinates(&self, w: Vector3<f64>) -> Point2<f64> {
+        match self {
+            Domain::Sphere | Domain::Hemisphere => {
+                let phi = w.y.atan2(w.x);
+                let phi = if phi < 0.0 { phi + 2.0 * PI } else { phi };
+                Point2::new(w.z, phi)
+            }
+            Domain::Rectangle(_) => Point2::new(w.x, w.y),
+        }
+    }
+
+    fn point(&self, p: Point2<f64>) -> Vector3<f64> {
+        match self {
+            Domain::Sphere | Domain::Hemisphere => {
+                let r = (1.0 - p.x * p.x).max(0.0).sqrt();
+                Vector3::new(r * p.y.cos(), r * p.y.sin(), p.x)
+            }
+            Domain::Rectangle(_) => Vector3::new(p.x, p.y, 0.0),
+        }
+    }
+
+    /// Integrates `f` over each of `nx` by `ny` bins of the domain with the
+    /// midpoint rule, returning the integrals in row-major order.
+    pub(crate) fn integrate_bins(
+        &self,
+        nx: usize,
+        ny: usize,
+        f: impl Fn(Vector3<f64>) -> f64,
+    ) -> Vec<f64> {
+        let [(x0, x1), (y0, y1)] = self.ranges();
+        let (dx, dy) = ((x1 - x0) / nx as f64, (y1 - y0) / ny as f64);
+        let n = SUBDIVISIONS;
+        let mut bins = Vec::with_capacity(nx * ny);
+        for j in 0..ny {
+            for i in 0..nx {
+                let mut sum = 0.0;
+                for sj in 0..n {
+                    for si in 0..n {
+                        let px = x0 + dx * (i as f64 + (si as f64 + 0.5) / n as f64);
+                        let py = y0 + dy * (j as f64 + (sj as f64 + 0.5) / n as f64);
+                        sum += f(self.point(Point2::new(px, py)));
+                    }
+                }
+                bins.push(sum * dx * dy / (n * n) as f64);
+            }
+        }
+        bins
+    }
+
+    /// Integrates `f` over the whole domain.
+    pub(crate) fn integrate(&self, f: impl Fn(Vector3<f64>) -> f64) -> f64 {
+        self.integrate_bins(200, 200, f).iter().sum()
+    }
+}
+
+/// A chi-squared goodness-of-fit test of a sampling routine against the
+/// density it claims.
+///
+/// The routine maps a uniform sample in `[0, 1)^2` to a point of the
+/// domain and its density there, or `None` if it fails to sample. The
+/// claimed density is also given as a function, which is integrated over
+/// the bins to find how many samples each should get; the density each
+/// sample returns must agree with it.
+#[derive(Clone, Debug)]
+pub(crate) struct Chi2Test {
+    domain: Domain,
+    nx: usize,
+    ny: usize,
+    samples: usize,
+    test_count: usize,
+}
+
+impl Chi2Test {
+    /// Creates a test over the unit sphere.
+    pub(crate) fn sphere() -> Self {
+        Self::new(Domain::Sphere, 24, 48)
+    }
+
+    /// Creates a test over the hemisphere around +z.
+    pub(crate) fn hemisphere() -> Self {
+        Self::new(Domain::Hemisphere, 12, 48)
+    }
+
+    /// Creates a test over a rectangle.
+    pub(crate) fn rectangle(bounds: Bounds2<f64>) -> Self {
+        Self::new(Domain::Rectangle(bounds), 20, 20)
+    }
+
+    /// Creates a test over the interval `[min, max]`; samples give their
+    /// position in `x`.
+    pub(crate) fn interval(min: f64, max: f64) -> Self {
+        let bounds = Bounds2::new(Point2::new(min, 0.0), Point2::new(max, 1.0));
+        Self::new(Domain::Rectangle(bounds), 60, 1)
+    }
+
+    fn new(domain: Domain, nx: usize, ny: usize) -> Self {
+        Self {
+            domain,
+            nx,
+            ny,
+            samples: 100_000,
+            test_count: 1,
+        }
+    }
+
+    /// Sets the number of bins along each axis. On the sphere, `nx` bins
+    /// split `cos(theta)` and `ny` split `phi`.
+    pub(crate) fn resolution(mut self, nx: usize, ny: usize) -> Self {
+        self.nx = nx;
+        self.ny = ny;
+        self
+    }
+
+    /// Sets the number of tests in the suite this one belongs to, which
+    /// share the significance level.
+    pub(crate) fn test_count(mut self, test_count: usize) -> Self {
+        self.test_count = test_count;
+        self
+    }
+
+    /// Runs the test, returning a description of the failure if the
+    /// samples are unlikely to come from the claimed density.
+    pub(crate) fn run(
+        &self,
+        name: &str,
+        sample: impl Fn(Point2<f64>) -> Option<(Vector3<f64>, f64)>,
+        pdf: impl Fn(Vector3<f64>) -> f64,
+    ) -> Result<(), String> {
+        let [(x0, x1), (y0, y1)] = self.domain.ranges();
+        let (nx, ny) = (self.nx, self.ny);
+        let overflow = nx * ny;
+
+        let mut rng = Rng::with_sequence(0, 0);
+        let mut observed = vec![0.0; overflow + 1];
+        for _ in 0..self.samples {
+            let u = Point2::new(rng.uniform(), rng.uniform());
+            let Some((w, sample_pdf)) = sample(u) else {
+                observed[overflow] += 1.0;
+                continue;
+            };
+            let expected_pdf = pdf(w);
+            if (sample_pdf - expected_pdf).abs() > 1e-3 * sample_pdf.max(expected_pdf) {
+                return Err(format!(
+                    "{name}: sample {w:?} has density {sample_pdf}, but the density function \
+                     gives {expected_pdf}"
+                ));
+            }
+            let p = self.domain.coordinates(w);
+            let i = ((p.x - x0) / (x1 - x0) * nx as f64).floor();
+            let j = ((p.y - y0) / (y1 - y0) * ny as f64).floor();
+            let cell = if i >= 0.0 && j >= 0.0 && i < nx as f64 && j < ny as f64 {
+                j as usize * nx + i as usize
+            } else {
+                overflow
+            };
+            observed[cell] += 1.0;
+        }
+
+        let mut expected: Vec<f64> = self
+            .domain
+            .integrate_bins(nx, ny, pdf)
+            .into_iter()
+            .map(|p| p * self.samples as f64)
+            .collect();
+        let inside: f64 = expected.iter().sum();
+        expected.push((self.samples as f64 - inside).max(0.0));
+
+        if let Ok(dir) = std::env::var("PBRT_CHI2_DUMP") {
+            let dir = Path::new(&dir);
+            write_pfm(&dir.join(format!("{name}-observed.pfm")), &observed, nx, ny)
+                .and_then(|_| {
+                    write_pfm(&dir.join(format!("{name}-expected.pfm")), &expected, nx, ny)
+                })
+                .map_err(|e| format!("{name}: writing histograms: {e}"))?;
+        }
+
+        let (chi2, dof, p_value) = chi2_test(&observed, &expected);
+        let alpha = SIGNIFICANCE / self.test_count as f64;
+        if p_value < alpha {
+            return Err(format!(
+                "{name}: chi2 = {chi2} with {dof} degrees of freedom, p = {p_value} < {alpha}"
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Runs Pearson's chi-squared test on observed counts and the counts
+/// expected of them, after pooling the cells expected to get fewer than
+/// five samples. Returns the statistic, its degrees of freedom and the
+/// p-value: the probability of a statistic at least this large if the
+/// counts do follow the expected ones.
+pub(crate) fn chi2_test(observed: &[f64], expected: &[f64]) -> (f64, usize, f64) {
+    let mut cells: Vec<(f64, f64)> = expected
+        .iter()
+        .copied()
+        .zip(observed.iter().copied())
+        .collect();
+    cells.sort_by(|a, b| a.0.total_cmp(&b.0));
+    let mut pooled = (0.0, 0.0);
+    let mut kept = Vec::new();
+    for (e, o) in cells {
+        if e < MIN_EXPECTED {
+            pooled = (pooled.0 + e, pooled.1 + o);
+        } else {
+            kept.push((e, o));
+        }
+    }
+    if pooled.0 >= MIN_EXPECTED || kept.is_empty() {
+        kept.push(pooled);
+    } else {
+        kept[0] = (kept[0].0 + pooled.0, kept[0].1 + pooled.1);
+    }
+
+    let chi2: f64 = kept
+        .iter()
+        .map(|&(e, o)| {
+            if e > 0.0 {
+                (o - e) * (o - e) / e
+            } else if o > 0.0 {
+                f64::INFINITY
+            } else {
+                0.0
+            }
+        })
+        .sum();
+    let dof = kept.len().saturating_sub(1).max(1);
+    (chi2, dof, gamma_q(dof as f64 / 2.0, chi2 / 2.0))
+}
+
+/// Runs the Kolmogorov–Smirnov test of one-dimensional samples against a
+/// CDF, as one of `test_count` tests sharing the significance level.
+pub(crate) fn ks_test(
+    name: &str,
+    mut samples: Vec<f64>,
+    cdf: impl Fn(f64) -> f64,
+    test_count: usize,
+) -> Result<(), String> {
+    samples.sort_by(f64::total_cmp);
+    let n = samples.len() as f64;
+    let d = samples
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| {
+            let c = cdf(x);
+            (c - i as f64 / n).max((i + 1) as f64 / n - c)
+        })
+        .fold(0.0, f64::max);
+    // The asymptotic Kolmogorov distribution, with Stephens' correction
+    // for finite sample counts.
+    let t = d * (n.sqrt() + 0.12 + 0.11 / n.sqrt());
+    let p_value = kolmogorov_q(t);
+    let alpha = SIGNIFICANCE / test_count as f64;
+    if p_value < alpha {
+        return Err(format!("{name}: D = {d}, p = {p_value} < {alpha}"));
+    }
+    Ok(())
+}
+
+/// Returns the CDF of the density `pdf` over `[min, max]`, integrated
+/// numerically; it is zero below `min` and one above `max`.
+pub(crate) fn cdf_from_pdf(pdf: impl Fn(f64) -> f64, min: f64, max: f64) -> impl Fn(f64) -> f64 {
+    const STEPS: usize = 100_000;
+    let dx = (max - min) / STEPS as f64;
+    let mut table = Vec::with_capacity(STEPS + 1);
+    let mut sum = 0.0;
+    table.push(0.0);
+    for i in 0..STEPS {
+        // Simpson's rule over each step.
+        let x = min + i as f64 * dx;
+        sum += (pdf(x) + 4.0 * pdf(x + 0.5 * dx) + pdf(x + dx)) * dx / 6.0;
+        table.push(sum);
+    }
+    move |x| {
+        if x <= min {
+            return 0.0;
+        }
+        if x >= max {
+            return 1.0;
+        }
+        let t = (x - min) / dx;
+        let i = (t as usize).min(STEPS - 1);
+        let f = t - i as f64;
+        (1.0 - f) * table[i] + f * table[i + 1]
+    }
+}
+
+// The probability that the Kolmogorov statistic scaled by sqrt(n) exceeds
+// t.
+fn kolmogorov_q(t: f64) -> f64 {
+    if t < 0.2 {
+        return 1.0;
+    }
+    let sum: f64 = (1..100)
+        .map(|k| {
+            let k = k as f64;
+            let sign = if k as u32 % 2 == 1 { 1.0 } else { -1.0 };
+            sign * (-2.0 * k * k * t * t).exp()
+        })
+        .sum();
+    (2.0 * sum).clamp(0.0, 1.0)
+}
+
+fn ln_gamma(x: f64) -> f64 {
+    // Shift up to where Stirling's series is accurate.
+    let mut shift = 0.0;
+    let mut x = x;
+    while x < 10.0 {
+        shift -= x.ln();
+        x += 1.0;
+    }
+    let x2 = x * x;
+    shift + (x - 0.5) * x.ln() - x + 0.5 * (2.0 * PI).ln() + 1.0 / (12.0 * x)
+        - 1.0 / (360.0 * x * x2)
+        + 1.0 / (1260.0 * x * x2 * x2)
+        - 1.0 / (1680.0 * x * x2 * x2 * x2)
+}
+
+// The regularized upper incomplete gamma function Q(a, x); the chi-squared
+// p-value for k degrees of freedom is Q(k / 2, chi2 / 2).
+fn gamma_q(a: f64, x: f64) -> f64 {
+    if x.is_infinite() {
+        return 0.0;
+    }
+    if x <= 0.0 {
+        return 1.0;
+    }
+    let prefix = (-x + a * x.ln() - ln_gamma(a)).exp();
+    if x < a + 1.0 {
+        let (mut term, mut sum, mut n) = (1.0 / a, 1.0 / a, a);
+        while term > sum * 1e-15 {
+            n += 1.0;
+            term *= x / n;
+            sum += term;
+        }
+        1.0 - prefix * sum
+    } else {
+        // Lentz's method for the continued fraction.
+        let tiny = 1e-300;
+        let mut b = x + 1.0 - a;
+        let mut c = 1.0 / tiny;
+        let mut d = 1.0 / b;
+        let mut h = d;
+        for i in 1..10_000 {
+            let an = -(i as f64) * (i as f64 - a);
+            b += 2.0;
+            d = an * d + b;
+            if d.abs() < tiny {
+                d = tiny;
+            }
+            c = b + an / c;
+            if c.abs() < tiny {
+                c = tiny;
+            }
+            d = 1.0 / d;
+            let delta = d * c;
+            h *= delta;
+            if (delta - 1.0).abs() < 1e-15 {
+                break;
+            }
+        }
+        prefix * h
+    }
+}
+
+// Writes the first nx * ny values as a greyscale PFM image, with the first
+// row at the top.
+fn write_pfm(path: &Path, values: &[f64], nx: usize, ny: usize) -> std::io::Result<()> {
+    let mut data = format!("Pf\n{nx} {ny}\n-1\n").into_bytes();
+    // PFM stores rows bottom to top.
+    for y in (0..ny).rev() {
+        for &v in &values[y * nx..(y + 1) * nx] {
+            data.extend_from_slice(&(v as f32).to_le_bytes());
+        }
+    }
+    fs::write(path, data)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::bounds2::Bounds2;
+    use crate::math::point2::Point2;
+    use crate::math::vector3::Vector3;
+    use crate::testing::{Chi2Test, cdf_from_pdf, chi2_test, gamma_q, ks_test, write_pfm};
+
+    #[test]
+    fn test_gamma_q() {
+        // Q(1, x) = e^-x, and the chi-squared quantiles for 1 and 10
+        // degrees of freedom.
+        assert!((gamma_q(1.0, 2.0) - (-2.0f64).exp()).abs() < 1e-12);
+        assert!((gamma_q(0.5, 3.841_458_8 / 2.0) - 0.05).abs() < 1e-6);
+        assert!((gamma_q(5.0, 23.209_251 / 2.0) - 0.01).abs() < 1e-6);
+        assert!((gamma_q(400.0, 400.0) - 0.493_35).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_chi2_test_pools_cells() {
+        let (chi2, dof, p) = chi2_test(&[10.0, 10.0, 1.0, 2.0], &[10.0, 10.0, 2.0, 1.0]);
+        assert_eq!((chi2, dof), (0.0, 1));
+        assert_eq!(p, 1.0);
+    }
+
+    #[test]
+    fn test_detects_wrong_densities() {
+        // Samples uniform on the square, against a density that is off by
+        // 10% on either half.
+        let square = Bounds2::new(Point2::new(0.0, 0.0), Point2::new(1.0, 1.0));
+        let sample = |u: Point2<f64>| Some((Vector3::new(u.x, u.y, 0.0), 1.0));
+        let test = Chi2Test::rectangle(square);
+        assert!(test.run("uniform", sample, |_| 1.0).is_ok());
+        let skewed = |w: Vector3<f64>| if w.x < 0.5 { 1.1 } else { 0.9 };
+        assert!(
+            test.run("skewed", |u| sample(u).map(|(w, _)| (w, skewed(w))), skewed)
+                .is_err()
+        );
+        // A returned density that disagrees with the density function.
+        assert!(test.run("inconsistent", sample, |_| 2.0).is_err());
+
+        let uniform: Vec<f64> = (0..10_000).map(|i| (i as f64 + 0.5) / 10_000.0).collect();
+        assert!(ks_test("uniform", uniform.clone(), |x| x, 1).is_ok());
+        let squared = cdf_from_pdf(|x| 2.0 * x, 0.0, 1.0);
+        assert!((squared(0.5) - 0.25).abs() < 1e-9);
+        assert!(ks_test("linear", uniform, squared, 1).is_err());
+    }
+
+    #[test]
+    fn test_write_pfm() {
+        let path = std::env::temp_dir().join(format!("pbrt-test-{}.pfm", std::process::id()));
+        write_pfm(&path, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 3, 2).unwrap();
+        let data = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let header = b"Pf\n3 2\n-1\n";
+        assert_eq!(&data[..header.len()], header);
+        // The bottom row comes first.
+        let first = f32::from_le_bytes(data[header.len()..header.len() + 4].try_into().unwrap());
+        assert_eq!(first, 4.0);
+        assert_eq!(data.len(), header.len() + 6 * 4);
+    }
+}